@@ -0,0 +1,29 @@
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error};
+
+/// Implements `PreprocessAsEnumVariant` for the given enum, listing the names
+/// of all of its variants in the order they are declared.
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+	let Data::Enum(data) = &input.data else {
+		return Err(Error::new_spanned(
+			&input.ident,
+			"`PreprocessAsEnumVariant` can only be derived for enums",
+		));
+	};
+
+	let ident = &input.ident;
+	let (impl_generics, ty_generics, where_clause) =
+		input.generics.split_for_impl();
+	let variants = data
+		.variants
+		.iter()
+		.map(|variant| variant.ident.to_string());
+
+	Ok(quote! {
+		impl #impl_generics ::preprocess::validators::PreprocessAsEnumVariant for #ident #ty_generics #where_clause {
+			const VARIANTS: &'static [&'static str] = &[#(#variants),*];
+		}
+	}
+	.into())
+}
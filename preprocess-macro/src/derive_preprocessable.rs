@@ -0,0 +1,72 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, GenericParam};
+
+/// Implements `Preprocessable` for the given enum with only unit variants,
+/// which has nothing to preprocess, so that it can be used as a nested field
+/// of a struct that uses `#[preprocess::sync]`. The processed type is the enum
+/// itself, which is also aliased as `<Enum>Processed`, since nested fields
+/// are changed to that type.
+pub fn derive(input: DeriveInput) -> Result<TokenStream, Error> {
+	let Data::Enum(data) = &input.data else {
+		return Err(Error::new_spanned(
+			&input.ident,
+			"`Preprocessable` can only be derived for enums",
+		));
+	};
+
+	if let Some(variant) = data
+		.variants
+		.iter()
+		.find(|variant| !matches!(variant.fields, Fields::Unit))
+	{
+		return Err(Error::new_spanned(
+			variant,
+			concat!(
+				"`Preprocessable` can only be derived for enums with unit ",
+				"variants. Use `#[preprocess::sync]` instead"
+			),
+		));
+	}
+
+	let DeriveInput {
+		vis,
+		ident,
+		generics,
+		..
+	} = &input;
+	let processed_ident = format_ident!("{}Processed", ident);
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+	// Bounds on type aliases are not enforced, and trigger the
+	// `type_alias_bounds` lint, so the alias only declares the parameters
+	let mut alias_generics = generics.clone();
+	alias_generics.where_clause = None;
+	for param in &mut alias_generics.params {
+		match param {
+			GenericParam::Lifetime(lifetime) => {
+				lifetime.colon_token = None;
+				lifetime.bounds.clear();
+			}
+			GenericParam::Type(ty) => {
+				ty.colon_token = None;
+				ty.bounds.clear();
+			}
+			GenericParam::Const(_) => (),
+		}
+	}
+
+	Ok(quote! {
+		#vis type #processed_ident #alias_generics = #ident #ty_generics;
+
+		impl #impl_generics ::preprocess::Preprocessable for #ident #ty_generics #where_clause {
+			type Processed = Self;
+			type Error = ::preprocess::Error;
+
+			fn preprocess(self) -> ::std::result::Result<Self, ::preprocess::Error> {
+				::std::result::Result::Ok(self)
+			}
+		}
+	}
+	.into())
+}
@@ -1,10 +1,21 @@
-use syn::{spanned::Spanned, Error, Expr, ExprLit, Lit, LitStr};
+use syn::{
+	spanned::Spanned,
+	Error,
+	Expr,
+	ExprArray,
+	ExprLit,
+	Lit,
+	LitBool,
+	LitInt,
+	LitStr,
+};
 
 pub trait ExprExt
 where
 	Self: Sized,
 {
 	fn require_lit(self) -> Result<ExprLit, Error>;
+	fn require_array(self) -> Result<ExprArray, Error>;
 }
 
 impl ExprExt for Expr {
@@ -14,10 +25,19 @@ impl ExprExt for Expr {
 			_ => Err(Error::new(self.span(), "expected literal")),
 		}
 	}
+
+	fn require_array(self) -> Result<ExprArray, Error> {
+		match self {
+			Expr::Array(array) => Ok(array),
+			_ => Err(Error::new(self.span(), "expected array")),
+		}
+	}
 }
 
 pub trait LitExpr {
 	fn require_str(self) -> Result<LitStr, Error>;
+	fn require_bool(self) -> Result<LitBool, Error>;
+	fn require_int(self) -> Result<LitInt, Error>;
 }
 
 impl LitExpr for Lit {
@@ -27,4 +47,18 @@ impl LitExpr for Lit {
 			_ => Err(Error::new(self.span(), "expected string literal")),
 		}
 	}
+
+	fn require_bool(self) -> Result<LitBool, Error> {
+		match self {
+			Lit::Bool(lit) => Ok(lit),
+			_ => Err(Error::new(self.span(), "expected boolean literal")),
+		}
+	}
+
+	fn require_int(self) -> Result<LitInt, Error> {
+		match self {
+			Lit::Int(lit) => Ok(lit),
+			_ => Err(Error::new(self.span(), "expected integer literal")),
+		}
+	}
 }
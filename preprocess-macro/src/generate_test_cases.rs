@@ -0,0 +1,123 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+	parse::{Parse, ParseStream},
+	punctuated::Punctuated,
+	Error,
+	Expr,
+	ExprArray,
+	MetaNameValue,
+	Path,
+	Token,
+};
+
+/// Arguments given to `generate_test_cases!(...)`
+pub struct TestCases {
+	/// The validator to test
+	validator: Path,
+	/// Additional arguments passed to the validator after the value
+	args: Vec<Expr>,
+	/// Values that must pass the validator
+	valid: Vec<Expr>,
+	/// Values that must fail the validator
+	invalid: Vec<Expr>,
+}
+
+impl Parse for TestCases {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		let validator = input.parse()?;
+		if input.is_empty() {
+			return Err(Error::new(
+				input.span(),
+				"expected `valid = [...]` or `invalid = [...]`",
+			));
+		}
+		input.parse::<Token![,]>()?;
+
+		let (mut args, mut valid, mut invalid) = (None, None, None);
+		for name_value in
+			Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?
+		{
+			let option = if name_value.path.is_ident("args") {
+				&mut args
+			} else if name_value.path.is_ident("valid") {
+				&mut valid
+			} else if name_value.path.is_ident("invalid") {
+				&mut invalid
+			} else {
+				return Err(Error::new_spanned(
+					name_value.path,
+					"expected `args`, `valid` or `invalid` as the argument",
+				));
+			};
+			if option.is_some() {
+				return Err(Error::new_spanned(
+					name_value.path,
+					"duplicate argument",
+				));
+			}
+
+			let Expr::Array(ExprArray { elems, .. }) = name_value.value else {
+				return Err(Error::new_spanned(
+					name_value.value,
+					"expected an array of values",
+				));
+			};
+			*option = Some(elems.into_iter().collect::<Vec<_>>());
+		}
+
+		if valid.is_none() && invalid.is_none() {
+			return Err(Error::new(
+				input.span(),
+				"expected `valid = [...]` or `invalid = [...]`",
+			));
+		}
+
+		Ok(Self {
+			validator,
+			args: args.unwrap_or_default(),
+			valid: valid.unwrap_or_default(),
+			invalid: invalid.unwrap_or_default(),
+		})
+	}
+}
+
+/// Generates a `#[test]` function for every valid and invalid case, named
+/// after the validator, the kind of the case and its index.
+pub fn generate(input: TestCases) -> Result<TokenStream, Error> {
+	let TestCases {
+		validator,
+		args,
+		valid,
+		invalid,
+	} = input;
+	let Some(name) = validator.segments.last().map(|segment| &segment.ident)
+	else {
+		return Err(Error::new_spanned(validator, "expected a validator"));
+	};
+
+	let valid = valid.iter().enumerate().map(|(index, value)| {
+		let test_name = format_ident!("{}_valid_{}", name, index);
+		quote! {
+			#[test]
+			fn #test_name() {
+				let _ = ::preprocess::assert_valid!(#validator, #value #(, #args)*);
+			}
+		}
+	});
+	let invalid = invalid.iter().enumerate().map(|(index, value)| {
+		let test_name = format_ident!("{}_invalid_{}", name, index);
+		quote! {
+			#[test]
+			fn #test_name() {
+				let _ = ::preprocess::assert_invalid!(#validator, #value #(, #args)*);
+			}
+		}
+	});
+
+	Ok(quote! {
+		#(#valid)*
+		#(#invalid)*
+	}
+	.into())
+}
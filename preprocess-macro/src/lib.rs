@@ -1,8 +1,22 @@
 use proc_macro::TokenStream;
-use quote::ToTokens;
-use syn::{parse::Parse, Attribute, ItemEnum, ItemStruct, Token};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+	parse::Parse,
+	punctuated::Punctuated,
+	Attribute,
+	Ident,
+	ItemEnum,
+	ItemStruct,
+	MetaNameValue,
+	Path,
+	Token,
+};
 
+mod derive_enum_variant;
+mod derive_preprocessable;
 mod ext_traits;
+mod generate_test_cases;
 mod preprocessor;
 mod process_enum;
 mod process_struct;
@@ -41,60 +55,293 @@ impl From<Item> for TokenStream {
 	}
 }
 
+/// Arguments given to the `#[preprocess::sync(...)]` attribute
+struct MacroArgs {
+	/// Requires every field to have at least one preprocessor
+	strict_mode: bool,
+	/// Derives `Debug`, `Clone` and `PartialEq` on the processed type, if the
+	/// original type doesn't already derive them
+	auto_derive: bool,
+	/// Prints the generated code to stderr during compilation. This can also
+	/// be enabled for every invocation using the `PREPROCESS_DEBUG=1`
+	/// environment variable
+	debug_output: bool,
+	/// Collects the errors of every field instead of returning the first one,
+	/// changing the error type of `Preprocessable` to `Vec<Error>`
+	collect_errors: bool,
+	/// Implements `Deref` from the processed struct to the original struct.
+	/// Requires the `opt_deref` feature and a `#[repr(C)]` or
+	/// `#[repr(transparent)]` struct whose field types are not changed
+	deref: bool,
+}
+
+impl Default for MacroArgs {
+	fn default() -> Self {
+		Self {
+			strict_mode: false,
+			auto_derive: true,
+			debug_output: false,
+			collect_errors: false,
+			deref: false,
+		}
+	}
+}
+
+impl Parse for MacroArgs {
+	fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+		let mut args = Self::default();
+		let (
+			mut strict_mode,
+			mut auto_derive,
+			mut debug_output,
+			mut collect_errors,
+			mut deref,
+		) = (None, None, None, None, None);
+
+		for name_value in
+			Punctuated::<MetaNameValue, Token![,]>::parse_terminated(input)?
+		{
+			let option = if name_value.path.is_ident("strict_mode") {
+				&mut strict_mode
+			} else if name_value.path.is_ident("auto_derive") {
+				&mut auto_derive
+			} else if name_value.path.is_ident("debug_output") {
+				&mut debug_output
+			} else if name_value.path.is_ident("collect_errors") {
+				&mut collect_errors
+			} else if name_value.path.is_ident("deref") {
+				&mut deref
+			} else {
+				return Err(syn::Error::new_spanned(
+					name_value.path,
+					concat!(
+						"expected `strict_mode`, `auto_derive`, `debug_output`,",
+						" `collect_errors` or `deref` as the attribute argument"
+					),
+				));
+			};
+			if option.is_some() {
+				return Err(syn::Error::new_spanned(
+					name_value.path,
+					"duplicate attribute argument",
+				));
+			}
+
+			match &name_value.value {
+				syn::Expr::Lit(syn::ExprLit {
+					attrs: _,
+					lit: syn::Lit::Bool(lit),
+				}) => *option = Some(lit.value),
+				_ => {
+					return Err(syn::Error::new_spanned(
+						name_value.value,
+						"expected a boolean literal as the attribute argument",
+					));
+				}
+			}
+		}
+
+		if let Some(strict_mode) = strict_mode {
+			args.strict_mode = strict_mode;
+		}
+		if let Some(auto_derive) = auto_derive {
+			args.auto_derive = auto_derive;
+		}
+		if let Some(debug_output) = debug_output {
+			args.debug_output = debug_output;
+		}
+		if let Some(collect_errors) = collect_errors {
+			args.collect_errors = collect_errors;
+		}
+		if let Some(deref) = deref {
+			args.deref = deref;
+		}
+
+		Ok(args)
+	}
+}
+
+/// Generates a derive attribute for the traits that are automatically derived
+/// on the processed type (`Debug`, `Clone` and `PartialEq`), excluding the
+/// ones that the original type already derives. The generated attribute is
+/// spanned to the `#[preprocess::sync]` invocation.
+fn auto_derive_attr(attrs: &[Attribute]) -> TokenStream2 {
+	let derived = attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("derive"))
+		.filter_map(|attr| {
+			attr.parse_args_with(
+				Punctuated::<Path, Token![,]>::parse_terminated,
+			)
+			.ok()
+		})
+		.flatten()
+		.filter_map(|path| {
+			path.segments
+				.last()
+				.map(|segment| segment.ident.to_string())
+		})
+		.collect::<Vec<_>>();
+
+	let missing = [
+		("Debug", quote! { ::core::fmt::Debug }),
+		("Clone", quote! { ::core::clone::Clone }),
+		("PartialEq", quote! { ::core::cmp::PartialEq }),
+	]
+	.into_iter()
+	.filter(|(name, _)| !derived.iter().any(|derived| derived == name))
+	.map(|(_, path)| path)
+	.collect::<Vec<_>>();
+
+	if missing.is_empty() {
+		return TokenStream2::new();
+	}
+	quote_spanned! {Span::call_site()=>
+		#[derive(#(#missing),*)]
+	}
+}
+
+/// Generates a statement that enters a tracing span for preprocessing the
+/// entire struct / enum, which is exited when the `preprocess` function
+/// returns. Generates nothing if the `tracing` feature is not enabled.
+fn struct_span() -> TokenStream2 {
+	if !cfg!(feature = "tracing") {
+		return TokenStream2::new();
+	}
+
+	quote! {
+		let _span = ::preprocess::__private::tracing::debug_span!(
+			"preprocess::struct",
+			"type" = ::std::any::type_name::<Self>(),
+		)
+		.entered();
+	}
+}
+
+/// The name of the variable that the errors are collected into when
+/// `collect_errors = true` is used. The identifier is hygienic, so that it
+/// cannot clash with the name of a field.
+fn errors_ident() -> Ident {
+	Ident::new("errors", Span::mixed_site())
+}
+
+/// Generates the statement that reports the given error. The error is pushed
+/// to the collected errors if `collect_errors` is set, and returned otherwise.
+fn report_error(error: TokenStream2, collect_errors: bool) -> TokenStream2 {
+	if collect_errors {
+		let errors = errors_ident();
+		quote! { #errors.push(#error); }
+	} else {
+		quote! { return ::std::result::Result::Err(#error); }
+	}
+}
+
+/// Generates the error type of the `Preprocessable` implementation, which is
+/// `Vec<Error>` if `collect_errors` is set, and `Error` otherwise.
+fn error_type(args: &MacroArgs) -> TokenStream2 {
+	if args.collect_errors {
+		quote! { ::std::vec::Vec<::preprocess::Error> }
+	} else {
+		quote! { ::preprocess::Error }
+	}
+}
+
+/// Generates the code that runs the type-level preprocessors on `value`. If
+/// `collect_errors` is set, the preprocessors are run in a closure and an
+/// error is returned as a list containing only that error, since the fields
+/// cannot be processed without the value.
+fn global_token_stream(
+	global: Vec<TokenStream2>,
+	args: &MacroArgs,
+) -> TokenStream2 {
+	if !args.collect_errors || global.is_empty() {
+		return quote! { #(#global)* };
+	}
+
+	quote! {
+		let value = (|| -> ::std::result::Result<Self, ::preprocess::Error> {
+			#(#global)*
+			::std::result::Result::Ok(value)
+		})()
+		.map_err(|err| ::std::vec![err])?;
+	}
+}
+
+/// Generates the declaration of the list that the errors are collected into,
+/// and the check that returns them once every field is processed. Generates
+/// nothing if `collect_errors` is not set.
+fn collect_errors_token_stream(
+	args: &MacroArgs,
+) -> (TokenStream2, TokenStream2) {
+	if !args.collect_errors {
+		return (TokenStream2::new(), TokenStream2::new());
+	}
+
+	let errors = errors_ident();
+	(
+		quote! {
+			#[allow(unused_mut)]
+			let mut #errors = ::std::vec::Vec::new();
+		},
+		quote! {
+			if !#errors.is_empty() {
+				return ::std::result::Result::Err(#errors);
+			}
+		},
+	)
+}
+
 impl Item {
-	fn into_processed(self, strict_mode: bool) -> TokenStream {
+	fn into_processed(self, args: &MacroArgs) -> TokenStream {
 		let result = match self {
-			Item::Struct(item) => {
-				process_struct::into_processed(item, strict_mode)
-			}
-			Item::Enum(item) => process_enum::into_processed(item, strict_mode),
+			Item::Struct(item) => process_struct::into_processed(item, args),
+			Item::Enum(item) => process_enum::into_processed(item, args),
 		};
 
-		match result {
+		let token_stream = match result {
 			Ok(token_stream) => token_stream,
 			Err(error) => error.to_compile_error().into(),
+		};
+
+		if args.debug_output ||
+			std::env::var("PREPROCESS_DEBUG").is_ok_and(|value| value == "1")
+		{
+			eprintln!("{}", token_stream);
 		}
+
+		token_stream
 	}
 }
 
 #[proc_macro_attribute]
 pub fn sync(args: TokenStream, input: TokenStream) -> TokenStream {
 	let input = syn::parse_macro_input!(input as Item);
+	let args = syn::parse_macro_input!(args as MacroArgs);
 
-	let strict_mode = if !args.is_empty() {
-		let meta = syn::parse_macro_input!(args as syn::Meta);
-		let name_value = match meta.require_name_value() {
-			Ok(name_value) => name_value,
-			Err(err) => {
-				return err.to_compile_error().into();
-			}
-		};
-		if !name_value.path.is_ident("strict_mode") {
-			return syn::Error::new_spanned(
-				name_value.path.clone(),
-				"expected `strict_mode` as the attribute argument",
-			)
-			.to_compile_error()
-			.into();
-		}
+	input.into_processed(&args)
+}
 
-		match &name_value.value {
-			syn::Expr::Lit(syn::ExprLit {
-				attrs: _,
-				lit: syn::Lit::Bool(lit),
-			}) => lit.value,
-			_ => {
-				return syn::Error::new_spanned(
-					name_value.value.clone(),
-					"expected a boolean literal as the attribute argument",
-				)
-				.to_compile_error()
-				.into();
-			}
-		}
-	} else {
-		false
-	};
+#[proc_macro_derive(PreprocessAsEnumVariant)]
+pub fn derive_enum_variant(input: TokenStream) -> TokenStream {
+	let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+	derive_enum_variant::derive(input)
+		.unwrap_or_else(|error| error.to_compile_error().into())
+}
+
+#[proc_macro_derive(Preprocessable)]
+pub fn derive_preprocessable(input: TokenStream) -> TokenStream {
+	let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+	derive_preprocessable::derive(input)
+		.unwrap_or_else(|error| error.to_compile_error().into())
+}
+
+#[proc_macro]
+pub fn generate_test_cases(input: TokenStream) -> TokenStream {
+	let input =
+		syn::parse_macro_input!(input as generate_test_cases::TestCases);
 
-	input.into_processed(strict_mode)
+	generate_test_cases::generate(input)
+		.unwrap_or_else(|error| error.to_compile_error().into())
 }
@@ -1,28 +1,125 @@
-use proc_macro2::TokenStream as TokenStream2;
-use quote::{format_ident, quote, ToTokens};
+use std::{net::IpAddr, time::Duration};
+
+use chrono::{
+	format::{Item, StrftimeItems},
+	Datelike,
+	NaiveDate,
+};
+use proc_macro2::{
+	Group,
+	Literal,
+	Span,
+	TokenStream as TokenStream2,
+	TokenTree,
+};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use regex::Regex;
 use syn::{
+	parse::ParseStream,
 	punctuated::Punctuated,
 	spanned::Spanned,
 	Attribute,
 	Error,
 	Expr,
 	ExprLit,
+	ExprParen,
+	ExprUnary,
+	Field,
+	GenericArgument,
 	Ident,
 	Lit,
 	LitInt,
+	LitStr,
 	Meta,
+	MetaNameValue,
 	Path,
+	PathArguments,
 	Token,
+	Type,
+	TypePath,
+	UnOp,
 };
 
 use crate::ext_traits::{ExprExt, LitExpr};
 
+/// The countries supported by `#[preprocess(zip_code(country = "..."))]`.
+/// Must be kept in sync with
+/// `preprocess::validators::SUPPORTED_ZIP_CODE_COUNTRIES`.
+const ZIP_CODE_COUNTRIES: &[&str] = &["US", "GB", "UK", "DE", "CA", "FR"];
+
+/// The format used by `#[preprocess(date)]` when no format is given
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// The format used by `#[preprocess(datetime)]` and
+/// `#[preprocess(datetime_local)]` when no format is given
+const DEFAULT_DATETIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
 #[derive(Debug)]
 pub enum IpPreprocessorType {
 	V4,
 	V6,
 	Any,
+	NormalizeV4,
+	NormalizeV6,
+	NormalizeAny,
+	IpInNetwork { network: String },
+}
+
+#[derive(Debug)]
+pub enum DateTimeZone {
+	Utc,
+	Local,
+}
+
+#[derive(Debug)]
+pub enum IsbnFormat {
+	Isbn10,
+	Isbn13,
+}
+
+#[derive(Debug)]
+pub enum HexCase {
+	Lowercase,
+	Uppercase,
+}
+
+#[derive(Debug)]
+pub enum AlphabetKind {
+	Base58,
+	Base62,
+	Custom(String),
+}
+
+#[derive(Debug)]
+pub enum PhoneFormat {
+	E164,
+	Local,
+}
+
+#[derive(Debug)]
+pub enum UuidType {
+	Any,
+	V1,
+	V3,
+	V4,
+	V5,
+}
+
+#[derive(Debug)]
+pub enum UrlComponentKind {
+	Path,
+	Query,
+	Fragment,
+	Host,
+	Scheme,
+}
+
+#[derive(Debug)]
+pub enum NormalizationForm {
+	Nfc,
+	Nfd,
+	Nfkc,
+	Nfkd,
 }
 
 pub enum Preprocessor {
@@ -31,45 +128,333 @@ pub enum Preprocessor {
 
 	/// Complex type handlers
 	Optional(Vec<Preprocessor>),
+	Keys(Vec<Preprocessor>),
+	Values(Vec<Preprocessor>),
+	CsvItems {
+		preprocessors: Vec<Preprocessor>,
+		delimiter: char,
+	},
+
+	/// Field modifiers, applied to the entire chain of the field
+	Default(Expr),
+	DefaultFn(String),
+	OnError(Expr),
+	Sensitive,
+	/// Logs errors using `tracing::warn!` instead of returning them, keeping
+	/// the original value of the field
+	WarnOnly,
+	/// Passes the error of the chain through the given function
+	/// `fn(preprocess::Error) -> preprocess::Error`
+	MapError(String),
+	/// Replaces the message of the error of the chain, keeping the field
+	ErrorMessage(String),
+	/// Cross-field validator, applied by
+	/// `ProcessedFields::confirm_token_stream` after all the fields are
+	/// processed
+	Confirm {
+		other_field: LitStr,
+	},
+	/// Struct-level validator, checking that exactly one of the given
+	/// `Option` fields is `Some`. Applied by
+	/// `ProcessedFields::exactly_one_of_token_stream` after all the fields are
+	/// processed
+	ExactlyOneOf(Vec<LitStr>),
+	/// Runs the given preprocessors with access to the processed value of
+	/// another field. The fields are reordered by
+	/// `ProcessedFields::preprocess_token_stream` so that the other field is
+	/// processed first
+	MapField {
+		key: LitStr,
+		preprocessors: Vec<Preprocessor>,
+	},
 
 	// Validators
-	Email,
+	Email {
+		allow_subaddressing: bool,
+		normalize_subaddress: bool,
+	},
 	Domain,
-	Url,
+	Url {
+		schemes: Option<Vec<String>>,
+		no_credentials: bool,
+		allow_fragment: bool,
+	},
 	Length {
 		min: Option<Expr>,
 		max: Option<Expr>,
 		equal: Option<Expr>,
 	},
+	LengthBytes {
+		min: Option<Expr>,
+		max: Option<Expr>,
+		equal: Option<Expr>,
+	},
 	Range {
 		min: Option<Expr>,
 		max: Option<Expr>,
 	},
+	NumberInRange {
+		min: Option<f64>,
+		max: Option<f64>,
+		integer_only: bool,
+	},
+	NumericString {
+		integer_only: bool,
+		positive_only: bool,
+		no_scientific: bool,
+	},
+	WordCount {
+		min: Option<Expr>,
+		max: Option<Expr>,
+	},
+	MinWords(Expr),
+	MaxWords(Expr),
+	ItemCount {
+		min: Option<Expr>,
+		max: Option<Expr>,
+	},
+	MinItems(Expr),
+	MaxItems(Expr),
+	MinBytes(Expr),
+	MaxBytes(Expr),
+	ExactLength(Expr),
+	Min(Expr),
+	Max(Expr),
+	NoWhitespace,
+	NoLeadingWhitespace,
+	NoTrailingWhitespace,
+	CsvLength {
+		min: Option<Expr>,
+		max: Option<Expr>,
+		delimiter: char,
+	},
 	Contains(String),
 	DoesNotContain(String),
+	ContainsItem(Expr),
+	DoesNotContainItem(Expr),
+	Whitelist(Vec<Expr>),
+	Blacklist(Vec<Expr>),
 	Custom(String),
+	CustomType(Type),
+	Transform(String),
+	TransformRef(String),
 	Regex(Expr),
+	RegexNamed(Expr),
+	RegexRef(Expr),
+	EnumVariant(Path),
+	Unique,
+	UniqueSorted,
+	Positive,
+	Negative,
+	NonNegative,
+	NonZero,
 	Nested,
-	Type(String),
+	Coerce(String),
+	FromStr {
+		target_type: String,
+	},
 	Ip(IpPreprocessorType),
+	/// Wraps a preprocessor that was written in a longer form than necessary,
+	/// generating the same code along with a warning that suggests the
+	/// shorter form
+	Suggestion {
+		preprocessor: Box<Preprocessor>,
+		message: String,
+	},
+	PrivateIpOnly,
+	PublicIpOnly,
+	IpCidr {
+		strict: bool,
+		as_pair: bool,
+	},
+	Isbn(Option<IsbnFormat>),
+	ZipCode {
+		country: String,
+	},
+	Cron {
+		allow_seconds: bool,
+		next_run_within: Option<Duration>,
+	},
+	Date {
+		format: String,
+		min: Option<NaiveDate>,
+		max: Option<NaiveDate>,
+		as_date: bool,
+	},
+	DateTime {
+		format: String,
+		timezone: Option<DateTimeZone>,
+	},
+	FutureDate,
+	PastDate,
+	Hex {
+		length: Option<usize>,
+		prefix: Option<String>,
+		normalize: Option<HexCase>,
+	},
+	MinAge {
+		years: u32,
+	},
+	MaxAge {
+		years: u32,
+	},
 
 	// Preprocessors
 	Trim,
 	Lowercase,
 	Uppercase,
+	Abs,
+	Neg,
+	SnakeCase,
+	CamelCase,
+	KebabCase,
+	PascalCase,
+	UppercaseFirst,
+	LowercaseFirst,
+	DigitsOnly,
+	LettersOnly,
+	AlphanumericOnly,
+	TitleCase {
+		stop_words: Option<Vec<String>>,
+	},
+	NormalizeUnicode {
+		form: NormalizationForm,
+	},
+	NormalizeIsbn,
+	HexDecode,
+	StripHtml {
+		decode_entities: bool,
+	},
+	StripSubaddress,
+	NormalizeZipCode,
+	NumberFormat {
+		thousands: char,
+		decimal: char,
+	},
+	DecimalString,
+	Alphabet(AlphabetKind),
+	Ipv4Mapped,
+	NormalizeIpAny,
+	NoScriptTags,
+	StripPrefix(String),
+	StripSuffix(String),
+	RequirePrefix(String),
+	RequireSuffix(String),
+	Uuid {
+		version: UuidType,
+		as_string: bool,
+	},
+	Phone {
+		country: Option<Ident>,
+		format: PhoneFormat,
+	},
+	NormalizePhone {
+		country: Option<Ident>,
+	},
+	EachKeyValue {
+		key_preprocessors: Vec<Preprocessor>,
+		value_preprocessors: Vec<Preprocessor>,
+	},
+	NonNan,
+	Finite,
+	ValidJsonPath,
+	JsonPath(String),
+	Interpolate(String),
+	AllMatch(Vec<(Ident, Expr)>),
+	UrlComponent {
+		component: UrlComponentKind,
+	},
+	LowerBound(Expr),
+	UpperBound(Expr),
+	Version {
+		allow_v_prefix: bool,
+		allow_prerelease: bool,
+		allow_build_metadata: bool,
+		normalize: bool,
+	},
+	NoControlChars,
+	StripControlChars,
+	StripNonPrintable,
+	Equal(Expr),
+	NotEqual(Expr),
+	PasswordStrength {
+		min_score: u8,
+	},
+	LanguageCode {
+		strict: bool,
+	},
+	NormalizeLanguageCode,
+	CurrencyCode {
+		active_only: bool,
+	},
+	NormalizeCurrencyCode,
 	// TODO add later on:
-	// KeyValue {
-	// 	key: Vec<Preprocessor>,
-	// 	value: Vec<Preprocessor>,
-	// },
 	// If {
 	// 	condition: String,
 	// 	then: Vec<Preprocessor>,
 	// },
-	// UUID(type)
 }
 
 impl Preprocessor {
+	/// Checks the preprocessors of a field in strict mode, where every field
+	/// must have at least one preprocessor, and errors cannot be silently
+	/// replaced using `on_error`.
+	pub fn check_strict_mode(
+		field: &Field,
+		preprocessors: &[Preprocessor],
+	) -> Result<(), Error> {
+		if preprocessors.is_empty() {
+			return Err(Error::new_spanned(
+				field,
+				"every field must have at least one preprocessor in strict mode",
+			));
+		}
+
+		if let Some(fallback) =
+			preprocessors
+				.iter()
+				.find_map(|preprocessor| match preprocessor {
+					Preprocessor::OnError(fallback) => Some(fallback),
+					_ => None,
+				}) {
+			return Err(Error::new_spanned(
+				fallback,
+				"`on_error` cannot be used in strict mode",
+			));
+		}
+
+		if preprocessors
+			.iter()
+			.any(|preprocessor| matches!(preprocessor, Preprocessor::WarnOnly))
+		{
+			return Err(Error::new_spanned(
+				field,
+				"`warn_only` cannot be used in strict mode",
+			));
+		}
+
+		Ok(())
+	}
+
+	/// Returns the type of a field after running the given preprocessors on
+	/// it. Fields with `warn_only` keep their original type, since the
+	/// original value is used when preprocessing fails.
+	pub fn chain_new_type(
+		preprocessors: &[Preprocessor],
+		ty: &TokenStream2,
+	) -> TokenStream2 {
+		if preprocessors
+			.iter()
+			.any(|preprocessor| matches!(preprocessor, Preprocessor::WarnOnly))
+		{
+			return ty.clone();
+		}
+
+		preprocessors.iter().fold(ty.clone(), |acc, preprocessor| {
+			preprocessor.get_new_type(&acc)
+		})
+	}
+
 	pub fn from_attr(
 		attr: &Attribute,
 		is_global: bool,
@@ -83,18 +468,73 @@ impl Preprocessor {
 		}
 
 		// If the attribute is `#[preprocess]`, but not global, it is shorthand
-		// for `#[preprocess(nested)]`.
-		if let (Meta::Path(_), false) = (&attr.meta, is_global) {
-			return Ok(vec![Preprocessor::Nested]);
+		// for `#[preprocess(nested)]`. There is nothing to nest at the type
+		// level, so it is rejected there.
+		match (&attr.meta, is_global) {
+			(Meta::Path(_), false) => return Ok(vec![Preprocessor::Nested]),
+			(Meta::Path(path), true) => {
+				return Err(Error::new(
+					path.span(),
+					concat!(
+						"`#[preprocess]` without arguments is a shorthand for ",
+						"`#[preprocess(nested)]` and can only be used on ",
+						"fields. Use `#[preprocess(nested)]` on the fields ",
+						"that need to be preprocessed instead"
+					),
+				));
+			}
+			_ => (),
 		}
 
 		// If the attribute is `#[preprocess(...)]`, parse the inner contents.
-		attr.meta
-			.require_list()?
-			.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?
-			.into_iter()
-			.map(Preprocessor::try_from)
-			.collect::<Result<Vec<_>, Error>>()
+		let metas = attr.meta.require_list()?.parse_args_with(parse_metas)?;
+
+		let mut preprocessors = Vec::<Self>::new();
+		for meta in metas {
+			let span = meta.path().span();
+			let preprocessor = Preprocessor::try_from(meta)?;
+
+			// `each_key` and `each_value` in the same attribute are merged
+			// into a single pass over the map, at the position of the first
+			// one.
+			let Preprocessor::EachKeyValue {
+				key_preprocessors,
+				value_preprocessors,
+			} = preprocessor
+			else {
+				preprocessors.push(preprocessor);
+				continue;
+			};
+			let existing = preprocessors.iter_mut().find_map(|preprocessor| {
+				match preprocessor {
+					Preprocessor::EachKeyValue {
+						key_preprocessors,
+						value_preprocessors,
+					} => Some((key_preprocessors, value_preprocessors)),
+					_ => None,
+				}
+			});
+			let Some((existing_keys, existing_values)) = existing else {
+				preprocessors.push(Preprocessor::EachKeyValue {
+					key_preprocessors,
+					value_preprocessors,
+				});
+				continue;
+			};
+			if (!key_preprocessors.is_empty() && !existing_keys.is_empty()) ||
+				(!value_preprocessors.is_empty() &&
+					!existing_values.is_empty())
+			{
+				return Err(Error::new(
+					span,
+					"`each_key` and `each_value` can only be used once per attribute",
+				));
+			}
+			existing_keys.extend(key_preprocessors);
+			existing_values.extend(value_preprocessors);
+		}
+
+		Ok(preprocessors)
 	}
 
 	pub fn get_new_type(&self, current_type: &TokenStream2) -> TokenStream2 {
@@ -122,33 +562,162 @@ impl Preprocessor {
 					.trim()
 					.parse()
 					.expect("unable to parse token stream");
-				let inner_type = preprocessors
-					.iter()
-					.fold(current_type, |ty, preprocessor| {
-						preprocessor.get_new_type(&ty)
-					});
+				let inner_type =
+					Self::chain_new_type(preprocessors, &current_type);
 				quote! {
 					::core::option::Option<#inner_type>
 				}
 			}
+			Self::Keys(preprocessors) => {
+				let Some((map_type, key_type, value_type)) =
+					split_map_type(current_type)
+				else {
+					return current_type.clone();
+				};
+				let (_, key_type) = Self::chain_token_stream(
+					preprocessors,
+					&format_ident!("key"),
+					&key_type.to_token_stream(),
+					Span::call_site(),
+				);
+				join_map_type(
+					&map_type,
+					&key_type,
+					&value_type.to_token_stream(),
+				)
+			}
+			Self::Values(preprocessors) => {
+				let Some((map_type, key_type, value_type)) =
+					split_map_type(current_type)
+				else {
+					return current_type.clone();
+				};
+				let (_, value_type) = Self::chain_token_stream(
+					preprocessors,
+					&format_ident!("value"),
+					&value_type.to_token_stream(),
+					Span::call_site(),
+				);
+				join_map_type(
+					&map_type,
+					&key_type.to_token_stream(),
+					&value_type,
+				)
+			}
+			Self::EachKeyValue {
+				key_preprocessors,
+				value_preprocessors,
+			} => {
+				let Some((map_type, key_type, value_type)) =
+					split_map_type(current_type)
+				else {
+					return current_type.clone();
+				};
+				let (_, key_type) = Self::chain_token_stream(
+					key_preprocessors,
+					&format_ident!("key"),
+					&key_type.to_token_stream(),
+					Span::call_site(),
+				);
+				let (_, value_type) = Self::chain_token_stream(
+					value_preprocessors,
+					&format_ident!("value"),
+					&value_type.to_token_stream(),
+					Span::call_site(),
+				);
+				join_map_type(&map_type, &key_type, &value_type)
+			}
+
+			Self::CsvItems { .. } => "::std::string::String"
+				.parse()
+				.expect("unable to parse token stream"),
+
+			Self::Default(_) => current_type.clone(),
+			Self::DefaultFn(_) => current_type.clone(),
+			Self::OnError(_) => current_type.clone(),
+			Self::Sensitive => current_type.clone(),
+			Self::WarnOnly => current_type.clone(),
+			Self::MapError(_) | Self::ErrorMessage(_) => current_type.clone(),
+			Self::Confirm { .. } => current_type.clone(),
+			Self::ExactlyOneOf(_) => current_type.clone(),
+			Self::MapField { preprocessors, .. } => {
+				Self::chain_new_type(preprocessors, current_type)
+			}
 
-			Self::Email => current_type.clone(),
+			Self::Email {
+				normalize_subaddress: false,
+				..
+			} => current_type.clone(),
+			Self::Email {
+				normalize_subaddress: true,
+				..
+			} => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
 			Self::Domain => current_type.clone(),
-			Self::Url => "::preprocess::types::Url"
+			Self::Url { .. } => "::preprocess::types::Url"
 				.parse()
 				.expect("unable to parse token stream"),
 			Self::Length { .. } => current_type.clone(),
+			Self::LengthBytes { .. } => current_type.clone(),
 			Self::Range { .. } => current_type.clone(),
+			Self::NumberInRange { .. } => current_type.clone(),
+			Self::Min(_) => current_type.clone(),
+			Self::Max(_) => current_type.clone(),
+			Self::WordCount { .. } => current_type.clone(),
+			Self::NumericString { .. } => current_type.clone(),
+			Self::ItemCount { .. } => current_type.clone(),
+			Self::MinItems(_) => current_type.clone(),
+			Self::MaxItems(_) => current_type.clone(),
+			Self::MinBytes(_) => current_type.clone(),
+			Self::MaxBytes(_) => current_type.clone(),
+			Self::ExactLength(_) => current_type.clone(),
+			Self::MinWords(_) => current_type.clone(),
+			Self::MaxWords(_) => current_type.clone(),
+			Self::NoWhitespace => current_type.clone(),
+			Self::NoControlChars => current_type.clone(),
+			Self::NoLeadingWhitespace => current_type.clone(),
+			Self::NoTrailingWhitespace => current_type.clone(),
+			Self::CsvLength { .. } => current_type.clone(),
 			Self::Contains(_) => current_type.clone(),
+			Self::ContainsItem(_) => current_type.clone(),
+			Self::DoesNotContainItem(_) => current_type.clone(),
+			Self::Whitelist(_) | Self::Blacklist(_) => current_type.clone(),
+			Self::Equal(_) | Self::NotEqual(_) => current_type.clone(),
+			Self::PasswordStrength { .. } => current_type.clone(),
+			Self::LanguageCode { .. } => current_type.clone(),
+			Self::CurrencyCode { .. } => current_type.clone(),
 			Self::DoesNotContain(_) => current_type.clone(),
 			Self::Custom(_) => current_type.clone(),
+			Self::CustomType(_) => current_type.clone(),
+			Self::Transform(_) => current_type.clone(),
+			Self::TransformRef(_) => current_type.clone(),
 			Self::Regex(_) => current_type.clone(),
-			Self::Nested => {
-				let current_type = current_type.to_string();
-				format_ident!("{}Processed", current_type).to_token_stream()
+			Self::RegexNamed(_) => current_type.clone(),
+			Self::RegexRef(_) => current_type.clone(),
+			Self::EnumVariant(_) => current_type.clone(),
+			Self::Unique => current_type.clone(),
+			Self::UniqueSorted => current_type.clone(),
+			Self::Positive => current_type.clone(),
+			Self::Negative => current_type.clone(),
+			Self::NonNegative => current_type.clone(),
+			Self::NonZero => current_type.clone(),
+			// Types defined using `#[preprocess::sync]` have a processed type
+			// named after them. Other types, like `Result<T, E>`, use the
+			// processed type of their `Preprocessable` implementation.
+			Self::Nested => match syn::parse2::<Ident>(current_type.clone()) {
+				Ok(ident) => {
+					format_ident!("{}Processed", ident).to_token_stream()
+				}
+				Err(_) => quote! {
+					<#current_type as ::preprocess::Preprocessable>::Processed
+				},
+			},
+			Self::Coerce(target_type) => {
+				target_type.parse().expect("unable to parse token stream")
 			}
-			Self::Type(r#type) => {
-				r#type.parse().expect("unable to parse token stream")
+			Self::FromStr { target_type } => {
+				target_type.parse().expect("unable to parse token stream")
 			}
 			Self::Ip(IpPreprocessorType::V4) => "::std::net::Ipv4Addr"
 				.parse()
@@ -156,9 +725,53 @@ impl Preprocessor {
 			Self::Ip(IpPreprocessorType::V6) => "::std::net::Ipv6Addr"
 				.parse()
 				.expect("unable to parse token stream"),
-			Self::Ip(IpPreprocessorType::Any) => "::std::net::IpAddr"
+			Self::Ip(
+				IpPreprocessorType::NormalizeV4 |
+				IpPreprocessorType::NormalizeV6 |
+				IpPreprocessorType::NormalizeAny,
+			) => "::std::borrow::Cow<'static, str>"
 				.parse()
 				.expect("unable to parse token stream"),
+			Self::Suggestion { preprocessor, .. } => {
+				preprocessor.get_new_type(current_type)
+			}
+			Self::Ip(
+				IpPreprocessorType::Any |
+				IpPreprocessorType::IpInNetwork { .. },
+			) |
+			Self::PrivateIpOnly |
+			Self::PublicIpOnly |
+			Self::Ipv4Mapped => "::std::net::IpAddr"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::IpCidr { as_pair: false, .. } => current_type.clone(),
+			Self::Isbn(_) => current_type.clone(),
+			Self::IpCidr { as_pair: true, .. } => "(::std::net::IpAddr, u8)"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::ZipCode { .. } => current_type.clone(),
+			Self::Cron { .. } => current_type.clone(),
+			Self::FutureDate => current_type.clone(),
+			Self::PastDate => current_type.clone(),
+			Self::MinAge { .. } => current_type.clone(),
+			Self::MaxAge { .. } => current_type.clone(),
+			Self::Date { as_date: false, .. } => current_type.clone(),
+			Self::Date { as_date: true, .. } => {
+				"::preprocess::types::NaiveDate"
+					.parse()
+					.expect("unable to parse token stream")
+			}
+			Self::DateTime { timezone, .. } => match timezone {
+				None => "::preprocess::types::NaiveDateTime",
+				Some(DateTimeZone::Utc) => {
+					"::preprocess::types::DateTime<::preprocess::types::Utc>"
+				}
+				Some(DateTimeZone::Local) => {
+					"::preprocess::types::DateTime<::preprocess::types::Local>"
+				}
+			}
+			.parse()
+			.expect("unable to parse token stream"),
 
 			Self::Trim => "::std::borrow::Cow<'static, str>"
 				.parse()
@@ -166,79 +779,987 @@ impl Preprocessor {
 			Self::Lowercase => "::std::borrow::Cow<'static, str>"
 				.parse()
 				.expect("unable to parse token stream"),
+			Self::Abs => current_type.clone(),
+			Self::Neg => current_type.clone(),
 			Self::Uppercase => "::std::borrow::Cow<'static, str>"
 				.parse()
 				.expect("unable to parse token stream"),
+			Self::SnakeCase => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::CamelCase => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::KebabCase => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::PascalCase => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::UppercaseFirst => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::LowercaseFirst => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::DigitsOnly => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::LettersOnly => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::AlphanumericOnly => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::TitleCase { .. } => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::NormalizeUnicode { .. } => "::std::string::String"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::Hex {
+				length: None,
+				prefix: None,
+				normalize: None,
+			} => current_type.clone(),
+			Self::Hex { .. } => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::HexDecode => "::std::vec::Vec<u8>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::NormalizeIsbn => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::StripHtml { .. } => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::StripSubaddress => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::StripControlChars | Self::StripNonPrintable => {
+				"::std::borrow::Cow<'static, str>"
+					.parse()
+					.expect("unable to parse token stream")
+			}
+			Self::NormalizeZipCode => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::NormalizeLanguageCode => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::NormalizeCurrencyCode => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::NumberFormat { .. } => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::NormalizeIpAny => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::DecimalString => current_type.clone(),
+			Self::Alphabet(_) => current_type.clone(),
+			Self::NoScriptTags => current_type.clone(),
+			Self::StripPrefix(_) => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::StripSuffix(_) => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::RequirePrefix(_) => current_type.clone(),
+			Self::RequireSuffix(_) => current_type.clone(),
+			Self::Uuid {
+				as_string: false, ..
+			} => "::preprocess::types::Uuid"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::Uuid {
+				as_string: true, ..
+			} => "::std::string::String"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::Phone { .. } => current_type.clone(),
+			Self::NonNan => current_type.clone(),
+			Self::Finite => current_type.clone(),
+			Self::ValidJsonPath => current_type.clone(),
+			Self::JsonPath(_) => current_type.clone(),
+			Self::Interpolate(_) => current_type.clone(),
+			Self::AllMatch(_) => current_type.clone(),
+			Self::UrlComponent { .. } => current_type.clone(),
+			Self::LowerBound(_) => current_type.clone(),
+			Self::UpperBound(_) => current_type.clone(),
+			Self::Version {
+				normalize: false, ..
+			} => current_type.clone(),
+			Self::Version {
+				normalize: true, ..
+			} => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
+			Self::NormalizePhone { .. } => "::std::borrow::Cow<'static, str>"
+				.parse()
+				.expect("unable to parse token stream"),
 		}
 	}
 
-	pub fn as_processor_token_stream(
+	/// Returns the name of the preprocessor along with its kind (`validator` or
+	/// `preprocessor`), if it can only be applied to strings.
+	fn string_only_name(&self) -> Option<(&'static str, &'static str)> {
+		Some(match self {
+			Self::Email { .. } => ("email", "validator"),
+			Self::Domain => ("domain", "validator"),
+			Self::Url { .. } => ("url", "validator"),
+			Self::WordCount { .. } => ("word_count", "validator"),
+			Self::MinWords(_) => ("min_words", "validator"),
+			Self::MaxWords(_) => ("max_words", "validator"),
+			Self::NumberInRange { .. } => ("number_in_range", "validator"),
+			Self::NumericString { .. } => ("numeric_string", "validator"),
+			Self::NoWhitespace => ("no_whitespace", "validator"),
+			Self::NoControlChars => ("no_control_characters", "validator"),
+			Self::NoLeadingWhitespace => ("no_leading_whitespace", "validator"),
+			Self::NoTrailingWhitespace => {
+				("no_trailing_whitespace", "validator")
+			}
+			Self::CsvLength { .. } => ("csv_length", "validator"),
+			Self::Regex(_) => ("regex", "validator"),
+			Self::RegexNamed(_) => ("regex_named", "validator"),
+			Self::RegexRef(_) => ("regex_ref", "validator"),
+			Self::EnumVariant(_) => ("enum_variant", "validator"),
+			Self::Ip(
+				IpPreprocessorType::NormalizeV4 |
+				IpPreprocessorType::NormalizeV6 |
+				IpPreprocessorType::NormalizeAny,
+			) => ("ip", "preprocessor"),
+			Self::Ip(_) => ("ip", "validator"),
+			Self::PrivateIpOnly => ("private_ip_only", "validator"),
+			Self::PublicIpOnly => ("public_ip_only", "validator"),
+			Self::IpCidr { .. } => ("ip_cidr", "validator"),
+			Self::Isbn(_) => ("isbn", "validator"),
+			Self::ZipCode { .. } => ("zip_code", "validator"),
+			Self::Cron { .. } => ("cron", "validator"),
+			Self::ValidJsonPath => ("valid_json_path", "validator"),
+			Self::JsonPath(_) => ("json", "validator"),
+			Self::Interpolate(_) => ("interpolate", "validator"),
+			Self::AllMatch(_) => ("all_match", "validator"),
+			Self::UrlComponent { .. } => ("url_component", "validator"),
+			Self::Version { .. } => ("version", "validator"),
+			Self::PasswordStrength { .. } => ("password_strength", "validator"),
+			Self::LanguageCode { .. } => ("language_code", "validator"),
+			Self::CurrencyCode { .. } => ("currency_code", "validator"),
+			Self::Date { .. } => ("date", "validator"),
+			Self::DateTime {
+				timezone: Some(DateTimeZone::Local),
+				..
+			} => ("datetime_local", "validator"),
+			Self::DateTime { .. } => ("datetime", "validator"),
+			Self::FromStr { .. } => ("from_str", "preprocessor"),
+			Self::CsvItems { .. } => ("csv_items", "preprocessor"),
+			Self::Trim => ("trim", "preprocessor"),
+			Self::Lowercase => ("lowercase", "preprocessor"),
+			Self::Uppercase => ("uppercase", "preprocessor"),
+			Self::SnakeCase => ("snake_case", "preprocessor"),
+			Self::CamelCase => ("camel_case", "preprocessor"),
+			Self::KebabCase => ("kebab_case", "preprocessor"),
+			Self::PascalCase => ("pascal_case", "preprocessor"),
+			Self::UppercaseFirst => ("uppercase_first", "preprocessor"),
+			Self::LowercaseFirst => ("lowercase_first", "preprocessor"),
+			Self::DigitsOnly => ("digits_only", "preprocessor"),
+			Self::LettersOnly => ("letters_only", "preprocessor"),
+			Self::AlphanumericOnly => ("alphanumeric_only", "preprocessor"),
+			Self::TitleCase { .. } => ("title_case", "preprocessor"),
+			Self::NormalizeUnicode { .. } => {
+				("normalize_unicode", "preprocessor")
+			}
+			Self::NormalizeIsbn => ("normalize_isbn", "preprocessor"),
+			Self::Hex { .. } => ("hex", "validator"),
+			Self::HexDecode => ("hex_decode", "preprocessor"),
+			Self::StripHtml { .. } => ("strip_html", "preprocessor"),
+			Self::StripSubaddress => ("strip_subaddress", "preprocessor"),
+			Self::StripControlChars => {
+				("strip_control_characters", "preprocessor")
+			}
+			Self::StripNonPrintable => ("strip_non_printable", "preprocessor"),
+			Self::NormalizeZipCode => ("normalize_zip_code", "preprocessor"),
+			Self::NormalizeLanguageCode => {
+				("normalize_language_code", "preprocessor")
+			}
+			Self::NormalizeCurrencyCode => {
+				("normalize_currency_code", "preprocessor")
+			}
+			Self::NumberFormat { .. } => ("number_format", "preprocessor"),
+			Self::DecimalString => ("decimal_string", "validator"),
+			Self::Alphabet(_) => ("alphabet", "validator"),
+			Self::Ipv4Mapped => ("ipv4_mapped", "validator"),
+			Self::NormalizeIpAny => ("normalize_ip_any", "preprocessor"),
+			Self::NoScriptTags => ("no_script_tags", "validator"),
+			Self::StripPrefix(_) => ("strip_prefix", "preprocessor"),
+			Self::StripSuffix(_) => ("strip_suffix", "preprocessor"),
+			Self::RequirePrefix(_) => ("require_prefix", "validator"),
+			Self::RequireSuffix(_) => ("require_suffix", "validator"),
+			Self::Uuid { .. } => ("uuid", "validator"),
+			Self::Phone { .. } => ("phone", "validator"),
+			Self::NormalizePhone { .. } => ("normalize_phone", "preprocessor"),
+			Self::Suggestion { preprocessor, .. } => {
+				return preprocessor.string_only_name();
+			}
+			_ => return None,
+		})
+	}
+
+	/// Emits a compile error if the preprocessor can only be applied to
+	/// strings, but the given type is known not to be a string, like a number
+	/// or a `bool`. Other types are left to the trait bounds of the respective
+	/// function to reject.
+	fn incompatible_type_error(
 		&self,
-		field_name: &Ident,
 		ty: &TokenStream2,
-	) -> TokenStream2 {
-		let new_ty = self.get_new_type(ty);
+	) -> Option<TokenStream2> {
+		let (name, kind) = self.string_only_name()?;
+		let ty = ty.to_string();
+		if ![
+			"u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32",
+			"i64", "i128", "isize", "f32", "f64", "bool", "char",
+		]
+		.contains(&ty.as_str())
+		{
+			return None;
+		}
 
-		match self {
-			Preprocessor::None => quote! {},
+		let message = format!(
+			"cannot apply `{}` {} to field of type `{}`",
+			name, kind, ty
+		);
+		Some(quote! {
+			::std::compile_error!(#message);
+		})
+	}
 
-			Preprocessor::Optional(preprocessors) => {
-				let (preprocessors, new_type) = preprocessors.iter().fold(
-					(
-						quote! {},
-						ty.to_token_stream()
-							.to_string()
-							.trim()
-							.trim_start_matches("::std::option::Option")
-							.trim()
-							.trim_start_matches(":: std :: option :: Option")
-							.trim()
-							.trim_start_matches("::core::option::Option")
-							.trim()
-							.trim_start_matches(":: core :: option :: Option")
-							.trim()
-							.trim_start_matches("Option")
-							.trim()
-							.trim_start_matches("<")
-							.trim()
-							.trim_end_matches(">")
-							.trim()
-							.parse()
-							.expect("unable to parse token stream"),
+	/// Generates the code for a chain of preprocessors applied one after the
+	/// other on the given binding. Returns the generated code along with the
+	/// resulting type of the binding after the entire chain is applied.
+	///
+	/// If the chain contains a `sensitive` modifier, errors from the entire
+	/// chain are redacted. If it contains a `default` or `default_fn`
+	/// modifier, the entire chain is wrapped so that the fallback value is used
+	/// instead of returning an error.
+	///
+	/// The types of the bindings and the compile errors are spanned to the
+	/// given span, which is usually the span of the type of the field, so that
+	/// type errors in the generated code point to the field instead of the
+	/// `#[preprocess::sync]` attribute. User-supplied expressions keep their
+	/// own spans.
+	pub fn chain_token_stream(
+		preprocessors: &[Preprocessor],
+		binding: &Ident,
+		ty: &TokenStream2,
+		span: Span,
+	) -> (TokenStream2, TokenStream2) {
+		// `min_words` and `max_words` on the same field are combined into a
+		// single `word_count` validation, at the position of the first one.
+		let min_words =
+			preprocessors
+				.iter()
+				.find_map(|preprocessor| match preprocessor {
+					Preprocessor::MinWords(min) => Some(min.clone()),
+					_ => None,
+				});
+		let max_words =
+			preprocessors
+				.iter()
+				.find_map(|preprocessor| match preprocessor {
+					Preprocessor::MaxWords(max) => Some(max.clone()),
+					_ => None,
+				});
+		let mut word_count = Some(Preprocessor::WordCount {
+			min: min_words,
+			max: max_words,
+		});
+
+		// `min_items` and `max_items` are combined into a single item count
+		// validation as well.
+		let min_items =
+			preprocessors
+				.iter()
+				.find_map(|preprocessor| match preprocessor {
+					Preprocessor::MinItems(min) => Some(min.clone()),
+					_ => None,
+				});
+		let max_items =
+			preprocessors
+				.iter()
+				.find_map(|preprocessor| match preprocessor {
+					Preprocessor::MaxItems(max) => Some(max.clone()),
+					_ => None,
+				});
+		let mut item_count = Some(Preprocessor::ItemCount {
+			min: min_items,
+			max: max_items,
+		});
+
+		// `min_bytes` and `max_bytes` are combined into a single
+		// `length_bytes` validation.
+		let min_bytes =
+			preprocessors
+				.iter()
+				.find_map(|preprocessor| match preprocessor {
+					Preprocessor::MinBytes(min) => Some(min.clone()),
+					_ => None,
+				});
+		let max_bytes =
+			preprocessors
+				.iter()
+				.find_map(|preprocessor| match preprocessor {
+					Preprocessor::MaxBytes(max) => Some(max.clone()),
+					_ => None,
+				});
+		if let Some(error) = byte_shorthand_error(
+			preprocessors,
+			min_bytes.as_ref(),
+			max_bytes.as_ref(),
+		) {
+			return (respan(error, span), ty.clone());
+		}
+		let mut length_bytes = Some(Preprocessor::LengthBytes {
+			min: min_bytes,
+			max: max_bytes,
+			equal: None,
+		});
+
+		// Similarly, `min` and `max` are combined into a single `range`
+		// validation, at the position of the first one.
+		let min =
+			preprocessors
+				.iter()
+				.find_map(|preprocessor| match preprocessor {
+					Preprocessor::Min(min) => Some(min.clone()),
+					_ => None,
+				});
+		let max =
+			preprocessors
+				.iter()
+				.find_map(|preprocessor| match preprocessor {
+					Preprocessor::Max(max) => Some(max.clone()),
+					_ => None,
+				});
+		if let Some(error) =
+			range_shorthand_error(preprocessors, min.as_ref(), max.as_ref())
+		{
+			return (respan(error, span), ty.clone());
+		}
+		let mut range = Some(Preprocessor::Range { min, max });
+
+		// `lower_bound` and `upper_bound` are emitted together as well, at the
+		// position of the first one, but keep their own error messages.
+		let lower_bound =
+			preprocessors
+				.iter()
+				.find_map(|preprocessor| match preprocessor {
+					Preprocessor::LowerBound(lower) => Some(lower.clone()),
+					_ => None,
+				});
+		let upper_bound =
+			preprocessors
+				.iter()
+				.find_map(|preprocessor| match preprocessor {
+					Preprocessor::UpperBound(upper) => Some(upper.clone()),
+					_ => None,
+				});
+		if let Some(error) =
+			bound_order_error(lower_bound.as_ref(), upper_bound.as_ref())
+		{
+			return (respan(error, span), ty.clone());
+		}
+		let mut bounds = Some(
+			lower_bound
+				.map(Preprocessor::LowerBound)
+				.into_iter()
+				.chain(upper_bound.map(Preprocessor::UpperBound))
+				.collect::<Vec<_>>(),
+		);
+
+		if let Some(error) = exact_length_error(preprocessors) {
+			return (respan(error, span), ty.clone());
+		}
+		if let Some(error) = warn_only_error(preprocessors) {
+			return (respan(error, span), ty.clone());
+		}
+		if let Some(error) = map_error_error(preprocessors) {
+			return (respan(error, span), ty.clone());
+		}
+
+		let (mut chain, new_ty) = preprocessors.iter().fold(
+			(quote! {}, respan(ty.clone(), span)),
+			|(mut acc, ty), preprocessor| {
+				match preprocessor {
+					// The warning is spanned to the field here, since
+					// `as_processor_token_stream` doesn't know its span
+					Preprocessor::Suggestion {
+						preprocessor,
+						message,
+					} => {
+						acc.extend(warning(span, message));
+						acc.extend(
+							preprocessor
+								.as_processor_token_stream(binding, &ty),
+						);
+					}
+					Preprocessor::MinWords(_) | Preprocessor::MaxWords(_) => {
+						if let Some(word_count) = word_count.take() {
+							acc.extend(
+								word_count
+									.as_processor_token_stream(binding, &ty),
+							);
+						}
+					}
+					Preprocessor::MinItems(_) | Preprocessor::MaxItems(_) => {
+						if let Some(item_count) = item_count.take() {
+							acc.extend(
+								item_count
+									.as_processor_token_stream(binding, &ty),
+							);
+						}
+					}
+					Preprocessor::MinBytes(_) | Preprocessor::MaxBytes(_) => {
+						if let Some(length_bytes) = length_bytes.take() {
+							if preprocessors.iter().any(|preprocessor| {
+								matches!(
+									preprocessor,
+									Preprocessor::Length { .. }
+								)
+							}) {
+								acc.extend(warning(
+									span,
+									concat!(
+									"`length` counts the characters of the value, while ",
+									"`min_bytes` and `max_bytes` count its bytes, which ",
+									"differ for non-ASCII characters"
+								),
+								));
+							}
+							acc.extend(
+								length_bytes
+									.as_processor_token_stream(binding, &ty),
+							);
+						}
+					}
+					Preprocessor::Min(_) | Preprocessor::Max(_) => {
+						if let Some(range) = range.take() {
+							acc.extend(
+								range.as_processor_token_stream(binding, &ty),
+							);
+						}
+					}
+					Preprocessor::LowerBound(_) |
+					Preprocessor::UpperBound(_) => {
+						if let Some(bounds) = bounds.take() {
+							if preprocessors.iter().any(|preprocessor| {
+								matches!(
+									preprocessor,
+									Preprocessor::Range { .. } |
+										Preprocessor::Min(_) | Preprocessor::Max(_)
+								)
+							}) {
+								acc.extend(warning(
+									span,
+									concat!(
+									"`lower_bound` and `upper_bound` check the same ",
+									"bounds as `range`, `min` and `max`, so only one ",
+									"of them should be used on a field"
+								),
+								));
+							}
+							for bound in bounds {
+								acc.extend(
+									bound.as_processor_token_stream(
+										binding, &ty,
+									),
+								);
+							}
+						}
+					}
+					_ => acc.extend(
+						preprocessor
+							.incompatible_type_error(&ty)
+							.map(|error| respan(error, span))
+							.unwrap_or_else(|| {
+								preprocessor
+									.as_processor_token_stream(binding, &ty)
+							}),
 					),
-					|(mut acc, new_ty), preprocessor| {
-						let new_ty = preprocessor.get_new_type(&new_ty);
-						acc.extend(preprocessor.as_processor_token_stream(
-							&format_ident!("value"),
-							&new_ty,
-						));
+				}
+				(acc, respan(preprocessor.get_new_type(&ty), span))
+			},
+		);
 
-						(acc, new_ty)
-					},
-				);
-				quote! {
-					let #field_name: ::core::option::Option<#new_type> = ::core::option::Option::map::<::core::result::Result<#new_type, ::preprocess::Error>, _>(#field_name, |value| {
-						#preprocessors
-						Ok(value)
-					})
-					.transpose()
-					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+		// The error is mapped before it is redacted, so that custom messages of
+		// sensitive fields cannot contain the value either.
+		let error_mapper = preprocessors.iter().find_map(|preprocessor| {
+			match preprocessor {
+				Preprocessor::MapError(map_error) => {
+					let map_error = syn::parse_str::<Path>(map_error)
+						.expect("map_error path is validated while parsing");
+					Some(quote! { #map_error })
 				}
+				Preprocessor::ErrorMessage(message) => Some(quote! {
+					|err: ::preprocess::Error| ::preprocess::Error::new(#message).set_field(err.field)
+				}),
+				_ => None,
 			}
+		});
+		if let Some(error_mapper) = error_mapper {
+			chain = quote! {
+				let #binding: #new_ty = (|| -> ::core::result::Result<#new_ty, ::preprocess::Error> {
+					#chain
+					Ok(#binding)
+				})()
+				.map_err(#error_mapper)?;
+			};
+		}
 
-			Preprocessor::Email => quote! {
-				let #field_name: #new_ty = ::preprocess::validators::validate_email(#field_name)
-					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
-			},
-			Preprocessor::Domain => quote! {
-				let #field_name: #new_ty = ::preprocess::validators::validate_domain(#field_name)
+		if preprocessors
+			.iter()
+			.any(|preprocessor| matches!(preprocessor, Preprocessor::Sensitive))
+		{
+			chain = quote! {
+				let #binding: #new_ty = (|| -> ::core::result::Result<#new_ty, ::preprocess::Error> {
+					#chain
+					Ok(#binding)
+				})()
+				.map_err(::preprocess::Error::redacted)?;
+			};
+		}
+
+		// With `warn_only`, errors are logged and the original value is kept.
+		// The processed value is converted back into the original type.
+		let new_ty = if matches!(
+			preprocessors.last(),
+			Some(Preprocessor::WarnOnly)
+		) {
+			chain = quote! {
+				let #binding: #ty = match (|| -> ::core::result::Result<#new_ty, ::preprocess::Error> {
+					let #binding = ::core::clone::Clone::clone(&#binding);
+					#chain
+					Ok(#binding)
+				})() {
+					::core::result::Result::Ok(processed) => ::core::convert::Into::into(processed),
+					::core::result::Result::Err(err) => {
+						::preprocess::__private::tracing::warn!(
+							field = ::std::stringify!(#binding),
+							error = %err.message,
+							"preprocessing failed, keeping the original value",
+						);
+						#binding
+					}
+				};
+			};
+			ty.clone()
+		} else {
+			new_ty
+		};
+
+		let mut defaults =
+			preprocessors.iter().filter_map(
+				|preprocessor| match preprocessor {
+					// String literals are converted so that they can be used
+					// as the default for fields of type `String` or `Cow`
+					Preprocessor::Default(Expr::Lit(ExprLit {
+						lit: Lit::Str(lit),
+						..
+					})) |
+					Preprocessor::OnError(Expr::Lit(ExprLit {
+						lit: Lit::Str(lit),
+						..
+					})) if new_ty.to_string() == ty.to_string() ||
+						is_string_type(&new_ty) =>
+					{
+						Some(quote! {
+							::std::convert::Into::into(#lit)
+						})
+					}
+					// If the chain turned the value into something else, like a
+					// `Url` or a number, the literal is parsed into that type
+					Preprocessor::Default(Expr::Lit(ExprLit {
+						lit: Lit::Str(lit),
+						..
+					})) |
+					Preprocessor::OnError(Expr::Lit(ExprLit {
+						lit: Lit::Str(lit),
+						..
+					})) => Some(quote! {
+						<#new_ty as ::core::str::FromStr>::from_str(#lit)
+							.map_err(|err| {
+								::preprocess::Error::with_source(
+									"invalid fallback value",
+									err,
+								)
+								.set_field(::std::stringify!(#binding))
+							})?
+					}),
+					Preprocessor::Default(expr) |
+					Preprocessor::OnError(expr) => Some(quote! { #expr }),
+					Preprocessor::DefaultFn(default_fn) => {
+						let default_fn = format_ident!("{default_fn}");
+						Some(quote! { #default_fn() })
+					}
+					_ => None,
+				},
+			);
+		if let Some(default) = defaults.next() {
+			if defaults.next().is_some() {
+				return (
+					respan(
+						quote! {
+							::std::compile_error!("only one of `default`, `default_fn` or `on_error` can be used on a field");
+						},
+						span,
+					),
+					new_ty,
+				);
+			}
+
+			chain = quote! {
+				let #binding: #new_ty = match (|| -> ::core::result::Result<#new_ty, ::preprocess::Error> {
+					#chain
+					Ok(#binding)
+				})() {
+					::core::result::Result::Ok(#binding) => #binding,
+					::core::result::Result::Err(_) => #default,
+				};
+			};
+		}
+
+		(respan_compile_errors(chain, span), new_ty)
+	}
+
+	/// Generates the code for preprocessing a single field of a struct or an
+	/// enum variant. This is the same as [`Self::chain_token_stream`], except
+	/// that the chain is preprocessed inside a tracing span if the `tracing`
+	/// feature is enabled. Spans of sensitive fields are emitted at the `TRACE`
+	/// level with the value redacted.
+	pub fn field_token_stream(
+		preprocessors: &[Preprocessor],
+		binding: &Ident,
+		ty: &TokenStream2,
+		span: Span,
+	) -> TokenStream2 {
+		let (chain, _) =
+			Self::chain_token_stream(preprocessors, binding, ty, span);
+		if !cfg!(feature = "tracing") || preprocessors.is_empty() {
+			return chain;
+		}
+
+		let field_span = if preprocessors
+			.iter()
+			.any(|preprocessor| matches!(preprocessor, Preprocessor::Sensitive))
+		{
+			quote! {
+				::preprocess::__private::tracing::trace_span!(
+					"preprocess::field",
+					field = ::std::stringify!(#binding),
+					value = "[redacted]",
+				)
+			}
+		} else {
+			quote! {
+				::preprocess::__private::tracing::debug_span!(
+					"preprocess::field",
+					field = ::std::stringify!(#binding),
+				)
+			}
+		};
+
+		quote! {
+			let #binding = {
+				let _span = #field_span.entered();
+				#chain
+				#binding
+			};
+		}
+	}
+
+	pub fn as_processor_token_stream(
+		&self,
+		field_name: &Ident,
+		ty: &TokenStream2,
+	) -> TokenStream2 {
+		let new_ty = self.get_new_type(ty);
+
+		match self {
+			Preprocessor::None => quote! {},
+
+			Preprocessor::Optional(preprocessors) => {
+				let (preprocessors, new_type) = Self::chain_token_stream(
+					preprocessors,
+					&format_ident!("value"),
+					&ty.to_token_stream()
+						.to_string()
+						.trim()
+						.trim_start_matches("::std::option::Option")
+						.trim()
+						.trim_start_matches(":: std :: option :: Option")
+						.trim()
+						.trim_start_matches("::core::option::Option")
+						.trim()
+						.trim_start_matches(":: core :: option :: Option")
+						.trim()
+						.trim_start_matches("Option")
+						.trim()
+						.trim_start_matches("<")
+						.trim()
+						.trim_end_matches(">")
+						.trim()
+						.parse()
+						.expect("unable to parse token stream"),
+					// The outer chain respans the nested chain to the field
+					Span::call_site(),
+				);
+				quote! {
+					let #field_name: ::core::option::Option<#new_type> = ::core::option::Option::map::<::core::result::Result<#new_type, ::preprocess::Error>, _>(#field_name, |value| {
+						#preprocessors
+						Ok(value)
+					})
+					.transpose()
 					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::Keys(preprocessors) => {
+				let Some((_, key_type, _)) = split_map_type(ty) else {
+					return not_a_map_error("keys");
+				};
+				let (preprocessors, new_key_type) = Self::chain_token_stream(
+					preprocessors,
+					&format_ident!("key"),
+					&key_type.to_token_stream(),
+					// The outer chain respans the nested chain to the field
+					Span::call_site(),
+				);
+				// Keys that are identical after preprocessing are rejected
+				// instead of silently overwriting each other
+				let map = map_ident();
+				quote! {
+					let #field_name: #new_ty = {
+						let mut #map = <#new_ty as ::core::default::Default>::default();
+						for (key, value) in ::std::iter::IntoIterator::into_iter(#field_name) {
+							let key = (|| -> ::core::result::Result<#new_key_type, ::preprocess::Error> {
+								#preprocessors
+								Ok(key)
+							})()
+							.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+							if #map.contains_key(&key) {
+								return ::core::result::Result::Err(
+									::preprocess::Error::duplicate_key(&key)
+										.set_field(::std::stringify!(#field_name))
+								);
+							}
+							#map.insert(key, value);
+						}
+						#map
+					};
+				}
+			}
+			Preprocessor::Values(preprocessors) => {
+				let Some((_, _, value_type)) = split_map_type(ty) else {
+					return not_a_map_error("values");
+				};
+				let (preprocessors, _) = Self::chain_token_stream(
+					preprocessors,
+					&format_ident!("value"),
+					&value_type.to_token_stream(),
+					// The outer chain respans the nested chain to the field
+					Span::call_site(),
+				);
+				quote! {
+					let #field_name: #new_ty = ::std::iter::IntoIterator::into_iter(#field_name)
+						.map(|(key, value)| {
+							#preprocessors
+							Ok((key, value))
+						})
+						.collect::<::core::result::Result<#new_ty, ::preprocess::Error>>()
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::EachKeyValue {
+				key_preprocessors,
+				value_preprocessors,
+			} => {
+				let Some((_, key_type, value_type)) = split_map_type(ty) else {
+					return not_a_map_error(
+						if key_preprocessors.is_empty() {
+							"each_value"
+						} else {
+							"each_key"
+						},
+					);
+				};
+				let map = map_ident();
+				let key = Ident::new("key", Span::mixed_site());
+				let value = Ident::new("value", Span::mixed_site());
+				let (key_preprocessors_stream, new_key_type) =
+					Self::chain_token_stream(
+						key_preprocessors,
+						&key,
+						&key_type.to_token_stream(),
+						// The outer chain respans the nested chain to the
+						// field
+						Span::call_site(),
+					);
+				let (value_preprocessors_stream, new_value_type) =
+					Self::chain_token_stream(
+						value_preprocessors,
+						&value,
+						&value_type.to_token_stream(),
+						Span::call_site(),
+					);
+				// The keys of the original map are unique, so they can only
+				// collide if they are preprocessed
+				let duplicate_check =
+					(!key_preprocessors.is_empty()).then(|| {
+						quote! {
+							if #map.contains_key(&#key) {
+								return ::core::result::Result::Err(
+									::preprocess::Error::duplicate_key(&#key)
+										.set_field(::std::stringify!(#field_name))
+								);
+							}
+						}
+					});
+				quote! {
+					let #field_name: #new_ty = {
+						let mut #map = <#new_ty as ::core::default::Default>::default();
+						for (#key, #value) in ::std::iter::IntoIterator::into_iter(#field_name) {
+							let (#key, #value) = (|| -> ::core::result::Result<(#new_key_type, #new_value_type), ::preprocess::Error> {
+								#key_preprocessors_stream
+								#value_preprocessors_stream
+								Ok((#key, #value))
+							})()
+							.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+							#duplicate_check
+							#map.insert(#key, #value);
+						}
+						#map
+					};
+				}
+			}
+			Preprocessor::CsvItems {
+				preprocessors,
+				delimiter,
+			} => {
+				let (preprocessors, _) = Self::chain_token_stream(
+					preprocessors,
+					&format_ident!("item"),
+					&quote! { ::std::string::String },
+					// The outer chain respans the nested chain to the field
+					Span::call_site(),
+				);
+				let separator = delimiter.to_string();
+				// An empty string has no items, instead of a single empty item
+				quote! {
+					let #field_name: #new_ty = match ::std::convert::AsRef::<str>::as_ref(&#field_name) {
+						"" => ::std::string::String::new(),
+						value => value
+							.split(#delimiter)
+							.map(|item| {
+								let item = ::std::string::String::from(item);
+								#preprocessors
+								Ok(::std::string::ToString::to_string(&item))
+							})
+							.collect::<::core::result::Result<::std::vec::Vec<_>, ::preprocess::Error>>()
+							.map_err(|err| err.set_field(::std::stringify!(#field_name)))?
+							.join(#separator),
+					};
+				}
+			}
+
+			// Modifiers are applied to the entire chain in
+			// `Preprocessor::chain_token_stream`.
+			Preprocessor::Default(_) |
+			Preprocessor::DefaultFn(_) |
+			Preprocessor::OnError(_) |
+			Preprocessor::Sensitive => quote! {},
+			Preprocessor::WarnOnly => quote! {},
+			Preprocessor::MapError(_) | Preprocessor::ErrorMessage(_) => {
+				quote! {}
+			}
+			// Cross-field validators are applied in
+			// `ProcessedFields::confirm_token_stream`, after all the fields
+			// are processed.
+			Preprocessor::Confirm { .. } => quote! {},
+			// Struct-level validators are filtered out by `process_struct`
+			Preprocessor::ExactlyOneOf(_) => quote! {
+				::std::compile_error!("`exactly_one_of` can only be used on structs, not on fields or enums");
 			},
-			Preprocessor::Url => quote! {
-				let #field_name: #new_ty = ::preprocess::validators::validate_url(#field_name)
+			Preprocessor::MapField { preprocessors, .. } => {
+				// The outer chain respans the nested chain to the field
+				Self::chain_token_stream(
+					preprocessors,
+					field_name,
+					ty,
+					Span::call_site(),
+				)
+				.0
+			}
+
+			Preprocessor::Email {
+				allow_subaddressing,
+				normalize_subaddress,
+			} => {
+				let normalize_subaddress = normalize_subaddress.then(|| {
+					quote! {
+						let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_strip_subaddress(#field_name)
+							.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+					}
+				});
+				quote! {
+					#normalize_subaddress
+					let #field_name: #new_ty = ::preprocess::validators::validate_email(#field_name, #allow_subaddressing)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::Domain => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_domain(#field_name)
 					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 			},
-			Preprocessor::Length { min, max, equal } => {
+			Preprocessor::Url {
+				schemes,
+				no_credentials,
+				allow_fragment,
+			} => {
+				let schemes = schemes.as_ref().map(|schemes| {
+					quote! {
+						let #field_name: #new_ty = ::preprocess::validators::validate_url_scheme(#field_name, &[#(#schemes),*])
+							.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+					}
+				});
+				let no_credentials = no_credentials.then(|| {
+					quote! {
+						let #field_name: #new_ty = ::preprocess::validators::validate_url_no_credentials(#field_name)
+							.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+					}
+				});
+				let no_fragment = (!allow_fragment).then(|| {
+					quote! {
+						let #field_name: #new_ty = ::preprocess::validators::validate_url_no_fragment(#field_name)
+							.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+					}
+				});
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_url(#field_name)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+					#schemes
+					#no_credentials
+					#no_fragment
+				}
+			}
+			Preprocessor::Length { min, max, equal } |
+			Preprocessor::LengthBytes { min, max, equal } => {
+				let validator = if let Preprocessor::LengthBytes { .. } = self {
+					if let Some(error) = non_byte_type_error(ty) {
+						return error;
+					}
+					format_ident!("validate_length_bytes")
+				} else {
+					format_ident!("validate_length")
+				};
 				let min = min
 					.as_ref()
 					.map(|min| {
@@ -276,7 +1797,7 @@ impl Preprocessor {
 						}
 					});
 				quote! {
-					let #field_name: #new_ty = ::preprocess::validators::validate_length(#field_name, #min, #max, #equal)
+					let #field_name: #new_ty = ::preprocess::validators::#validator(#field_name, #min, #max, #equal)
 						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 				}
 			}
@@ -311,214 +1832,2913 @@ impl Preprocessor {
 						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 				}
 			}
-			Preprocessor::Contains(look_for) => quote! {
-				let #field_name: #new_ty = ::preprocess::validators::validate_contains(#field_name, #look_for)
+			Preprocessor::WordCount { min, max } => {
+				let min = min
+					.as_ref()
+					.map(|min| {
+						quote! {
+							::std::option::Option::Some(#min)
+						}
+					})
+					.unwrap_or_else(|| {
+						quote! {
+							::std::option::Option::None
+						}
+					});
+				let max = max
+					.as_ref()
+					.map(|max| {
+						quote! {
+							::std::option::Option::Some(#max)
+						}
+					})
+					.unwrap_or_else(|| {
+						quote! {
+							::std::option::Option::None
+						}
+					});
+
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_word_count(#field_name, #min, #max)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::ItemCount { min, max } => {
+				let min = min
+					.as_ref()
+					.map(|min| {
+						quote! {
+							::std::option::Option::Some(#min)
+						}
+					})
+					.unwrap_or_else(|| {
+						quote! {
+							::std::option::Option::None
+						}
+					});
+				let max = max
+					.as_ref()
+					.map(|max| {
+						quote! {
+							::std::option::Option::Some(#max)
+						}
+					})
+					.unwrap_or_else(|| {
+						quote! {
+							::std::option::Option::None
+						}
+					});
+
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_item_count(#field_name, #min, #max)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::MinItems(min) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_item_count(#field_name, ::std::option::Option::Some(#min), ::std::option::Option::None)
 					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 			},
-			Preprocessor::DoesNotContain(look_for) => quote! {
-				let #field_name: #new_ty = ::preprocess::validators::validate_does_not_contain(#field_name, #look_for)
+			Preprocessor::MaxItems(max) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_item_count(#field_name, ::std::option::Option::None, ::std::option::Option::Some(#max))
 					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 			},
-			Preprocessor::Custom(validator) => {
-				let validator = format_ident!("{validator}");
+			Preprocessor::ExactLength(length) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_exact_length(#field_name, #length)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::NoWhitespace => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_no_whitespace(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::NoControlChars => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_no_control_chars(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::NoLeadingWhitespace => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_no_leading_whitespace(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::NoTrailingWhitespace => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_no_trailing_whitespace(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::CsvLength {
+				min,
+				max,
+				delimiter,
+			} => {
+				let [min, max] = [min, max].map(|bound| match bound {
+					Some(bound) => quote! {
+						::std::option::Option::Some(#bound)
+					},
+					None => quote! {
+						::std::option::Option::None
+					},
+				});
 				quote! {
-					let #field_name: #new_ty = #validator (#field_name)
+					let #field_name: #new_ty = ::preprocess::validators::validate_csv_length(#field_name, #delimiter, #min, #max)
 						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 				}
 			}
-			Preprocessor::Regex(regex) => quote! {
-				let #field_name: #new_ty = ::preprocess::validators::validate_regex(#field_name, #regex)
+			Preprocessor::MinBytes(min) => Preprocessor::LengthBytes {
+				min: Some(min.clone()),
+				max: None,
+				equal: None,
+			}
+			.as_processor_token_stream(field_name, ty),
+			Preprocessor::MaxBytes(max) => Preprocessor::LengthBytes {
+				min: None,
+				max: Some(max.clone()),
+				equal: None,
+			}
+			.as_processor_token_stream(field_name, ty),
+			Preprocessor::MinWords(min) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_word_count(#field_name, ::std::option::Option::Some(#min), ::std::option::Option::None)
 					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 			},
-			Preprocessor::Nested => quote! {
-				let #field_name: <#ty as ::preprocess::Preprocessable>::Processed = ::preprocess::Preprocessable::preprocess(#field_name)
+			Preprocessor::MaxWords(max) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_word_count(#field_name, ::std::option::Option::None, ::std::option::Option::Some(#max))
 					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 			},
-			Preprocessor::Type(r#type) => {
-				let ident = format_ident!("{}", r#type);
+			Preprocessor::NumberInRange {
+				min,
+				max,
+				integer_only,
+			} => {
+				let min = min
+					.map(|min| {
+						let min = Literal::f64_suffixed(min);
+						quote! {
+							::std::option::Option::Some(#min)
+						}
+					})
+					.unwrap_or_else(|| {
+						quote! {
+							::std::option::Option::None
+						}
+					});
+				let max = max
+					.map(|max| {
+						let max = Literal::f64_suffixed(max);
+						quote! {
+							::std::option::Option::Some(#max)
+						}
+					})
+					.unwrap_or_else(|| {
+						quote! {
+							::std::option::Option::None
+						}
+					});
 				quote! {
-					::std::convert::TryInto::<#ident>::try_into
+					let #field_name: #new_ty = ::preprocess::validators::validate_number_in_range(#field_name, #min, #max, #integer_only)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 				}
 			}
-
-			Preprocessor::Ip(IpPreprocessorType::V4) => quote! {
-				let #field_name: #new_ty = ::preprocess::validators::validate_ipv4(#field_name)
-					.map_err(|err| err.set_field(::std::stringify(#field_name)))?;
+			Preprocessor::NumericString {
+				integer_only: false,
+				positive_only: false,
+				no_scientific: false,
+			} => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_numeric_string(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 			},
-			Preprocessor::Ip(IpPreprocessorType::V6) => quote! {
-				let #field_name: #new_ty = ::preprocess::validators::validate_ipv6(#field_name)
-					.map_err(|err| err.set_field(::std::stringify(#field_name)))?;
+			Preprocessor::NumericString {
+				integer_only,
+				positive_only,
+				no_scientific,
+			} => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_numeric_string_with_options(
+					#field_name,
+					#integer_only,
+					#positive_only,
+					#no_scientific,
+				)
+				.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 			},
-			Preprocessor::Ip(IpPreprocessorType::Any) => quote! {
-				let #field_name: #new_ty = ::preprocess::validators::validate_ip(#field_name)
-					.map_err(|err| err.set_field(::std::stringify(#field_name)))?;
+			Preprocessor::Min(min) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_range(#field_name, ::std::option::Option::Some(#min), ::std::option::Option::None)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 			},
-
-			Preprocessor::Trim => quote! {
-				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_trim(#field_name)
+			Preprocessor::Max(max) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_range(#field_name, ::std::option::Option::None, ::std::option::Option::Some(#max))
 					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 			},
-
-			Preprocessor::Lowercase => quote! {
-				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_lowercase(#field_name)
+			Preprocessor::LowerBound(lower) => {
+				let message = format!(
+					"value must be at least {}",
+					bound_to_string(lower)
+				);
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_range(#field_name, ::std::option::Option::Some(#lower), ::std::option::Option::None)
+						.map_err(|_| ::preprocess::Error::new(#message).set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::UpperBound(upper) => {
+				let message =
+					format!("value must not exceed {}", bound_to_string(upper));
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_range(#field_name, ::std::option::Option::None, ::std::option::Option::Some(#upper))
+						.map_err(|_| ::preprocess::Error::new(#message).set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::Contains(look_for) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_contains(#field_name, #look_for)
 					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 			},
-			Preprocessor::Uppercase => quote! {
-				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_uppercase(#field_name)
+			Preprocessor::DoesNotContain(look_for) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_does_not_contain(#field_name, #look_for)
 					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
 			},
-		}
-	}
-}
-
-impl TryFrom<Meta> for Preprocessor {
-	type Error = Error;
-
-	/// By the time it comes to this function, this is what is passed:
-	/// #[preprocess(length(min = 1, max = 10))]
-	///              ^^^^^^^^^^^^^^^^^^^^^^^^
-	/// #[preprocess(email, url, custom = "some-custom-validator")]
-	///              ^^^^^  ^^^  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
-	/// #[preprocess(regex = "some-regexp")]
-	///              ^^^^^^^^^^^^^^^^^^^^^
-	fn try_from(value: Meta) -> Result<Self, Self::Error> {
-		match value {
-			// #[preprocess(optional(...))]
-			Meta::List(list) if list.path.is_ident("optional") => {
-				let args = list.parse_args_with(
-					Punctuated::<Meta, Token![,]>::parse_terminated,
-				)?;
-
+			Preprocessor::ContainsItem(item) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_contains_item(#field_name, &#item)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::DoesNotContainItem(item) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_does_not_contain_item(#field_name, &#item)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Whitelist(items) => {
+				if let Some(error) =
+					list_item_type_error("whitelist", items, ty)
+				{
+					return error;
+				}
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_whitelist(#field_name, &[#(#items),*])
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::Blacklist(items) => {
+				if let Some(error) =
+					list_item_type_error("blacklist", items, ty)
+				{
+					return error;
+				}
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_blacklist(#field_name, &[#(#items),*])
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::Equal(value) | Preprocessor::NotEqual(value) => {
+				let (name, validator) = if let Preprocessor::Equal(_) = self {
+					("equal", format_ident!("validate_equal"))
+				} else {
+					("not_equal", format_ident!("validate_not_equal"))
+				};
+				if let Some(error) = value_type_error(name, value, ty) {
+					return error;
+				}
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::#validator(#field_name, &#value)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::EnumVariant(enum_path) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_enum_variant::<#enum_path, _>(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Custom(validator) => {
+				let validator = format_ident!("{validator}");
+				quote! {
+					let #field_name: #new_ty = #validator (#field_name)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::CustomType(validator) => quote! {
+				let #field_name: #new_ty = <#validator as ::preprocess::FieldValidator<#ty>>::validate(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			// Transforms cannot fail, so the result is used as is, without
+			// any error handling.
+			Preprocessor::Transform(transform) => {
+				let transform = syn::parse_str::<Path>(transform)
+					.expect("transform path is validated while parsing");
+				quote! {
+					let #field_name: #new_ty = #transform (#field_name);
+				}
+			}
+			Preprocessor::TransformRef(transform) => {
+				let transform = syn::parse_str::<Path>(transform)
+					.expect("transform path is validated while parsing");
+				quote! {
+					let #field_name: #new_ty = #transform (&#field_name);
+				}
+			}
+			Preprocessor::Regex(regex) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_regex(#field_name, #regex)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::RegexNamed(regex) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_regex(#field_name, #regex)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::AllMatch(patterns) => {
+				let (labels, regexes) =
+					patterns.iter().cloned().unzip::<_, _, Vec<_>, Vec<_>>();
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_all_match(#field_name, &[#((::std::stringify!(#labels), #regexes)),*])
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::RegexRef(regex) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_regex_with_ref(#field_name, &#regex)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Unique => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_unique(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::UniqueSorted => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_unique_sorted(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Positive => {
+				if let Some(error) = non_numeric_type_error("positive", ty)
+					.or_else(|| unsigned_type_error("positive", ty))
+				{
+					return error;
+				}
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_positive(#field_name)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::Negative => {
+				if let Some(error) = non_numeric_type_error("negative", ty)
+					.or_else(|| unsigned_type_error("negative", ty))
+				{
+					return error;
+				}
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_negative(#field_name)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::NonNegative => {
+				if let Some(error) = non_numeric_type_error("non_negative", ty)
+					.or_else(|| unsigned_type_error("non_negative", ty))
+				{
+					return error;
+				}
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_non_negative(#field_name)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::NonNan => {
+				if let Some(error) = non_numeric_type_error("non_nan", ty)
+					.or_else(|| integer_type_error("non_nan", ty))
+				{
+					return error;
+				}
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_non_nan(#field_name)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::Finite => {
+				if let Some(error) = non_numeric_type_error("finite", ty)
+					.or_else(|| integer_type_error("finite", ty))
+				{
+					return error;
+				}
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_finite(#field_name)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::NonZero => {
+				if let Some(error) = non_numeric_type_error("non_zero", ty) {
+					return error;
+				}
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_non_zero(#field_name)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::Nested => quote! {
+				let #field_name: <#ty as ::preprocess::Preprocessable>::Processed = ::preprocess::Preprocessable::preprocess(#field_name)
+					.map_err(|err| ::preprocess::__private::nested_error(err).set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Coerce(_) => quote! {
+				let #field_name: #new_ty = ::std::convert::TryFrom::try_from(#field_name)
+					.map_err(|err| ::preprocess::Error::new(::std::string::ToString::to_string(&err)).set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::FromStr { .. } => quote! {
+				let #field_name: #new_ty = ::std::primitive::str::parse(&#field_name)
+					.map_err(|err| ::preprocess::Error::with_source(::std::string::ToString::to_string(&err), err).set_field(::std::stringify!(#field_name)))?;
+			},
+
+			Preprocessor::Ip(IpPreprocessorType::V4) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_ipv4(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Ip(IpPreprocessorType::V6) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_ipv6(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Suggestion {
+				preprocessor,
+				message,
+			} => {
+				let processor =
+					preprocessor.as_processor_token_stream(field_name, ty);
+				let warning = warning(Span::call_site(), message);
+				quote! {
+					#warning
+					#processor
+				}
+			}
+			Preprocessor::Ip(IpPreprocessorType::Any) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_ip(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Ip(IpPreprocessorType::IpInNetwork { network }) => {
+				quote! {
+					let #field_name: #new_ty = {
+						static NETWORK: ::std::sync::OnceLock<(::std::net::IpAddr, u8)> = ::std::sync::OnceLock::new();
+						let network = *NETWORK.get_or_init(|| {
+							::preprocess::validators::validate_ip_cidr_pair(#network, true)
+								.expect("the network should be checked at compile time")
+						});
+						::preprocess::validators::validate_ip_in_network(#field_name, network)
+					}
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::Ip(IpPreprocessorType::NormalizeV4) => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_normalize_ipv4(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Ip(IpPreprocessorType::NormalizeV6) => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_normalize_ipv6(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Ip(IpPreprocessorType::NormalizeAny) => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_normalize_ip(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::FutureDate | Preprocessor::PastDate => {
+				let (name, validator) = if let Preprocessor::FutureDate = self {
+					("future_date", format_ident!("validate_future_date"))
+				} else {
+					("past_date", format_ident!("validate_past_date"))
+				};
+				if let Some(error) = non_date_type_error(name, ty) {
+					return error;
+				}
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::#validator(#field_name)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::MinAge { years } | Preprocessor::MaxAge { years } => {
+				let (name, validator) =
+					if let Preprocessor::MinAge { .. } = self {
+						("min_age", format_ident!("validate_min_age"))
+					} else {
+						("max_age", format_ident!("validate_max_age"))
+					};
+				if let Some(error) = non_date_type_error(name, ty) {
+					return error;
+				}
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::#validator(#field_name, #years)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::PrivateIpOnly => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_private_ip(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::PublicIpOnly => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_public_ip(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Ipv4Mapped => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_ipv4_mapped(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::NoScriptTags => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_no_script_tags(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::RequirePrefix(prefix) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_require_prefix(#field_name, #prefix)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::RequireSuffix(suffix) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_require_suffix(#field_name, #suffix)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Uuid { version, as_string } => {
+				let uuid = match version {
+					UuidType::Any => quote! {
+						::preprocess::validators::validate_uuid(#field_name)
+					},
+					UuidType::V1 => quote! {
+						::preprocess::validators::validate_uuid_version(#field_name, 1)
+					},
+					UuidType::V3 => quote! {
+						::preprocess::validators::validate_uuid_version(#field_name, 3)
+					},
+					UuidType::V4 => quote! {
+						::preprocess::validators::validate_uuid_version(#field_name, 4)
+					},
+					UuidType::V5 => quote! {
+						::preprocess::validators::validate_uuid_version(#field_name, 5)
+					},
+				};
+				let to_string = as_string.then(|| {
+					quote! {
+						.map(|uuid| ::std::string::ToString::to_string(&uuid))
+					}
+				});
+				quote! {
+					let #field_name: #new_ty = #uuid
+						#to_string
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::Phone { country, format } => {
+				let country = phone_country_token_stream(country.as_ref());
+				let format = match format {
+					PhoneFormat::E164 => quote! {
+						::preprocess::validators::PhoneFormat::E164
+					},
+					PhoneFormat::Local => quote! {
+						::preprocess::validators::PhoneFormat::Local
+					},
+				};
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_phone(#field_name, #country, #format)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::NormalizePhone { country } => {
+				let country = phone_country_token_stream(country.as_ref());
+				quote! {
+					let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_normalize_phone(#field_name, #country)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::NormalizeIpAny => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_normalize_ip_any(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::IpCidr {
+				strict,
+				as_pair: false,
+			} => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_ip_cidr(#field_name, #strict)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::IpCidr {
+				strict,
+				as_pair: true,
+			} => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_ip_cidr_pair(#field_name, #strict)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Isbn(format) => {
+				let format = match format {
+					Some(IsbnFormat::Isbn10) => quote! {
+						::std::option::Option::Some(::preprocess::validators::IsbnFormat::Isbn10)
+					},
+					Some(IsbnFormat::Isbn13) => quote! {
+						::std::option::Option::Some(::preprocess::validators::IsbnFormat::Isbn13)
+					},
+					None => quote! {
+						::std::option::Option::None
+					},
+				};
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_isbn(#field_name, #format)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::Cron {
+				allow_seconds,
+				next_run_within,
+			} => {
+				let next_run_within = match next_run_within {
+					Some(duration) => {
+						let (secs, nanos) =
+							(duration.as_secs(), duration.subsec_nanos());
+						quote! {
+							::std::option::Option::Some(::std::time::Duration::new(#secs, #nanos))
+						}
+					}
+					None => quote! {
+						::std::option::Option::None
+					},
+				};
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_cron(#field_name, #allow_seconds, #next_run_within)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::ValidJsonPath => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_json_path(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::JsonPath(json_path) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_json_matches_path(#field_name, #json_path)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Interpolate(template) => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_template(#field_name, #template)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::UrlComponent { component } => {
+				let validator = match component {
+					UrlComponentKind::Path => quote!(validate_url_path),
+					UrlComponentKind::Query => quote!(validate_url_query),
+					UrlComponentKind::Fragment => quote!(validate_url_fragment),
+					UrlComponentKind::Host => quote!(validate_url_host),
+					UrlComponentKind::Scheme => {
+						quote!(validate_url_scheme_name)
+					}
+				};
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::#validator(#field_name)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::Version {
+				allow_v_prefix,
+				allow_prerelease,
+				allow_build_metadata,
+				normalize: false,
+			} => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_version(#field_name, #allow_v_prefix, #allow_prerelease, #allow_build_metadata)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Version {
+				allow_prerelease,
+				allow_build_metadata,
+				normalize: true,
+				..
+			} => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_version_normalized(#field_name, #allow_prerelease, #allow_build_metadata)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::PasswordStrength { min_score } => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_password_strength(#field_name, #min_score)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::LanguageCode { strict: false } => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_language_code(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::LanguageCode { strict: true } => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_language_code_strict(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::CurrencyCode { active_only } => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_currency_code(#field_name, #active_only)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::ZipCode { country } => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_zip_code(#field_name, #country)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Date {
+				format,
+				min,
+				max,
+				as_date,
+			} => {
+				let [min, max] = [min, max].map(|date| match date {
+					Some(date) => {
+						let (year, month, day) =
+							(date.year(), date.month(), date.day());
+						quote! {
+							::preprocess::types::NaiveDate::from_ymd_opt(#year, #month, #day)
+						}
+					}
+					None => quote! {
+						::std::option::Option::None
+					},
+				});
+				let validator = if *as_date {
+					quote! { validate_date_as_date }
+				} else {
+					quote! { validate_date }
+				};
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::#validator(#field_name, #format, #min, #max)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::DateTime { format, timezone } => {
+				let validator = match timezone {
+					None => quote! { validate_datetime },
+					Some(DateTimeZone::Utc) => quote! { validate_datetime_utc },
+					Some(DateTimeZone::Local) => {
+						quote! { validate_datetime_local }
+					}
+				};
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::#validator(#field_name, #format)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+
+			Preprocessor::Trim => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_trim(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+
+			Preprocessor::Lowercase => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_lowercase(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Uppercase => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_uppercase(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Abs => {
+				if let Some(error) = non_numeric_type_error("abs", ty)
+					.or_else(|| unsigned_type_error("abs", ty))
+				{
+					return error;
+				}
+				quote! {
+					let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_abs(#field_name)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::Neg => {
+				if let Some(error) = non_numeric_type_error("neg", ty)
+					.or_else(|| unsigned_type_error("neg", ty))
+				{
+					return error;
+				}
+				quote! {
+					let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_neg(#field_name)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::SnakeCase => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_snake_case(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::CamelCase => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_camel_case(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::KebabCase => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_kebab_case(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::PascalCase => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_pascal_case(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::UppercaseFirst => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_uppercase_first(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::LowercaseFirst => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_lowercase_first(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::DigitsOnly => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_digits_only(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::LettersOnly => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_letters_only(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::AlphanumericOnly => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_alphanumeric_only(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::TitleCase { stop_words } => {
+				let stop_words = stop_words
+					.as_ref()
+					.map(|stop_words| {
+						quote! {
+							&[#(#stop_words),*]
+						}
+					})
+					.unwrap_or_else(|| {
+						quote! {
+							::preprocess::preprocessors::DEFAULT_TITLE_CASE_STOP_WORDS
+						}
+					});
+				quote! {
+					let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_title_case(#field_name, #stop_words)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::NormalizeUnicode { form } => {
+				let form = match form {
+					NormalizationForm::Nfc => quote! { Nfc },
+					NormalizationForm::Nfd => quote! { Nfd },
+					NormalizationForm::Nfkc => quote! { Nfkc },
+					NormalizationForm::Nfkd => quote! { Nfkd },
+				};
+				quote! {
+					let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_normalize_unicode(
+						#field_name,
+						::preprocess::preprocessors::NormalizationForm::#form,
+					)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::Hex {
+				length: None,
+				prefix: None,
+				normalize: None,
+			} => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_hex(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Hex {
+				length,
+				prefix,
+				normalize,
+			} => {
+				let length = match length {
+					Some(length) => {
+						quote! { ::std::option::Option::Some(#length) }
+					}
+					None => quote! { ::std::option::Option::None },
+				};
+				let prefix = match prefix {
+					Some(prefix) => {
+						quote! { ::std::option::Option::Some(#prefix) }
+					}
+					None => quote! { ::std::option::Option::None },
+				};
+				let normalize = match normalize {
+					Some(HexCase::Lowercase) => quote! {
+						::std::option::Option::Some(::preprocess::validators::HexCase::Lowercase)
+					},
+					Some(HexCase::Uppercase) => quote! {
+						::std::option::Option::Some(::preprocess::validators::HexCase::Uppercase)
+					},
+					None => quote! { ::std::option::Option::None },
+				};
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_hex_with_options(
+						#field_name,
+						#length,
+						#prefix,
+						#normalize,
+					)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+			Preprocessor::HexDecode => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_hex_decode(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::NormalizeIsbn => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_normalize_isbn(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::StripHtml { decode_entities } => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_strip_html(#field_name, #decode_entities)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::StripSubaddress => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_strip_subaddress(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::StripControlChars => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_strip_control_chars(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::StripNonPrintable => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_strip_non_printable(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::StripPrefix(prefix) => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_strip_prefix(#field_name, #prefix)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::StripSuffix(suffix) => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_strip_suffix(#field_name, #suffix)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::NormalizeZipCode => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_normalize_zip_code(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::NormalizeCurrencyCode => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_normalize_currency_code(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::NormalizeLanguageCode => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_normalize_language_code(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::NumberFormat { thousands, decimal } => quote! {
+				let #field_name: #new_ty = ::preprocess::preprocessors::preprocess_number_format(#field_name, #thousands, #decimal)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::DecimalString => quote! {
+				let #field_name: #new_ty = ::preprocess::validators::validate_decimal_string(#field_name)
+					.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+			},
+			Preprocessor::Alphabet(kind) => {
+				let charset = match kind {
+					AlphabetKind::Base58 => {
+						quote! { ::preprocess::validators::BASE58_ALPHABET }
+					}
+					AlphabetKind::Base62 => {
+						quote! { ::preprocess::validators::BASE62_ALPHABET }
+					}
+					AlphabetKind::Custom(charset) => quote! { #charset },
+				};
+				quote! {
+					let #field_name: #new_ty = ::preprocess::validators::validate_alphabet(#field_name, #charset)
+						.map_err(|err| err.set_field(::std::stringify!(#field_name)))?;
+				}
+			}
+		}
+	}
+}
+
+impl TryFrom<Meta> for Preprocessor {
+	type Error = Error;
+
+	/// By the time it comes to this function, this is what is passed:
+	/// #[preprocess(length(min = 1, max = 10))]
+	///              ^^^^^^^^^^^^^^^^^^^^^^^^
+	/// #[preprocess(email, url, custom = "some-custom-validator")]
+	///              ^^^^^  ^^^  ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+	/// #[preprocess(regex = "some-regexp")]
+	///              ^^^^^^^^^^^^^^^^^^^^^
+	fn try_from(value: Meta) -> Result<Self, Self::Error> {
+		match value {
+			// #[preprocess(optional(...))]
+			Meta::List(list) if list.path.is_ident("optional") => {
+				let args = list.parse_args_with(parse_metas)?;
+
 				let preprocessors =
 					args.into_iter().map(Preprocessor::try_from);
 
-				Ok(Self::Optional(
-					preprocessors.collect::<Result<Vec<_>, Error>>()?,
-				))
+				Ok(Self::Optional(
+					preprocessors.collect::<Result<Vec<_>, Error>>()?,
+				))
+			}
+			// #[preprocess(keys(...))]
+			Meta::List(list) if list.path.is_ident("keys") => {
+				let args = list.parse_args_with(parse_metas)?;
+
+				let preprocessors =
+					args.into_iter().map(Preprocessor::try_from);
+
+				Ok(Self::Keys(
+					preprocessors.collect::<Result<Vec<_>, Error>>()?,
+				))
+			}
+			// #[preprocess(each_key(...))] or #[preprocess(each_value(...))]
+			Meta::List(list)
+				if list.path.is_ident("each_key") ||
+					list.path.is_ident("each_value") =>
+			{
+				let args = list.parse_args_with(parse_metas)?;
+				if args.is_empty() {
+					return Err(Error::new(
+						list.path.span(),
+						"expected at least one preprocessor",
+					));
+				}
+
+				let preprocessors = args
+					.into_iter()
+					.map(Preprocessor::try_from)
+					.collect::<Result<Vec<_>, Error>>()?;
+
+				if list.path.is_ident("each_key") {
+					Ok(Self::EachKeyValue {
+						key_preprocessors: preprocessors,
+						value_preprocessors: vec![],
+					})
+				} else {
+					Ok(Self::EachKeyValue {
+						key_preprocessors: vec![],
+						value_preprocessors: preprocessors,
+					})
+				}
+			}
+			// #[preprocess(csv_items(trim, lowercase, delimiter = ";"))]
+			Meta::List(list) if list.path.is_ident("csv_items") => {
+				let args = list.parse_args_with(parse_metas)?;
+
+				let mut delimiter = None;
+				let mut preprocessors = vec![];
+				for meta in args {
+					match meta {
+						Meta::NameValue(meta)
+							if meta.path.is_ident("delimiter") =>
+						{
+							if delimiter.is_some() {
+								return Err(Error::new(
+									meta.path.span(),
+									"duplicate argument `delimiter`",
+								));
+							}
+							delimiter =
+								Some(parse_char(meta.value, "delimiter")?);
+						}
+						meta => {
+							preprocessors.push(Preprocessor::try_from(meta)?)
+						}
+					}
+				}
+
+				Ok(Self::CsvItems {
+					preprocessors,
+					delimiter: delimiter.unwrap_or(','),
+				})
+			}
+			// #[preprocess(number_format(thousands = ".", decimal = ","))]
+			Meta::List(list) if list.path.is_ident("number_format") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let (mut thousands, mut decimal) = (None, None);
+				for meta in args {
+					let (option, name) = if meta.path.is_ident("thousands") {
+						(&mut thousands, "thousands separator")
+					} else if meta.path.is_ident("decimal") {
+						(&mut decimal, "decimal separator")
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							"expected argument `thousands` or `decimal`",
+						));
+					};
+					if option.is_some() {
+						return Err(Error::new(
+							meta.path.span(),
+							"duplicate argument",
+						));
+					}
+					let span = meta.value.span();
+					let separator = parse_char(meta.value, name)?;
+					if separator.is_ascii_digit() ||
+						['+', '-'].contains(&separator)
+					{
+						return Err(Error::new(
+							span,
+							format!("the {} cannot be a digit or a sign", name),
+						));
+					}
+					*option = Some((separator, span));
+				}
+
+				// The default of a separator is swapped if the other one is
+				// given as its default, so that `number_format(decimal = ",")`
+				// can be used on its own
+				let (thousands, decimal) = match (thousands, decimal) {
+					(None, None) => (',', '.'),
+					(Some((thousands, _)), None) => {
+						(thousands, if thousands == '.' { ',' } else { '.' })
+					}
+					(None, Some((decimal, _))) => {
+						(if decimal == ',' { '.' } else { ',' }, decimal)
+					}
+					(Some((thousands, _)), Some((decimal, span))) => {
+						if thousands == decimal {
+							return Err(Error::new(
+								span,
+								concat!(
+									"the decimal separator must be different ",
+									"from the thousands separator"
+								),
+							));
+						}
+						(thousands, decimal)
+					}
+				};
+
+				Ok(Self::NumberFormat { thousands, decimal })
+			}
+			// #[preprocess(values(...))]
+			Meta::List(list) if list.path.is_ident("values") => {
+				let args = list.parse_args_with(parse_metas)?;
+
+				let preprocessors =
+					args.into_iter().map(Preprocessor::try_from);
+
+				Ok(Self::Values(
+					preprocessors.collect::<Result<Vec<_>, Error>>()?,
+				))
+			}
+			// #[preprocess(sensitive)]
+			Meta::Path(path) if path.is_ident("sensitive") => {
+				Ok(Self::Sensitive)
+			}
+			// #[preprocess(warn_only)]
+			Meta::Path(path) if path.is_ident("warn_only") => {
+				Ok(Self::WarnOnly)
+			}
+			// #[preprocess(none)]
+			Meta::Path(path) if path.is_ident("none") => Ok(Self::None),
+			// #[preprocess(email)]
+			Meta::Path(path) if path.is_ident("email") => Ok(Self::Email {
+				allow_subaddressing: true,
+				normalize_subaddress: false,
+			}),
+			// #[preprocess(domain)]
+			Meta::Path(path) if path.is_ident("domain") => Ok(Self::Domain),
+			// #[preprocess(url)]
+			Meta::Path(path) if path.is_ident("url") => Ok(Self::Url {
+				schemes: None,
+				no_credentials: false,
+				allow_fragment: true,
+			}),
+			// #[preprocess(unique)]
+			Meta::Path(path) if path.is_ident("unique") => Ok(Self::Unique),
+			// #[preprocess(unique_sorted)]
+			Meta::Path(path) if path.is_ident("unique_sorted") => {
+				Ok(Self::UniqueSorted)
+			}
+			// #[preprocess(positive)]
+			Meta::Path(path) if path.is_ident("positive") => Ok(Self::Positive),
+			// #[preprocess(non_nan)]
+			Meta::Path(path) if path.is_ident("non_nan") => Ok(Self::NonNan),
+			// #[preprocess(finite)]
+			Meta::Path(path) if path.is_ident("finite") => Ok(Self::Finite),
+			// #[preprocess(negative)]
+			Meta::Path(path) if path.is_ident("negative") => Ok(Self::Negative),
+			// #[preprocess(no_whitespace)]
+			Meta::Path(path) if path.is_ident("no_whitespace") => {
+				Ok(Self::NoWhitespace)
+			}
+			// #[preprocess(no_control_characters)]
+			Meta::Path(path) if path.is_ident("no_control_characters") => {
+				Ok(Self::NoControlChars)
+			}
+			// #[preprocess(no_leading_whitespace)]
+			Meta::Path(path) if path.is_ident("no_leading_whitespace") => {
+				Ok(Self::NoLeadingWhitespace)
+			}
+			// #[preprocess(no_trailing_whitespace)]
+			Meta::Path(path) if path.is_ident("no_trailing_whitespace") => {
+				Ok(Self::NoTrailingWhitespace)
+			}
+			// #[preprocess(non_negative)]
+			Meta::Path(path) if path.is_ident("non_negative") => {
+				Ok(Self::NonNegative)
+			}
+			// #[preprocess(non_zero)]
+			Meta::Path(path) if path.is_ident("non_zero") => Ok(Self::NonZero),
+			// #[preprocess(nested)]
+			Meta::Path(path) if path.is_ident("nested") => Ok(Self::Nested),
+			// #[preprocess(trim)]
+			Meta::Path(path) if path.is_ident("trim") => Ok(Self::Trim),
+			// #[preprocess(lowercase)]
+			Meta::Path(path) if path.is_ident("lowercase") => {
+				Ok(Self::Lowercase)
+			}
+			// #[preprocess(uppercase)]
+			Meta::Path(path) if path.is_ident("uppercase") => {
+				Ok(Self::Uppercase)
+			}
+			// #[preprocess(abs)]
+			Meta::Path(path) if path.is_ident("abs") => Ok(Self::Abs),
+			// #[preprocess(neg)]
+			Meta::Path(path) if path.is_ident("neg") => Ok(Self::Neg),
+			// #[preprocess(snake_case)]
+			Meta::Path(path) if path.is_ident("snake_case") => {
+				Ok(Self::SnakeCase)
+			}
+			// #[preprocess(camel_case)]
+			Meta::Path(path) if path.is_ident("camel_case") => {
+				Ok(Self::CamelCase)
+			}
+			// #[preprocess(kebab_case)]
+			Meta::Path(path) if path.is_ident("kebab_case") => {
+				Ok(Self::KebabCase)
+			}
+			// #[preprocess(pascal_case)]
+			Meta::Path(path) if path.is_ident("pascal_case") => {
+				Ok(Self::PascalCase)
+			}
+			// #[preprocess(uppercase_first)]
+			Meta::Path(path) if path.is_ident("uppercase_first") => {
+				Ok(Self::UppercaseFirst)
+			}
+			// #[preprocess(lowercase_first)]
+			Meta::Path(path) if path.is_ident("lowercase_first") => {
+				Ok(Self::LowercaseFirst)
+			}
+			// #[preprocess(digits_only)]
+			Meta::Path(path) if path.is_ident("digits_only") => {
+				Ok(Self::DigitsOnly)
+			}
+			// #[preprocess(letters_only)]
+			Meta::Path(path) if path.is_ident("letters_only") => {
+				Ok(Self::LettersOnly)
+			}
+			// #[preprocess(alphanumeric_only)]
+			Meta::Path(path) if path.is_ident("alphanumeric_only") => {
+				Ok(Self::AlphanumericOnly)
+			}
+			// #[preprocess(title_case)]
+			Meta::Path(path) if path.is_ident("title_case") => {
+				Ok(Self::TitleCase { stop_words: None })
+			}
+			// #[preprocess(normalize_unicode)]
+			Meta::Path(path) if path.is_ident("normalize_unicode") => {
+				Ok(Self::NormalizeUnicode {
+					form: NormalizationForm::Nfc,
+				})
+			}
+			// #[preprocess(normalize_isbn)]
+			Meta::Path(path) if path.is_ident("normalize_isbn") => {
+				Ok(Self::NormalizeIsbn)
+			}
+			// #[preprocess(numeric_string)]
+			Meta::Path(path) if path.is_ident("numeric_string") => {
+				Ok(Self::NumericString {
+					integer_only: false,
+					positive_only: false,
+					no_scientific: false,
+				})
+			}
+			// #[preprocess(hex)]
+			Meta::Path(path) if path.is_ident("hex") => Ok(Self::Hex {
+				length: None,
+				prefix: None,
+				normalize: None,
+			}),
+			// #[preprocess(hex_decode)]
+			Meta::Path(path) if path.is_ident("hex_decode") => {
+				Ok(Self::HexDecode)
+			}
+			// #[preprocess(strip_subaddress)]
+			Meta::Path(path) if path.is_ident("strip_subaddress") => {
+				Ok(Self::StripSubaddress)
+			}
+			// #[preprocess(strip_control_characters)]
+			Meta::Path(path) if path.is_ident("strip_control_characters") => {
+				Ok(Self::StripControlChars)
+			}
+			// #[preprocess(strip_non_printable)]
+			Meta::Path(path) if path.is_ident("strip_non_printable") => {
+				Ok(Self::StripNonPrintable)
+			}
+			// #[preprocess(normalize_zip_code)]
+			Meta::Path(path) if path.is_ident("normalize_zip_code") => {
+				Ok(Self::NormalizeZipCode)
+			}
+			// #[preprocess(normalize_currency_code)]
+			Meta::Path(path) if path.is_ident("normalize_currency_code") => {
+				Ok(Self::NormalizeCurrencyCode)
+			}
+			// #[preprocess(normalize_language_code)]
+			Meta::Path(path) if path.is_ident("normalize_language_code") => {
+				Ok(Self::NormalizeLanguageCode)
+			}
+			// #[preprocess(number_format)]
+			Meta::Path(path) if path.is_ident("number_format") => {
+				Ok(Self::NumberFormat {
+					thousands: ',',
+					decimal: '.',
+				})
+			}
+			// #[preprocess(decimal_string)]
+			Meta::Path(path) if path.is_ident("decimal_string") => {
+				Ok(Self::DecimalString)
+			}
+			// #[preprocess(strip_html)]
+			Meta::Path(path) if path.is_ident("strip_html") => {
+				Ok(Self::StripHtml {
+					decode_entities: false,
+				})
+			}
+			// #[preprocess(isbn)]
+			Meta::Path(path) if path.is_ident("isbn") => Ok(Self::Isbn(None)),
+			// #[preprocess(length)]
+			Meta::Path(path) if path.is_ident("length") => Ok(Self::Length {
+				min: Some(Expr::Lit(ExprLit {
+					attrs: vec![],
+					lit: Lit::Int(LitInt::new("0usize", path.span())),
+				})),
+				max: None,
+				equal: None,
+			}),
+			// #[preprocess(ip)]
+			Meta::Path(path) if path.is_ident("ip") => {
+				Ok(Self::Ip(IpPreprocessorType::Any))
+			}
+			// #[preprocess(ipv4)]
+			Meta::Path(path) if path.is_ident("ipv4") => {
+				Ok(Self::Ip(IpPreprocessorType::V4))
+			}
+			// #[preprocess(ipv6)]
+			Meta::Path(path) if path.is_ident("ipv6") => {
+				Ok(Self::Ip(IpPreprocessorType::V6))
+			}
+			// #[preprocess(future_date)]
+			Meta::Path(path) if path.is_ident("future_date") => {
+				Ok(Self::FutureDate)
+			}
+			// #[preprocess(past_date)]
+			Meta::Path(path) if path.is_ident("past_date") => {
+				Ok(Self::PastDate)
+			}
+			// #[preprocess(private_ip_only)]
+			Meta::Path(path) if path.is_ident("private_ip_only") => {
+				Ok(Self::PrivateIpOnly)
+			}
+			// #[preprocess(public_ip_only)]
+			Meta::Path(path) if path.is_ident("public_ip_only") => {
+				Ok(Self::PublicIpOnly)
+			}
+			// #[preprocess(phone)]
+			Meta::Path(path) if path.is_ident("phone") => Ok(Self::Phone {
+				country: None,
+				format: PhoneFormat::E164,
+			}),
+			// #[preprocess(normalize_phone)]
+			Meta::Path(path) if path.is_ident("normalize_phone") => {
+				Ok(Self::NormalizePhone { country: None })
+			}
+			// #[preprocess(uuid)]
+			Meta::Path(path) if path.is_ident("uuid") => Ok(Self::Uuid {
+				version: UuidType::Any,
+				as_string: false,
+			}),
+			// #[preprocess(no_script_tags)]
+			Meta::Path(path) if path.is_ident("no_script_tags") => {
+				Ok(Self::NoScriptTags)
+			}
+			// #[preprocess(ipv4_mapped)]
+			Meta::Path(path) if path.is_ident("ipv4_mapped") => {
+				Ok(Self::Ipv4Mapped)
+			}
+			// #[preprocess(normalize_ip_any)]
+			Meta::Path(path) if path.is_ident("normalize_ip_any") => {
+				Ok(Self::NormalizeIpAny)
+			}
+			// #[preprocess(cron)]
+			Meta::Path(path) if path.is_ident("cron") => Ok(Self::Cron {
+				allow_seconds: false,
+				next_run_within: None,
+			}),
+			// #[preprocess(password_strength)]
+			Meta::Path(path) if path.is_ident("password_strength") => {
+				Ok(Self::PasswordStrength { min_score: 3 })
+			}
+			// #[preprocess(currency_code)]
+			Meta::Path(path) if path.is_ident("currency_code") => {
+				Ok(Self::CurrencyCode { active_only: false })
+			}
+			// #[preprocess(language_code)]
+			Meta::Path(path) if path.is_ident("language_code") => {
+				Ok(Self::LanguageCode { strict: false })
+			}
+			// #[preprocess(version)]
+			Meta::Path(path) if path.is_ident("version") => Ok(Self::Version {
+				allow_v_prefix: true,
+				allow_prerelease: true,
+				allow_build_metadata: true,
+				normalize: false,
+			}),
+			// #[preprocess(valid_json_path)]
+			Meta::Path(path) if path.is_ident("valid_json_path") => {
+				Ok(Self::ValidJsonPath)
+			}
+			// #[preprocess(date)]
+			Meta::Path(path) if path.is_ident("date") => Ok(Self::Date {
+				format: DEFAULT_DATE_FORMAT.to_string(),
+				min: None,
+				max: None,
+				as_date: false,
+			}),
+			// #[preprocess(datetime)]
+			Meta::Path(path) if path.is_ident("datetime") => {
+				Ok(Self::DateTime {
+					format: DEFAULT_DATETIME_FORMAT.to_string(),
+					timezone: None,
+				})
+			}
+			// #[preprocess(datetime_local)]
+			Meta::Path(path) if path.is_ident("datetime_local") => {
+				Ok(Self::DateTime {
+					format: DEFAULT_DATETIME_FORMAT.to_string(),
+					timezone: Some(DateTimeZone::Local),
+				})
+			}
+			// #[preprocess(from_str)]
+			Meta::Path(path) if path.is_ident("from_str") => Err(Error::new(
+				path.span(),
+				concat!(
+					"`from_str` requires the type to parse into. ",
+					"Use `from_str(as = \"u64\")` instead"
+				),
+			)),
+			// #[preprocess(ip_cidr)]
+			Meta::Path(path) if path.is_ident("ip_cidr") => Ok(Self::IpCidr {
+				strict: true,
+				as_pair: false,
+			}),
+			// #[preprocess(length = 10)]
+			Meta::NameValue(meta) if meta.path.is_ident("length") => {
+				Ok(Self::Length {
+					min: None,
+					max: None,
+					equal: Some(meta.value),
+				})
+			}
+			// #[preprocess(min_age = 18)]
+			Meta::NameValue(meta) if meta.path.is_ident("min_age") => {
+				Ok(Self::MinAge {
+					years: meta
+						.value
+						.require_lit()?
+						.lit
+						.require_int()?
+						.base10_parse()?,
+				})
+			}
+			// #[preprocess(max_age = 120)]
+			Meta::NameValue(meta) if meta.path.is_ident("max_age") => {
+				Ok(Self::MaxAge {
+					years: meta
+						.value
+						.require_lit()?
+						.lit
+						.require_int()?
+						.base10_parse()?,
+				})
+			}
+			// #[preprocess(min_words = 5)]
+			Meta::NameValue(meta) if meta.path.is_ident("min_words") => {
+				Ok(Self::MinWords(meta.value))
+			}
+			// #[preprocess(max_words = 200)]
+			Meta::NameValue(meta) if meta.path.is_ident("max_words") => {
+				Ok(Self::MaxWords(meta.value))
+			}
+			// #[preprocess(min_items = 1)]
+			Meta::NameValue(meta) if meta.path.is_ident("min_items") => {
+				Ok(Self::MinItems(meta.value))
+			}
+			// #[preprocess(max_items = 10)]
+			Meta::NameValue(meta) if meta.path.is_ident("max_items") => {
+				Ok(Self::MaxItems(meta.value))
+			}
+			// #[preprocess(min_bytes = 4)]
+			Meta::NameValue(meta) if meta.path.is_ident("min_bytes") => {
+				Ok(Self::MinBytes(meta.value))
+			}
+			// #[preprocess(max_bytes = 255)]
+			Meta::NameValue(meta) if meta.path.is_ident("max_bytes") => {
+				Ok(Self::MaxBytes(meta.value))
+			}
+			// #[preprocess(exact_length = 6)]
+			Meta::NameValue(meta) if meta.path.is_ident("exact_length") => {
+				Ok(Self::ExactLength(meta.value))
+			}
+			// #[preprocess(min = 0)]
+			Meta::NameValue(meta) if meta.path.is_ident("min") => {
+				Ok(Self::Min(meta.value))
+			}
+			// #[preprocess(max = 100)]
+			Meta::NameValue(meta) if meta.path.is_ident("max") => {
+				Ok(Self::Max(meta.value))
+			}
+			// #[preprocess(lower_bound = 0)]
+			Meta::NameValue(meta) if meta.path.is_ident("lower_bound") => {
+				Ok(Self::LowerBound(meta.value))
+			}
+			// #[preprocess(upper_bound = 100)]
+			Meta::NameValue(meta) if meta.path.is_ident("upper_bound") => {
+				Ok(Self::UpperBound(meta.value))
+			}
+			// #[preprocess(contains = "some-string")]
+			Meta::NameValue(meta) if meta.path.is_ident("contains") => {
+				Ok(Self::Contains(
+					meta.value.require_lit()?.lit.require_str()?.value(),
+				))
+			}
+			// #[preprocess(does_not_contain = "some-string")]
+			Meta::NameValue(meta) if meta.path.is_ident("does_not_contain") => {
+				Ok(Self::DoesNotContain(
+					meta.value.require_lit()?.lit.require_str()?.value(),
+				))
+			}
+			// #[preprocess(contains_item = 42)]
+			Meta::NameValue(meta) if meta.path.is_ident("contains_item") => {
+				Ok(Self::ContainsItem(meta.value))
+			}
+			// #[preprocess(does_not_contain_item = MyEnum::Foo)]
+			Meta::NameValue(meta)
+				if meta.path.is_ident("does_not_contain_item") =>
+			{
+				Ok(Self::DoesNotContainItem(meta.value))
+			}
+			// #[preprocess(equal = "DELETE")]
+			Meta::NameValue(meta) if meta.path.is_ident("equal") => {
+				Ok(Self::Equal(meta.value))
+			}
+			// #[preprocess(not_equal = "admin")]
+			Meta::NameValue(meta) if meta.path.is_ident("not_equal") => {
+				Ok(Self::NotEqual(meta.value))
+			}
+			// #[preprocess(whitelist = ["a", "b", "c"])] or
+			// #[preprocess(blacklist = ["a", "b", "c"])]
+			Meta::NameValue(meta)
+				if meta.path.is_ident("whitelist") ||
+					meta.path.is_ident("blacklist") =>
+			{
+				let array = meta.value.require_array()?;
+				if array.elems.is_empty() {
+					return Err(Error::new(
+						array.span(),
+						"expected at least one item",
+					));
+				}
+				let items = array.elems.into_iter().collect();
+				if meta.path.is_ident("whitelist") {
+					Ok(Self::Whitelist(items))
+				} else {
+					Ok(Self::Blacklist(items))
+				}
+			}
+			// #[preprocess(custom = "some-string")]
+			Meta::NameValue(meta) if meta.path.is_ident("custom") => {
+				Ok(Self::Custom(
+					meta.value.require_lit()?.lit.require_str()?.value(),
+				))
+			}
+			// #[preprocess(custom_type = MyValidator)]
+			Meta::NameValue(meta) if meta.path.is_ident("custom_type") => {
+				syn::parse2::<Type>(meta.value.to_token_stream())
+					.map(Self::CustomType)
+					.map_err(|_| {
+						Error::new(
+							meta.value.span(),
+							concat!(
+								"expected a type implementing ",
+								"`FieldValidator`, like `MyValidator`"
+							),
+						)
+					})
+			}
+			// #[preprocess(transform = "some_function")]
+			Meta::NameValue(meta) if meta.path.is_ident("transform") => {
+				let value = meta.value.require_lit()?.lit.require_str()?;
+				syn::parse_str::<Path>(&value.value()).map_err(|_| {
+					Error::new(
+						value.span(),
+						concat!(
+							"expected a path to a function `fn(T) -> T`. ",
+							"Use `custom` instead for functions that can fail ",
+							"and return a `Result<T, Error>`"
+						),
+					)
+				})?;
+				Ok(Self::Transform(value.value()))
+			}
+			// #[preprocess(transform_ref = "some_function")]
+			Meta::NameValue(meta) if meta.path.is_ident("transform_ref") => {
+				let value = meta.value.require_lit()?.lit.require_str()?;
+				syn::parse_str::<Path>(&value.value()).map_err(|_| {
+					Error::new(
+						value.span(),
+						concat!(
+							"expected a path to a function `fn(&T) -> T`. ",
+							"Use `custom` instead for functions that can fail ",
+							"and return a `Result<T, Error>`"
+						),
+					)
+				})?;
+				Ok(Self::TransformRef(value.value()))
+			}
+			// #[preprocess(enum_variant = "MyEnum")]
+			Meta::NameValue(meta) if meta.path.is_ident("enum_variant") => {
+				let value = meta.value.require_lit()?.lit.require_str()?;
+				Ok(Self::EnumVariant(value.parse().map_err(|_| {
+					Error::new(value.span(), "expected a path to an enum")
+				})?))
+			}
+			// #[preprocess(alphabet = "base58")]
+			Meta::NameValue(meta) if meta.path.is_ident("alphabet") => {
+				let charset = meta.value.require_lit()?.lit.require_str()?;
+				let kind = match charset.value().as_str() {
+					"base58" => AlphabetKind::Base58,
+					"base62" => AlphabetKind::Base62,
+					"" => {
+						return Err(Error::new(
+							charset.span(),
+							concat!(
+								"expected `\"base58\"`, `\"base62\"` or a ",
+								"non-empty string of characters as the alphabet"
+							),
+						));
+					}
+					value => {
+						let mut seen = Vec::new();
+						for ch in value.chars() {
+							if seen.contains(&ch) {
+								return Err(Error::new(
+									charset.span(),
+									format!(
+										"duplicate character `{}` in the alphabet",
+										ch
+									),
+								));
+							}
+							seen.push(ch);
+						}
+						AlphabetKind::Custom(value.to_string())
+					}
+				};
+				Ok(Self::Alphabet(kind))
+			}
+			// #[preprocess(strip_prefix = "Bearer ")]
+			Meta::NameValue(meta) if meta.path.is_ident("strip_prefix") => {
+				Ok(Self::StripPrefix(non_empty_str(meta.value, "prefix")?))
+			}
+			// #[preprocess(strip_suffix = ".json")]
+			Meta::NameValue(meta) if meta.path.is_ident("strip_suffix") => {
+				Ok(Self::StripSuffix(non_empty_str(meta.value, "suffix")?))
+			}
+			// #[preprocess(require_prefix = "Bearer ")]
+			Meta::NameValue(meta) if meta.path.is_ident("require_prefix") => {
+				Ok(Self::RequirePrefix(non_empty_str(meta.value, "prefix")?))
+			}
+			// #[preprocess(require_suffix = ".pdf")]
+			Meta::NameValue(meta) if meta.path.is_ident("require_suffix") => {
+				Ok(Self::RequireSuffix(non_empty_str(meta.value, "suffix")?))
+			}
+			// #[preprocess(default = "some-value")]
+			Meta::NameValue(meta) if meta.path.is_ident("default") => {
+				Ok(Self::Default(meta.value))
+			}
+			// #[preprocess(confirm = "other_field")]
+			Meta::NameValue(meta) if meta.path.is_ident("confirm") => {
+				Ok(Self::Confirm {
+					other_field: meta.value.require_lit()?.lit.require_str()?,
+				})
+			}
+			// #[preprocess(exactly_one_of = ["code", "assertion"])]
+			Meta::NameValue(meta) if meta.path.is_ident("exactly_one_of") => {
+				let array = meta.value.require_array()?;
+				let fields = array
+					.elems
+					.iter()
+					.map(|field| field.clone().require_lit()?.lit.require_str())
+					.collect::<Result<Vec<_>, Error>>()?;
+				if fields.len() < 2 {
+					return Err(Error::new(
+						array.span(),
+						"expected at least two field names",
+					));
+				}
+				Ok(Self::ExactlyOneOf(fields))
+			}
+			// #[preprocess(on_error = "some-value")]
+			Meta::NameValue(meta) if meta.path.is_ident("on_error") => {
+				Ok(Self::OnError(meta.value))
+			}
+			// #[preprocess(map_error = "some_function")]
+			Meta::NameValue(meta) if meta.path.is_ident("map_error") => {
+				let value = meta.value.require_lit()?.lit.require_str()?;
+				syn::parse_str::<Path>(&value.value()).map_err(|_| {
+					Error::new(
+						value.span(),
+						"expected a path to a function `fn(preprocess::Error) -> preprocess::Error`",
+					)
+				})?;
+				Ok(Self::MapError(value.value()))
+			}
+			// #[preprocess(error_message = "some message")]
+			Meta::NameValue(meta) if meta.path.is_ident("error_message") => {
+				Ok(Self::ErrorMessage(
+					meta.value.require_lit()?.lit.require_str()?.value(),
+				))
+			}
+			// #[preprocess(default_fn = "some-function")]
+			Meta::NameValue(meta) if meta.path.is_ident("default_fn") => {
+				Ok(Self::DefaultFn(
+					meta.value.require_lit()?.lit.require_str()?.value(),
+				))
+			}
+			// #[preprocess(regex = "some-string")]
+			Meta::NameValue(meta) if meta.path.is_ident("regex") => {
+				if let Ok(Ok(value)) = meta
+					.value
+					.clone()
+					.require_lit()
+					.map(|lit| lit.lit.require_str().map(|lit| lit.value()))
+				{
+					Regex::new(&value).map_err(|err| {
+						Error::new(
+							value.span(),
+							format!("invalid regex: {}", err),
+						)
+					})?;
+				}
+
+				Ok(Self::Regex(meta.value))
+			}
+			// #[preprocess(regex_named = MY_PATTERN)]
+			Meta::NameValue(meta) if meta.path.is_ident("regex_named") => {
+				match &meta.value {
+					Expr::Path(_) => Ok(Self::RegexNamed(meta.value)),
+					value => Err(Error::new(
+						value.span(),
+						"expected the path to a `&str` constant",
+					)),
+				}
+			}
+			// #[preprocess(all_match(uppercase = "[A-Z]", digit = "[0-9]"))]
+			Meta::List(list) if list.path.is_ident("all_match") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let mut patterns = Vec::<(Ident, Expr)>::new();
+				for meta in args {
+					let Some(label) = meta.path.get_ident() else {
+						return Err(Error::new(
+							meta.path.span(),
+							"expected a label for the pattern, like `uppercase`",
+						));
+					};
+					if patterns.iter().any(|(existing, _)| existing == label) {
+						return Err(Error::new(
+							label.span(),
+							"duplicate label",
+						));
+					}
+					match &meta.value {
+						Expr::Lit(ExprLit {
+							lit: Lit::Str(value),
+							..
+						}) => {
+							Regex::new(&value.value()).map_err(|err| {
+								Error::new(
+									value.span(),
+									format!("invalid regex: {}", err),
+								)
+							})?;
+						}
+						Expr::Path(_) => (),
+						value => {
+							return Err(Error::new(
+								value.span(),
+								"expected a regex string or the path to a `&str` constant",
+							));
+						}
+					}
+					patterns.push((label.clone(), meta.value));
+				}
+
+				if patterns.is_empty() {
+					return Err(Error::new(
+						list.span(),
+						"expected at least one labelled pattern, like `uppercase = \"[A-Z]\"`",
+					));
+				}
+				Ok(Self::AllMatch(patterns))
+			}
+			// #[preprocess(version(allow_v_prefix = true, allow_prerelease =
+			// false, normalize = true))]
+			Meta::List(list) if list.path.is_ident("version") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let (
+					mut allow_v_prefix,
+					mut allow_prerelease,
+					mut allow_build_metadata,
+					mut normalize,
+				) = (None, None, None, None);
+				for meta in args {
+					let option = if meta.path.is_ident("allow_v_prefix") {
+						&mut allow_v_prefix
+					} else if meta.path.is_ident("allow_prerelease") {
+						&mut allow_prerelease
+					} else if meta.path.is_ident("allow_build_metadata") {
+						&mut allow_build_metadata
+					} else if meta.path.is_ident("normalize") {
+						&mut normalize
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							concat!(
+								"expected argument `allow_v_prefix`, ",
+								"`allow_prerelease`, `allow_build_metadata` or ",
+								"`normalize`"
+							),
+						));
+					};
+					if option.is_some() {
+						return Err(Error::new(
+							meta.path.span(),
+							"duplicate argument",
+						));
+					}
+					*option =
+						Some(meta.value.require_lit()?.lit.require_bool()?);
+				}
+
+				if let (Some(allow_v_prefix), Some(normalize)) =
+					(&allow_v_prefix, &normalize)
+				{
+					if !allow_v_prefix.value && normalize.value {
+						return Err(Error::new(
+							normalize.span(),
+							concat!(
+								"`normalize` strips the `v` prefix, so it ",
+								"cannot be used with `allow_v_prefix = false`"
+							),
+						));
+					}
+				}
+
+				let [allow_v_prefix, allow_prerelease, allow_build_metadata] =
+					[allow_v_prefix, allow_prerelease, allow_build_metadata]
+						.map(|option| option.is_none_or(|option| option.value));
+				Ok(Self::Version {
+					allow_v_prefix,
+					allow_prerelease,
+					allow_build_metadata,
+					normalize: normalize
+						.is_some_and(|normalize| normalize.value),
+				})
+			}
+			// #[preprocess(url_component = "path")]
+			Meta::NameValue(meta) if meta.path.is_ident("url_component") => {
+				let component = meta.value.require_lit()?.lit.require_str()?;
+				let component = match component.value().as_str() {
+					"path" => UrlComponentKind::Path,
+					"query" => UrlComponentKind::Query,
+					"fragment" => UrlComponentKind::Fragment,
+					"host" => UrlComponentKind::Host,
+					"scheme" => UrlComponentKind::Scheme,
+					_ => {
+						return Err(Error::new(
+							component.span(),
+							"expected one of `path`, `query`, `fragment`, `host` or `scheme`",
+						));
+					}
+				};
+				Ok(Self::UrlComponent { component })
+			}
+			// #[preprocess(regex_ref = MY_REGEX)]
+			Meta::NameValue(meta) if meta.path.is_ident("regex_ref") => {
+				Ok(Self::RegexRef(meta.value))
+			}
+			// #[preprocess(coerce = "u32")] or
+			// #[preprocess(coerce = std::num::NonZeroU32)]
+			Meta::NameValue(meta) if meta.path.is_ident("coerce") => {
+				Ok(Self::Coerce(parse_coerce_type(&meta.value)?))
+			}
+			// #[preprocess(type = "u32")], which is kept for backwards
+			// compatibility, since `type` is a keyword
+			Meta::NameValue(meta) if meta.path.is_ident("type") => {
+				let target_type = parse_coerce_type(&meta.value)?;
+				Ok(Self::Suggestion {
+					message: format!(
+						"use `#[preprocess(coerce = \"{}\")]` instead of `#[preprocess(type = ...)]`",
+						target_type
+					),
+					preprocessor: Box::new(Self::Coerce(target_type)),
+				})
+			}
+			// #[preprocess(ip(normalize))] or #[preprocess(ip(v4, normalize))]
+			Meta::List(list)
+				if list.path.is_ident("ip") &&
+					list.parse_args_with(
+						Punctuated::<Meta, Token![,]>::parse_terminated,
+					)
+					.is_ok_and(|args| {
+						args.iter().any(|arg| arg.path().is_ident("normalize"))
+					}) =>
+			{
+				let args = list.parse_args_with(
+					Punctuated::<Meta, Token![,]>::parse_terminated,
+				)?;
+
+				let mut version = None;
+				let mut normalize = false;
+				for arg in args {
+					match arg {
+						Meta::Path(path)
+							if path.is_ident("normalize") && !normalize =>
+						{
+							normalize = true;
+						}
+						Meta::Path(path)
+							if (path.is_ident("v4") || path.is_ident("v6")) &&
+								version.is_none() =>
+						{
+							version = Some(
+								if path.is_ident("v4") {
+									IpPreprocessorType::NormalizeV4
+								} else {
+									IpPreprocessorType::NormalizeV6
+								},
+							);
+						}
+						arg => {
+							return Err(Error::new(
+								arg.span(),
+								concat!(
+									"expected at most one of `v4` or `v6` ",
+									"along with `normalize`"
+								),
+							));
+						}
+					}
+				}
+
+				Ok(Self::Ip(
+					version.unwrap_or(IpPreprocessorType::NormalizeAny),
+				))
+			}
+			// #[preprocess(ip(v4))], #[preprocess(ip(version = "v4"))] or
+			// #[preprocess(ip(scope = "private"))]
+			Meta::List(list) if list.path.is_ident("ip") => {
+				let args = list.parse_args::<Meta>()?;
+
+				match args {
+					Meta::Path(path) if path.is_ident("v4") => {
+						Ok(Self::Ip(IpPreprocessorType::V4))
+					}
+					Meta::Path(path) if path.is_ident("v6") => {
+						Ok(Self::Ip(IpPreprocessorType::V6))
+					}
+					Meta::NameValue(meta) if meta.path.is_ident("version") => {
+						let version =
+							meta.value.require_lit()?.lit.require_str()?;
+						match version.value().as_str() {
+							"v4" => Ok(Self::Ip(IpPreprocessorType::V4)),
+							"v6" => Ok(Self::Ip(IpPreprocessorType::V6)),
+							_ => Err(Error::new(
+								version.span(),
+								"expected `v4` or `v6` as the version",
+							)),
+						}
+					}
+					Meta::NameValue(meta) if meta.path.is_ident("network") => {
+						let network =
+							meta.value.require_lit()?.lit.require_str()?;
+						check_network(&network.value()).map_err(|message| {
+							Error::new(network.span(), message)
+						})?;
+						Ok(Self::Ip(IpPreprocessorType::IpInNetwork {
+							network: network.value(),
+						}))
+					}
+					Meta::NameValue(meta) if meta.path.is_ident("scope") => {
+						let scope =
+							meta.value.require_lit()?.lit.require_str()?;
+						match scope.value().as_str() {
+							"private" => Ok(Self::PrivateIpOnly),
+							"public" => Ok(Self::PublicIpOnly),
+							_ => Err(Error::new(
+								scope.span(),
+								"expected `private` or `public` as the scope",
+							)),
+						}
+					}
+					args => Err(Error::new(
+						args.span(),
+						concat!(
+							"expected `v4`, `v6`, `normalize`, ",
+							"`version = \"...\"`, `scope = \"...\"` or ",
+							"`network = \"...\"`"
+						),
+					)),
+				}
+			}
+			// #[preprocess(number_in_range(min = "1", max = "999"))]
+			Meta::List(list) if list.path.is_ident("number_in_range") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let (mut min, mut max, mut integer_only) = (None, None, None);
+				for meta in args {
+					if meta.path.is_ident("integer_only") {
+						if integer_only.is_some() {
+							return Err(Error::new(
+								meta.path.span(),
+								"duplicate argument",
+							));
+						}
+						integer_only = Some(
+							meta.value.require_lit()?.lit.require_bool()?.value,
+						);
+						continue;
+					}
+
+					let option = if meta.path.is_ident("min") {
+						&mut min
+					} else if meta.path.is_ident("max") {
+						&mut max
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							"expected argument `min`, `max` or `integer_only`",
+						));
+					};
+					if option.is_some() {
+						return Err(Error::new(
+							meta.path.span(),
+							"duplicate argument",
+						));
+					}
+					*option = Some(parse_number(&meta.value)?);
+				}
+
+				if let (Some(min), Some(max)) = (min, max) {
+					if min > max {
+						return Err(Error::new(
+							list.span(),
+							format!(
+								"`min = {}` cannot be greater than `max = {}`",
+								min, max
+							),
+						));
+					}
+				}
+
+				Ok(Self::NumberInRange {
+					min,
+					max,
+					integer_only: integer_only.unwrap_or(false),
+				})
+			}
+			// #[preprocess(email(allow_subaddressing = false))]
+			Meta::List(list) if list.path.is_ident("email") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let (mut allow_subaddressing, mut normalize_subaddress) =
+					(None, None);
+				for meta in args {
+					let option = if meta.path.is_ident("allow_subaddressing") {
+						&mut allow_subaddressing
+					} else if meta.path.is_ident("normalize_subaddress") {
+						&mut normalize_subaddress
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							concat!(
+								"expected argument `allow_subaddressing` or ",
+								"`normalize_subaddress`"
+							),
+						));
+					};
+					if option.is_some() {
+						return Err(Error::new(
+							meta.path.span(),
+							"duplicate argument",
+						));
+					}
+					*option = Some(
+						meta.value.require_lit()?.lit.require_bool()?.value,
+					);
+				}
+
+				Ok(Self::Email {
+					allow_subaddressing: allow_subaddressing.unwrap_or(true),
+					normalize_subaddress: normalize_subaddress.unwrap_or(false),
+				})
+			}
+			// #[preprocess(url(scheme = ["https"]))],
+			// #[preprocess(url(require_https = true))] or
+			// #[preprocess(url(no_credentials = true, allow_fragment = false))]
+			Meta::List(list) if list.path.is_ident("url") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let (mut schemes, mut no_credentials, mut allow_fragment) =
+					(None, None, None);
+				for meta in args {
+					if meta.path.is_ident("no_credentials") ||
+						meta.path.is_ident("allow_fragment")
+					{
+						let flag = if meta.path.is_ident("no_credentials") {
+							&mut no_credentials
+						} else {
+							&mut allow_fragment
+						};
+						if flag.is_some() {
+							return Err(Error::new(
+								meta.path.span(),
+								"duplicate argument",
+							));
+						}
+						*flag = Some(
+							meta.value.require_lit()?.lit.require_bool()?.value,
+						);
+						continue;
+					}
+
+					let value = if meta.path.is_ident("scheme") {
+						meta.value
+							.require_array()?
+							.elems
+							.into_iter()
+							.map(|scheme| {
+								Ok(scheme
+									.require_lit()?
+									.lit
+									.require_str()?
+									.value()
+									.to_lowercase())
+							})
+							.collect::<Result<Vec<_>, Error>>()?
+					} else if meta.path.is_ident("require_https") {
+						if meta.value.require_lit()?.lit.require_bool()?.value {
+							vec!["https".to_string()]
+						} else {
+							continue;
+						}
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							concat!(
+								"expected argument `scheme`, `require_https`, ",
+								"`no_credentials` or `allow_fragment`"
+							),
+						));
+					};
+
+					if schemes.is_some() {
+						return Err(Error::new(
+							meta.path.span(),
+							"only one of `scheme` or `require_https` can be used",
+						));
+					}
+					schemes = Some(value);
+				}
+
+				Ok(Self::Url {
+					schemes,
+					no_credentials: no_credentials.unwrap_or(false),
+					allow_fragment: allow_fragment.unwrap_or(true),
+				})
+			}
+			// #[preprocess(ip_cidr(strict = false, as_pair = true))]
+			Meta::List(list) if list.path.is_ident("ip_cidr") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let (mut strict, mut as_pair) = (None, None);
+				for meta in args {
+					let option = if meta.path.is_ident("strict") {
+						&mut strict
+					} else if meta.path.is_ident("as_pair") {
+						&mut as_pair
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							"expected argument `strict` or `as_pair`",
+						));
+					};
+					if option.is_some() {
+						return Err(Error::new(
+							meta.path.span(),
+							"duplicate argument",
+						));
+					}
+					*option = Some(
+						meta.value.require_lit()?.lit.require_bool()?.value,
+					);
+				}
+
+				Ok(Self::IpCidr {
+					strict: strict.unwrap_or(true),
+					as_pair: as_pair.unwrap_or(false),
+				})
 			}
-			// #[preprocess(none)]
-			Meta::Path(path) if path.is_ident("none") => Ok(Self::None),
-			// #[preprocess(email)]
-			Meta::Path(path) if path.is_ident("email") => Ok(Self::Email),
-			// #[preprocess(domain)]
-			Meta::Path(path) if path.is_ident("domain") => Ok(Self::Domain),
-			// #[preprocess(url)]
-			Meta::Path(path) if path.is_ident("url") => Ok(Self::Url),
-			// #[preprocess(nested)]
-			Meta::Path(path) if path.is_ident("nested") => Ok(Self::Nested),
-			// #[preprocess(trim)]
-			Meta::Path(path) if path.is_ident("trim") => Ok(Self::Trim),
-			// #[preprocess(lowercase)]
-			Meta::Path(path) if path.is_ident("lowercase") => {
-				Ok(Self::Lowercase)
+			// #[preprocess(cron(allow_seconds = true, next_run_within = "1
+			// hour"))]
+			Meta::List(list) if list.path.is_ident("cron") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let (mut allow_seconds, mut next_run_within) = (None, None);
+				for meta in args {
+					if meta.path.is_ident("allow_seconds") {
+						if allow_seconds.is_some() {
+							return Err(Error::new(
+								meta.path.span(),
+								"duplicate argument",
+							));
+						}
+						allow_seconds = Some(
+							meta.value.require_lit()?.lit.require_bool()?.value,
+						);
+					} else if meta.path.is_ident("next_run_within") {
+						if next_run_within.is_some() {
+							return Err(Error::new(
+								meta.path.span(),
+								"duplicate argument",
+							));
+						}
+						let value =
+							meta.value.require_lit()?.lit.require_str()?;
+						next_run_within = Some(
+							humantime::parse_duration(&value.value()).map_err(
+								|err| {
+									Error::new(
+										value.span(),
+										format!("invalid duration: {}", err),
+									)
+								},
+							)?,
+						);
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							"expected argument `allow_seconds` or `next_run_within`",
+						));
+					}
+				}
+
+				Ok(Self::Cron {
+					allow_seconds: allow_seconds.unwrap_or(false),
+					next_run_within,
+				})
 			}
-			// #[preprocess(uppercase)]
-			Meta::Path(path) if path.is_ident("uppercase") => {
-				Ok(Self::Uppercase)
+			// #[preprocess(json(json_path = "$.name"))]
+			Meta::List(list) if list.path.is_ident("json") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let mut json_path = None;
+				for meta in args {
+					if meta.path.is_ident("json_path") {
+						if json_path.is_some() {
+							return Err(Error::new(
+								meta.path.span(),
+								"duplicate argument",
+							));
+						}
+						let lit =
+							meta.value.require_lit()?.lit.require_str()?;
+						if !lit.value().starts_with('$') {
+							return Err(Error::new(
+								lit.span(),
+								"expected a JSONPath expression starting with `$`",
+							));
+						}
+						json_path = Some(lit.value());
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							"expected argument `json_path`",
+						));
+					}
+				}
+
+				let Some(json_path) = json_path else {
+					return Err(Error::new(
+						list.span(),
+						"expected argument `json_path`",
+					));
+				};
+				Ok(Self::JsonPath(json_path))
 			}
-			// #[preprocess(length)]
-			Meta::Path(path) if path.is_ident("length") => Ok(Self::Length {
-				min: Some(Expr::Lit(ExprLit {
-					attrs: vec![],
-					lit: Lit::Int(LitInt::new("0usize", path.span())),
-				})),
-				max: None,
-				equal: None,
-			}),
-			// #[preprocess(ip)]
-			Meta::Path(path) if path.is_ident("ip") => {
-				Ok(Self::Ip(IpPreprocessorType::Any))
+			// #[preprocess(interpolate(template = "order-{id}"))]
+			Meta::List(list) if list.path.is_ident("interpolate") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let mut template = None;
+				for meta in args {
+					if meta.path.is_ident("template") {
+						if template.is_some() {
+							return Err(Error::new(
+								meta.path.span(),
+								"duplicate argument",
+							));
+						}
+						let lit =
+							meta.value.require_lit()?.lit.require_str()?;
+						check_template(&lit.value()).map_err(|message| {
+							Error::new(lit.span(), message)
+						})?;
+						template = Some(lit.value());
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							"expected argument `template`",
+						));
+					}
+				}
+
+				let Some(template) = template else {
+					return Err(Error::new(
+						list.span(),
+						"expected argument `template`",
+					));
+				};
+				Ok(Self::Interpolate(template))
 			}
-			// #[preprocess(length = 10)]
-			Meta::NameValue(meta) if meta.path.is_ident("length") => {
-				Ok(Self::Length {
-					min: None,
-					max: None,
-					equal: Some(meta.value),
+			// #[preprocess(phone(country = "US", format = "local"))]
+			Meta::List(list) if list.path.is_ident("phone") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let (mut country, mut format) = (None, None);
+				for meta in args {
+					if meta.path.is_ident("country") {
+						if country.is_some() {
+							return Err(Error::new(
+								meta.path.span(),
+								"duplicate argument",
+							));
+						}
+						country = Some(parse_phone_country(meta.value)?);
+					} else if meta.path.is_ident("format") {
+						if format.is_some() {
+							return Err(Error::new(
+								meta.path.span(),
+								"duplicate argument",
+							));
+						}
+						let value =
+							meta.value.require_lit()?.lit.require_str()?;
+						format = Some(match value.value().as_str() {
+							"e164" => PhoneFormat::E164,
+							"local" => PhoneFormat::Local,
+							_ => {
+								return Err(Error::new(
+									value.span(),
+									"expected `\"e164\"` or `\"local\"` as the format",
+								));
+							}
+						});
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							"expected argument `country` or `format`",
+						));
+					}
+				}
+
+				Ok(Self::Phone {
+					country,
+					format: format.unwrap_or(PhoneFormat::E164),
 				})
 			}
-			// #[preprocess(contains = "some-string")]
-			Meta::NameValue(meta) if meta.path.is_ident("contains") => {
-				Ok(Self::Contains(
-					meta.value.require_lit()?.lit.require_str()?.value(),
-				))
-			}
-			// #[preprocess(does_not_contain = "some-string")]
-			Meta::NameValue(meta) if meta.path.is_ident("does_not_contain") => {
-				Ok(Self::DoesNotContain(
-					meta.value.require_lit()?.lit.require_str()?.value(),
-				))
+			// #[preprocess(normalize_phone(country = "US"))]
+			Meta::List(list) if list.path.is_ident("normalize_phone") => {
+				let meta = list.parse_args::<MetaNameValue>()?;
+				if !meta.path.is_ident("country") {
+					return Err(Error::new(
+						meta.path.span(),
+						"expected argument `country`",
+					));
+				}
+
+				Ok(Self::NormalizePhone {
+					country: Some(parse_phone_country(meta.value)?),
+				})
 			}
-			// #[preprocess(custom = "some-string")]
-			Meta::NameValue(meta) if meta.path.is_ident("custom") => {
-				Ok(Self::Custom(
-					meta.value.require_lit()?.lit.require_str()?.value(),
-				))
+			// #[preprocess(uuid(version = 4, as_string = true))]
+			Meta::List(list) if list.path.is_ident("uuid") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let (mut version, mut as_string) = (None, None);
+				for meta in args {
+					if meta.path.is_ident("version") {
+						if version.is_some() {
+							return Err(Error::new(
+								meta.path.span(),
+								"duplicate argument",
+							));
+						}
+						let value =
+							meta.value.require_lit()?.lit.require_int()?;
+						version = Some(match value.base10_parse::<u8>() {
+							Ok(1) => UuidType::V1,
+							Ok(3) => UuidType::V3,
+							Ok(4) => UuidType::V4,
+							Ok(5) => UuidType::V5,
+							_ => {
+								return Err(Error::new(
+									value.span(),
+									"expected `1`, `3`, `4` or `5` as the UUID version",
+								));
+							}
+						});
+					} else if meta.path.is_ident("as_string") {
+						if as_string.is_some() {
+							return Err(Error::new(
+								meta.path.span(),
+								"duplicate argument",
+							));
+						}
+						as_string = Some(
+							meta.value.require_lit()?.lit.require_bool()?.value,
+						);
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							"expected argument `version` or `as_string`",
+						));
+					}
+				}
+
+				Ok(Self::Uuid {
+					version: version.unwrap_or(UuidType::Any),
+					as_string: as_string.unwrap_or(false),
+				})
 			}
-			// #[preprocess(regex = "some-string")]
-			Meta::NameValue(meta) if meta.path.is_ident("regex") => {
-				if let Ok(Ok(value)) = meta
-					.value
-					.clone()
-					.require_lit()
-					.map(|lit| lit.lit.require_str().map(|lit| lit.value()))
-				{
-					Regex::new(&value).map_err(|err| {
-						Error::new(
-							value.span(),
-							format!("invalid regex: {}", err),
-						)
+			// #[preprocess(from_str(as = "u64"))]
+			Meta::List(list) if list.path.is_ident("from_str") => {
+				// `as` is a keyword, so it cannot be parsed as a
+				// `MetaNameValue`
+				let target_type =
+					list.parse_args_with(|input: ParseStream| {
+						input.parse::<Token![as]>().map_err(|err| {
+							Error::new(err.span(), "expected argument `as`")
+						})?;
+						input.parse::<Token![=]>()?;
+						input.parse::<LitStr>()
 					})?;
+				target_type.parse::<Type>().map_err(|_| {
+					Error::new(
+						target_type.span(),
+						"expected a type to parse into",
+					)
+				})?;
+				Ok(Self::FromStr {
+					target_type: target_type.value(),
+				})
+			}
+			// #[preprocess(password_strength(min_score = 3))]
+			Meta::List(list) if list.path.is_ident("password_strength") => {
+				let meta = list.parse_args::<MetaNameValue>()?;
+				if !meta.path.is_ident("min_score") {
+					return Err(Error::new(
+						meta.path.span(),
+						"expected argument `min_score`",
+					));
 				}
 
-				Ok(Self::Regex(meta.value))
+				let min_score = meta.value.require_lit()?.lit.require_int()?;
+				match min_score.base10_parse::<u8>() {
+					Ok(min_score @ 0..=4) => {
+						Ok(Self::PasswordStrength { min_score })
+					}
+					_ => Err(Error::new(
+						min_score.span(),
+						"`min_score` must be between 0 and 4",
+					)),
+				}
 			}
-			// #[preprocess(type = "String")] or
-			// #[preprocess(type = std::string::String)]
-			Meta::NameValue(meta) if meta.path.is_ident("type") => {
-				let r#type = match &meta.value {
-					Expr::Lit(lit) => {
-						let Lit::Str(lit_str) = &lit.lit else {
+			// #[preprocess(currency_code(active_only = true))]
+			Meta::List(list) if list.path.is_ident("currency_code") => {
+				let meta = list.parse_args::<MetaNameValue>()?;
+				if !meta.path.is_ident("active_only") {
+					return Err(Error::new(
+						meta.path.span(),
+						"expected argument `active_only`",
+					));
+				}
+
+				Ok(Self::CurrencyCode {
+					active_only: meta
+						.value
+						.require_lit()?
+						.lit
+						.require_bool()?
+						.value,
+				})
+			}
+			// #[preprocess(language_code(strict = true))]
+			Meta::List(list) if list.path.is_ident("language_code") => {
+				let meta = list.parse_args::<MetaNameValue>()?;
+				if !meta.path.is_ident("strict") {
+					return Err(Error::new(
+						meta.path.span(),
+						"expected argument `strict`",
+					));
+				}
+
+				Ok(Self::LanguageCode {
+					strict: meta.value.require_lit()?.lit.require_bool()?.value,
+				})
+			}
+			// #[preprocess(zip_code(country = "US"))]
+			Meta::List(list) if list.path.is_ident("zip_code") => {
+				let meta = list.parse_args::<MetaNameValue>()?;
+				if !meta.path.is_ident("country") {
+					return Err(Error::new(
+						meta.path.span(),
+						"expected argument `country`",
+					));
+				}
+
+				let country = meta.value.require_lit()?.lit.require_str()?;
+				if !ZIP_CODE_COUNTRIES.contains(&country.value().as_str()) {
+					return Err(Error::new(
+						country.span(),
+						format!(
+							"unsupported country, expected one of {}",
+							ZIP_CODE_COUNTRIES.join(", ")
+						),
+					));
+				}
+
+				Ok(Self::ZipCode {
+					country: country.value(),
+				})
+			}
+			// #[preprocess(date(format = "%Y-%m-%d", min = "2000-01-01"))]
+			Meta::List(list) if list.path.is_ident("date") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let (mut format, mut min, mut max, mut as_date) =
+					(None, None, None, None);
+				for meta in args {
+					if meta.path.is_ident("as_date") {
+						if as_date.is_some() {
 							return Err(Error::new(
-								meta.span(),
-								"only string literals are allowed here",
+								meta.path.span(),
+								"duplicate argument",
 							));
-						};
-						lit_str.value()
+						}
+						as_date = Some(
+							meta.value.require_lit()?.lit.require_bool()?.value,
+						);
+						continue;
 					}
-					Expr::Path(path) => {
-						if let Some(ident) = path.path.get_ident() {
-							ident.to_string()
-						} else {
+
+					let option = if meta.path.is_ident("format") {
+						&mut format
+					} else if meta.path.is_ident("min") {
+						&mut min
+					} else if meta.path.is_ident("max") {
+						&mut max
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							concat!(
+								"expected argument `format`, `min`, `max` ",
+								"or `as_date`"
+							),
+						));
+					};
+					if option.is_some() {
+						return Err(Error::new(
+							meta.path.span(),
+							"duplicate argument",
+						));
+					}
+					*option =
+						Some(meta.value.require_lit()?.lit.require_str()?);
+				}
+
+				let format = match format {
+					Some(format) => {
+						let value = format.value();
+						if StrftimeItems::new(&value)
+							.any(|item| item == Item::Error)
+						{
 							return Err(Error::new(
-								meta.span(),
-								"expected resulting type",
+								format.span(),
+								"invalid date format",
 							));
 						}
+						value
 					}
+					None => DEFAULT_DATE_FORMAT.to_string(),
+				};
+				let [min, max] = [min, max].map(|date| {
+					date.map(|date| {
+						NaiveDate::parse_from_str(&date.value(), &format)
+							.map_err(|err| {
+								Error::new(
+									date.span(),
+									format!(
+										"invalid date for format `{}`: {}",
+										format, err
+									),
+								)
+							})
+					})
+					.transpose()
+				});
+
+				Ok(Self::Date {
+					min: min?,
+					max: max?,
+					format,
+					as_date: as_date.unwrap_or(false),
+				})
+			}
+			// #[preprocess(datetime(format = "%Y-%m-%dT%H:%M:%S", timezone =
+			// "UTC"))] or #[preprocess(datetime_local(format = "%Y-%m-%d
+			// %H:%M"))]
+			Meta::List(list)
+				if list.path.is_ident("datetime") ||
+					list.path.is_ident("datetime_local") =>
+			{
+				let is_local = list.path.is_ident("datetime_local");
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let (mut format, mut timezone) = (None, None);
+				for meta in args {
+					let option = if meta.path.is_ident("format") {
+						&mut format
+					} else if meta.path.is_ident("timezone") && !is_local {
+						&mut timezone
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							if is_local {
+								"expected argument `format`"
+							} else {
+								"expected argument `format` or `timezone`"
+							},
+						));
+					};
+					if option.is_some() {
+						return Err(Error::new(
+							meta.path.span(),
+							"duplicate argument",
+						));
+					}
+					*option =
+						Some(meta.value.require_lit()?.lit.require_str()?);
+				}
+
+				let format = match format {
+					Some(format) => {
+						let value = format.value();
+						if StrftimeItems::new(&value)
+							.any(|item| item == Item::Error)
+						{
+							return Err(Error::new(
+								format.span(),
+								"invalid date and time format",
+							));
+						}
+						value
+					}
+					None => DEFAULT_DATETIME_FORMAT.to_string(),
+				};
+				let timezone = match timezone {
+					_ if is_local => Some(DateTimeZone::Local),
+					Some(timezone) if timezone.value() == "UTC" => {
+						Some(DateTimeZone::Utc)
+					}
+					Some(timezone) => {
+						return Err(Error::new(
+							timezone.span(),
+							concat!(
+								"expected `UTC` as the timezone. ",
+								"Use `datetime_local` for the local time zone"
+							),
+						));
+					}
+					None => None,
+				};
+
+				Ok(Self::DateTime { format, timezone })
+			}
+			// #[preprocess(strip_html(decode_entities = true))]
+			Meta::List(list) if list.path.is_ident("strip_html") => {
+				let meta = list.parse_args::<MetaNameValue>()?;
+				if !meta.path.is_ident("decode_entities") {
+					return Err(Error::new(
+						meta.path.span(),
+						"expected argument `decode_entities`",
+					));
+				}
+
+				Ok(Self::StripHtml {
+					decode_entities: meta
+						.value
+						.require_lit()?
+						.lit
+						.require_bool()?
+						.value,
+				})
+			}
+			// #[preprocess(numeric_string(integer_only = true, positive_only =
+			// true, no_scientific = true))]
+			Meta::List(list) if list.path.is_ident("numeric_string") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let (mut integer_only, mut positive_only, mut no_scientific) =
+					(None, None, None);
+				for meta in args {
+					let option = if meta.path.is_ident("integer_only") {
+						&mut integer_only
+					} else if meta.path.is_ident("positive_only") {
+						&mut positive_only
+					} else if meta.path.is_ident("no_scientific") {
+						&mut no_scientific
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							concat!(
+								"expected argument `integer_only`, ",
+								"`positive_only` or `no_scientific`"
+							),
+						));
+					};
+					if option.is_some() {
+						return Err(Error::new(
+							meta.path.span(),
+							"duplicate argument",
+						));
+					}
+					*option = Some(
+						meta.value.require_lit()?.lit.require_bool()?.value,
+					);
+				}
+
+				Ok(Self::NumericString {
+					integer_only: integer_only.unwrap_or(false),
+					positive_only: positive_only.unwrap_or(false),
+					no_scientific: no_scientific.unwrap_or(false),
+				})
+			}
+			// #[preprocess(hex(length = 64, prefix = "0x", normalize =
+			// "lowercase"))]
+			Meta::List(list) if list.path.is_ident("hex") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let (mut length, mut prefix, mut normalize) =
+					(None, None, None);
+				for meta in args {
+					if meta.path.is_ident("length") {
+						if length.is_some() {
+							return Err(Error::new(
+								meta.path.span(),
+								"duplicate argument",
+							));
+						}
+						length = Some(
+							meta.value
+								.require_lit()?
+								.lit
+								.require_int()?
+								.base10_parse()?,
+						);
+					} else if meta.path.is_ident("prefix") {
+						if prefix.is_some() {
+							return Err(Error::new(
+								meta.path.span(),
+								"duplicate argument",
+							));
+						}
+						prefix = Some(
+							meta.value
+								.require_lit()?
+								.lit
+								.require_str()?
+								.value(),
+						);
+					} else if meta.path.is_ident("normalize") {
+						if normalize.is_some() {
+							return Err(Error::new(
+								meta.path.span(),
+								"duplicate argument",
+							));
+						}
+						let case =
+							meta.value.require_lit()?.lit.require_str()?;
+						normalize = Some(match case.value().as_str() {
+							"lowercase" => HexCase::Lowercase,
+							"uppercase" => HexCase::Uppercase,
+							_ => {
+								return Err(Error::new(
+									case.span(),
+									"expected one of `lowercase` or `uppercase`",
+								));
+							}
+						});
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							"expected argument `length`, `prefix` or `normalize`",
+						));
+					}
+				}
+
+				Ok(Self::Hex {
+					length,
+					prefix,
+					normalize,
+				})
+			}
+			// #[preprocess(isbn(format = "isbn13"))]
+			Meta::List(list) if list.path.is_ident("isbn") => {
+				let meta = list.parse_args::<MetaNameValue>()?;
+				if !meta.path.is_ident("format") {
+					return Err(Error::new(
+						meta.path.span(),
+						"expected argument `format`",
+					));
+				}
+
+				let format = meta.value.require_lit()?.lit.require_str()?;
+				let format = match format.value().to_lowercase().as_str() {
+					"isbn10" => IsbnFormat::Isbn10,
+					"isbn13" => IsbnFormat::Isbn13,
 					_ => {
 						return Err(Error::new(
-							meta.span(),
-							"expected string literal",
-						))
+							format.span(),
+							"expected one of `isbn10` or `isbn13`",
+						));
 					}
 				};
-				Ok(Self::Type(r#type))
+
+				Ok(Self::Isbn(Some(format)))
 			}
-			// #[preprocess(ip(v4))]
-			Meta::List(list) if list.path.is_ident("ip") => {
-				let args = list.parse_args::<Path>()?;
+			// #[preprocess(title_case(stop_words = ["le", "la", "les"]))]
+			Meta::List(list) if list.path.is_ident("title_case") => {
+				let meta = list.parse_args::<MetaNameValue>()?;
+				if !meta.path.is_ident("stop_words") {
+					return Err(Error::new(
+						meta.path.span(),
+						"expected argument `stop_words`",
+					));
+				}
 
-				if args.is_ident("v4") {
-					Ok(Self::Ip(IpPreprocessorType::V4))
-				} else if args.is_ident("v6") {
-					Ok(Self::Ip(IpPreprocessorType::V6))
-				} else {
-					Err(Error::new(args.span(), "expected `v4` or `v6`"))
+				let stop_words = meta
+					.value
+					.require_array()?
+					.elems
+					.into_iter()
+					.map(|word| {
+						Ok(word.require_lit()?.lit.require_str()?.value())
+					})
+					.collect::<Result<Vec<_>, Error>>()?;
+
+				Ok(Self::TitleCase {
+					stop_words: Some(stop_words),
+				})
+			}
+			// #[preprocess(normalize_unicode(form = "nfc"))]
+			Meta::List(list) if list.path.is_ident("normalize_unicode") => {
+				let meta = list.parse_args::<MetaNameValue>()?;
+				if !meta.path.is_ident("form") {
+					return Err(Error::new(
+						meta.path.span(),
+						"expected argument `form`",
+					));
 				}
+
+				let form = meta.value.require_lit()?.lit.require_str()?;
+				let form = match form.value().to_lowercase().as_str() {
+					"nfc" => NormalizationForm::Nfc,
+					"nfd" => NormalizationForm::Nfd,
+					"nfkc" => NormalizationForm::Nfkc,
+					"nfkd" => NormalizationForm::Nfkd,
+					_ => {
+						return Err(Error::new(
+							form.span(),
+							"expected one of `nfc`, `nfd`, `nfkc` or `nfkd`",
+						));
+					}
+				};
+
+				Ok(Self::NormalizeUnicode { form })
 			}
-			// #[preprocess(length(min = 1, max = 10))]
-			Meta::List(list) if list.path.is_ident("length") => {
+			// #[preprocess(length(min = 1, max = 10))] or
+			// #[preprocess(length_bytes(min = 1, max = 10))]
+			Meta::List(list)
+				if list.path.is_ident("length") ||
+					list.path.is_ident("length_bytes") =>
+			{
 				let args = list.parse_args_with(
 					Punctuated::<Meta, Token![,]>::parse_terminated,
 				)?;
@@ -556,22 +4776,14 @@ impl TryFrom<Meta> for Preprocessor {
 							Ok((min, max, Some(meta.value)))
 						}
 						meta => {
-							return Err(
-								if let Some(ident) = meta.path().get_ident() {
-									Error::new(
-										meta.span(),
-										format!(
-											"unexpected argument `{}`",
-											ident,
-										),
-									)
-								} else {
-									Error::new(
-										meta.span(),
-										"unexpected argument",
-									)
-								},
-							)
+							Err(if let Some(ident) = meta.path().get_ident() {
+								Error::new(
+									meta.span(),
+									format!("unexpected argument `{}`", ident,),
+								)
+							} else {
+								Error::new(meta.span(), "unexpected argument")
+							})
 						}
 					},
 				)?;
@@ -581,16 +4793,173 @@ impl TryFrom<Meta> for Preprocessor {
 						list.span(),
 						"expected at least one argument `min`, `max` or `equal`",
 					))
+				} else if list.path.is_ident("length_bytes") {
+					Ok(Self::LengthBytes { min, max, equal })
 				} else {
 					Ok(Self::Length { min, max, equal })
 				}
 			}
-			// #[preprocess(range(min = 1, max = 10))]
+			// #[preprocess(range(min = 1, max = 10))] or
+			// #[preprocess(range(min = 0, max_field = "total_amount"))]
 			Meta::List(list) if list.path.is_ident("range") => {
 				let args = list.parse_args_with(
 					Punctuated::<Meta, Token![,]>::parse_terminated,
 				)?;
 
+				let (mut min, mut max) = (None, None);
+				let (mut min_field, mut max_field) = (None, None);
+				for meta in args {
+					let meta = match meta {
+						Meta::NameValue(meta) => meta,
+						meta => {
+							return Err(Error::new(
+								meta.span(),
+								"expected `min`, `max`, `min_field` or `max_field`",
+							));
+						}
+					};
+					let name = meta
+						.path
+						.get_ident()
+						.map(ToString::to_string)
+						.unwrap_or_default();
+					let duplicate = match name.as_str() {
+						"min" => min.replace(meta.value.clone()).is_some(),
+						"max" => max.replace(meta.value.clone()).is_some(),
+						"min_field" => min_field
+							.replace(
+								meta.value
+									.clone()
+									.require_lit()?
+									.lit
+									.require_str()?,
+							)
+							.is_some(),
+						"max_field" => max_field
+							.replace(
+								meta.value
+									.clone()
+									.require_lit()?
+									.lit
+									.require_str()?,
+							)
+							.is_some(),
+						_ => {
+							return Err(Error::new(
+								meta.span(),
+								format!("unexpected argument `{}`", name),
+							));
+						}
+					};
+					if duplicate {
+						return Err(Error::new(
+							meta.span(),
+							format!("duplicate argument `{}`", name),
+						));
+					}
+				}
+
+				if let (Some(_), Some(field)) = (&min, &min_field) {
+					return Err(Error::new(
+						field.span(),
+						"`min` and `min_field` cannot be used together",
+					));
+				}
+				if let (Some(_), Some(field)) = (&max, &max_field) {
+					return Err(Error::new(
+						field.span(),
+						"`max` and `max_field` cannot be used together",
+					));
+				}
+				if min.is_none() && max.is_none() {
+					if let (None, None) = (&min_field, &max_field) {
+						return Err(Error::new(
+							list.span(),
+							"expected at least one argument `min` or `max`",
+						));
+					}
+				}
+
+				// The other field is cloned, since it is still needed to
+				// construct the processed struct
+				let field_value = |field: &LitStr| -> Result<Expr, Error> {
+					let ident = field.parse::<Ident>()?;
+					Ok(syn::parse_quote! {
+						::core::clone::Clone::clone(&#ident)
+					})
+				};
+				if let Some(field) = &min_field {
+					min = Some(field_value(field)?);
+				}
+				if let Some(field) = &max_field {
+					max = Some(field_value(field)?);
+				}
+
+				Ok([min_field, max_field].into_iter().flatten().fold(
+					Self::Range { min, max },
+					|preprocessor, key| Self::MapField {
+						key,
+						preprocessors: vec![preprocessor],
+					},
+				))
+			}
+			// #[preprocess(csv_length(min = 1, max = 10, delimiter = ","))]
+			Meta::List(list) if list.path.is_ident("csv_length") => {
+				let args = list.parse_args_with(
+					Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+				)?;
+
+				let (mut min, mut max, mut delimiter) = (None, None, None);
+				for meta in args {
+					if meta.path.is_ident("delimiter") {
+						if delimiter.is_some() {
+							return Err(Error::new(
+								meta.path.span(),
+								"duplicate argument `delimiter`",
+							));
+						}
+						delimiter = Some(parse_char(meta.value, "delimiter")?);
+						continue;
+					}
+
+					let option = if meta.path.is_ident("min") {
+						&mut min
+					} else if meta.path.is_ident("max") {
+						&mut max
+					} else {
+						return Err(Error::new(
+							meta.path.span(),
+							"expected argument `min`, `max` or `delimiter`",
+						));
+					};
+					if option.is_some() {
+						return Err(Error::new(
+							meta.path.span(),
+							"duplicate argument",
+						));
+					}
+					*option = Some(meta.value);
+				}
+
+				if min.is_none() && max.is_none() {
+					Err(Error::new(
+						list.span(),
+						"expected at least one argument `min` or `max`",
+					))
+				} else {
+					Ok(Self::CsvLength {
+						min,
+						max,
+						delimiter: delimiter.unwrap_or(','),
+					})
+				}
+			}
+			// #[preprocess(word_count(min = 5, max = 200))]
+			Meta::List(list) if list.path.is_ident("word_count") => {
+				let args = list.parse_args_with(
+					Punctuated::<Meta, Token![,]>::parse_terminated,
+				)?;
+
 				let (min, max) = args.into_iter().try_fold(
 					(None, None),
 					|(min, max), meta| match meta {
@@ -613,22 +4982,14 @@ impl TryFrom<Meta> for Preprocessor {
 							Ok((min, Some(meta.value)))
 						}
 						meta => {
-							return Err(
-								if let Some(ident) = meta.path().get_ident() {
-									Error::new(
-										meta.span(),
-										format!(
-											"unexpected argument `{}`",
-											ident,
-										),
-									)
-								} else {
-									Error::new(
-										meta.span(),
-										"unexpected argument",
-									)
-								},
-							)
+							Err(if let Some(ident) = meta.path().get_ident() {
+								Error::new(
+									meta.span(),
+									format!("unexpected argument `{}`", ident,),
+								)
+							} else {
+								Error::new(meta.span(), "unexpected argument")
+							})
 						}
 					},
 				)?;
@@ -639,7 +5000,7 @@ impl TryFrom<Meta> for Preprocessor {
 						"expected at least one argument `min` or `max`",
 					))
 				} else {
-					Ok(Self::Range { min, max })
+					Ok(Self::WordCount { min, max })
 				}
 			}
 			_ => Err(Error::new(
@@ -653,3 +5014,926 @@ impl TryFrom<Meta> for Preprocessor {
 		}
 	}
 }
+
+/// Splits a map type like `HashMap<K, V>` or `BTreeMap<K, V>` into the map's
+/// type path, the key type and the value type. Returns [`None`] if the given
+/// type is not a map.
+fn split_map_type(ty: &TokenStream2) -> Option<(TypePath, Type, Type)> {
+	let Ok(Type::Path(map_type)) = syn::parse2::<Type>(ty.clone()) else {
+		return None;
+	};
+
+	let segment = map_type.path.segments.last()?;
+	if segment.ident != "HashMap" && segment.ident != "BTreeMap" {
+		return None;
+	}
+	let PathArguments::AngleBracketed(args) = &segment.arguments else {
+		return None;
+	};
+
+	let mut types = args.args.iter().filter_map(|arg| match arg {
+		GenericArgument::Type(ty) => Some(ty.clone()),
+		_ => None,
+	});
+	let key_type = types.next()?;
+	let value_type = types.next()?;
+
+	Some((map_type, key_type, value_type))
+}
+
+/// Rebuilds a map type split using [`split_map_type`], with the given key and
+/// value types. Any other generic arguments (like the hasher) are retained.
+fn join_map_type(
+	map_type: &TypePath,
+	key_type: &TokenStream2,
+	value_type: &TokenStream2,
+) -> TokenStream2 {
+	let mut map_type = map_type.clone();
+	if let Some(PathArguments::AngleBracketed(args)) = map_type
+		.path
+		.segments
+		.last_mut()
+		.map(|segment| &mut segment.arguments)
+	{
+		let mut types = args.args.iter_mut().filter_map(|arg| match arg {
+			GenericArgument::Type(ty) => Some(ty),
+			_ => None,
+		});
+		if let Some(ty) = types.next() {
+			*ty = syn::parse2(key_type.clone())
+				.expect("unable to parse token stream");
+		}
+		if let Some(ty) = types.next() {
+			*ty = syn::parse2(value_type.clone())
+				.expect("unable to parse token stream");
+		}
+	}
+	map_type.to_token_stream()
+}
+
+/// Emits a compile error for map preprocessors applied on non-map fields.
+fn not_a_map_error(preprocessor: &str) -> TokenStream2 {
+	let message = format!(
+		"`{}` can only be applied to map fields like `HashMap` or `BTreeMap`",
+		preprocessor
+	);
+	quote! {
+		::std::compile_error!(#message);
+	}
+}
+
+/// Emits a compile error for sign preprocessors applied on unsigned integer
+/// fields. Other types are left to the `Signed` trait bound to reject.
+fn unsigned_type_error(
+	preprocessor: &str,
+	ty: &TokenStream2,
+) -> Option<TokenStream2> {
+	let ty = ty.to_string();
+	if !["u8", "u16", "u32", "u64", "u128", "usize"].contains(&ty.as_str()) {
+		return None;
+	}
+
+	let message = format!(
+		"`{}` cannot be applied to the unsigned type `{}`",
+		preprocessor, ty
+	);
+	Some(quote! {
+		::std::compile_error!(#message);
+	})
+}
+
+/// Emits a compile error for float validators applied on integer fields.
+/// Other types are left to the `Float` trait bound to reject.
+fn integer_type_error(
+	preprocessor: &str,
+	ty: &TokenStream2,
+) -> Option<TokenStream2> {
+	let ty = ty.to_string();
+	if ![
+		"u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+		"i128", "isize",
+	]
+	.contains(&ty.as_str())
+	{
+		return None;
+	}
+
+	let message = format!(
+		"`{}` cannot be applied to the integer type `{}`",
+		preprocessor, ty
+	);
+	Some(quote! {
+		::std::compile_error!(#message);
+	})
+}
+
+/// Emits a compile error for numeric preprocessors applied on fields that are
+/// known not to be numbers, like strings and collections. Other types are left
+/// to the trait bounds of the respective function to reject.
+fn non_numeric_type_error(
+	preprocessor: &str,
+	ty: &TokenStream2,
+) -> Option<TokenStream2> {
+	let mut parsed = syn::parse2::<Type>(ty.clone()).ok()?;
+	while let Type::Reference(reference) = parsed {
+		parsed = *reference.elem;
+	}
+	let Type::Path(TypePath { path, .. }) = parsed else {
+		return None;
+	};
+	let ident = path.segments.last()?.ident.to_string();
+	if ![
+		"String", "str", "Cow", "bool", "char", "Vec", "VecDeque", "HashMap",
+		"HashSet", "BTreeMap", "BTreeSet", "Option", "Url", "IpAddr",
+		"Ipv4Addr", "Ipv6Addr",
+	]
+	.contains(&ident.as_str())
+	{
+		return None;
+	}
+
+	let message = format!(
+		"`{}` can only be applied to numeric fields, not `{}`",
+		preprocessor, ident
+	);
+	Some(quote! {
+		::std::compile_error!(#message);
+	})
+}
+
+/// Emits a compile error for date validators applied on fields that are not
+/// of the type `NaiveDate` at that point of the chain, which is usually
+/// because `date(as_date = true)` is missing before them.
+fn non_date_type_error(
+	preprocessor: &str,
+	ty: &TokenStream2,
+) -> Option<TokenStream2> {
+	let is_date = syn::parse2::<Type>(ty.clone()).is_ok_and(|ty| {
+		matches!(
+			ty,
+			Type::Path(TypePath { path, .. })
+				if path.segments.last().is_some_and(|segment| {
+					segment.ident == "NaiveDate"
+				})
+		)
+	});
+	if is_date {
+		return None;
+	}
+
+	let message = format!(
+		concat!(
+			"`{}` can only be applied to `NaiveDate` fields. ",
+			"Use `date(as_date = true)` before it to parse the field into a ",
+			"`NaiveDate`"
+		),
+		preprocessor
+	);
+	Some(quote! {
+		::std::compile_error!(#message);
+	})
+}
+
+/// Emits a compile error for `length_bytes` applied on fields that are known
+/// not to have a length in bytes, like numbers and collections of anything
+/// other than bytes. Other types are left to the `HasByteLen` trait bound to
+/// reject.
+fn non_byte_type_error(ty: &TokenStream2) -> Option<TokenStream2> {
+	let mut parsed = syn::parse2::<Type>(ty.clone()).ok()?;
+	while let Type::Reference(reference) = parsed {
+		parsed = *reference.elem;
+	}
+	let name = match parsed {
+		Type::Path(TypePath { path, .. }) => {
+			let segment = path.segments.last()?;
+			let is_bytes = match &segment.arguments {
+				PathArguments::AngleBracketed(args) => {
+					args.args.len() == 1 &&
+						matches!(
+							args.args.first(),
+							Some(GenericArgument::Type(Type::Path(arg)))
+								if arg.path.is_ident("u8")
+						)
+				}
+				_ => false,
+			};
+			let name = segment.ident.to_string();
+			if name == "Vec" && is_bytes {
+				return None;
+			}
+			if ![
+				"u8",
+				"u16",
+				"u32",
+				"u64",
+				"u128",
+				"usize",
+				"i8",
+				"i16",
+				"i32",
+				"i64",
+				"i128",
+				"isize",
+				"f32",
+				"f64",
+				"bool",
+				"char",
+				"Vec",
+				"VecDeque",
+				"LinkedList",
+				"BinaryHeap",
+				"HashMap",
+				"HashSet",
+				"BTreeMap",
+				"BTreeSet",
+				"Option",
+				"Url",
+				"IpAddr",
+				"Ipv4Addr",
+				"Ipv6Addr",
+			]
+			.contains(&name.as_str())
+			{
+				return None;
+			}
+			name
+		}
+		Type::Slice(slice) => {
+			if matches!(&*slice.elem, Type::Path(elem) if elem.path.is_ident("u8"))
+			{
+				return None;
+			}
+			ty.to_string()
+		}
+		_ => return None,
+	};
+
+	let message = format!(
+		"`length_bytes` can only be applied to strings and byte buffers, not `{}`",
+		name
+	);
+	Some(quote! {
+		::std::compile_error!(#message);
+	})
+}
+
+/// Returns the kind of the given literal, like `string` or `number`, or
+/// [`None`] if the expression is not a literal.
+fn literal_kind(expr: &Expr) -> Option<&'static str> {
+	match expr {
+		Expr::Lit(ExprLit { lit, .. }) => match lit {
+			Lit::Str(_) => Some("string"),
+			Lit::Int(_) | Lit::Float(_) => Some("number"),
+			Lit::Bool(_) => Some("boolean"),
+			Lit::Char(_) => Some("character"),
+			_ => None,
+		},
+		Expr::Unary(ExprUnary {
+			op: UnOp::Neg(_),
+			expr,
+			..
+		}) => literal_kind(expr).filter(|kind| *kind == "number"),
+		Expr::Paren(ExprParen { expr, .. }) => literal_kind(expr),
+		_ => None,
+	}
+}
+
+/// Returns the kind of literals that can be compared with a field of the given
+/// type, along with the name of the type, or [`None`] if the type is not a
+/// string, number, boolean or character type.
+fn field_literal_kind(ty: &TokenStream2) -> Option<(&'static str, String)> {
+	let mut parsed = syn::parse2::<Type>(ty.clone()).ok()?;
+	while let Type::Reference(reference) = parsed {
+		parsed = *reference.elem;
+	}
+	let Type::Path(TypePath { path, .. }) = parsed else {
+		return None;
+	};
+	let ident = path.segments.last()?.ident.to_string();
+	let kind = match ident.as_str() {
+		"String" | "str" | "Cow" => "string",
+		"u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" |
+		"i32" | "i64" | "i128" | "isize" | "f32" | "f64" => "number",
+		"bool" => "boolean",
+		"char" => "character",
+		_ => return None,
+	};
+	Some((kind, ident))
+}
+
+/// Emits a compile error for `whitelist` and `blacklist` if all the items are
+/// literals, but they are not all of the same kind, or their kind does not
+/// match the type of the field, like string literals for an `i32` field.
+/// Fields of any other type are left to the `PartialEq` trait bound to reject.
+fn list_item_type_error(
+	preprocessor: &str,
+	items: &[Expr],
+	ty: &TokenStream2,
+) -> Option<TokenStream2> {
+	let kinds = items.iter().map(literal_kind).collect::<Option<Vec<_>>>()?;
+	let kind = kinds[0];
+	let message = if kinds.iter().any(|other| *other != kind) {
+		format!(
+			"all the items of `{}` must be of the same type",
+			preprocessor
+		)
+	} else {
+		let (field_kind, ident) = field_literal_kind(ty)?;
+		if field_kind == kind {
+			return None;
+		}
+		format!(
+			"the items of `{}` are {} literals, which cannot be compared with the field of the type `{}`",
+			preprocessor, kind, ident
+		)
+	};
+	Some(quote! {
+		::std::compile_error!(#message);
+	})
+}
+
+/// Emits a compile error for `equal` and `not_equal` if the value is a literal
+/// whose kind does not match the type of the field, like a string literal for
+/// an `i32` field. Like [`list_item_type_error`], fields of any other type are
+/// left to the `PartialEq` trait bound to reject.
+fn value_type_error(
+	preprocessor: &str,
+	value: &Expr,
+	ty: &TokenStream2,
+) -> Option<TokenStream2> {
+	let kind = literal_kind(value)?;
+	let (field_kind, ident) = field_literal_kind(ty)?;
+	if field_kind == kind {
+		return None;
+	}
+	let message = format!(
+		"the value of `{}` is a {} literal, which cannot be compared with the field of the type `{}`",
+		preprocessor, kind, ident
+	);
+	Some(quote! {
+		::std::compile_error!(#message);
+	})
+}
+
+/// Emits a compile error if the `min` and `max` shorthands are used together
+/// with `range` on the same field, or if both of them are integer literals and
+/// `min` is greater than `max`.
+fn range_shorthand_error(
+	preprocessors: &[Preprocessor],
+	min: Option<&Expr>,
+	max: Option<&Expr>,
+) -> Option<TokenStream2> {
+	if min.is_none() && max.is_none() {
+		return None;
+	}
+
+	let message = if preprocessors.iter().any(|preprocessor| {
+		matches!(
+			preprocessor,
+			Preprocessor::Range { .. } | Preprocessor::MapField { .. }
+		)
+	}) {
+		"`min` and `max` cannot be used together with `range` on the same field"
+			.to_string()
+	} else {
+		let (min_value, max_value) =
+			(integer_literal(min?)?, integer_literal(max?)?);
+		if min_value <= max_value {
+			return None;
+		}
+		format!(
+			"`min = {}` cannot be greater than `max = {}`",
+			min_value, max_value
+		)
+	};
+	Some(quote! {
+		::std::compile_error!(#message);
+	})
+}
+
+/// Emits a compile error if both `lower_bound` and `upper_bound` are integer
+/// literals, and `lower_bound` is greater than `upper_bound`.
+fn bound_order_error(
+	lower: Option<&Expr>,
+	upper: Option<&Expr>,
+) -> Option<TokenStream2> {
+	let (lower, upper) = (integer_literal(lower?)?, integer_literal(upper?)?);
+	if lower <= upper {
+		return None;
+	}
+
+	let message = format!(
+		"`lower_bound = {}` cannot be greater than `upper_bound = {}`",
+		lower, upper
+	);
+	Some(quote! {
+		::std::compile_error!(#message);
+	})
+}
+
+/// Formats the bound of `lower_bound` or `upper_bound` for the error message,
+/// like `100`, `-5` or `MAX_LIMIT`.
+fn bound_to_string(bound: &Expr) -> String {
+	bound.to_token_stream().to_string().replace(' ', "")
+}
+
+/// Emits a compile error if `min_bytes` or `max_bytes` is used together with
+/// `length_bytes` on the same field, or if both bounds are integer literals
+/// and the minimum is greater than the maximum.
+fn byte_shorthand_error(
+	preprocessors: &[Preprocessor],
+	min: Option<&Expr>,
+	max: Option<&Expr>,
+) -> Option<TokenStream2> {
+	if min.is_none() && max.is_none() {
+		return None;
+	}
+
+	let message = if preprocessors.iter().any(|preprocessor| {
+		matches!(preprocessor, Preprocessor::LengthBytes { .. })
+	}) {
+		"`min_bytes` and `max_bytes` cannot be used together with `length_bytes` on the same field"
+			.to_string()
+	} else {
+		let (min_value, max_value) =
+			(integer_literal(min?)?, integer_literal(max?)?);
+		if min_value <= max_value {
+			return None;
+		}
+		format!(
+			"`min_bytes = {}` cannot be greater than `max_bytes = {}`",
+			min_value, max_value
+		)
+	};
+	Some(quote! {
+		::std::compile_error!(#message);
+	})
+}
+
+/// Generates a statement that emits the given message as a warning. There is
+/// no way to emit a warning from a proc macro on stable, so a deprecated item
+/// is used instead.
+fn warning(span: Span, message: &str) -> TokenStream2 {
+	quote_spanned! {span=>
+		{
+			#[deprecated(note = #message)]
+			#[allow(non_upper_case_globals)]
+			const suggestion: () = ();
+			#[allow(clippy::let_unit_value)]
+			let _ = suggestion;
+		}
+	}
+}
+
+/// Emits a compile error if `exact_length` is used together with `length` on
+/// the same field, since the two would check the same length twice.
+fn exact_length_error(preprocessors: &[Preprocessor]) -> Option<TokenStream2> {
+	let exact_length = preprocessors.iter().any(|preprocessor| {
+		matches!(preprocessor, Preprocessor::ExactLength(_))
+	});
+	let length = preprocessors.iter().any(|preprocessor| {
+		matches!(preprocessor, Preprocessor::Length { .. })
+	});
+	if !exact_length || !length {
+		return None;
+	}
+
+	Some(quote! {
+		::std::compile_error!("`exact_length` cannot be used together with `length` on the same field");
+	})
+}
+
+/// Emits a compile error if `warn_only` is not the last preprocessor on the
+/// field, if it is used together with a fallback value, or if the `tracing`
+/// feature is not enabled.
+fn warn_only_error(preprocessors: &[Preprocessor]) -> Option<TokenStream2> {
+	let position = preprocessors.iter().position(|preprocessor| {
+		matches!(preprocessor, Preprocessor::WarnOnly)
+	})?;
+
+	let message = if !cfg!(feature = "tracing") {
+		"`warn_only` requires the `tracing` feature to be enabled"
+	} else if position != preprocessors.len() - 1 {
+		"`warn_only` must be the last preprocessor on the field"
+	} else if preprocessors.iter().any(|preprocessor| {
+		matches!(
+			preprocessor,
+			Preprocessor::Default(_) |
+				Preprocessor::DefaultFn(_) |
+				Preprocessor::OnError(_)
+		)
+	}) {
+		"`warn_only` cannot be used together with `default`, `default_fn` or `on_error`"
+	} else {
+		return None;
+	};
+	Some(quote! {
+		::std::compile_error!(#message);
+	})
+}
+
+/// Emits a compile error if `map_error` or `error_message` is followed by
+/// anything other than a field modifier, or if both of them are used on the
+/// same field.
+fn map_error_error(preprocessors: &[Preprocessor]) -> Option<TokenStream2> {
+	let position = preprocessors.iter().position(|preprocessor| {
+		matches!(
+			preprocessor,
+			Preprocessor::MapError(_) | Preprocessor::ErrorMessage(_)
+		)
+	})?;
+
+	let mut modifiers = preprocessors[position + 1..].iter();
+	let message = if !modifiers.all(|preprocessor| {
+		matches!(
+			preprocessor,
+			Preprocessor::Default(_) |
+				Preprocessor::DefaultFn(_) |
+				Preprocessor::OnError(_) |
+				Preprocessor::Sensitive |
+				Preprocessor::WarnOnly |
+				Preprocessor::MapError(_) |
+				Preprocessor::ErrorMessage(_)
+		)
+	}) {
+		"`map_error` and `error_message` must be specified after all the other preprocessors on the field"
+	} else if preprocessors[position + 1..].iter().any(|preprocessor| {
+		matches!(
+			preprocessor,
+			Preprocessor::MapError(_) | Preprocessor::ErrorMessage(_)
+		)
+	}) {
+		"only one of `map_error` or `error_message` can be used on a field"
+	} else {
+		return None;
+	};
+	Some(quote! {
+		::std::compile_error!(#message);
+	})
+}
+
+/// Returns the value of the given expression if it is an integer literal,
+/// optionally negated.
+fn integer_literal(expr: &Expr) -> Option<i128> {
+	match expr {
+		Expr::Lit(ExprLit {
+			lit: Lit::Int(lit), ..
+		}) => lit.base10_parse().ok(),
+		Expr::Unary(ExprUnary {
+			op: UnOp::Neg(_),
+			expr,
+			..
+		}) => integer_literal(expr).map(|value: i128| -value),
+		Expr::Paren(ExprParen { expr, .. }) => integer_literal(expr),
+		_ => None,
+	}
+}
+
+/// Parses a bound of `number_in_range`, which can be a number or a string
+/// containing a number, like `1`, `-2.5` or `"100"`.
+fn parse_number(value: &Expr) -> Result<f64, Error> {
+	let number = match value {
+		Expr::Lit(ExprLit {
+			lit: Lit::Str(lit), ..
+		}) => lit.value().parse::<f64>().ok(),
+		Expr::Lit(ExprLit {
+			lit: Lit::Int(lit), ..
+		}) => lit.base10_parse::<f64>().ok(),
+		Expr::Lit(ExprLit {
+			lit: Lit::Float(lit),
+			..
+		}) => lit.base10_parse::<f64>().ok(),
+		Expr::Unary(ExprUnary {
+			op: UnOp::Neg(_),
+			expr,
+			..
+		}) if !matches!(
+			**expr,
+			Expr::Lit(ExprLit {
+				lit: Lit::Str(_),
+				..
+			})
+		) =>
+		{
+			parse_number(expr).ok().map(|number| -number)
+		}
+		_ => None,
+	};
+
+	number.filter(|number| number.is_finite()).ok_or_else(|| {
+		Error::new(
+			value.span(),
+			"expected a finite number, like `1` or `\"1\"`",
+		)
+	})
+}
+
+/// Parses a character argument, like the delimiter of `csv_length` and
+/// `csv_items`, which must be a string literal containing exactly one
+/// character. `name` is the name of the argument used in the error message.
+fn parse_char(value: Expr, name: &str) -> Result<char, Error> {
+	let lit = value.require_lit()?.lit.require_str()?;
+	let value = lit.value();
+	let mut chars = value.chars();
+	match (chars.next(), chars.next()) {
+		(Some(ch), None) => Ok(ch),
+		_ => Err(Error::new(
+			lit.span(),
+			format!("expected a {} with exactly one character", name),
+		)),
+	}
+}
+
+/// Parses a string argument that must not be empty, like the prefix of
+/// `strip_prefix` and `require_prefix`. `name` is the name of the argument
+/// used in the error message.
+fn non_empty_str(value: Expr, name: &str) -> Result<String, Error> {
+	let lit = value.require_lit()?.lit.require_str()?;
+	let value = lit.value();
+	if value.is_empty() {
+		return Err(Error::new(
+			lit.span(),
+			format!("expected a non-empty string as the {}", name),
+		));
+	}
+	Ok(value)
+}
+
+/// Parses a comma-separated list of preprocessors, like
+/// `Punctuated::<Meta, Token![,]>::parse_terminated`, except that the `type`
+/// keyword is also allowed as the name of a preprocessor, so that the older
+/// `#[preprocess(type = "u32")]` form keeps working.
+fn parse_metas(
+	input: ParseStream,
+) -> Result<Punctuated<Meta, Token![,]>, Error> {
+	let mut metas = Punctuated::new();
+	while !input.is_empty() {
+		if input.peek(Token![type]) {
+			let keyword = input.parse::<Token![type]>()?;
+			metas.push_value(Meta::NameValue(MetaNameValue {
+				path: Ident::new("type", keyword.span).into(),
+				eq_token: input.parse()?,
+				value: input.parse()?,
+			}));
+		} else {
+			metas.push_value(input.parse()?);
+		}
+		if input.is_empty() {
+			break;
+		}
+		metas.push_punct(input.parse()?);
+	}
+	Ok(metas)
+}
+
+/// Parses the target type of `coerce` (or `type`), which can be a string
+/// literal like `"u32"` or a path like `std::num::NonZeroU32`.
+fn parse_coerce_type(value: &Expr) -> Result<String, Error> {
+	match value {
+		Expr::Lit(ExprLit {
+			lit: Lit::Str(lit), ..
+		}) => {
+			syn::parse_str::<Type>(&lit.value()).map_err(|_| {
+				Error::new(lit.span(), "expected a type, like `\"u32\"`")
+			})?;
+			Ok(lit.value())
+		}
+		Expr::Path(path) => Ok(path.to_token_stream().to_string()),
+		value => {
+			Err(Error::new(value.span(), "expected a type, like `\"u32\"`"))
+		}
+	}
+}
+
+/// Checks the template of `interpolate` at compile time, using the same rules
+/// as `validate_template`: `{{` and `}}` are literal braces, placeholders must
+/// have a name, and two placeholders must be separated by some literal text.
+/// The template must also contain at least one placeholder.
+fn check_template(template: &str) -> Result<(), &'static str> {
+	let mut placeholders = 0;
+	let mut previous_was_placeholder = false;
+	let mut chars = template.chars().peekable();
+	while let Some(ch) = chars.next() {
+		match ch {
+			'{' if chars.next_if_eq(&'{').is_some() => (),
+			'}' if chars.next_if_eq(&'}').is_some() => (),
+			'{' => {
+				if previous_was_placeholder {
+					return Err(
+						"placeholders must be separated by literal text, like `{a}-{b}`",
+					);
+				}
+				let mut name = String::new();
+				loop {
+					match chars.next() {
+						Some('}') => break,
+						Some('{') | None => {
+							return Err("unclosed `{` in template");
+						}
+						Some(ch) => name.push(ch),
+					}
+				}
+				if name.is_empty() {
+					return Err("placeholders must have a name, like `{id}`");
+				}
+				placeholders += 1;
+				previous_was_placeholder = true;
+				continue;
+			}
+			'}' => {
+				return Err(
+					"unmatched `}` in template, use `}}` for a literal brace",
+				);
+			}
+			_ => (),
+		}
+		previous_was_placeholder = false;
+	}
+
+	if placeholders == 0 {
+		return Err(
+			"expected at least one placeholder in the template, like `{id}`",
+		);
+	}
+	Ok(())
+}
+
+/// Checks the network of `ip(network = "...")` at compile time, using the same
+/// rules as `validate_ip_cidr_pair` with `strict` set to `true`, so that the
+/// network can be parsed without failing at runtime.
+fn check_network(network: &str) -> Result<(), String> {
+	let Some((address, prefix)) = network.split_once('/') else {
+		return Err(
+			"expected a network in CIDR notation, like `\"10.0.0.0/8\"`"
+				.to_string(),
+		);
+	};
+	let address = address
+		.parse::<IpAddr>()
+		.map_err(|err| format!("invalid network address: {}", err))?;
+	let max_prefix = if address.is_ipv4() { 32 } else { 128 };
+	let prefix = prefix
+		.parse::<u8>()
+		.ok()
+		.filter(|prefix| *prefix <= max_prefix)
+		.ok_or_else(|| {
+			format!(
+				"network prefix length must be between 0 and {}",
+				max_prefix
+			)
+		})?;
+
+	let host_bits = match address {
+		IpAddr::V4(address) => u128::from(
+			u32::from(address).checked_shl(prefix.into()).unwrap_or(0),
+		),
+		IpAddr::V6(address) => {
+			u128::from(address).checked_shl(prefix.into()).unwrap_or(0)
+		}
+	};
+	if host_bits != 0 {
+		return Err(format!(
+			"network address must not have host bits set, use `{}/{}` instead",
+			mask_network(address, prefix),
+			prefix
+		));
+	}
+	Ok(())
+}
+
+/// Clears the host bits of the given network address, to suggest the correct
+/// network when `check_network` fails.
+fn mask_network(address: IpAddr, prefix: u8) -> IpAddr {
+	match address {
+		IpAddr::V4(address) => {
+			let mask =
+				u32::MAX.checked_shl(32 - u32::from(prefix)).unwrap_or(0);
+			IpAddr::V4((u32::from(address) & mask).into())
+		}
+		IpAddr::V6(address) => {
+			let mask =
+				u128::MAX.checked_shl(128 - u32::from(prefix)).unwrap_or(0);
+			IpAddr::V6((u128::from(address) & mask).into())
+		}
+	}
+}
+
+/// Parses the country of `phone` and `normalize_phone`, which must be a
+/// two-letter country code, like `"US"`. The country is returned as an
+/// identifier, since it is used as a variant of `PhoneCountry`, so unknown
+/// countries are reported when the generated code is compiled.
+fn parse_phone_country(value: Expr) -> Result<Ident, Error> {
+	let lit = value.require_lit()?.lit.require_str()?;
+	let value = lit.value();
+	if value.len() != 2 || !value.chars().all(|ch| ch.is_ascii_uppercase()) {
+		return Err(Error::new(
+			lit.span(),
+			"expected a two-letter country code, like `\"US\"`",
+		));
+	}
+	Ok(Ident::new(&value, lit.span()))
+}
+
+/// Generates the `Option<PhoneCountry>` argument of the phone validators.
+fn phone_country_token_stream(country: Option<&Ident>) -> TokenStream2 {
+	match country {
+		Some(country) => quote! {
+			::std::option::Option::Some(::preprocess::types::PhoneCountry::#country)
+		},
+		None => quote! { ::std::option::Option::None },
+	}
+}
+
+/// The name of the variable that the processed entries of a map are collected
+/// into. The identifier is hygienic, so that it cannot clash with the name of
+/// a field.
+/// Whether the given type is a string type that a string literal can be
+/// converted into, such as `String`, `Cow<str>` or `&str`.
+fn is_string_type(ty: &TokenStream2) -> bool {
+	match syn::parse2::<Type>(ty.clone()) {
+		Ok(Type::Reference(_)) => true,
+		Ok(Type::Path(TypePath { path, .. })) => {
+			path.segments.last().is_some_and(|segment| {
+				segment.ident == "String" || segment.ident == "Cow"
+			})
+		}
+		_ => false,
+	}
+}
+
+fn map_ident() -> Ident {
+	Ident::new("map", Span::mixed_site())
+}
+
+/// Sets the span of every `::std::compile_error!(...)` invocation in the given
+/// token stream to the given span, so that the errors point to that span. The
+/// rest of the tokens, including user-supplied expressions, keep their spans.
+fn respan_compile_errors(tokens: TokenStream2, span: Span) -> TokenStream2 {
+	let tokens = tokens.into_iter().collect::<Vec<_>>();
+	let mut respanned = Vec::with_capacity(tokens.len());
+	let mut index = 0;
+	while index < tokens.len() {
+		let is_compile_error = matches!(
+			&tokens[index..],
+			[
+				TokenTree::Punct(colon1),
+				TokenTree::Punct(colon2),
+				TokenTree::Ident(std),
+				TokenTree::Punct(colon3),
+				TokenTree::Punct(colon4),
+				TokenTree::Ident(compile_error),
+				TokenTree::Punct(bang),
+				TokenTree::Group(_),
+				..
+			] if colon1.as_char() == ':' &&
+				colon2.as_char() == ':' &&
+				std == "std" &&
+				colon3.as_char() == ':' &&
+				colon4.as_char() == ':' &&
+				compile_error == "compile_error" &&
+				bang.as_char() == '!'
+		);
+		if is_compile_error {
+			respanned.extend(respan(
+				tokens[index..index + 8].iter().cloned().collect(),
+				span,
+			));
+			index += 8;
+			continue;
+		}
+
+		respanned.push(match &tokens[index] {
+			TokenTree::Group(group) => {
+				let mut inner = Group::new(
+					group.delimiter(),
+					respan_compile_errors(group.stream(), span),
+				);
+				inner.set_span(group.span());
+				TokenTree::Group(inner)
+			}
+			token => token.clone(),
+		});
+		index += 1;
+	}
+	respanned.into_iter().collect()
+}
+
+/// Sets the span of every token in the given token stream to the given span,
+/// so that errors in the generated code point to that span.
+fn respan(tokens: TokenStream2, span: Span) -> TokenStream2 {
+	tokens
+		.into_iter()
+		.map(|token| match token {
+			TokenTree::Group(group) => {
+				let mut respanned =
+					Group::new(group.delimiter(), respan(group.stream(), span));
+				respanned.set_span(span);
+				TokenTree::Group(respanned)
+			}
+			mut token => {
+				token.set_span(span);
+				token
+			}
+		})
+		.collect()
+}
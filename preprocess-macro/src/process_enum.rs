@@ -2,9 +2,11 @@ use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote, ToTokens};
 use syn::{
+	punctuated::Punctuated,
 	token::Brace,
 	Attribute,
 	Error,
+	Expr,
 	Field,
 	Fields,
 	FieldsNamed,
@@ -19,8 +21,14 @@ use syn::{
 };
 
 use crate::{
+	auto_derive_attr,
+	collect_errors_token_stream,
+	error_type,
+	global_token_stream,
 	preprocessor::Preprocessor,
 	processed_fields::{ProcessedFields, ProcessedNamed, ProcessedUnnamed},
+	struct_span,
+	MacroArgs,
 };
 
 pub struct ParsedEnum {
@@ -39,6 +47,7 @@ pub struct ProcessedVariant {
 	attrs: Vec<Attribute>,
 	ident: Ident,
 	fields: ProcessedFields,
+	discriminant: Option<(Token![=], Expr)>,
 }
 
 impl ToTokens for ProcessedVariant {
@@ -46,14 +55,107 @@ impl ToTokens for ProcessedVariant {
 		let attrs = &self.attrs;
 		let ident = &self.ident.clone();
 		let fields = self.fields.to_token_stream();
+		let discriminant = self
+			.discriminant
+			.as_ref()
+			.map(|(eq_token, expr)| quote! { #eq_token #expr });
 
 		tokens.extend(quote! {
 			#(#attrs) *
-			#ident #fields
+			#ident #fields #discriminant
 		});
 	}
 }
 
+/// The integer types that can be used in the `#[repr]` of a C-style enum
+const INTEGER_REPRS: &[&str] = &[
+	"u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+	"i128", "isize",
+];
+
+/// Returns the integer type of the enum if it is a C-style enum, which is an
+/// enum with only unit variants and a `#[repr]` with an integer type, like
+/// `#[repr(u8)]`. Discriminants and discriminant based validation are only
+/// supported on such enums.
+fn c_style_repr(
+	attrs: &[Attribute],
+	variants: &[ProcessedVariant],
+) -> Option<Ident> {
+	if !variants
+		.iter()
+		.all(|variant| matches!(variant.fields, ProcessedFields::Unit))
+	{
+		return None;
+	}
+
+	attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("repr"))
+		.filter_map(|attr| {
+			attr.parse_args_with(
+				Punctuated::<Ident, Token![,]>::parse_terminated,
+			)
+			.ok()
+		})
+		.flatten()
+		.find(|repr| INTEGER_REPRS.iter().any(|int| repr == int))
+}
+
+/// Generates the code for a type-level `range`, `min` or `max` validator on a
+/// C-style enum, which validates the discriminant of the variant instead of
+/// the value itself. The discriminant is read by matching on the variant, so
+/// that the enum doesn't need to implement `Copy`. Returns `None` for other
+/// preprocessors.
+fn discriminant_range_token_stream(
+	preprocessor: &Preprocessor,
+	ident: &Ident,
+	repr: Option<&Ident>,
+	variants: &[ProcessedVariant],
+) -> Option<Result<TokenStream2, Error>> {
+	let (min, max) = match preprocessor {
+		Preprocessor::Range { min, max } => (min.as_ref(), max.as_ref()),
+		Preprocessor::Min(min) => (Some(min), None),
+		Preprocessor::Max(max) => (None, Some(max)),
+		_ => return None,
+	};
+	let Some(repr) = repr else {
+		return Some(Err(Error::new_spanned(
+			ident,
+			concat!(
+				"`range`, `min` and `max` can only be used on enums with only ",
+				"unit variants and an integer `#[repr]`, like `#[repr(u8)]`"
+			),
+		)));
+	};
+
+	let min = match min {
+		Some(min) => quote! { ::std::option::Option::Some(#min) },
+		None => quote! { ::std::option::Option::None },
+	};
+	let max = match max {
+		Some(max) => quote! { ::std::option::Option::Some(#max) },
+		None => quote! { ::std::option::Option::None },
+	};
+	let arms = variants.iter().map(|variant| {
+		let cfgs = variant
+			.attrs
+			.iter()
+			.filter(|attr| attr.path().is_ident("cfg"));
+		let variant = &variant.ident;
+		quote! {
+			#(#cfgs)*
+			Self::#variant => Self::#variant as #repr,
+		}
+	});
+
+	Some(Ok(quote! {
+		let discriminant: #repr = match &value {
+			#(#arms)*
+		};
+		::preprocess::validators::validate_range(discriminant, #min, #max)?;
+	}))
+}
+
 impl TryFrom<ItemEnum> for ParsedEnum {
 	type Error = Error;
 
@@ -78,22 +180,32 @@ impl TryFrom<ItemEnum> for ParsedEnum {
 					discriminant,
 				} = variant;
 
-				if let Some(discriminant) = discriminant {
-					return Err(Error::new_spanned(
-						&discriminant.1,
-						"Preprocess does not support discriminants.",
-					));
-				}
-
 				// For now, no preprocessors are allowed on variants.
 
 				Ok(ProcessedVariant {
 					attrs,
 					ident,
 					fields: fields.try_into()?,
+					discriminant,
 				})
 			})
-			.collect::<Result<_, Error>>()?;
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		if let Some((_, discriminant)) = variants
+			.iter()
+			.find_map(|variant| variant.discriminant.as_ref())
+		{
+			if c_style_repr(&attrs, &variants).is_none() {
+				return Err(Error::new_spanned(
+					discriminant,
+					concat!(
+						"discriminants are only supported on enums with only ",
+						"unit variants and an integer `#[repr]`, like ",
+						"`#[repr(u8)]`"
+					),
+				));
+			}
+		}
 
 		let global = attrs
 			.iter()
@@ -122,7 +234,7 @@ impl TryFrom<ItemEnum> for ParsedEnum {
 
 pub fn into_processed(
 	item: ItemEnum,
-	strict_mode: bool,
+	args: &MacroArgs,
 ) -> Result<TokenStream, Error> {
 	let parsed: ParsedEnum = item.try_into()?;
 
@@ -137,6 +249,13 @@ pub fn into_processed(
 		global,
 	} = parsed;
 
+	if args.deref {
+		return Err(Error::new(
+			ident.span(),
+			"`deref` can only be used on structs, not on enums",
+		));
+	}
+
 	let processed_ident = format_ident!("{}Processed", ident);
 
 	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
@@ -154,21 +273,17 @@ pub fn into_processed(
 					named: named
 						.iter()
 						.map(|(field, preprocessors)| {
-							if strict_mode && preprocessors.is_empty() {
-								return Err(Error::new_spanned(
+							if args.strict_mode {
+								Preprocessor::check_strict_mode(
 									field,
-									"every field must have at least one preprocessor in strict mode",
-								));
+									preprocessors,
+								)?;
 							}
-							let new_type = preprocessors
-								.iter()
-								.fold(
-									field.ty.to_token_stream(),
-									|acc, preprocessor| {
-										preprocessor.get_new_type(&acc)
-									},
-								)
-								.to_string();
+							let new_type = Preprocessor::chain_new_type(
+								preprocessors,
+								&field.ty.to_token_stream(),
+							)
+							.to_string();
 
 							let ty: Type = syn::parse_str(&new_type)?;
 							Ok(Field {
@@ -190,21 +305,17 @@ pub fn into_processed(
 					unnamed: unnamed
 						.iter()
 						.map(|(field, preprocessors)| {
-							if strict_mode && preprocessors.is_empty() {
-								return Err(Error::new_spanned(
+							if args.strict_mode {
+								Preprocessor::check_strict_mode(
 									field,
-									"every field must have at least one preprocessor in strict mode",
-								));
+									preprocessors,
+								)?;
 							}
-							let new_type = preprocessors
-								.iter()
-								.fold(
-									field.ty.to_token_stream(),
-									|acc, preprocessor| {
-										preprocessor.get_new_type(&acc)
-									},
-								)
-								.to_string();
+							let new_type = Preprocessor::chain_new_type(
+								preprocessors,
+								&field.ty.to_token_stream(),
+							)
+							.to_string();
 
 							let ty: Type = syn::parse_str(&new_type)?;
 							Ok(Field {
@@ -223,98 +334,50 @@ pub fn into_processed(
 				attrs: variant.attrs.clone(),
 				ident: variant.ident.clone(),
 				fields,
-				discriminant: None,
+				discriminant: variant.discriminant.clone(),
 			})
 		})
 		.collect::<Result<Vec<_>, Error>>()?;
 
-	let global_preprocessors = global.into_iter().map(|preprocessor| {
-		preprocessor.as_processor_token_stream(
-			&format_ident!("value"),
-			&ident.to_token_stream(),
-		)
-	});
+	let repr = c_style_repr(&attrs, &variants);
+	let global_preprocessors = global
+		.into_iter()
+		.map(|preprocessor| {
+			discriminant_range_token_stream(
+				&preprocessor,
+				&ident,
+				repr.as_ref(),
+				&variants,
+			)
+			.unwrap_or_else(|| {
+				Ok(preprocessor.as_processor_token_stream(
+					&format_ident!("value"),
+					&quote! { #ident #ty_generics },
+				))
+			})
+		})
+		.collect::<Result<_, Error>>()?;
+	let global_preprocessors = global_token_stream(global_preprocessors, args);
+
+	let (collect_errors, return_errors) = collect_errors_token_stream(args);
 
 	let variants_destructed = variants.iter().map(|variant| {
 		let ProcessedVariant {
 			attrs,
 			ident,
 			fields,
+			..
 		} = variant;
 
-		let field_names_destructured = match &fields {
-			ProcessedFields::Unit => TokenStream2::new(),
-			ProcessedFields::Named(ProcessedNamed { named, .. }) => {
-				let named =
-					named.iter().map(|(field, _)| field.ident.clone().unwrap());
-				quote! {
-					{
-						#(#named),*
-					}
-				}
-			}
-			ProcessedFields::Unnamed(ProcessedUnnamed { unnamed, .. }) => {
-				let unnamed = unnamed
-					.iter()
-					.enumerate()
-					.map(|(index, _)| format_ident!("field_{}", index));
-				quote! {
-					(
-						#(#unnamed),*
-					)
-				}
-			}
-		};
+		let field_names_destructured = fields.destructure_token_stream();
 
-		let field_preprocessors = match &fields {
-			ProcessedFields::Unit => vec![],
-			ProcessedFields::Named(ProcessedNamed { named, .. }) => named
-				.iter()
-				.flat_map(|(field, preprocessors)| {
-					preprocessors
-						.iter()
-						.fold(
-							(quote! {}, field.ty.to_token_stream()),
-							|(mut acc, new_ty), preprocessor| {
-								let new_ty = preprocessor.get_new_type(&new_ty);
-								acc.extend(
-									preprocessor.as_processor_token_stream(
-										field.ident.as_ref().unwrap(),
-										&new_ty,
-									),
-								);
-
-								(acc, new_ty)
-							},
-						)
-						.0
-				})
-				.collect(),
-			ProcessedFields::Unnamed(ProcessedUnnamed { unnamed, .. }) => {
-				unnamed
-					.iter()
-					.enumerate()
-					.flat_map(|(index, (field, preprocessors))| {
-						preprocessors
-							.iter()
-							.fold(
-								(quote! {}, field.ty.to_token_stream()),
-								|(mut acc, new_ty), preprocessor| {
-									acc.extend(
-										preprocessor.as_processor_token_stream(
-											&format_ident!("field_{}", index),
-											&new_ty,
-										),
-									);
-
-									(acc, preprocessor.get_new_type(&new_ty))
-								},
-							)
-							.0
-					})
-					.collect()
-			}
-		};
+		let field_preprocessors = fields
+			.preprocess_token_stream(args.collect_errors)
+			.unwrap_or_else(|error| error.to_compile_error());
+
+		let confirm_checks = fields
+			.confirm_token_stream(args.collect_errors)
+			.unwrap_or_else(|error| error.to_compile_error());
 
 		// Don't include docs for the match arm
 		let attrs = attrs
@@ -325,8 +388,11 @@ pub fn into_processed(
 		quote! {
 			#(#attrs) *
 			Self:: #ident #field_names_destructured => {
-				#(#field_preprocessors
-				)*
+				#field_preprocessors
+
+				#confirm_checks
+
+				#return_errors
 
 				Ok(#processed_ident :: #ident
 					#field_names_destructured
@@ -335,25 +401,34 @@ pub fn into_processed(
 		}
 	});
 
+	let auto_derive = args.auto_derive.then(|| auto_derive_attr(&attrs));
+	let struct_span = struct_span();
+	let error_type = error_type(args);
+
 	Ok(quote! {
 		#(#attrs)*
-		#vis #enum_token #ident #generics {
+		#vis #enum_token #ident #generics #where_clause {
 			#(#variants,)*
 		}
 
 		#(#attrs)*
-		#vis #enum_token #processed_ident #generics {
+		#auto_derive
+		#vis #enum_token #processed_ident #generics #where_clause {
 			#(#new_variants,)*
 		}
 
 		impl #impl_generics ::preprocess::Preprocessable for #ident #ty_generics #where_clause {
 			type Processed = #processed_ident #ty_generics;
+			type Error = #error_type;
+
+			fn preprocess(self) -> ::std::result::Result<#processed_ident #ty_generics, #error_type> {
+				#struct_span
 
-			fn preprocess(self) -> ::std::result::Result<#processed_ident #ty_generics, ::preprocess::Error> {
 				let value = self;
 
-				#(#global_preprocessors
-				)*
+				#global_preprocessors
+
+				#collect_errors
 
 				match value {
 					#(#variants_destructed) *
@@ -2,6 +2,7 @@ use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{format_ident, quote, ToTokens};
 use syn::{
+	punctuated::Punctuated,
 	Attribute,
 	Error,
 	Field,
@@ -11,14 +12,21 @@ use syn::{
 	Generics,
 	Ident,
 	ItemStruct,
+	Meta,
 	Token,
 	Type,
 	Visibility,
 };
 
 use crate::{
+	auto_derive_attr,
+	collect_errors_token_stream,
+	error_type,
+	global_token_stream,
 	preprocessor::Preprocessor,
 	processed_fields::{ProcessedFields, ProcessedNamed, ProcessedUnnamed},
+	struct_span,
+	MacroArgs,
 };
 
 pub struct ParsedStruct {
@@ -75,7 +83,7 @@ impl TryFrom<ItemStruct> for ParsedStruct {
 
 pub fn into_processed(
 	item: ItemStruct,
-	strict_mode: bool,
+	args: &MacroArgs,
 ) -> Result<TokenStream, Error> {
 	let parsed: ParsedStruct = item.try_into()?;
 
@@ -100,21 +108,17 @@ pub fn into_processed(
 				named: named
 					.iter()
 					.map(|(field, preprocessors)| {
-						if strict_mode && preprocessors.is_empty() {
-							return Err(Error::new_spanned(
+						if args.strict_mode {
+							Preprocessor::check_strict_mode(
 								field,
-								"every field must have at least one preprocessor in strict mode",
-							));
+								preprocessors,
+							)?;
 						}
-						let new_type = preprocessors
-							.iter()
-							.fold(
-								field.ty.to_token_stream(),
-								|acc, preprocessor| {
-									preprocessor.get_new_type(&acc)
-								},
-							)
-							.to_string();
+						let new_type = Preprocessor::chain_new_type(
+							preprocessors,
+							&field.ty.to_token_stream(),
+						)
+						.to_string();
 
 						let ty: Type = syn::parse_str(&new_type)?;
 						Ok(Field {
@@ -137,19 +141,14 @@ pub fn into_processed(
 			unnamed: unnamed
 				.iter()
 				.map(|(field, preprocessors)| {
-					if strict_mode && preprocessors.is_empty() {
-						return Err(Error::new_spanned(
-							field,
-							"every field must have at least one preprocessor in strict mode",
-						));
+					if args.strict_mode {
+						Preprocessor::check_strict_mode(field, preprocessors)?;
 					}
-					let new_type = preprocessors
-						.iter()
-						.fold(
-							field.ty.to_token_stream(),
-							|acc, preprocessor| preprocessor.get_new_type(&acc),
-						)
-						.to_string();
+					let new_type = Preprocessor::chain_new_type(
+						preprocessors,
+						&field.ty.to_token_stream(),
+					)
+					.to_string();
 
 					let ty: Type = syn::parse_str(&new_type)?;
 					Ok(Field {
@@ -167,111 +166,178 @@ pub fn into_processed(
 
 	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-	let global_preprocessors = global.into_iter().map(|preprocessor| {
-		preprocessor.as_processor_token_stream(
-			&format_ident!("value"),
-			&ident.to_token_stream(),
-		)
-	});
-
-	let field_names_destructured = match &fields {
-		ProcessedFields::Unit => TokenStream2::new(),
-		ProcessedFields::Named(ProcessedNamed { named, .. }) => {
-			let named =
-				named.iter().map(|(field, _)| field.ident.clone().unwrap());
-			quote! {
-				{
-					#(#named),*
-				}
-			}
-		}
-		ProcessedFields::Unnamed(ProcessedUnnamed { unnamed, .. }) => {
-			let unnamed = unnamed
-				.iter()
-				.enumerate()
-				.map(|(index, _)| format_ident!("field_{}", index));
-			quote! {
-				(
-					#(#unnamed),*
-				)
-			}
+	// The where clause goes before the fields of a struct with named fields,
+	// but after the fields of a tuple struct, right before the semicolon.
+	let (where_before_fields, where_after_fields) = match &fields {
+		ProcessedFields::Named(_) => (where_clause, None),
+		ProcessedFields::Unit | ProcessedFields::Unnamed(_) => {
+			(None, where_clause)
 		}
 	};
 
-	let field_preprocessors = match &fields {
-		ProcessedFields::Unit => quote! {},
-		ProcessedFields::Named(ProcessedNamed { named, .. }) => named
-			.iter()
-			.flat_map(|(field, preprocessors)| {
-				preprocessors
-					.iter()
-					.fold(
-						(quote! {}, field.ty.to_token_stream()),
-						|(mut acc, new_ty), preprocessor| {
-							acc.extend(preprocessor.as_processor_token_stream(
-								field.ident.as_ref().unwrap(),
-								&new_ty,
-							));
-
-							(acc, preprocessor.get_new_type(&new_ty))
-						},
-					)
-					.0
-			})
-			.collect(),
-		ProcessedFields::Unnamed(ProcessedUnnamed { unnamed, .. }) => unnamed
-			.iter()
-			.enumerate()
-			.flat_map(|(index, (field, preprocessors))| {
-				preprocessors
-					.iter()
-					.fold(
-						(quote! {}, field.ty.to_token_stream()),
-						|(mut acc, new_ty), preprocessor| {
-							let new_ty = preprocessor.get_new_type(&new_ty);
-							acc.extend(preprocessor.as_processor_token_stream(
-								&format_ident!("field_{}", index),
-								&new_ty,
-							));
-
-							(acc, new_ty)
-						},
-					)
-					.0
-			})
-			.collect(),
-	};
+	let exactly_one_of_checks =
+		fields.exactly_one_of_token_stream(&global, args.collect_errors)?;
+
+	let global_preprocessors = global
+		.into_iter()
+		.filter(|preprocessor| {
+			!matches!(preprocessor, Preprocessor::ExactlyOneOf(_))
+		})
+		.map(|preprocessor| {
+			preprocessor.as_processor_token_stream(
+				&format_ident!("value"),
+				&quote! { #ident #ty_generics },
+			)
+		})
+		.collect();
+	let global_preprocessors = global_token_stream(global_preprocessors, args);
+
+	let field_names_destructured = fields.destructure_token_stream();
+
+	let field_preprocessors =
+		fields.preprocess_token_stream(args.collect_errors)?;
+
+	let confirm_checks = fields.confirm_token_stream(args.collect_errors)?;
+
+	let error_type = error_type(args);
+	let (collect_errors, return_errors) = collect_errors_token_stream(args);
+
+	let auto_derive = args.auto_derive.then(|| auto_derive_attr(&attrs));
+	let struct_span = struct_span();
+	let deref = deref_token_stream(
+		args,
+		&attrs,
+		&fields,
+		&ident,
+		&processed_ident,
+		&generics,
+	)?;
 
 	Ok(quote! {
 		#(#attrs)*
-		#vis #struct_token #ident #generics
+		#vis #struct_token #ident #generics #where_before_fields
 			#fields
-		#semi_token
+		#where_after_fields #semi_token
 
 		#(#attrs)*
-		#vis #struct_token #processed_ident #generics
+		#auto_derive
+		#vis #struct_token #processed_ident #generics #where_before_fields
 			#new_fields
-		#semi_token
+		#where_after_fields #semi_token
 
 		impl #impl_generics ::preprocess::Preprocessable for #ident #ty_generics #where_clause {
 			type Processed = #processed_ident #ty_generics;
+			type Error = #error_type;
+
+			fn preprocess(self) -> ::std::result::Result<#processed_ident #ty_generics, #error_type> {
+				#struct_span
 
-			fn preprocess(self) -> ::std::result::Result<#processed_ident #ty_generics, ::preprocess::Error> {
 				let value = self;
 
-				#(#global_preprocessors
-				)*
+				#global_preprocessors
 
 				let #ident
 					#field_names_destructured = value;
 
+				#collect_errors
+
 				#field_preprocessors
 
+				#confirm_checks
+
+				#exactly_one_of_checks
+
+				#return_errors
+
 				Ok(#processed_ident
 					#field_names_destructured
 				)
 			}
 		}
+
+		#deref
 	}
 	.into())
 }
+
+/// Generates an implementation of `Deref` from the processed struct to the
+/// original struct, if `deref = true` is given to the macro. The pointer cast
+/// is only sound if both structs have the same layout, so the struct must
+/// already have a `#[repr(C)]` or `#[repr(transparent)]` attribute, which is
+/// inherited by the processed struct, and no preprocessor may change the type
+/// of any field. The `#[repr]` of the user's struct is never changed.
+fn deref_token_stream(
+	args: &MacroArgs,
+	attrs: &[Attribute],
+	fields: &ProcessedFields,
+	ident: &Ident,
+	processed_ident: &Ident,
+	generics: &Generics,
+) -> Result<TokenStream2, Error> {
+	if !args.deref {
+		return Ok(TokenStream2::new());
+	}
+	if !cfg!(feature = "opt_deref") {
+		return Err(Error::new(
+			ident.span(),
+			"`deref = true` requires the `opt_deref` feature of `preprocess`",
+		));
+	}
+
+	let fields = match fields {
+		ProcessedFields::Unit => &[][..],
+		ProcessedFields::Named(ProcessedNamed { named, .. }) => named,
+		ProcessedFields::Unnamed(ProcessedUnnamed { unnamed, .. }) => unnamed,
+	};
+	if let Some((field, _)) = fields.iter().find(|(field, preprocessors)| {
+		let ty = field.ty.to_token_stream();
+		Preprocessor::chain_new_type(preprocessors, &ty).to_string() !=
+			ty.to_string()
+	}) {
+		return Err(Error::new_spanned(
+			&field.ty,
+			concat!(
+				"`deref = true` cannot be used if a preprocessor changes the ",
+				"type of a field"
+			),
+		));
+	}
+
+	let has_stable_layout = attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("repr"))
+		.any(|attr| {
+			attr.parse_args_with(
+				Punctuated::<Meta, Token![,]>::parse_terminated,
+			)
+			.is_ok_and(|reprs| {
+				reprs.iter().any(|repr| {
+					repr.path().is_ident("C") ||
+						repr.path().is_ident("transparent")
+				})
+			})
+		});
+	if !has_stable_layout {
+		return Err(Error::new(
+			ident.span(),
+			concat!(
+				"`deref = true` requires the struct to have a `#[repr(C)]` ",
+				"or `#[repr(transparent)]` attribute"
+			),
+		));
+	}
+
+	let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+	Ok(quote! {
+		impl #impl_generics ::core::ops::Deref for #processed_ident #ty_generics #where_clause {
+			type Target = #ident #ty_generics;
+
+			fn deref(&self) -> &Self::Target {
+				// SAFETY: Both structs have the same `#[repr]`, which is either
+				// `C` or `transparent`, and fields of the same types in the
+				// same order, so they have the same layout
+				unsafe { &*(self as *const Self as *const Self::Target) }
+			}
+		}
+	})
+}
@@ -1,15 +1,20 @@
 use proc_macro2::TokenStream as TokenStream2;
-use quote::ToTokens;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::{
+	spanned::Spanned,
 	token::{Brace, Paren},
+	Attribute,
 	Error,
 	Field,
 	Fields,
 	FieldsNamed,
 	FieldsUnnamed,
+	Ident,
+	LitStr,
+	Type,
 };
 
-use crate::preprocessor::Preprocessor;
+use crate::{errors_ident, preprocessor::Preprocessor, report_error};
 
 pub enum ProcessedFields {
 	Unit,
@@ -120,6 +125,459 @@ impl TryInto<ProcessedFields> for Fields {
 	}
 }
 
+impl ProcessedFields {
+	/// Generates the code for preprocessing every field. Fields that reference
+	/// other fields, like `range(max_field = "other_field")`, are processed
+	/// after the fields they reference, so that they can use the processed
+	/// values. Otherwise, the fields are processed in the order they are
+	/// declared. If `collect_errors` is set, the error of a field is collected
+	/// instead of being returned, and the field is set to its default value.
+	pub fn preprocess_token_stream(
+		&self,
+		collect_errors: bool,
+	) -> Result<TokenStream2, Error> {
+		match self {
+			ProcessedFields::Unit => Ok(TokenStream2::new()),
+			ProcessedFields::Named(ProcessedNamed { named, .. }) => {
+				Ok(processing_order(named)?
+					.into_iter()
+					.flat_map(|index| {
+						let (field, preprocessors) = &named[index];
+						let binding = field.ident.as_ref().unwrap();
+						let tokens = field_statements(
+							field,
+							preprocessors,
+							binding,
+							collect_errors,
+						);
+						let cfgs = cfg_attrs(field).collect::<Vec<_>>();
+						if cfgs.is_empty() || tokens.is_empty() {
+							return tokens;
+						}
+						// The statements of a field are wrapped in a single
+						// statement, so that the `#[cfg]` attributes of the
+						// field apply to all of them
+						quote! {
+							#(#cfgs)*
+							let #binding = {
+								#tokens
+								#binding
+							};
+						}
+					})
+					.collect())
+			}
+			ProcessedFields::Unnamed(ProcessedUnnamed { unnamed, .. }) => {
+				if let Some(cfg) = unnamed
+					.iter()
+					.flat_map(|(field, _)| cfg_attrs(field))
+					.next()
+				{
+					return Err(Error::new(
+						cfg.span(),
+						"`#[cfg]` attributes are only supported on named fields",
+					));
+				}
+				if let Some(key) = unnamed
+					.iter()
+					.flat_map(|(_, preprocessors)| {
+						field_references(preprocessors)
+					})
+					.next()
+				{
+					return Err(Error::new(
+						key.span(),
+						"other fields can only be referenced by named fields",
+					));
+				}
+
+				Ok(unnamed
+					.iter()
+					.enumerate()
+					.flat_map(|(index, (field, preprocessors))| {
+						field_statements(
+							field,
+							preprocessors,
+							&format_ident!("field_{}", index),
+							collect_errors,
+						)
+					})
+					.collect())
+			}
+		}
+	}
+
+	/// Generates the pattern that binds every field to a variable, which is
+	/// used both to destructure the original value and to construct the
+	/// processed value. Named fields keep their `#[cfg]` attributes, so that
+	/// fields that are compiled out are skipped.
+	pub fn destructure_token_stream(&self) -> TokenStream2 {
+		match self {
+			ProcessedFields::Unit => TokenStream2::new(),
+			ProcessedFields::Named(ProcessedNamed { named, .. }) => {
+				let named = named.iter().map(|(field, _)| {
+					let cfgs = cfg_attrs(field);
+					let ident = field.ident.as_ref().unwrap();
+					quote! { #(#cfgs)* #ident }
+				});
+				quote! {
+					{
+						#(#named),*
+					}
+				}
+			}
+			ProcessedFields::Unnamed(ProcessedUnnamed { unnamed, .. }) => {
+				let unnamed = unnamed
+					.iter()
+					.enumerate()
+					.map(|(index, _)| format_ident!("field_{}", index));
+				quote! {
+					(
+						#(#unnamed),*
+					)
+				}
+			}
+		}
+	}
+
+	/// Generates the checks for the struct-level
+	/// `#[preprocess(exactly_one_of = [...])]` validators, which check that
+	/// exactly one of the given `Option` fields is `Some` after all the fields
+	/// are processed.
+	pub fn exactly_one_of_token_stream(
+		&self,
+		global: &[Preprocessor],
+		collect_errors: bool,
+	) -> Result<TokenStream2, Error> {
+		global
+			.iter()
+			.filter_map(|preprocessor| match preprocessor {
+				Preprocessor::ExactlyOneOf(names) => Some(names),
+				_ => None,
+			})
+			.map(|names| {
+				let ProcessedFields::Named(ProcessedNamed { named, .. }) = self
+				else {
+					return Err(Error::new(
+						names[0].span(),
+						"`exactly_one_of` can only be used on structs with named fields",
+					));
+				};
+
+				let idents = names
+					.iter()
+					.map(|name| {
+						let Some((field, _)) =
+							named.iter().find(|(field, _)| {
+								field
+									.ident
+									.as_ref()
+									.is_some_and(|ident| *ident == name.value())
+							})
+						else {
+							return Err(Error::new(
+								name.span(),
+								format!("no field named `{}`", name.value()),
+							));
+						};
+						if !is_option_type(&field.ty) {
+							return Err(Error::new(
+								name.span(),
+								format!(
+									"the field `{}` must be an `Option`",
+									name.value()
+								),
+							));
+						}
+						Ok(field.ident.as_ref().unwrap())
+					})
+					.collect::<Result<Vec<_>, Error>>()?;
+
+				let mut names = names
+					.iter()
+					.map(|name| format!("`{}`", name.value()))
+					.collect::<Vec<_>>();
+				let last = names.pop().unwrap_or_default();
+				let message = format!(
+					"exactly one of {} or {} must be set",
+					names.join(", "),
+					last
+				);
+				let report = report_error(
+					quote! { ::preprocess::Error::new(#message) },
+					collect_errors,
+				);
+				Ok(quote! {
+					if [#(::core::option::Option::is_some(&#idents)),*]
+						.into_iter()
+						.filter(|is_some| *is_some)
+						.count() != 1
+					{
+						#report
+					}
+				})
+			})
+			.collect()
+	}
+
+	/// Generates the checks for `#[preprocess(confirm = "other_field")]`,
+	/// which compare the processed value of a field with the processed value
+	/// of another field using `PartialEq`. The checks are run after all the
+	/// fields are processed, so that a field can be confirmed by a field that
+	/// is declared either before or after it.
+	pub fn confirm_token_stream(
+		&self,
+		collect_errors: bool,
+	) -> Result<TokenStream2, Error> {
+		let fields = match self {
+			ProcessedFields::Unit => return Ok(TokenStream2::new()),
+			ProcessedFields::Named(ProcessedNamed { named, .. }) => named,
+			ProcessedFields::Unnamed(ProcessedUnnamed { unnamed, .. }) => {
+				return match confirmed_fields(unnamed).next() {
+					Some((_, other_field)) => Err(Error::new(
+						other_field.span(),
+						"`confirm` can only be used on named fields",
+					)),
+					None => Ok(TokenStream2::new()),
+				};
+			}
+		};
+
+		confirmed_fields(fields)
+			.map(|(field, other_field)| {
+				let field_name = field.ident.as_ref().unwrap();
+				let other_name = other_field.value();
+				if field_name == &other_name {
+					return Err(Error::new(
+						other_field.span(),
+						"a field cannot confirm itself",
+					));
+				}
+				let Some((other, _)) = fields.iter().find(|(field, _)| {
+					field
+						.ident
+						.as_ref()
+						.is_some_and(|ident| *ident == other_name)
+				}) else {
+					return Err(Error::new(
+						other_field.span(),
+						format!("no field named `{}`", other_name),
+					));
+				};
+				let other_ident = other.ident.as_ref().unwrap();
+				let cfgs = cfg_attrs(field).chain(cfg_attrs(other));
+
+				let message = format!("value does not match `{}`", other_name);
+				let report = report_error(
+					quote_spanned! {field.ty.span()=>
+						::preprocess::Error::new(#message)
+							.set_field(::std::stringify!(#field_name))
+					},
+					collect_errors,
+				);
+				// When collecting errors, a field that failed has a placeholder
+				// value, so the check is skipped if either field failed
+				let failed = collect_errors.then(|| {
+					let errors = errors_ident();
+					quote! {
+						#errors.iter().any(|err| {
+							err.field == ::std::stringify!(#field_name) ||
+								err.field == ::std::stringify!(#other_ident)
+						}) ||
+					}
+				});
+				Ok(quote_spanned! {field.ty.span()=>
+					#(#cfgs)*
+					{
+						if !(#failed ::core::cmp::PartialEq::eq(&#field_name, &#other_ident)) {
+							#report
+						}
+					}
+				})
+			})
+			.collect()
+	}
+}
+
+/// Generates the code for preprocessing a single field. If `collect_errors` is
+/// set, the preprocessors are run in a closure, so that an error is pushed to
+/// the collected errors instead of being returned, along with every error of a
+/// nested field. The field is then set to
+/// the default value of its processed type, so that the other fields can
+/// still be processed.
+fn field_statements(
+	field: &Field,
+	preprocessors: &[Preprocessor],
+	binding: &Ident,
+	collect_errors: bool,
+) -> TokenStream2 {
+	let ty = field.ty.to_token_stream();
+	let tokens = Preprocessor::field_token_stream(
+		preprocessors,
+		binding,
+		&ty,
+		field.ty.span(),
+	);
+	if !collect_errors || tokens.is_empty() {
+		return tokens;
+	}
+
+	let new_ty = Preprocessor::chain_new_type(preprocessors, &ty);
+	let errors = errors_ident();
+	quote_spanned! {field.ty.span()=>
+		let #binding: #new_ty = match (|| -> ::std::result::Result<#new_ty, ::preprocess::Error> {
+			#tokens
+			::std::result::Result::Ok(#binding)
+		})() {
+			::std::result::Result::Ok(value) => value,
+			::std::result::Result::Err(err) => {
+				#errors.extend(::preprocess::__private::field_errors(err));
+				::core::default::Default::default()
+			}
+		};
+	}
+}
+
+/// Returns the `#[cfg(...)]` attributes of the given field.
+fn cfg_attrs(field: &Field) -> impl Iterator<Item = &Attribute> {
+	field
+		.attrs
+		.iter()
+		.filter(|attr| attr.path().is_ident("cfg"))
+}
+
+/// Returns `true` if the given type is an `Option`, like `Option<T>` or
+/// `std::option::Option<T>`.
+fn is_option_type(ty: &Type) -> bool {
+	match ty {
+		Type::Path(path) => path
+			.path
+			.segments
+			.last()
+			.is_some_and(|segment| segment.ident == "Option"),
+		_ => false,
+	}
+}
+
+/// Returns the names of the other fields that the given preprocessors
+/// reference, like `range(max_field = "other_field")`.
+fn field_references(preprocessors: &[Preprocessor]) -> Vec<&LitStr> {
+	preprocessors
+		.iter()
+		.flat_map(|preprocessor| match preprocessor {
+			Preprocessor::MapField { key, preprocessors } => {
+				let mut references = field_references(preprocessors);
+				references.insert(0, key);
+				references
+			}
+			Preprocessor::Optional(preprocessors) => {
+				field_references(preprocessors)
+			}
+			_ => vec![],
+		})
+		.collect()
+}
+
+/// Returns the indices of the given fields in the order they need to be
+/// processed, so that every field is processed after the fields it
+/// references. Emits an error if a referenced field does not exist, or if the
+/// references are circular.
+fn processing_order(
+	fields: &[(Field, Vec<Preprocessor>)],
+) -> Result<Vec<usize>, Error> {
+	let dependencies = fields
+		.iter()
+		.map(|(_, preprocessors)| {
+			field_references(preprocessors)
+				.into_iter()
+				.map(|key| {
+					fields
+						.iter()
+						.position(|(field, _)| {
+							field
+								.ident
+								.as_ref()
+								.is_some_and(|ident| *ident == key.value())
+						})
+						.map(|index| (index, key))
+						.ok_or_else(|| {
+							Error::new(
+								key.span(),
+								format!("no field named `{}`", key.value()),
+							)
+						})
+				})
+				.collect::<Result<Vec<_>, Error>>()
+		})
+		.collect::<Result<Vec<_>, Error>>()?;
+
+	let mut order = Vec::with_capacity(fields.len());
+	for index in 0..fields.len() {
+		visit_field(index, fields, &dependencies, &mut vec![], &mut order)?;
+	}
+	Ok(order)
+}
+
+/// Adds the given field to the processing order after all the fields it
+/// depends on, using a depth-first search. `path` contains the fields that
+/// are currently being visited, which is used to detect circular references.
+fn visit_field(
+	index: usize,
+	fields: &[(Field, Vec<Preprocessor>)],
+	dependencies: &[Vec<(usize, &LitStr)>],
+	path: &mut Vec<usize>,
+	order: &mut Vec<usize>,
+) -> Result<(), Error> {
+	if order.contains(&index) {
+		return Ok(());
+	}
+
+	for &(dependency, key) in &dependencies[index] {
+		let visiting = path.iter().chain([&index]).copied().collect::<Vec<_>>();
+		if let Some(start) =
+			visiting.iter().position(|&field| field == dependency)
+		{
+			let cycle = visiting[start..]
+				.iter()
+				.chain([&dependency])
+				.map(|&field| {
+					format!("`{}`", fields[field].0.ident.as_ref().unwrap())
+				})
+				.collect::<Vec<_>>()
+				.join(" -> ");
+			return Err(Error::new(
+				key.span(),
+				format!("circular reference between fields: {}", cycle),
+			));
+		}
+
+		path.push(index);
+		let result = visit_field(dependency, fields, dependencies, path, order);
+		path.pop();
+		result?;
+	}
+
+	order.push(index);
+	Ok(())
+}
+
+/// Returns every field that has a `confirm` preprocessor, along with the name
+/// of the field that it confirms.
+fn confirmed_fields(
+	fields: &[(Field, Vec<Preprocessor>)],
+) -> impl Iterator<Item = (&Field, &LitStr)> {
+	fields.iter().flat_map(|(field, preprocessors)| {
+		preprocessors.iter().filter_map(
+			move |preprocessor| match preprocessor {
+				Preprocessor::Confirm { other_field } => {
+					Some((field, other_field))
+				}
+				_ => None,
+			},
+		)
+	})
+}
+
 impl ToTokens for ProcessedFields {
 	fn to_tokens(&self, tokens: &mut TokenStream2) {
 		match &self {
@@ -1,3 +1,5 @@
+use std::collections::{BTreeMap, HashMap};
+
 use preprocess::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -31,6 +33,15 @@ pub enum EnumRequest {
 	},
 }
 
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MapRequest {
+	#[preprocess(keys(trim, lowercase), values(length(min = 1)))]
+	pub labels: HashMap<String, String>,
+	#[preprocess(values(range(max = 100)))]
+	pub limits: BTreeMap<String, u32>,
+}
+
 fn main() {
 	let _processed: LoginRequestProcessed =
 		Preprocessable::preprocess(LoginRequest {
@@ -0,0 +1,23 @@
+use preprocess::testing::*;
+
+fn validate_even(value: u32) -> Result<u32, preprocess::Error> {
+	if value.is_multiple_of(2) {
+		Ok(value)
+	} else {
+		Err(preprocess::Error::new("value must be even"))
+	}
+}
+
+generate_test_cases!(
+	validate_email,
+	args = [false],
+	valid = ["user@example.com", "first.last@example.co.uk"],
+	invalid = ["not-an-email", "user@", "user+tag@example.com"],
+);
+
+generate_test_cases!(validate_even, valid = [0, 2, 100], invalid = [1, 99]);
+
+generate_test_cases!(
+	preprocess::validators::validate_ip,
+	valid = ["127.0.0.1", "::1"],
+);
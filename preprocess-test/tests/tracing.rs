@@ -0,0 +1,172 @@
+use std::sync::{Arc, Mutex};
+
+use preprocess::Preprocessable;
+use tracing::{
+	field::{Field, Visit},
+	span::{Attributes, Id, Record},
+	Event,
+	Level,
+	Metadata,
+	Subscriber,
+};
+
+/// The name, level and fields of a span
+type RecordedSpan = (String, Level, Vec<(String, String)>);
+
+/// The level and fields of an event
+type RecordedEvent = (Level, Vec<(String, String)>);
+
+/// A subscriber that records the name, level and fields of every span, and the
+/// level and fields of every event
+#[derive(Default, Clone)]
+struct SpanRecorder {
+	spans: Arc<Mutex<Vec<RecordedSpan>>>,
+	events: Arc<Mutex<Vec<RecordedEvent>>>,
+}
+
+/// Records every field of a span as its name and debug representation
+struct FieldRecorder(Vec<(String, String)>);
+
+impl Visit for FieldRecorder {
+	fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+		self.0
+			.push((field.name().to_string(), format!("{:?}", value)));
+	}
+}
+
+impl Subscriber for SpanRecorder {
+	fn enabled(&self, _: &Metadata<'_>) -> bool {
+		true
+	}
+
+	fn new_span(&self, span: &Attributes<'_>) -> Id {
+		let mut fields = FieldRecorder(Vec::new());
+		span.record(&mut fields);
+		let mut spans = self.spans.lock().unwrap();
+		spans.push((
+			span.metadata().name().to_string(),
+			*span.metadata().level(),
+			fields.0,
+		));
+		Id::from_u64(spans.len() as u64)
+	}
+
+	fn record(&self, _: &Id, _: &Record<'_>) {}
+
+	fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+	fn event(&self, event: &Event<'_>) {
+		let mut fields = FieldRecorder(Vec::new());
+		event.record(&mut fields);
+		self.events
+			.lock()
+			.unwrap()
+			.push((*event.metadata().level(), fields.0));
+	}
+
+	fn enter(&self, _: &Id) {}
+
+	fn exit(&self, _: &Id) {}
+}
+
+#[preprocess::sync]
+pub struct UserSignUpRequest {
+	#[preprocess(trim, email)]
+	pub email: String,
+	#[preprocess(sensitive, length(min = 8))]
+	pub password: String,
+	pub referral_code: Option<String>,
+}
+
+#[test]
+fn test_tracing_spans() {
+	let recorder = SpanRecorder::default();
+	tracing::subscriber::with_default(recorder.clone(), || {
+		UserSignUpRequest {
+			email: "user@example.com".to_string(),
+			password: "hunter2hunter2".to_string(),
+			referral_code: None,
+		}
+		.preprocess()
+		.unwrap();
+	});
+
+	let spans = recorder.spans.lock().unwrap();
+	assert_eq!(spans.len(), 3);
+
+	assert_eq!(spans[0].0, "preprocess::struct");
+	assert_eq!(spans[0].1, Level::DEBUG);
+	assert!(spans[0].2[0].1.contains("UserSignUpRequest"));
+
+	assert_eq!(spans[1].0, "preprocess::field");
+	assert_eq!(spans[1].1, Level::DEBUG);
+	assert_eq!(spans[1].2, [("field".to_string(), "\"email\"".to_string())]);
+
+	assert_eq!(spans[2].1, Level::TRACE);
+	assert_eq!(
+		spans[2].2,
+		[
+			("field".to_string(), "\"password\"".to_string()),
+			("value".to_string(), "\"[redacted]\"".to_string())
+		]
+	);
+	assert!(!format!("{:?}", spans[2]).contains("hunter2"));
+}
+
+#[preprocess::sync]
+pub struct UpdateProfileRequest {
+	#[preprocess(trim, url, warn_only)]
+	pub website: String,
+	#[preprocess(trim, lowercase, length(min = 3), warn_only)]
+	pub username: String,
+}
+
+#[test]
+fn test_warn_only() {
+	let recorder = SpanRecorder::default();
+	let processed = tracing::subscriber::with_default(recorder.clone(), || {
+		UpdateProfileRequest {
+			website: " not a url ".to_string(),
+			username: " Alice ".to_string(),
+		}
+		.preprocess()
+		.unwrap()
+	});
+	// The failing field keeps its original value, while the other field is
+	// still processed
+	assert_eq!(processed.website, " not a url ");
+	assert_eq!(processed.username, "alice");
+
+	let events = recorder.events.lock().unwrap();
+	assert_eq!(events.len(), 1);
+	assert_eq!(events[0].0, Level::WARN);
+	assert!(events[0]
+		.1
+		.contains(&("field".to_string(), "\"website\"".to_string())));
+	assert!(events[0].1.iter().any(|(name, _)| name == "error"));
+	assert!(events[0].1.iter().any(|(name, value)| {
+		name == "message" &&
+			value
+				.contains("preprocessing failed, keeping the original value")
+	}));
+	drop(events);
+
+	let recorder = SpanRecorder::default();
+	let processed = tracing::subscriber::with_default(recorder.clone(), || {
+		UpdateProfileRequest {
+			website: " https://example.com ".to_string(),
+			username: " Al ".to_string(),
+		}
+		.preprocess()
+		.unwrap()
+	});
+	assert_eq!(processed.website, "https://example.com/");
+	assert_eq!(processed.username, " Al ");
+
+	let events = recorder.events.lock().unwrap();
+	assert_eq!(events.len(), 1);
+	assert_eq!(events[0].0, Level::WARN);
+	assert!(events[0]
+		.1
+		.contains(&("field".to_string(), "\"username\"".to_string())));
+}
@@ -0,0 +1,21 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct EmptyPrefix {
+	#[preprocess(strip_prefix = "")]
+	pub token: String,
+}
+
+#[preprocess::sync]
+pub struct NotAString {
+	#[preprocess(require_suffix = 42)]
+	pub file_name: String,
+}
+
+#[preprocess::sync]
+pub struct NotAStringField {
+	#[preprocess(require_prefix = "id-")]
+	pub id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct InvalidRegex {
+	#[preprocess(all_match(uppercase = "[A-Z"))]
+	pub password: String,
+}
+
+#[preprocess::sync]
+pub struct DuplicateLabel {
+	#[preprocess(all_match(digit = "[0-9]", digit = "\\d"))]
+	pub password: String,
+}
+
+#[preprocess::sync]
+pub struct NoPatterns {
+	#[preprocess(all_match())]
+	pub password: String,
+}
+
+#[preprocess::sync]
+pub struct InvalidPattern {
+	#[preprocess(all_match(digit = 9))]
+	pub password: String,
+}
+
+fn main() {}
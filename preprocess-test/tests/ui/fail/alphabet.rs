@@ -0,0 +1,27 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct DuplicateCharacters {
+	#[preprocess(alphabet = "ACGTA")]
+	pub sequence: String,
+}
+
+#[preprocess::sync]
+pub struct EmptyAlphabet {
+	#[preprocess(alphabet = "")]
+	pub sequence: String,
+}
+
+#[preprocess::sync]
+pub struct NotAString {
+	#[preprocess(alphabet = 58)]
+	pub address: String,
+}
+
+#[preprocess::sync]
+pub struct NotAStringField {
+	#[preprocess(alphabet = "base58")]
+	pub address: u32,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#[preprocess::sync]
+#[preprocess]
+pub struct CreateUserRequest {
+	#[preprocess(trim)]
+	pub name: String,
+}
+
+#[preprocess::sync]
+#[preprocess]
+pub enum Destination {
+	Home(#[preprocess(trim)] String),
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![deny(deprecated)]
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct InvertedBounds {
+	#[preprocess(lower_bound = 10, upper_bound = 1)]
+	pub page_size: u32,
+}
+
+#[preprocess::sync]
+pub struct BoundsWithRange {
+	#[preprocess(range(min = 1), upper_bound = 100)]
+	pub page_size: u32,
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+#![deny(deprecated)]
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct WithLengthBytes {
+	#[preprocess(min_bytes = 1, length_bytes(max = 255))]
+	pub title: String,
+}
+
+#[preprocess::sync]
+pub struct MinGreaterThanMax {
+	#[preprocess(min_bytes = 10, max_bytes = 5)]
+	pub title: String,
+}
+
+#[preprocess::sync]
+pub struct NotBytes {
+	#[preprocess(max_bytes = 5)]
+	pub count: u32,
+}
+
+#[preprocess::sync]
+pub struct WithLength {
+	#[preprocess(length(max = 20), max_bytes = 80)]
+	pub title: String,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[preprocess(range(min = 1, max = 3))]
+#[derive(Debug, Deserialize, Serialize)]
+pub enum MissingRepr {
+	Low = 1,
+	High = 3,
+}
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+#[repr(u8)]
+pub enum NotUnitVariants {
+	Low = 1,
+	Custom(u8),
+}
+
+#[preprocess::sync]
+#[preprocess(max = 3)]
+#[derive(Debug, Deserialize, Serialize)]
+pub enum DataEnum {
+	Low,
+	Custom { value: u8 },
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[preprocess::sync]
+pub struct Port(#[cfg(not(test))] u16, #[preprocess(trim)] String);
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![deny(deprecated)]
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct DeprecatedType {
+	#[preprocess(type = "u8")]
+	pub cpu_count: u32,
+}
+
+#[preprocess::sync]
+pub struct InvalidType {
+	#[preprocess(coerce = "not a type")]
+	pub cpu_count: u32,
+}
+
+fn main() {}
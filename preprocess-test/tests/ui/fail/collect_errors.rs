@@ -0,0 +1,17 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync(collect_errors = true)]
+pub struct AddServerRequest {
+	#[preprocess(trim)]
+	pub name: String,
+	#[preprocess(ip)]
+	pub address: String,
+}
+
+#[preprocess::sync(collect_errors = yes)]
+pub struct RemoveServerRequest {
+	#[preprocess(trim)]
+	pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#[preprocess::sync]
+pub struct SignUpRequest {
+	#[preprocess(length(min = 8))]
+	pub password: String,
+	#[preprocess(confirm = "pasword")]
+	pub confirm_password: String,
+}
+
+#[preprocess::sync]
+pub struct ChangeEmailRequest(
+	pub String,
+	#[preprocess(confirm = "0")] pub String,
+);
+
+fn main() {}
@@ -0,0 +1,15 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct UnknownArgument {
+	#[preprocess(currency_code(historical = false))]
+	pub currency: String,
+}
+
+#[preprocess::sync]
+pub struct ActiveOnlyNotABool {
+	#[preprocess(currency_code(active_only = 1))]
+	pub currency: String,
+}
+
+fn main() {}
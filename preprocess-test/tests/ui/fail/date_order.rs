@@ -0,0 +1,7 @@
+#[preprocess::sync]
+pub struct CreateEventRequest {
+	#[preprocess(future_date, date(as_date = true))]
+	pub date: String,
+}
+
+fn main() {}
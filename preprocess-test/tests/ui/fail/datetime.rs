@@ -0,0 +1,19 @@
+#[preprocess::sync]
+pub struct CreateEventRequest {
+	#[preprocess(datetime(format = "%Y-%m-%d %Q"))]
+	pub starts_at: String,
+}
+
+#[preprocess::sync]
+pub struct UpdateEventRequest {
+	#[preprocess(datetime(timezone = "Europe/London"))]
+	pub starts_at: String,
+}
+
+#[preprocess::sync]
+pub struct DeleteEventRequest {
+	#[preprocess(datetime_local(timezone = "UTC"))]
+	pub starts_at: String,
+}
+
+fn main() {}
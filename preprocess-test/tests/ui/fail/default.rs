@@ -0,0 +1,17 @@
+fn default_name() -> String {
+	"anonymous".to_string()
+}
+
+#[preprocess::sync]
+pub struct DefaultAndDefaultFn {
+	#[preprocess(length(min = 1), default = "anonymous", default_fn = "default_name")]
+	pub name: String,
+}
+
+#[preprocess::sync]
+pub struct DefaultAndOnError {
+	#[preprocess(range(min = 1), default = 1, on_error = 2)]
+	pub count: u32,
+}
+
+fn main() {}
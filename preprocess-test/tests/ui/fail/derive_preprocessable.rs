@@ -0,0 +1,12 @@
+use preprocess::Preprocessable;
+
+#[derive(Preprocessable)]
+pub enum Shape {
+	Point,
+	Circle { radius: f64 },
+}
+
+#[derive(Preprocessable)]
+pub struct Point;
+
+fn main() {}
@@ -0,0 +1,23 @@
+use std::collections::HashMap;
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct DuplicateEachKey {
+	#[preprocess(each_key(trim), each_value(trim), each_key(lowercase))]
+	pub headers: HashMap<String, String>,
+}
+
+#[preprocess::sync]
+pub struct EmptyEachValue {
+	#[preprocess(each_value())]
+	pub headers: HashMap<String, String>,
+}
+
+#[preprocess::sync]
+pub struct NotAMap {
+	#[preprocess(each_key(trim))]
+	pub headers: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct StringForNumber {
+	#[preprocess(not_equal = "0")]
+	pub port: u16,
+}
+
+#[preprocess::sync]
+pub struct NumberForString {
+	#[preprocess(equal = 1)]
+	pub confirmation: String,
+}
+
+fn main() {}
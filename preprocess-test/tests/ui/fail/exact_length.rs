@@ -0,0 +1,7 @@
+#[preprocess::sync]
+pub struct VerifyOtpRequest {
+	#[preprocess(length(min = 4), exact_length = 6)]
+	pub code: String,
+}
+
+fn main() {}
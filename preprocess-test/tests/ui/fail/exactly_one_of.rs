@@ -0,0 +1,28 @@
+#[preprocess::sync]
+#[preprocess(exactly_one_of = ["code", "token"])]
+pub struct UnknownField {
+	pub code: Option<String>,
+	pub assertion: Option<String>,
+}
+
+#[preprocess::sync]
+#[preprocess(exactly_one_of = ["code", "assertion"])]
+pub struct NotOption {
+	pub code: Option<String>,
+	pub assertion: String,
+}
+
+#[preprocess::sync]
+#[preprocess(exactly_one_of = ["code"])]
+pub struct SingleField {
+	pub code: Option<String>,
+}
+
+#[preprocess::sync]
+pub struct FieldLevel {
+	#[preprocess(exactly_one_of = ["code", "assertion"])]
+	pub code: Option<String>,
+	pub assertion: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+#[preprocess::sync]
+pub struct UnknownField {
+	#[preprocess(range(max_field = "total"))]
+	pub amount: u64,
+}
+
+#[preprocess::sync]
+pub struct SelfReference {
+	#[preprocess(range(max_field = "amount"))]
+	pub amount: u64,
+}
+
+#[preprocess::sync]
+pub struct CircularReference {
+	#[preprocess(range(max_field = "end"))]
+	pub start: u64,
+	#[preprocess(range(max_field = "limit"))]
+	pub end: u64,
+	#[preprocess(range(min_field = "start"))]
+	pub limit: u64,
+}
+
+#[preprocess::sync]
+pub struct BothBounds {
+	#[preprocess(range(max = 10, max_field = "limit"))]
+	pub amount: u64,
+	pub limit: u64,
+}
+
+#[preprocess::sync]
+pub struct Tuple(#[preprocess(range(max_field = "limit"))] pub u64);
+
+fn main() {}
@@ -0,0 +1,21 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct IntegerField {
+	#[preprocess(non_nan)]
+	pub count: i32,
+}
+
+#[preprocess::sync]
+pub struct UnsignedField {
+	#[preprocess(finite)]
+	pub count: u64,
+}
+
+#[preprocess::sync]
+pub struct StringField {
+	#[preprocess(finite)]
+	pub value: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct MissingAs {
+	#[preprocess(from_str(type = "u64"))]
+	pub cpu_count: String,
+}
+
+#[preprocess::sync]
+pub struct InvalidType {
+	#[preprocess(from_str(as = "not a type"))]
+	pub cpu_count: String,
+}
+
+fn main() {}
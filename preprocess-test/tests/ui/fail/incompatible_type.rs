@@ -0,0 +1,9 @@
+#[preprocess::sync]
+pub struct CreateUserRequest {
+	#[preprocess(email)]
+	pub email: u32,
+	#[preprocess(regex = "^[a-z]+$")]
+	pub name: bool,
+}
+
+fn main() {}
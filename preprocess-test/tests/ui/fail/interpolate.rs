@@ -0,0 +1,33 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct NoPlaceholder {
+	#[preprocess(interpolate(template = "order"))]
+	pub reference: String,
+}
+
+#[preprocess::sync]
+pub struct AdjacentPlaceholders {
+	#[preprocess(interpolate(template = "{major}{minor}"))]
+	pub version: String,
+}
+
+#[preprocess::sync]
+pub struct UnclosedPlaceholder {
+	#[preprocess(interpolate(template = "order-{id"))]
+	pub reference: String,
+}
+
+#[preprocess::sync]
+pub struct EmptyPlaceholder {
+	#[preprocess(interpolate(template = "order-{}"))]
+	pub reference: String,
+}
+
+#[preprocess::sync]
+pub struct MissingTemplate {
+	#[preprocess(interpolate())]
+	pub reference: String,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct MissingPrefix {
+	#[preprocess(ip(network = "10.0.0.0"))]
+	pub address: String,
+}
+
+#[preprocess::sync]
+pub struct InvalidAddress {
+	#[preprocess(ip(network = "10.0.0.256/8"))]
+	pub address: String,
+}
+
+#[preprocess::sync]
+pub struct PrefixTooLong {
+	#[preprocess(ip(network = "10.0.0.0/33"))]
+	pub address: String,
+}
+
+#[preprocess::sync]
+pub struct HostBitsSet {
+	#[preprocess(ip(network = "10.1.2.3/8"))]
+	pub address: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct UnknownVersion {
+	#[preprocess(ip(version = "v5"))]
+	pub address: String,
+}
+
+#[preprocess::sync]
+pub struct NonStringVersion {
+	#[preprocess(ip(version = 4))]
+	pub address: String,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct MissingPath {
+	#[preprocess(json())]
+	pub payload: String,
+}
+
+#[preprocess::sync]
+pub struct RelativePath {
+	#[preprocess(json(json_path = "name"))]
+	pub payload: String,
+}
+
+#[preprocess::sync]
+pub struct UnknownArgument {
+	#[preprocess(json(schema = "user.json"))]
+	pub payload: String,
+}
+
+#[preprocess::sync]
+pub struct DuplicateArgument {
+	#[preprocess(json(json_path = "$.a", json_path = "$.b"))]
+	pub payload: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[preprocess::sync]
+pub struct SetTagsRequest {
+	#[preprocess(keys(trim))]
+	pub names: Vec<String>,
+	#[preprocess(values(trim))]
+	pub values: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct UnknownArgument {
+	#[preprocess(language_code(registered = true))]
+	pub language: String,
+}
+
+#[preprocess::sync]
+pub struct StrictNotABool {
+	#[preprocess(language_code(strict = "yes"))]
+	pub language: String,
+}
+
+fn main() {}
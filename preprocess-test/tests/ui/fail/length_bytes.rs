@@ -0,0 +1,9 @@
+#[preprocess::sync]
+pub struct UploadRequest {
+	#[preprocess(length_bytes(max = 10))]
+	pub size: u64,
+	#[preprocess(length_bytes(max = 10))]
+	pub tags: Vec<String>,
+}
+
+fn main() {}
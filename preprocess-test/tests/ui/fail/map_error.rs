@@ -0,0 +1,25 @@
+use preprocess::prelude::*;
+
+fn email_error(error: preprocess::Error) -> preprocess::Error {
+	error
+}
+
+#[preprocess::sync]
+pub struct NotLast {
+	#[preprocess(map_error = "email_error", email)]
+	pub email: String,
+}
+
+#[preprocess::sync]
+pub struct BothUsed {
+	#[preprocess(email, map_error = "email_error", error_message = "invalid email")]
+	pub email: String,
+}
+
+#[preprocess::sync]
+pub struct InvalidPath {
+	#[preprocess(email, map_error = "not a path")]
+	pub email: String,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#[preprocess::sync]
+pub struct CreatePaymentRequest {
+	#[preprocess(number_format(thousands = ",", decimal = ","))]
+	pub amount: String,
+}
+
+#[preprocess::sync]
+pub struct UpdatePaymentRequest {
+	#[preprocess(number_format(thousands = "0"))]
+	pub amount: String,
+}
+
+#[preprocess::sync]
+pub struct DeletePaymentRequest {
+	#[preprocess(number_format(decimal = ".,"))]
+	pub amount: String,
+}
+
+fn main() {}
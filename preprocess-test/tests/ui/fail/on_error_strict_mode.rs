@@ -0,0 +1,7 @@
+#[preprocess::sync(strict_mode = true)]
+pub struct UpdateSettingsRequest {
+	#[preprocess(trim, url, on_error = "https://example.com")]
+	pub homepage: String,
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+use std::ops::Deref;
+
+use preprocess::prelude::*;
+
+// Without `deref = true`, the layout of the struct is left alone and `Deref`
+// is not implemented
+#[preprocess::sync]
+pub struct NoDeref {
+	#[preprocess(length(min = 1))]
+	pub name: String,
+}
+
+#[preprocess::sync(deref = true)]
+pub struct MissingRepr {
+	#[preprocess(length(min = 1))]
+	pub name: String,
+}
+
+#[preprocess::sync(deref = true)]
+#[repr(C)]
+pub struct ChangedType {
+	#[preprocess(trim)]
+	pub name: String,
+}
+
+#[preprocess::sync(deref = true)]
+pub enum Enum {
+	Variant,
+}
+
+fn assert_deref<T: Deref>() {}
+
+fn main() {
+	assert_deref::<NoDerefProcessed>();
+}
@@ -0,0 +1,21 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct ScoreTooHigh {
+	#[preprocess(password_strength(min_score = 5))]
+	pub password: String,
+}
+
+#[preprocess::sync]
+pub struct UnknownArgument {
+	#[preprocess(password_strength(score = 3))]
+	pub password: String,
+}
+
+#[preprocess::sync]
+pub struct NotAString {
+	#[preprocess(password_strength)]
+	pub password: u32,
+}
+
+fn main() {}
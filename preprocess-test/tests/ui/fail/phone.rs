@@ -0,0 +1,27 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct InvalidCountryCode {
+	#[preprocess(phone(country = "usa"))]
+	pub phone: String,
+}
+
+#[preprocess::sync]
+pub struct UnknownCountry {
+	#[preprocess(normalize_phone(country = "UK"))]
+	pub phone: String,
+}
+
+#[preprocess::sync]
+pub struct UnknownFormat {
+	#[preprocess(phone(format = "national"))]
+	pub phone: String,
+}
+
+#[preprocess::sync]
+pub struct NotAStringField {
+	#[preprocess(phone)]
+	pub phone: u64,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#[preprocess::sync]
+pub struct SetVolumeRequest {
+	#[preprocess(min = 5, max = 3)]
+	pub volume: u8,
+	#[preprocess(min = -1, max = -10)]
+	pub offset: i32,
+}
+
+#[preprocess::sync]
+pub struct SetLimitRequest {
+	#[preprocess(range(min = 1, max = 10), max = 5)]
+	pub limit: u32,
+}
+
+fn main() {}
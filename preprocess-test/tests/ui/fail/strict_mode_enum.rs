@@ -0,0 +1,12 @@
+#[preprocess::sync(strict_mode = true)]
+pub enum ContactMethod {
+	Email {
+		#[preprocess(trim, email)]
+		address: String,
+		label: String,
+	},
+	Phone(#[preprocess(trim)] String, u16),
+	None,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[preprocess::sync]
+pub struct UpdateProfileRequest {
+	#[preprocess(transform = "normalize name")]
+	pub name: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct UnknownComponent {
+	#[preprocess(url_component = "port")]
+	pub port: String,
+}
+
+#[preprocess::sync]
+pub struct NotAString {
+	#[preprocess(url_component = path)]
+	pub path: String,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct UnsupportedVersion {
+	#[preprocess(uuid(version = 2))]
+	pub id: String,
+}
+
+#[preprocess::sync]
+pub struct UnknownArgument {
+	#[preprocess(uuid(format = "simple"))]
+	pub id: String,
+}
+
+#[preprocess::sync]
+pub struct DuplicateArgument {
+	#[preprocess(uuid(version = 4, version = 5))]
+	pub id: String,
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct UnknownArgument {
+	#[preprocess(version(allow_leading_zeros = true))]
+	pub version: String,
+}
+
+#[preprocess::sync]
+pub struct NormalizeWithoutPrefix {
+	#[preprocess(version(allow_v_prefix = false, normalize = true))]
+	pub version: String,
+}
+
+#[preprocess::sync]
+pub struct NonBooleanArgument {
+	#[preprocess(version(allow_prerelease = "no"))]
+	pub version: String,
+}
+
+fn main() {}
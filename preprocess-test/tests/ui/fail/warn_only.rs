@@ -0,0 +1,19 @@
+#[preprocess::sync]
+pub struct NotLast {
+	#[preprocess(warn_only, email)]
+	pub email: String,
+}
+
+#[preprocess::sync]
+pub struct WithDefault {
+	#[preprocess(email, default = "user@example.com", warn_only)]
+	pub email: String,
+}
+
+#[preprocess::sync(strict_mode = true)]
+pub struct StrictMode {
+	#[preprocess(email, warn_only)]
+	pub email: String,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+#[preprocess::sync]
+pub struct SetPortRequest {
+	#[preprocess(whitelist = ["80", "443"])]
+	pub port: u16,
+}
+
+#[preprocess::sync]
+pub struct SetRegionRequest {
+	#[preprocess(blacklist = ["us-east-1", 1])]
+	pub region: String,
+}
+
+#[preprocess::sync]
+pub struct SetNameRequest {
+	#[preprocess(whitelist = [])]
+	pub name: String,
+}
+
+fn main() {}
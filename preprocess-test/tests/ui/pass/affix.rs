@@ -0,0 +1,46 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AuthorizationHeader {
+	#[preprocess(trim, require_prefix = "Bearer ", strip_prefix = "Bearer ")]
+	pub token: String,
+	#[preprocess(strip_suffix = ".json")]
+	pub document: String,
+	#[preprocess(require_suffix = ".pdf")]
+	pub attachment: String,
+}
+
+fn main() {
+	let processed = AuthorizationHeader {
+		token: " Bearer abc.def ".to_string(),
+		document: "report.json".to_string(),
+		attachment: "invoice.pdf".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+
+	let token: std::borrow::Cow<'static, str> = processed.token;
+	assert_eq!(token, "abc.def");
+	assert_eq!(processed.document, "report");
+	assert_eq!(processed.attachment, "invoice.pdf");
+
+	let processed = AuthorizationHeader {
+		token: "Bearer abc".to_string(),
+		document: "report.txt".to_string(),
+		attachment: "invoice.pdf".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(processed.document, "report.txt");
+
+	let error = AuthorizationHeader {
+		token: "Basic abc".to_string(),
+		document: "report.json".to_string(),
+		attachment: "invoice.pdf".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "token");
+	assert_eq!(error.message, "value must start with `Bearer `");
+}
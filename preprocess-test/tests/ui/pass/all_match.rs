@@ -0,0 +1,33 @@
+use preprocess::prelude::*;
+
+pub const SPECIAL_PATTERN: &str = "[!@#$%^&*]";
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SignUpRequest {
+	#[preprocess(
+		length(min = 8),
+		all_match(uppercase = "[A-Z]", digit = "[0-9]", special = SPECIAL_PATTERN)
+	)]
+	pub password: String,
+}
+
+fn main() {
+	let request = SignUpRequest {
+		password: "Passw0rd!".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(request.password, "Passw0rd!");
+
+	let error = SignUpRequest {
+		password: "password".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "password");
+	assert_eq!(
+		error.message,
+		"value must match `uppercase`, `digit` and `special`"
+	);
+}
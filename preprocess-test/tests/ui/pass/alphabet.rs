@@ -0,0 +1,35 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SendPaymentRequest {
+	#[preprocess(trim, alphabet = "base58")]
+	pub address: String,
+	#[preprocess(alphabet = "base62")]
+	pub reference: String,
+	#[preprocess(alphabet = "ACGT")]
+	pub sequence: String,
+}
+
+fn request(address: &str, sequence: &str) -> SendPaymentRequest {
+	SendPaymentRequest {
+		address: address.to_string(),
+		reference: "Order42".to_string(),
+		sequence: sequence.to_string(),
+	}
+}
+
+fn main() {
+	let processed = request(" 1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa ", "GATTACA")
+		.preprocess()
+		.unwrap();
+	assert_eq!(processed.address, "1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa");
+	assert_eq!(processed.sequence, "GATTACA");
+
+	let error = request("0OIl", "GATTACA").preprocess().unwrap_err();
+	assert_eq!(error.field, "address");
+	assert_eq!(error.message, "invalid character at position 0");
+
+	let error = request("abc", "GATTUCA").preprocess().unwrap_err();
+	assert_eq!(error.field, "sequence");
+}
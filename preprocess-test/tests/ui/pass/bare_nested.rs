@@ -0,0 +1,77 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Address {
+	#[preprocess(trim)]
+	pub city: String,
+}
+
+#[preprocess::sync]
+pub struct CreateUserRequest {
+	#[preprocess]
+	pub home: Address,
+	#[preprocess(nested)]
+	pub work: Address,
+	#[preprocess(optional(nested))]
+	pub billing: Option<Address>,
+	#[preprocess]
+	pub shipping: std::result::Result<Address, String>,
+	#[preprocess(nested)]
+	pub pickup: std::result::Result<Address, String>,
+}
+
+#[preprocess::sync]
+pub enum Destination {
+	Home(#[preprocess] Address),
+	Work(#[preprocess(nested)] Address),
+}
+
+/// Only compiles if both values have the same type, which means that both
+/// forms generate the same processed type
+fn same_type<T>(_: &T, _: &T) {}
+
+fn address(city: &str) -> Address {
+	Address {
+		city: city.to_string(),
+	}
+}
+
+fn main() {
+	let processed = CreateUserRequest {
+		home: address(" London "),
+		work: address(" Paris "),
+		billing: Some(address(" Berlin ")),
+		shipping: Ok(address(" Rome ")),
+		pickup: Err("not set".to_string()),
+	}
+	.preprocess()
+	.unwrap();
+	same_type(&processed.home, &processed.work);
+	same_type(&processed.shipping, &processed.pickup);
+	assert_eq!(processed.home.city, "London");
+	assert_eq!(processed.work.city, "Paris");
+	assert_eq!(processed.billing.unwrap().city, "Berlin");
+	assert_eq!(processed.shipping.unwrap().city, "Rome");
+
+	let processed = CreateUserRequest {
+		home: address(""),
+		work: address(""),
+		billing: None,
+		shipping: Err("not set".to_string()),
+		pickup: Err("not set".to_string()),
+	}
+	.preprocess()
+	.unwrap();
+	assert!(processed.billing.is_none());
+	assert_eq!(processed.shipping.unwrap_err(), "not set");
+
+	let (DestinationProcessed::Home(home), DestinationProcessed::Work(work)) = (
+		Destination::Home(address(" London ")).preprocess().unwrap(),
+		Destination::Work(address(" Paris ")).preprocess().unwrap(),
+	) else {
+		panic!("the variants should not change");
+	};
+	same_type(&home, &work);
+	assert_eq!((home.city.as_ref(), work.city.as_ref()), ("London", "Paris"));
+}
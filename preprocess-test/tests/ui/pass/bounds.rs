@@ -0,0 +1,71 @@
+use preprocess::prelude::*;
+
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListRequest {
+	#[preprocess(lower_bound = 1, upper_bound = MAX_PAGE_SIZE)]
+	pub page_size: u32,
+	#[preprocess(lower_bound = -10)]
+	pub offset: i32,
+	#[preprocess(upper_bound = 1.5)]
+	pub ratio: f64,
+	#[preprocess(optional(upper_bound = 5))]
+	pub retries: Option<u8>,
+}
+
+fn main() {
+	let request = ListRequest {
+		page_size: 100,
+		offset: -10,
+		ratio: 1.5,
+		retries: Some(5),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(request.page_size, 100);
+
+	let error = ListRequest {
+		page_size: 101,
+		offset: 0,
+		ratio: 1.0,
+		retries: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "page_size");
+	assert_eq!(error.message, "value must not exceed MAX_PAGE_SIZE");
+
+	let error = ListRequest {
+		page_size: 0,
+		offset: 0,
+		ratio: 1.0,
+		retries: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.message, "value must be at least 1");
+
+	let error = ListRequest {
+		page_size: 10,
+		offset: -11,
+		ratio: 1.0,
+		retries: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "offset");
+	assert_eq!(error.message, "value must be at least -10");
+
+	let error = ListRequest {
+		page_size: 10,
+		offset: 0,
+		ratio: 1.0,
+		retries: Some(6),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "retries");
+	assert_eq!(error.message, "value must not exceed 5");
+}
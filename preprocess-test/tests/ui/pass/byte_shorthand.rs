@@ -0,0 +1,70 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreatePostRequest {
+	#[preprocess(trim, min_bytes = 1, max_bytes = 8)]
+	pub title: String,
+	#[preprocess(min_bytes = 2)]
+	pub slug: String,
+	#[preprocess(max_bytes = 4)]
+	pub thumbnail: Vec<u8>,
+	#[preprocess(optional(max_bytes = 3))]
+	pub summary: Option<String>,
+}
+
+fn request(title: &str) -> CreatePostRequest {
+	CreatePostRequest {
+		title: title.to_string(),
+		slug: "hi".to_string(),
+		thumbnail: vec![1, 2, 3, 4],
+		summary: Some("abc".to_string()),
+	}
+}
+
+fn main() {
+	let processed = request(" Café ").preprocess().unwrap();
+	assert_eq!(processed.title, "Café");
+
+	// 4 characters, but 9 bytes
+	let error = request("Cafééééé").preprocess().unwrap_err();
+	assert_eq!(error.field, "title");
+	assert_eq!(error.message, "length must be less than or equal to 8 bytes");
+
+	let error = request("  ").preprocess().unwrap_err();
+	assert_eq!(
+		error.message,
+		"length must be greater than or equal to 1 bytes"
+	);
+
+	// 1 character, but 2 bytes
+	assert!(CreatePostRequest {
+		slug: "é".to_string(),
+		..request("Title")
+	}
+	.preprocess()
+	.is_ok());
+	let error = CreatePostRequest {
+		slug: "h".to_string(),
+		..request("Title")
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "slug");
+
+	let error = CreatePostRequest {
+		thumbnail: vec![0; 5],
+		..request("Title")
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "thumbnail");
+
+	let error = CreatePostRequest {
+		summary: Some("€€".to_string()),
+		..request("Title")
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "summary");
+}
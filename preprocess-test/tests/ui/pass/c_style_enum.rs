@@ -0,0 +1,62 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[preprocess(range(min = 1, max = 3))]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[repr(u8)]
+pub enum Priority {
+	None = 0,
+	Low = 1,
+	Medium,
+	High,
+	Urgent = 10,
+}
+
+#[preprocess::sync]
+#[preprocess(min = -1)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[repr(i16)]
+pub enum Offset {
+	Invalid = -5,
+	Before = -1,
+	Current,
+	After,
+}
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateTaskRequest {
+	#[preprocess(trim)]
+	pub title: String,
+	#[preprocess(nested)]
+	pub priority: Priority,
+}
+
+fn main() {
+	assert_eq!(Priority::Medium.preprocess().unwrap() as u8, 2);
+	assert_eq!(PriorityProcessed::High as u8, 3);
+	assert!(Priority::Low.preprocess().is_ok());
+	assert!(Priority::High.preprocess().is_ok());
+	assert!(Priority::None.preprocess().is_err());
+	assert!(Priority::Urgent.preprocess().is_err());
+
+	assert_eq!(Offset::Current.preprocess().unwrap() as i16, 0);
+	assert!(Offset::Before.preprocess().is_ok());
+	assert!(Offset::Invalid.preprocess().is_err());
+
+	let processed = CreateTaskRequest {
+		title: " Write docs ".to_string(),
+		priority: Priority::Low,
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(processed.priority, PriorityProcessed::Low);
+
+	let error = CreateTaskRequest {
+		title: "Write docs".to_string(),
+		priority: Priority::Urgent,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "priority");
+}
@@ -0,0 +1,67 @@
+// The `serde` feature is never enabled for this crate, so the fields behind
+// it are always compiled out
+#![allow(unexpected_cfgs)]
+
+use preprocess::prelude::*;
+use serde::de::value::{Error, MapDeserializer};
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateUserRequest {
+	#[preprocess(trim)]
+	pub username: String,
+	#[cfg(feature = "serde")]
+	#[preprocess(trim, lowercase)]
+	pub nickname: String,
+	#[cfg(not(feature = "serde"))]
+	#[allow(dead_code)]
+	#[serde(rename = "displayName")]
+	#[preprocess(trim)]
+	pub display_name: String,
+	#[cfg(feature = "serde")]
+	#[preprocess(confirm = "username")]
+	pub confirm_username: String,
+}
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub enum Credentials {
+	Password {
+		#[preprocess(trim)]
+		username: String,
+		#[cfg(feature = "serde")]
+		#[preprocess(length(min = 8))]
+		password: String,
+	},
+}
+
+fn main() {
+	let processed = CreateUserRequest {
+		username: " alice ".to_string(),
+		display_name: " Alice ".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(processed.username, "alice");
+	assert_eq!(processed.display_name, "Alice");
+
+	// The `#[serde(...)]` attributes are forwarded to the processed struct
+	let processed = CreateUserRequestProcessed::deserialize(
+		MapDeserializer::<_, Error>::new(
+			[("username", "alice"), ("displayName", "Alice")].into_iter(),
+		),
+	)
+	.unwrap();
+	assert_eq!(processed.display_name, "Alice");
+
+	let Credentials::Password { username } = Credentials::Password {
+		username: " bob ".to_string(),
+	};
+	assert_eq!(username, " bob ");
+	let CredentialsProcessed::Password { username } = Credentials::Password {
+		username: " bob ".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(username, "bob");
+}
@@ -0,0 +1,72 @@
+#![allow(deprecated)]
+
+use std::num::NonZeroU32;
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateServerRequest {
+	#[preprocess(coerce = "u8")]
+	pub cpu_count: u32, // This type will be changed to u8
+	#[preprocess(coerce = "i64")]
+	pub memory: i32, // This type will be changed to i64
+	#[preprocess(coerce = NonZeroU32)]
+	pub replicas: u32, // This type will be changed to NonZeroU32
+	#[preprocess(coerce = "u16", range(min = 1024))]
+	pub port: i32, // This type will be changed to u16
+	#[preprocess(type = "u8")]
+	pub legacy: u32, // This type will be changed to u8
+}
+
+fn main() {
+	let request = CreateServerRequest {
+		cpu_count: 4,
+		memory: 512,
+		replicas: 2,
+		port: 8080,
+		legacy: 1,
+	}
+	.preprocess()
+	.unwrap();
+	let cpu_count: u8 = request.cpu_count;
+	let memory: i64 = request.memory;
+	assert_eq!(cpu_count, 4);
+	assert_eq!(memory, 512);
+	assert_eq!(request.replicas.get(), 2);
+	assert_eq!(request.port, 8080u16);
+	assert_eq!(request.legacy, 1u8);
+
+	let error = CreateServerRequest {
+		cpu_count: 300,
+		memory: 512,
+		replicas: 2,
+		port: 8080,
+		legacy: 1,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "cpu_count");
+
+	let error = CreateServerRequest {
+		cpu_count: 4,
+		memory: 512,
+		replicas: 0,
+		port: 8080,
+		legacy: 1,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "replicas");
+
+	let error = CreateServerRequest {
+		cpu_count: 4,
+		memory: 512,
+		replicas: 1,
+		port: -1,
+		legacy: 1,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "port");
+}
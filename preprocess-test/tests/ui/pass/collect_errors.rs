@@ -0,0 +1,100 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync(collect_errors = true)]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Address {
+	#[preprocess(trim, length(min = 1))]
+	pub city: String,
+	#[preprocess(trim, length(min = 1))]
+	pub country: String,
+}
+
+#[preprocess::sync(collect_errors = true)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignUpRequest {
+	#[preprocess(trim, lowercase, email)]
+	pub email: String,
+	#[preprocess(length(min = 8))]
+	pub password: String,
+	#[preprocess(confirm = "password")]
+	pub confirm_password: String,
+	#[preprocess(range(min = 18))]
+	pub age: u8,
+	#[preprocess(nested)]
+	pub address: Address,
+	pub referrer: Option<String>,
+}
+
+#[preprocess::sync(collect_errors = true)]
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContactRequest {
+	Email {
+		#[preprocess(email)]
+		email: String,
+		#[preprocess(trim, length(min = 1))]
+		subject: String,
+	},
+	Phone(#[preprocess(trim, length(min = 1))] String),
+}
+
+fn sign_up(password: &str, confirm_password: &str) -> SignUpRequest {
+	SignUpRequest {
+		email: " User@Example.com ".to_string(),
+		password: password.to_string(),
+		confirm_password: confirm_password.to_string(),
+		age: 30,
+		address: Address {
+			city: " London ".to_string(),
+			country: "UK".to_string(),
+		},
+		referrer: None,
+	}
+}
+
+fn fields(errors: &[Error]) -> Vec<&str> {
+	errors.iter().map(|error| error.field.as_str()).collect()
+}
+
+fn main() {
+	let processed = sign_up("password", "password").preprocess().unwrap();
+	assert_eq!(processed.email, "user@example.com");
+	assert_eq!(processed.address.city, "London");
+
+	let errors: Vec<Error> = SignUpRequest {
+		email: "invalid".to_string(),
+		age: 16,
+		address: Address {
+			city: " ".to_string(),
+			country: String::new(),
+		},
+		..sign_up("password", "different")
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(
+		fields(&errors),
+		[
+			"email",
+			"age",
+			"address.city",
+			"address.country",
+			"confirm_password"
+		]
+	);
+
+	// The confirm check is skipped, since the password already failed
+	let errors = sign_up("short", "short").preprocess().unwrap_err();
+	assert_eq!(fields(&errors), ["password"]);
+
+	let errors = ContactRequest::Email {
+		email: "invalid".to_string(),
+		subject: " ".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(fields(&errors), ["email", "subject"]);
+	assert_eq!(
+		ContactRequest::Phone(" 12345 ".to_string()).preprocess(),
+		Ok(ContactRequestProcessed::Phone("12345".into()))
+	);
+}
@@ -0,0 +1,52 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SetProfileRequest {
+	#[preprocess(strip_control_characters, trim, length(min = 1))]
+	pub display_name: String,
+	#[preprocess(strip_non_printable, length(min = 3))]
+	pub username: String,
+	#[preprocess(no_control_characters)]
+	pub bio: String,
+	#[preprocess(optional(strip_non_printable))]
+	pub website: Option<String>,
+}
+
+fn main() {
+	let request = SetProfileRequest {
+		display_name: " John\u{7}Doe\n".to_string(),
+		username: "ad\u{200B}min\u{202E}".to_string(),
+		bio: "Hello, world!".to_string(),
+		website: Some("\u{FEFF}example.com".to_string()),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(request.display_name, "JohnDoe");
+	assert_eq!(request.username, "admin");
+	assert_eq!(request.website.as_deref(), Some("example.com"));
+
+	let error = SetProfileRequest {
+		display_name: "John".to_string(),
+		username: "\u{200B}\u{200B}ab".to_string(),
+		bio: String::new(),
+		website: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "username");
+
+	let error = SetProfileRequest {
+		display_name: "John".to_string(),
+		username: "admin".to_string(),
+		bio: "Hello\u{1B}[31m".to_string(),
+		website: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "bio");
+	assert_eq!(
+		error.message,
+		"value must not contain control characters, found `U+001B` at position 5"
+	);
+}
@@ -0,0 +1,38 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SearchRequest {
+	#[preprocess(csv_items(trim, lowercase, length(min = 1)))]
+	pub tags: String,
+	#[preprocess(csv_items(trim, delimiter = ";"))]
+	pub sort: String,
+}
+
+fn main() {
+	let request = SearchRequest {
+		tags: " Rust, WEB ".to_string(),
+		sort: " name ; date".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(request.tags, "rust,web");
+	assert_eq!(request.sort, "name;date");
+
+	let request = SearchRequest {
+		tags: "".to_string(),
+		sort: "".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(request.tags, "");
+	assert_eq!(request.sort, "");
+
+	let error = SearchRequest {
+		tags: "rust,,web".to_string(),
+		sort: "".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "tags");
+}
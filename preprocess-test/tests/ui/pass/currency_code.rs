@@ -0,0 +1,46 @@
+use std::borrow::Cow;
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreatePaymentRequest {
+	#[preprocess(trim, normalize_currency_code, currency_code(active_only = true))]
+	pub currency: String,
+	#[preprocess(currency_code)]
+	pub original_currency: String,
+	#[preprocess(optional(currency_code(active_only = false)))]
+	pub settlement_currency: Option<String>,
+}
+
+fn main() {
+	let request = CreatePaymentRequest {
+		currency: " usd ".to_string(),
+		original_currency: "DDM".to_string(),
+		settlement_currency: Some("EUR".to_string()),
+	}
+	.preprocess()
+	.unwrap();
+	let currency: Cow<'static, str> = request.currency;
+	assert_eq!(currency, "USD");
+	assert_eq!(request.original_currency, "DDM");
+
+	let error = CreatePaymentRequest {
+		currency: "dem".to_string(),
+		original_currency: "USD".to_string(),
+		settlement_currency: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "currency");
+	assert_eq!(error.message, "currency code `DEM` is no longer in use");
+
+	let error = CreatePaymentRequest {
+		currency: "EUR".to_string(),
+		original_currency: "usd".to_string(),
+		settlement_currency: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "original_currency");
+}
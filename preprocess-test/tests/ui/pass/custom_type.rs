@@ -0,0 +1,75 @@
+use std::borrow::Cow;
+
+use preprocess::prelude::*;
+
+pub struct Slug;
+
+impl FieldValidator<String> for Slug {
+	fn validate(value: String) -> Result<String> {
+		Self::validate(Cow::Owned(value)).map(Cow::into_owned)
+	}
+}
+
+impl FieldValidator<Cow<'static, str>> for Slug {
+	fn validate(value: Cow<'static, str>) -> Result<Cow<'static, str>> {
+		if value.is_empty() ||
+			!value.bytes().all(|byte| {
+				byte.is_ascii_lowercase() || byte.is_ascii_digit() || byte == b'-'
+			}) {
+			return Err(Error::new("invalid slug"));
+		}
+		Ok(value)
+	}
+}
+
+pub struct MaxLen<const N: usize>;
+
+impl<const N: usize, T> FieldValidator<Vec<T>> for MaxLen<N> {
+	fn validate(value: Vec<T>) -> Result<Vec<T>> {
+		if value.len() > N {
+			return Err(Error::new(format!("at most {} items are allowed", N)));
+		}
+		Ok(value)
+	}
+}
+
+#[preprocess::sync]
+pub struct CreatePostRequest {
+	#[preprocess(custom_type = Slug)]
+	pub slug: String,
+	#[preprocess(trim, lowercase, custom_type = Slug)]
+	pub category: String,
+	#[preprocess(custom_type = MaxLen::<2>)]
+	pub tags: Vec<String>,
+}
+
+fn main() {
+	let processed = CreatePostRequest {
+		slug: "hello-world".to_string(),
+		category: " News ".to_string(),
+		tags: vec!["rust".to_string()],
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(processed.slug, "hello-world");
+	assert_eq!(processed.category, "news");
+
+	let error = CreatePostRequest {
+		slug: "Hello World".to_string(),
+		category: "news".to_string(),
+		tags: vec![],
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "slug");
+	assert_eq!(error.message, "invalid slug");
+
+	let error = CreatePostRequest {
+		slug: "hello".to_string(),
+		category: "news".to_string(),
+		tags: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "tags");
+}
@@ -0,0 +1,39 @@
+use preprocess::{
+	prelude::*,
+	types::{DateTime, Local, NaiveDateTime, Utc},
+};
+
+#[preprocess::sync]
+pub struct CreateEventRequest {
+	#[preprocess(trim, datetime)]
+	pub starts_at: String,
+	#[preprocess(datetime(format = "%Y-%m-%dT%H:%M:%S%:z", timezone = "UTC"))]
+	pub created_at: String,
+	#[preprocess(datetime_local(format = "%Y-%m-%d %H:%M"))]
+	pub remind_at: String,
+}
+
+fn main() {
+	let processed = CreateEventRequest {
+		starts_at: " 2024-02-29T10:30:00 ".to_string(),
+		created_at: "2024-02-29T10:30:00+05:30".to_string(),
+		remind_at: "2024-02-29 10:30".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	let starts_at: NaiveDateTime = processed.starts_at;
+	let created_at: DateTime<Utc> = processed.created_at;
+	let _: DateTime<Local> = processed.remind_at;
+	assert_eq!(starts_at.to_string(), "2024-02-29 10:30:00");
+	assert_eq!(created_at.to_rfc3339(), "2024-02-29T05:00:00+00:00");
+
+	let error = CreateEventRequest {
+		starts_at: "2024-02-30T10:30:00".to_string(),
+		created_at: "2024-02-29T10:30:00+05:30".to_string(),
+		remind_at: "2024-02-29 10:30".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "starts_at");
+	assert_eq!(error.message, "invalid date and time");
+}
@@ -0,0 +1,20 @@
+#[preprocess::sync(debug_output = true)]
+#[derive(Debug)]
+pub struct CreateUserRequest {
+	#[preprocess(trim, email)]
+	pub email: String,
+	#[preprocess(length(min = 8))]
+	pub password: String,
+}
+
+fn main() {
+	use preprocess::Preprocessable;
+
+	let request = CreateUserRequest {
+		email: " user@example.com ".to_string(),
+		password: "hunter22".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(request.email, "user@example.com");
+}
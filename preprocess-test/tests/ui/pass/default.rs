@@ -0,0 +1,40 @@
+use preprocess::prelude::*;
+
+fn default_tags() -> Vec<String> {
+	vec!["general".to_string()]
+}
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ListUsersRequest {
+	#[preprocess(trim, email, default = "noreply@example.com")]
+	pub reply_to: String,
+	#[preprocess(range(min = 1, max = 100), default = 20)]
+	pub page_size: u32,
+	#[preprocess(min_items = 1, default_fn = "default_tags")]
+	pub tags: Vec<String>,
+}
+
+fn main() {
+	let processed = ListUsersRequest {
+		reply_to: " user@example.com ".to_string(),
+		page_size: 50,
+		tags: vec!["admin".to_string()],
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(processed.reply_to, "user@example.com");
+	assert_eq!(processed.page_size, 50);
+	assert_eq!(processed.tags, vec!["admin".to_string()]);
+
+	let processed = ListUsersRequest {
+		reply_to: "not an email".to_string(),
+		page_size: 500,
+		tags: vec![],
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(processed.reply_to, "noreply@example.com");
+	assert_eq!(processed.page_size, 20);
+	assert_eq!(processed.tags, vec!["general".to_string()]);
+}
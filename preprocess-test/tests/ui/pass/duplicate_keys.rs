@@ -0,0 +1,47 @@
+use std::{collections::HashMap, error::Error as _};
+
+use preprocess::{prelude::*, utils::DuplicateKey};
+
+#[preprocess::sync]
+pub struct SetLabelsRequest {
+	#[preprocess(keys(trim, lowercase))]
+	pub labels: HashMap<String, String>,
+}
+
+// The generated code must not clash with a field named `map`
+#[preprocess::sync]
+pub struct SetMapRequest {
+	#[preprocess(keys(trim))]
+	pub map: HashMap<String, u32>,
+}
+
+fn main() {
+	let processed = SetLabelsRequest {
+		labels: [(" Env ".to_string(), "prod".to_string())].into(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(processed.labels["env"], "prod");
+
+	let error = SetLabelsRequest {
+		labels: [
+			("Env".to_string(), "prod".to_string()),
+			("env ".to_string(), "dev".to_string()),
+		]
+		.into(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "labels");
+	assert!(error
+		.source()
+		.and_then(|source| source.downcast_ref::<DuplicateKey>())
+		.is_some_and(|duplicate| duplicate.key == "\"env\""));
+
+	let processed = SetMapRequest {
+		map: [(" cpu ".to_string(), 4)].into(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(processed.map["cpu"], 4);
+}
@@ -0,0 +1,87 @@
+use std::{
+	borrow::Cow,
+	collections::{BTreeMap, HashMap},
+};
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct SetHeadersRequest {
+	#[preprocess(each_key(trim, lowercase), each_value(trim, length(max = 10)))]
+	pub headers: BTreeMap<String, String>,
+	#[preprocess(each_value(length(min = 1)), length(max = 2), each_key(uppercase))]
+	pub labels: HashMap<String, String>,
+	#[preprocess(each_value(range(max = 10)))]
+	pub limits: HashMap<String, u32>,
+}
+
+// The generated code must not clash with fields named `map`, `key` or `value`
+#[preprocess::sync]
+pub struct SetMapRequest {
+	#[preprocess(each_key(trim), each_value(trim))]
+	pub map: HashMap<String, String>,
+	#[preprocess(each_value(trim))]
+	pub key: HashMap<String, String>,
+	#[preprocess(each_key(trim))]
+	pub value: BTreeMap<String, String>,
+}
+
+fn main() {
+	let processed = SetHeadersRequest {
+		headers: [(" Accept ".to_string(), " text/html ".to_string())].into(),
+		labels: [("env".to_string(), "prod".to_string())].into(),
+		limits: [("cpu".to_string(), 4)].into(),
+	}
+	.preprocess()
+	.unwrap();
+
+	let headers: BTreeMap<Cow<'static, str>, Cow<'static, str>> =
+		processed.headers;
+	assert_eq!(headers["accept"], "text/html");
+	let labels: HashMap<Cow<'static, str>, String> = processed.labels;
+	assert_eq!(labels["ENV"], "prod");
+	assert_eq!(processed.limits["cpu"], 4);
+
+	let error = SetHeadersRequest {
+		headers: [
+			("Accept".to_string(), "text/html".to_string()),
+			("accept ".to_string(), "*/*".to_string()),
+		]
+		.into(),
+		labels: HashMap::new(),
+		limits: HashMap::new(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "headers");
+
+	let error = SetHeadersRequest {
+		headers: [("Accept".to_string(), "application/json".to_string())]
+			.into(),
+		labels: HashMap::new(),
+		limits: HashMap::new(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "headers");
+
+	let error = SetHeadersRequest {
+		headers: BTreeMap::new(),
+		labels: [("env".to_string(), String::new())].into(),
+		limits: HashMap::new(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "labels");
+
+	let processed = SetMapRequest {
+		map: [(" env ".to_string(), " prod ".to_string())].into(),
+		key: [("env".to_string(), " dev ".to_string())].into(),
+		value: [(" env ".to_string(), "test".to_string())].into(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(processed.map["env"], "prod");
+	assert_eq!(processed.key["env"], "dev");
+	assert_eq!(processed.value["env"], "test");
+}
@@ -0,0 +1,94 @@
+use preprocess::prelude::*;
+
+pub const RESERVED_PORT: u16 = 0;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum Plan {
+	Free,
+	Pro,
+}
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateUserRequest {
+	#[preprocess(trim, lowercase, not_equal = "admin")]
+	pub username: String,
+	#[preprocess(equal = "DELETE")]
+	pub confirmation: String,
+	#[preprocess(not_equal = RESERVED_PORT)]
+	pub port: u16,
+	#[preprocess(not_equal = Plan::Free)]
+	pub plan: Plan,
+	#[preprocess(optional(equal = true))]
+	pub accept_terms: Option<bool>,
+}
+
+fn main() {
+	let request = CreateUserRequest {
+		username: " John ".to_string(),
+		confirmation: "DELETE".to_string(),
+		port: 8080,
+		plan: Plan::Pro,
+		accept_terms: Some(true),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(request.username, "john");
+
+	let error = CreateUserRequest {
+		username: " Admin ".to_string(),
+		confirmation: "DELETE".to_string(),
+		port: 8080,
+		plan: Plan::Pro,
+		accept_terms: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "username");
+	assert_eq!(error.message, "value must not be equal to the forbidden value");
+
+	let error = CreateUserRequest {
+		username: "john".to_string(),
+		confirmation: "delete".to_string(),
+		port: 8080,
+		plan: Plan::Pro,
+		accept_terms: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "confirmation");
+	assert_eq!(error.message, "value must be equal to the expected value");
+
+	let error = CreateUserRequest {
+		username: "john".to_string(),
+		confirmation: "DELETE".to_string(),
+		port: 0,
+		plan: Plan::Pro,
+		accept_terms: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "port");
+
+	let error = CreateUserRequest {
+		username: "john".to_string(),
+		confirmation: "DELETE".to_string(),
+		port: 8080,
+		plan: Plan::Free,
+		accept_terms: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "plan");
+
+	let error = CreateUserRequest {
+		username: "john".to_string(),
+		confirmation: "DELETE".to_string(),
+		port: 8080,
+		plan: Plan::Pro,
+		accept_terms: Some(false),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "accept_terms");
+}
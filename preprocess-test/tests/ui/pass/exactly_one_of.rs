@@ -0,0 +1,26 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[preprocess(exactly_one_of = ["code", "assertion"])]
+pub struct TokenRequest {
+	#[preprocess(trim)]
+	pub client_id: String,
+	pub code: Option<String>,
+	pub assertion: Option<String>,
+}
+
+fn main() {
+	let request = |code: Option<&str>, assertion: Option<&str>| TokenRequest {
+		client_id: "client".to_string(),
+		code: code.map(ToString::to_string),
+		assertion: assertion.map(ToString::to_string),
+	};
+
+	assert!(request(Some("code"), None).preprocess().is_ok());
+	assert!(request(None, Some("assertion")).preprocess().is_ok());
+	assert_eq!(
+		request(None, None).preprocess().unwrap_err().message,
+		"exactly one of `code` or `assertion` must be set"
+	);
+	assert!(request(Some("code"), Some("assertion")).preprocess().is_err());
+}
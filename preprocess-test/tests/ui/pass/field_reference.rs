@@ -0,0 +1,42 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct RefundRequest {
+	#[preprocess(range(min = 1, max_field = "total_amount"))]
+	pub amount: u64,
+	#[preprocess(range(min_field = "minimum_total"))]
+	pub total_amount: u64,
+	pub minimum_total: u64,
+}
+
+#[preprocess::sync]
+pub enum Selection {
+	Range {
+		#[preprocess(range(max_field = "end"))]
+		start: u32,
+		end: u32,
+	},
+}
+
+fn main() {
+	let refund = RefundRequest {
+		amount: 5,
+		total_amount: 10,
+		minimum_total: 1,
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(refund.amount, 5);
+
+	let error = RefundRequest {
+		amount: 11,
+		total_amount: 10,
+		minimum_total: 1,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "amount");
+
+	assert!(Selection::Range { start: 1, end: 2 }.preprocess().is_ok());
+	assert!(Selection::Range { start: 3, end: 2 }.preprocess().is_err());
+}
@@ -0,0 +1,43 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Reading {
+	#[preprocess(non_nan)]
+	pub threshold: f64,
+	#[preprocess(finite, range(min = -90.0, max = 90.0))]
+	pub latitude: f32,
+	#[preprocess(optional(finite))]
+	pub offset: Option<f64>,
+}
+
+fn main() {
+	let reading = Reading {
+		threshold: f64::INFINITY,
+		latitude: 45.5,
+		offset: Some(-1.0),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(reading.threshold, f64::INFINITY);
+	assert_eq!(reading.latitude, 45.5);
+	assert_eq!(reading.offset, Some(-1.0));
+
+	let error = Reading {
+		threshold: f64::NAN,
+		latitude: 0.0,
+		offset: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "threshold");
+
+	let error = Reading {
+		threshold: 0.0,
+		latitude: 0.0,
+		offset: Some(f64::NEG_INFINITY),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "offset");
+}
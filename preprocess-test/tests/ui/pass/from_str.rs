@@ -0,0 +1,50 @@
+use std::{error::Error as _, net::IpAddr};
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateServerRequest {
+	#[preprocess(trim, from_str(as = "u64"), range(min = 1))]
+	pub cpu_count: String, // This type will be changed to u64
+	#[preprocess(from_str(as = "std::net::IpAddr"))]
+	pub address: String, // This type will be changed to IpAddr
+}
+
+fn main() {
+	let request = CreateServerRequest {
+		cpu_count: " 4 ".to_string(),
+		address: "127.0.0.1".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	let cpu_count: u64 = request.cpu_count;
+	let address: IpAddr = request.address;
+	assert_eq!(cpu_count, 4);
+	assert_eq!(address, IpAddr::from([127, 0, 0, 1]));
+
+	let error = CreateServerRequest {
+		cpu_count: "four".to_string(),
+		address: "127.0.0.1".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "cpu_count");
+	assert_eq!(error.message, "invalid digit found in string");
+	assert!(error
+		.source()
+		.unwrap()
+		.is::<std::num::ParseIntError>());
+
+	let error = CreateServerRequest {
+		cpu_count: "4".to_string(),
+		address: "localhost".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "address");
+	assert!(error
+		.source()
+		.unwrap()
+		.is::<std::net::AddrParseError>());
+}
@@ -0,0 +1,43 @@
+use std::fmt::{Debug, Display};
+
+use preprocess::prelude::*;
+
+fn validate_display<T: Display>(value: T) -> Result<T> {
+	if value.to_string().is_empty() {
+		return Err(Error::new("value cannot be empty"));
+	}
+	Ok(value)
+}
+
+#[preprocess::sync]
+pub enum Either<L: Display + Debug + Clone + PartialEq, R>
+where
+	R: Debug + Clone + PartialEq,
+{
+	Left(#[preprocess(custom = "validate_display")] L),
+	Right {
+		#[preprocess(trim)]
+		name: String,
+		value: R,
+	},
+}
+
+fn main() {
+	let left: EitherProcessed<String, u8> =
+		Either::Left("left".to_string()).preprocess().unwrap();
+	assert_eq!(left, EitherProcessed::Left("left".to_string()));
+
+	let right = Either::<String, u8>::Right {
+		name: " right ".to_string(),
+		value: 1,
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(
+		right,
+		EitherProcessed::Right {
+			name: "right".into(),
+			value: 1,
+		}
+	);
+}
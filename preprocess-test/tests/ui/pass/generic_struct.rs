@@ -0,0 +1,55 @@
+use std::fmt::{Debug, Display};
+
+use preprocess::prelude::*;
+
+fn validate_display<T: Display>(value: T) -> Result<T> {
+	if value.to_string().is_empty() {
+		return Err(Error::new("value cannot be empty"));
+	}
+	Ok(value)
+}
+
+#[preprocess::sync]
+pub struct Wrapper<T: Display + Debug + Clone + PartialEq>
+where
+	T: Default,
+{
+	#[preprocess(custom = "validate_display")]
+	pub value: T,
+	#[preprocess(trim)]
+	pub label: String,
+}
+
+#[preprocess::sync]
+pub struct Items<I>
+where
+	I: Iterator,
+	I::Item: Debug + Clone + PartialEq,
+{
+	#[preprocess(length(max = 10))]
+	pub items: Vec<I::Item>,
+}
+
+#[preprocess::sync]
+pub struct Pair<T>(#[preprocess(custom = "validate_display")] T, u8)
+where
+	T: Display + Debug + Clone + PartialEq;
+
+fn main() {
+	let wrapper: WrapperProcessed<u32> = Wrapper {
+		value: 5,
+		label: " five ".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(wrapper.label, "five");
+
+	let items: ItemsProcessed<std::vec::IntoIter<u8>> = Items {
+		items: vec![1, 2, 3],
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(items.items, vec![1, 2, 3]);
+
+	assert!(Pair(String::new(), 1).preprocess().is_err());
+}
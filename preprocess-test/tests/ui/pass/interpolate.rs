@@ -0,0 +1,38 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateOrder {
+	#[preprocess(trim, interpolate(template = "order-{id}"))]
+	pub reference: String,
+	#[preprocess(interpolate(template = "{{{key}}}: {value}"))]
+	pub header: String,
+}
+
+fn main() {
+	let order = CreateOrder {
+		reference: " order-42 ".to_string(),
+		header: "{accept}: json".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(order.reference, "order-42");
+
+	let error = CreateOrder {
+		reference: "invoice-42".to_string(),
+		header: "{accept}: json".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "reference");
+	assert_eq!(error.message, "value must start with `order-`");
+
+	let error = CreateOrder {
+		reference: "order-42".to_string(),
+		header: "{accept} json".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "header");
+	assert_eq!(error.message, "value must contain `}: ` after `{key}`");
+}
@@ -0,0 +1,62 @@
+use std::net::IpAddr;
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RegisterNodeRequest {
+	#[preprocess(ip(network = "10.0.0.0/8"))]
+	pub address: String,
+	#[preprocess(ip(network = "fd00::/8"))]
+	pub overlay_address: String,
+	#[preprocess(optional(ip(network = "192.168.1.0/24")))]
+	pub gateway: Option<String>,
+}
+
+fn main() {
+	let request = RegisterNodeRequest {
+		address: "10.1.2.3".to_string(),
+		overlay_address: "fd12:3456::1".to_string(),
+		gateway: Some("192.168.1.1".to_string()),
+	}
+	.preprocess()
+	.unwrap();
+	let _: IpAddr = request.address;
+	assert_eq!(request.address, "10.1.2.3".parse::<IpAddr>().unwrap());
+	assert_eq!(request.gateway, Some("192.168.1.1".parse().unwrap()));
+
+	let error = RegisterNodeRequest {
+		address: "11.1.2.3".to_string(),
+		overlay_address: "fd12:3456::1".to_string(),
+		gateway: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "address");
+	assert_eq!(
+		error.message,
+		"IP address is not within the allowed network 10.0.0.0/8"
+	);
+
+	let error = RegisterNodeRequest {
+		address: "10.1.2.3".to_string(),
+		overlay_address: "10.1.2.3".to_string(),
+		gateway: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "overlay_address");
+
+	let error = RegisterNodeRequest {
+		address: "10.1.2.3".to_string(),
+		overlay_address: "fd12:3456::1".to_string(),
+		gateway: Some("192.168.2.1".to_string()),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "gateway");
+	assert_eq!(
+		error.message,
+		"IP address is not within the allowed network 192.168.1.0/24"
+	);
+}
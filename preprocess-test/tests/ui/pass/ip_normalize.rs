@@ -0,0 +1,36 @@
+use std::borrow::Cow;
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct AddServerRequest {
+	#[preprocess(trim, ip(normalize))]
+	pub address: String,
+	#[preprocess(ip(v4, normalize))]
+	pub ipv4: String,
+	#[preprocess(ip(v6, normalize))]
+	pub ipv6: String,
+}
+
+fn main() {
+	let processed = AddServerRequest {
+		address: " ::0001 ".to_string(),
+		ipv4: "1.002.003.004".to_string(),
+		ipv6: "0:0:0:0:0:0:0:1".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	let address: Cow<'static, str> = processed.address;
+	assert_eq!(address, "::1");
+	assert_eq!(processed.ipv4, "1.2.3.4");
+	assert_eq!(processed.ipv6, "::1");
+
+	let error = AddServerRequest {
+		address: "1.2.3.4".to_string(),
+		ipv4: "::1".to_string(),
+		ipv6: "::1".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "ipv4");
+}
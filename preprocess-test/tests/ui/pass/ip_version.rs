@@ -0,0 +1,55 @@
+#![deny(warnings)]
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct AddServerRequest {
+	#[preprocess(ipv4)]
+	pub ipv4: String,
+	#[preprocess(ipv6)]
+	pub ipv6: String,
+	#[preprocess(ip(v4))]
+	pub long_ipv4: String,
+	#[preprocess(ip(v6))]
+	pub long_ipv6: String,
+	#[preprocess(ip(version = "v4"))]
+	pub version_ipv4: String,
+	#[preprocess(ip(version = "v6"))]
+	pub version_ipv6: String,
+}
+
+fn request(ipv4: &str, ipv6: &str) -> AddServerRequest {
+	AddServerRequest {
+		ipv4: ipv4.to_string(),
+		ipv6: ipv6.to_string(),
+		long_ipv4: ipv4.to_string(),
+		long_ipv6: ipv6.to_string(),
+		version_ipv4: ipv4.to_string(),
+		version_ipv6: ipv6.to_string(),
+	}
+}
+
+fn main() {
+	let processed = request("1.2.3.4", "::1").preprocess().unwrap();
+	let ipv4: Ipv4Addr = processed.ipv4;
+	let ipv6: Ipv6Addr = processed.ipv6;
+	assert_eq!(ipv4, Ipv4Addr::new(1, 2, 3, 4));
+	assert_eq!(ipv6, Ipv6Addr::LOCALHOST);
+	assert_eq!(processed.long_ipv4, ipv4);
+	assert_eq!(processed.long_ipv6, ipv6);
+	assert_eq!(processed.version_ipv4, ipv4);
+	assert_eq!(processed.version_ipv6, ipv6);
+
+	let error = request("::1", "::1").preprocess().unwrap_err();
+	assert_eq!(error.field, "ipv4");
+
+	let error = request("1.2.3.4", "1.2.3.4").preprocess().unwrap_err();
+	assert_eq!(error.field, "ipv6");
+
+	let mut invalid = request("1.2.3.4", "::1");
+	invalid.version_ipv4 = "::1".to_string();
+	let error = invalid.preprocess().unwrap_err();
+	assert_eq!(error.field, "version_ipv4");
+}
@@ -0,0 +1,44 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogVisitRequest {
+	#[preprocess(trim, ipv4_mapped)]
+	pub client: String,
+	#[preprocess(normalize_ip_any)]
+	pub proxy: String,
+}
+
+fn request(client: &str, proxy: &str) -> LogVisitRequest {
+	LogVisitRequest {
+		client: client.to_string(),
+		proxy: proxy.to_string(),
+	}
+}
+
+fn main() {
+	let processed = request(" ::ffff:192.0.2.1 ", "::FFFF:10.0.0.1")
+		.preprocess()
+		.unwrap();
+	let client: IpAddr = processed.client;
+	assert_eq!(client, IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)));
+	assert_eq!(processed.proxy, "10.0.0.1");
+
+	let processed = request("192.0.2.1", "010.0.0.1").preprocess().unwrap();
+	assert_eq!(processed.client, IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)));
+	assert_eq!(processed.proxy, "10.0.0.1");
+
+	let processed = request("2001:db8::1", "2001:DB8:0::1").preprocess().unwrap();
+	assert_eq!(
+		processed.client,
+		IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1))
+	);
+	assert_eq!(processed.proxy, "2001:db8::1");
+
+	let error = request("localhost", "::1").preprocess().unwrap_err();
+	assert_eq!(error.field, "client");
+	let error = request("::1", "::ffff:1.2.3").preprocess().unwrap_err();
+	assert_eq!(error.field, "proxy");
+}
@@ -0,0 +1,42 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateExtractor {
+	#[preprocess(valid_json_path)]
+	pub selector: String,
+	#[preprocess(json(json_path = "$.items[*].name"))]
+	pub sample: String,
+	#[preprocess(optional(valid_json_path))]
+	pub fallback: Option<String>,
+}
+
+fn main() {
+	let extractor = CreateExtractor {
+		selector: "$.items[*].name".to_string(),
+		sample: r#"{"items": [{"name": "a"}, {"name": "b"}]}"#.to_string(),
+		fallback: None,
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(extractor.selector, "$.items[*].name");
+
+	let error = CreateExtractor {
+		selector: "$.items[".to_string(),
+		sample: r#"{"items": [{"name": "a"}]}"#.to_string(),
+		fallback: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "selector");
+
+	let error = CreateExtractor {
+		selector: "$".to_string(),
+		sample: r#"{"items": []}"#.to_string(),
+		fallback: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "sample");
+	assert_eq!(error.message, "JSON must contain a value at `$.items[*].name`");
+}
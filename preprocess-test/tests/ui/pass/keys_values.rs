@@ -0,0 +1,45 @@
+use std::collections::{BTreeMap, HashMap};
+
+use preprocess::{prelude::*, types::Url};
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SetQuotasRequest {
+	#[preprocess(keys(trim, lowercase), values(trim, from_str(as = "u32")))]
+	pub quotas: HashMap<String, String>, // The values will be changed to u32
+	#[preprocess(keys(trim, lowercase), values(trim, url))]
+	pub webhooks: BTreeMap<String, String>, // The values will be changed to Url
+}
+
+fn main() {
+	let processed = SetQuotasRequest {
+		quotas: [(" CPU ".to_string(), " 4 ".to_string())].into(),
+		webhooks: [(
+			" Deploy ".to_string(),
+			" https://example.com/deploy ".to_string(),
+		)]
+		.into(),
+	}
+	.preprocess()
+	.unwrap();
+	let cpu: u32 = processed.quotas["cpu"];
+	let deploy: &Url = &processed.webhooks["deploy"];
+	assert_eq!(cpu, 4);
+	assert_eq!(deploy.as_str(), "https://example.com/deploy");
+
+	let error = SetQuotasRequest {
+		quotas: [("cpu".to_string(), "four".to_string())].into(),
+		webhooks: BTreeMap::new(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "quotas");
+
+	let error = SetQuotasRequest {
+		quotas: HashMap::new(),
+		webhooks: [("deploy".to_string(), "not a url".to_string())].into(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "webhooks");
+}
@@ -0,0 +1,45 @@
+use std::borrow::Cow;
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SetLocaleRequest {
+	#[preprocess(language_code, normalize_language_code)]
+	pub language: String,
+	#[preprocess(language_code(strict = true))]
+	pub fallback: String,
+	#[preprocess(optional(language_code(strict = false)))]
+	pub content_language: Option<String>,
+}
+
+fn main() {
+	let request = SetLocaleRequest {
+		language: "zh-hant-tw".to_string(),
+		fallback: "en-US".to_string(),
+		content_language: Some("zz-YY".to_string()),
+	}
+	.preprocess()
+	.unwrap();
+	let language: Cow<'static, str> = request.language;
+	assert_eq!(language, "zh-Hant-TW");
+
+	let error = SetLocaleRequest {
+		language: "en_US".to_string(),
+		fallback: "en-US".to_string(),
+		content_language: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "language");
+
+	let error = SetLocaleRequest {
+		language: "en".to_string(),
+		fallback: "zz-YY".to_string(),
+		content_language: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "fallback");
+	assert!(error.message.starts_with("invalid language code"));
+}
@@ -0,0 +1,26 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct Borrowed<'a, T: ?Sized + 'a> {
+	#[preprocess(length(min = 1))]
+	pub name: &'a str,
+	pub value: &'a T,
+}
+
+fn main() {
+	let name = String::from("name");
+	let borrowed: BorrowedProcessed<'_, [u8]> = Borrowed {
+		name: &name,
+		value: &[1, 2, 3][..],
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(borrowed.name, "name");
+
+	assert!(Borrowed {
+		name: "",
+		value: "value",
+	}
+	.preprocess()
+	.is_err());
+}
@@ -0,0 +1,60 @@
+use preprocess::prelude::*;
+
+mod errors {
+	pub fn email_error(error: preprocess::Error) -> preprocess::Error {
+		preprocess::Error::new(format!("please enter a valid email address ({})", error.message))
+			.set_field(error.field)
+	}
+}
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UserSignUpRequest {
+	#[preprocess(trim, lowercase, email, map_error = "errors::email_error")]
+	pub email: String,
+	#[preprocess(trim, length(min = 3), error_message = "username is too short")]
+	pub username: String,
+	#[preprocess(length(min = 8), error_message = "password contains 'hunter2'", sensitive)]
+	pub password: String,
+}
+
+fn main() {
+	let request = UserSignUpRequest {
+		email: " John@Example.com ".to_string(),
+		username: "john".to_string(),
+		password: "correct horse".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(request.email, "john@example.com");
+
+	let error = UserSignUpRequest {
+		email: "not an email".to_string(),
+		username: "john".to_string(),
+		password: "correct horse".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "email");
+	assert!(error.message.starts_with("please enter a valid email address ("));
+
+	let error = UserSignUpRequest {
+		email: "john@example.com".to_string(),
+		username: " jo ".to_string(),
+		password: "correct horse".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "username");
+	assert_eq!(error.message, "username is too short");
+
+	let error = UserSignUpRequest {
+		email: "john@example.com".to_string(),
+		username: "john".to_string(),
+		password: "hunter2".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "password");
+	assert_eq!(error.message, "value is invalid");
+}
@@ -0,0 +1,41 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct UserProfile {
+	#[preprocess(trim, length(min = 1))]
+	pub name: String,
+}
+
+#[preprocess::sync]
+pub struct UpdateUserRequest {
+	#[preprocess]
+	pub profile: std::result::Result<UserProfile, String>,
+}
+
+fn main() {
+	let processed = UpdateUserRequest {
+		profile: Ok(UserProfile {
+			name: " John ".to_string(),
+		}),
+	}
+	.preprocess()
+	.unwrap();
+	let profile: UserProfileProcessed = processed.profile.unwrap();
+	assert_eq!(profile.name, "John");
+
+	let processed = UpdateUserRequest {
+		profile: Err("profile not found".to_string()),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(processed.profile.unwrap_err(), "profile not found");
+
+	let error = UpdateUserRequest {
+		profile: Ok(UserProfile {
+			name: "   ".to_string(),
+		}),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "profile");
+}
@@ -0,0 +1,53 @@
+use std::{rc::Rc, sync::Arc};
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DatabaseConfig {
+	#[preprocess(trim, length(min = 1))]
+	pub host: String,
+}
+
+#[preprocess::sync]
+pub struct AppConfig {
+	#[preprocess(nested)]
+	pub database: Arc<DatabaseConfig>,
+	#[preprocess]
+	pub replica: Rc<DatabaseConfig>,
+	#[preprocess(optional(nested))]
+	pub cache: Option<Box<DatabaseConfig>>,
+}
+
+fn main() {
+	let database = Arc::new(DatabaseConfig {
+		host: " db.local ".to_string(),
+	});
+	let config = AppConfig {
+		database: Arc::clone(&database),
+		replica: Rc::new(DatabaseConfig {
+			host: "replica.local".to_string(),
+		}),
+		cache: Some(Box::new(DatabaseConfig {
+			host: " cache.local".to_string(),
+		})),
+	}
+	.preprocess()
+	.unwrap();
+	let processed: Arc<DatabaseConfigProcessed> = config.database;
+	assert_eq!(processed.host, "db.local");
+	assert_eq!(database.host, " db.local ");
+	assert_eq!(config.replica.host, "replica.local");
+	assert_eq!(config.cache.unwrap().host, "cache.local");
+
+	let error = AppConfig {
+		database,
+		replica: Rc::new(DatabaseConfig {
+			host: "  ".to_string(),
+		}),
+		cache: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "replica");
+}
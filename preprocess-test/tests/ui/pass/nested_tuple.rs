@@ -0,0 +1,62 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct Sender(#[preprocess(trim, email)] pub String);
+
+#[preprocess::sync]
+pub struct Recipient(
+	#[preprocess(trim, email)] pub String,
+	#[preprocess(range(max = 10))] pub u8,
+);
+
+#[preprocess::sync]
+pub struct SendMailRequest {
+	#[preprocess(nested)]
+	pub route: (Sender, Recipient),
+	#[preprocess(optional(nested))]
+	pub copies: Option<(Recipient, Recipient, Recipient)>,
+}
+
+fn main() {
+	let request = SendMailRequest {
+		route: (
+			Sender(" from@example.com ".to_string()),
+			Recipient(" to@example.com ".to_string(), 1),
+		),
+		copies: None,
+	}
+	.preprocess()
+	.unwrap();
+	let (SenderProcessed(from), RecipientProcessed(to, priority)) =
+		request.route;
+	assert_eq!(from, "from@example.com");
+	assert_eq!(to, "to@example.com");
+	assert_eq!(priority, 1);
+
+	let error = SendMailRequest {
+		route: (
+			Sender("from@example.com".to_string()),
+			Recipient("to@example.com".to_string(), 11),
+		),
+		copies: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "route");
+
+	let request = SendMailRequest {
+		route: (
+			Sender("from@example.com".to_string()),
+			Recipient("to@example.com".to_string(), 1),
+		),
+		copies: Some((
+			Recipient(" a@example.com".to_string(), 1),
+			Recipient(" b@example.com".to_string(), 2),
+			Recipient(" c@example.com".to_string(), 3),
+		)),
+	}
+	.preprocess()
+	.unwrap();
+	let (_, second, _) = request.copies.unwrap();
+	assert_eq!(second.0, "b@example.com");
+}
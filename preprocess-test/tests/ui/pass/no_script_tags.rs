@@ -0,0 +1,36 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateProfileRequest {
+	#[preprocess(trim, no_script_tags)]
+	pub bio: String,
+	#[preprocess(optional(no_script_tags))]
+	pub website: Option<String>,
+}
+
+fn request(bio: &str, website: Option<&str>) -> UpdateProfileRequest {
+	UpdateProfileRequest {
+		bio: bio.to_string(),
+		website: website.map(str::to_string),
+	}
+}
+
+fn main() {
+	let processed = request(" I write <b>Rust</b> ", Some("https://example.com"))
+		.preprocess()
+		.unwrap();
+	assert_eq!(processed.bio, "I write <b>Rust</b>");
+
+	let error = request("<script>alert(1)</script>", None)
+		.preprocess()
+		.unwrap_err();
+	assert_eq!(error.field, "bio");
+	assert_eq!(error.message, "value must not contain `<script`");
+
+	let error = request("Hi", Some("JavaScript:alert(1)"))
+		.preprocess()
+		.unwrap_err();
+	assert_eq!(error.field, "website");
+	assert_eq!(error.message, "value must not contain `javascript:`");
+}
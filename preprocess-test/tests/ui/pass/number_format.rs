@@ -0,0 +1,37 @@
+use std::borrow::Cow;
+
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct CreatePaymentRequest {
+	#[preprocess(trim, number_format, decimal_string)]
+	pub amount: String,
+	#[preprocess(number_format(thousands = ".", decimal = ","), from_str(as = "f64"))]
+	pub fee: String,
+	#[preprocess(number_format(decimal = ","))]
+	pub tax: String,
+}
+
+fn main() {
+	let processed = CreatePaymentRequest {
+		amount: " 1,234,567.89 ".to_string(),
+		fee: "1.234,5".to_string(),
+		tax: "1.000,25".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	let amount: Cow<'static, str> = processed.amount;
+	assert_eq!(amount, "1234567.89");
+	assert_eq!(processed.fee, 1234.5);
+	assert_eq!(processed.tax, "1000.25");
+
+	let error = CreatePaymentRequest {
+		amount: "12 EUR".to_string(),
+		fee: "1".to_string(),
+		tax: "1".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "amount");
+	assert_eq!(error.message, "value is not a valid decimal number");
+}
@@ -0,0 +1,68 @@
+use std::{error::Error as _, net::IpAddr};
+
+use preprocess::{prelude::*, types::Url};
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UpdateProfileRequest {
+	#[preprocess(trim, url, on_error = "https://default.example.com")]
+	pub website: String, // This type will be changed to Url
+	#[preprocess(trim, from_str(as = "u32"), on_error = "20")]
+	pub page_size: String, // This type will be changed to u32
+	#[preprocess(trim, lowercase, on_error = "light")]
+	pub theme: String,
+}
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ConnectRequest {
+	#[preprocess(from_str(as = "std::net::IpAddr"), on_error = "localhost")]
+	pub address: String, // This type will be changed to IpAddr
+}
+
+fn main() {
+	let processed = UpdateProfileRequest {
+		website: " https://example.com/about ".to_string(),
+		page_size: " 50 ".to_string(),
+		theme: " DARK ".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	let website: Url = processed.website;
+	let page_size: u32 = processed.page_size;
+	assert_eq!(website.as_str(), "https://example.com/about");
+	assert_eq!(page_size, 50);
+	assert_eq!(processed.theme, "dark");
+
+	let processed = UpdateProfileRequest {
+		website: "not a url".to_string(),
+		page_size: "fifty".to_string(),
+		theme: "".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(processed.website.as_str(), "https://default.example.com/");
+	assert_eq!(processed.page_size, 20);
+	assert_eq!(processed.theme, "");
+
+	let processed = ConnectRequest {
+		address: "127.0.0.1".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	let address: IpAddr = processed.address;
+	assert_eq!(address, IpAddr::from([127, 0, 0, 1]));
+
+	// A fallback that cannot be parsed into the new type is an error
+	let error = ConnectRequest {
+		address: "not an address".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "address");
+	assert_eq!(error.message, "invalid fallback value");
+	assert!(error
+		.source()
+		.unwrap()
+		.is::<std::net::AddrParseError>());
+}
@@ -0,0 +1,51 @@
+use std::ops::Deref;
+
+use preprocess::prelude::*;
+
+#[preprocess::sync(deref = true)]
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct LoginRequest {
+	#[preprocess(email)]
+	pub email: String,
+	#[preprocess(length(min = 8))]
+	pub password: String,
+	pub remember_me: bool,
+}
+
+#[preprocess::sync(deref = true)]
+#[derive(Debug, Clone, PartialEq)]
+#[repr(C)]
+pub struct Tag<'a, T>(#[preprocess(length(min = 1))] pub &'a str, pub T);
+
+#[preprocess::sync(deref = true)]
+#[derive(Debug, Clone, PartialEq)]
+#[repr(transparent)]
+pub struct Username(#[preprocess(length(min = 3))] pub String);
+
+/// Only compiles if the given type implements `Deref` to the given target
+fn assert_deref<T: Deref<Target = Target>, Target: ?Sized>() {}
+
+fn remember_me(request: &LoginRequest) -> bool {
+	request.remember_me
+}
+
+fn main() {
+	let processed = LoginRequest {
+		email: "user@example.com".to_string(),
+		password: "password".to_string(),
+		remember_me: true,
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(processed.deref().email, "user@example.com");
+	assert_eq!(processed.deref().password, "password");
+	assert!(remember_me(&processed));
+
+	let tag = Tag("rust", 42u8).preprocess().unwrap();
+	assert_eq!((tag.deref().0, tag.deref().1), ("rust", 42));
+	assert_deref::<TagProcessed<'static, u8>, Tag<'static, u8>>();
+
+	let username = Username("admin".to_string()).preprocess().unwrap();
+	assert_eq!(username.deref().0, "admin");
+}
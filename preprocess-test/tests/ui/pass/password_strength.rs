@@ -0,0 +1,37 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SignUpRequest {
+	#[preprocess(length(min = 8), password_strength(min_score = 3))]
+	pub password: String,
+	#[preprocess(optional(password_strength))]
+	pub recovery_phrase: Option<String>,
+}
+
+fn main() {
+	let request = SignUpRequest {
+		password: "correct horse battery staple".to_string(),
+		recovery_phrase: None,
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(request.password, "correct horse battery staple");
+
+	let error = SignUpRequest {
+		password: "password".to_string(),
+		recovery_phrase: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "password");
+	assert!(error.message.starts_with("password is too weak"));
+
+	let error = SignUpRequest {
+		password: "correct horse battery staple".to_string(),
+		recovery_phrase: Some("qwertyuiop".to_string()),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "recovery_phrase");
+}
@@ -0,0 +1,45 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SetPhoneRequest {
+	#[preprocess(phone)]
+	pub phone: String,
+	#[preprocess(trim, phone(country = "US", format = "local"))]
+	pub us_phone: String,
+	#[preprocess(phone(country = "DE"))]
+	pub de_phone: String,
+	#[preprocess(normalize_phone(country = "GB"))]
+	pub uk_phone: String,
+	#[preprocess(normalize_phone)]
+	pub any_phone: String,
+}
+
+fn main() {
+	let processed = SetPhoneRequest {
+		phone: "+447400123456".to_string(),
+		us_phone: " (201) 555-0123 ".to_string(),
+		de_phone: "+4930123456".to_string(),
+		uk_phone: "07400 123456".to_string(),
+		any_phone: "+49 30 123456".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+
+	assert_eq!(processed.us_phone, "(201) 555-0123");
+	let uk_phone: std::borrow::Cow<'static, str> = processed.uk_phone;
+	assert_eq!(uk_phone, "+447400123456");
+	assert_eq!(processed.any_phone, "+4930123456");
+
+	let error = SetPhoneRequest {
+		phone: "+447400123456".to_string(),
+		us_phone: "+1 416 555 0123".to_string(),
+		de_phone: "+4930123456".to_string(),
+		uk_phone: "07400 123456".to_string(),
+		any_phone: "+49 30 123456".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "us_phone");
+	assert_eq!(error.message, "phone number must be from `US`");
+}
@@ -0,0 +1,44 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+pub struct Contact(
+	#[preprocess(trim, length(min = 1))] pub String,
+	#[preprocess(trim, lowercase, email)] pub String,
+	#[preprocess(optional(trim, length(max = 20)))] pub Option<String>,
+);
+
+#[preprocess::sync]
+pub struct Port(#[preprocess(range(min = 1024))] pub u16);
+
+fn main() {
+	let ContactProcessed(name, email, phone) = Contact(
+		" John ".to_string(),
+		" John@Example.com ".to_string(),
+		Some(" 555-0100 ".to_string()),
+	)
+	.preprocess()
+	.unwrap();
+	assert_eq!(name, "John");
+	assert_eq!(email, "john@example.com");
+	assert_eq!(phone.as_deref(), Some("555-0100"));
+
+	let contact = Contact(
+		"John".to_string(),
+		"john@example.com".to_string(),
+		None,
+	)
+	.preprocess()
+	.unwrap();
+	assert!(contact.2.is_none());
+
+	assert!(Contact(
+		"John".to_string(),
+		"john@example.com".to_string(),
+		Some("1".repeat(21)),
+	)
+	.preprocess()
+	.is_err());
+
+	assert_eq!(Port(8080).preprocess().unwrap().0, 8080);
+	assert!(Port(80).preprocess().is_err());
+}
@@ -0,0 +1,47 @@
+#![deny(type_alias_bounds)]
+
+use preprocess::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Preprocessable)]
+pub enum Status {
+	Active,
+	Inactive,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Preprocessable)]
+pub enum Tier<const N: usize = 3>
+where
+	[(); N]: Sized,
+{
+	Free,
+	Paid,
+}
+
+#[preprocess::sync]
+pub struct UpdateUserRequest {
+	#[preprocess(trim)]
+	pub name: String,
+	#[preprocess(nested)]
+	pub status: Status,
+	#[preprocess]
+	pub previous_status: Status,
+	#[preprocess]
+	pub tier: Tier,
+}
+
+fn main() {
+	let processed = UpdateUserRequest {
+		name: " John ".to_string(),
+		status: Status::Active,
+		previous_status: Status::Inactive,
+		tier: Tier::Paid,
+	}
+	.preprocess()
+	.unwrap();
+
+	let status: Status = processed.status;
+	assert_eq!(status, Status::Active);
+	assert_eq!(processed.previous_status, Status::Inactive);
+	let tier: TierProcessed = processed.tier;
+	assert_eq!(tier, Tier::Paid);
+}
@@ -0,0 +1,52 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateRoute {
+	#[preprocess(trim, url_component = "path")]
+	pub path: String,
+	#[preprocess(optional(url_component = "query"))]
+	pub query: Option<String>,
+	#[preprocess(url_component = "fragment")]
+	pub fragment: String,
+	#[preprocess(url_component = "host")]
+	pub host: String,
+	#[preprocess(lowercase, url_component = "scheme")]
+	pub scheme: String,
+}
+
+fn main() {
+	let route = CreateRoute {
+		path: " /api/v1/users ".to_string(),
+		query: Some("?page=2&sort=name".to_string()),
+		fragment: "#top".to_string(),
+		host: "api.example.com".to_string(),
+		scheme: "HTTPS".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(route.path, "/api/v1/users");
+	assert_eq!(route.scheme, "https");
+
+	let error = CreateRoute {
+		path: "/static/../secret".to_string(),
+		query: None,
+		fragment: "top".to_string(),
+		host: "example.com".to_string(),
+		scheme: "https".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "path");
+
+	let error = CreateRoute {
+		path: "/".to_string(),
+		query: Some("a=1#injected".to_string()),
+		fragment: "top".to_string(),
+		host: "example.com".to_string(),
+		scheme: "https".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "query");
+}
@@ -0,0 +1,38 @@
+use preprocess::{prelude::*, types::Uuid};
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateSessionRequest {
+	#[preprocess(uuid)]
+	pub user_id: String,
+	#[preprocess(trim, uuid(version = 4))]
+	pub nonce: String,
+	#[preprocess(uuid(version = 1, as_string = true))]
+	pub request_id: String,
+}
+
+fn main() {
+	let processed = CreateSessionRequest {
+		user_id: "886313e1-3b8a-5372-9b90-0c9aee199e5d".to_string(),
+		nonce: " 67E55044-10B1-426F-9247-BB680E5FE0C8 ".to_string(),
+		request_id: "{C232AB00-9414-11EC-B3C8-9E6BDECED846}".to_string(),
+	}
+	.preprocess()
+	.unwrap();
+
+	let user_id: Uuid = processed.user_id;
+	assert_eq!(user_id.get_version_num(), 5);
+	assert_eq!(processed.nonce.get_version_num(), 4);
+	let request_id: String = processed.request_id;
+	assert_eq!(request_id, "c232ab00-9414-11ec-b3c8-9e6bdeced846");
+
+	let error = CreateSessionRequest {
+		user_id: "886313e1-3b8a-5372-9b90-0c9aee199e5d".to_string(),
+		nonce: "886313e1-3b8a-5372-9b90-0c9aee199e5d".to_string(),
+		request_id: "c232ab00-9414-11ec-b3c8-9e6bdeced846".to_string(),
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "nonce");
+	assert_eq!(error.message, "UUID must be version 4, found version 5");
+}
@@ -0,0 +1,74 @@
+use preprocess::prelude::*;
+
+#[preprocess::sync]
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PublishReleaseRequest {
+	#[preprocess(version)]
+	pub tag: String,
+	#[preprocess(version(allow_v_prefix = false, allow_prerelease = false))]
+	pub stable_version: String,
+	#[preprocess(version(normalize = true, allow_build_metadata = false))]
+	pub version: String,
+	#[preprocess(optional(version(normalize = true)))]
+	pub previous_version: Option<String>,
+}
+
+fn main() {
+	let request = PublishReleaseRequest {
+		tag: "v1.0.0-rc1+build.5".to_string(),
+		stable_version: "1.0.0".to_string(),
+		version: "v1.0.0-rc1".to_string(),
+		previous_version: Some("v0.9.0".to_string()),
+	}
+	.preprocess()
+	.unwrap();
+	assert_eq!(request.tag, "v1.0.0-rc1+build.5");
+	assert_eq!(request.version, "1.0.0-rc1");
+	assert_eq!(request.previous_version.as_deref(), Some("0.9.0"));
+
+	let error = PublishReleaseRequest {
+		tag: "1.0".to_string(),
+		stable_version: "1.0.0".to_string(),
+		version: "1.0.0".to_string(),
+		previous_version: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "tag");
+	assert_eq!(
+		error.message,
+		"version must be in the format `MAJOR.MINOR.PATCH`"
+	);
+
+	let error = PublishReleaseRequest {
+		tag: "1.0.0".to_string(),
+		stable_version: "v1.0.0".to_string(),
+		version: "1.0.0".to_string(),
+		previous_version: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "stable_version");
+	assert_eq!(error.message, "version must not start with `v`");
+
+	let error = PublishReleaseRequest {
+		tag: "1.0.0".to_string(),
+		stable_version: "1.0.0-beta".to_string(),
+		version: "1.0.0".to_string(),
+		previous_version: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.message, "version must not have a pre-release suffix");
+
+	let error = PublishReleaseRequest {
+		tag: "1.0.0".to_string(),
+		stable_version: "1.0.0".to_string(),
+		version: "v1.0.0+build.5".to_string(),
+		previous_version: None,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "version");
+	assert_eq!(error.message, "version must not have build metadata");
+}
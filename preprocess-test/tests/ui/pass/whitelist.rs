@@ -0,0 +1,58 @@
+use std::borrow::Cow;
+
+use preprocess::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub enum Plan {
+	Free,
+	Pro,
+	Enterprise,
+}
+
+#[preprocess::sync]
+pub struct CreateAccountRequest {
+	#[preprocess(trim, lowercase, blacklist = ["admin", "root"])]
+	pub username: String,
+	#[preprocess(whitelist = ["us-east-1", "eu-west-1"])]
+	pub region: String,
+	#[preprocess(whitelist = [80, 443, 8080])]
+	pub port: u16,
+	#[preprocess(whitelist = [Plan::Free, Plan::Pro])]
+	pub plan: Plan,
+}
+
+fn main() {
+	let processed = CreateAccountRequest {
+		username: " Alice ".to_string(),
+		region: "eu-west-1".to_string(),
+		port: 443,
+		plan: Plan::Pro,
+	}
+	.preprocess()
+	.unwrap();
+	let username: Cow<'static, str> = processed.username;
+	assert_eq!(username, "alice");
+	assert_eq!(processed.port, 443);
+
+	let error = CreateAccountRequest {
+		username: " Root".to_string(),
+		region: "eu-west-1".to_string(),
+		port: 443,
+		plan: Plan::Pro,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "username");
+	assert_eq!(error.message, r#"value must not be one of ["admin", "root"]"#);
+
+	let error = CreateAccountRequest {
+		username: "alice".to_string(),
+		region: "eu-west-1".to_string(),
+		port: 443,
+		plan: Plan::Enterprise,
+	}
+	.preprocess()
+	.unwrap_err();
+	assert_eq!(error.field, "plan");
+	assert_eq!(error.message, "value must be one of [Free, Pro]");
+}
@@ -0,0 +1 @@
+mod smart_ptrs;
@@ -0,0 +1,115 @@
+use std::{rc::Rc, sync::Arc};
+
+use crate::Preprocessable;
+
+/// Preprocesses the value inside an [`Arc`], and wraps the processed value in
+/// a new [`Arc`]. The value is only cloned if the [`Arc`] is shared, which is
+/// common for shared configuration objects.
+///
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Clone, Deserialize, Serialize)]
+/// pub struct DatabaseConfig {
+///     #[preprocess(trim, url)]
+///     pub url: String,
+/// }
+///
+/// #[preprocess::sync]
+/// #[derive(Debug)]
+/// pub struct AppConfig {
+///     // The type of this field will be changed to
+///     // `Arc<DatabaseConfigProcessed>`
+///     #[preprocess(nested)]
+///     pub database: Arc<DatabaseConfig>,
+/// }
+/// ```
+impl<T> Preprocessable for Arc<T>
+where
+	T: Preprocessable + Clone,
+{
+	type Processed = Arc<T::Processed>;
+	type Error = T::Error;
+
+	fn preprocess(self) -> Result<Self::Processed, Self::Error> {
+		Arc::unwrap_or_clone(self).preprocess().map(Arc::new)
+	}
+}
+
+/// Preprocesses the value inside an [`Rc`], and wraps the processed value in
+/// a new [`Rc`]. The value is only cloned if the [`Rc`] is shared.
+impl<T> Preprocessable for Rc<T>
+where
+	T: Preprocessable + Clone,
+{
+	type Processed = Rc<T::Processed>;
+	type Error = T::Error;
+
+	fn preprocess(self) -> Result<Self::Processed, Self::Error> {
+		Rc::unwrap_or_clone(self).preprocess().map(Rc::new)
+	}
+}
+
+/// Preprocesses the value inside a [`Box`], and wraps the processed value in
+/// a new [`Box`]. Unlike [`Arc`] and [`Rc`], the value is never cloned, since
+/// the [`Box`] owns it.
+impl<T> Preprocessable for Box<T>
+where
+	T: Preprocessable,
+{
+	type Processed = Box<T::Processed>;
+	type Error = T::Error;
+
+	fn preprocess(self) -> Result<Self::Processed, Self::Error> {
+		(*self).preprocess().map(Box::new)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::Error;
+
+	#[derive(Debug, Clone, PartialEq)]
+	struct Name(String);
+
+	impl Preprocessable for Name {
+		type Processed = String;
+		type Error = Error;
+
+		fn preprocess(self) -> Result<Self::Processed, Self::Error> {
+			let name = self.0.trim();
+			if name.is_empty() {
+				return Err(Error::new("name must not be empty"));
+			}
+			Ok(name.to_string())
+		}
+	}
+
+	#[test]
+	fn test_preprocess_arc() {
+		let name = Arc::new(Name(" John ".to_string()));
+		let shared = Arc::clone(&name);
+		assert_eq!(name.preprocess(), Ok(Arc::new("John".to_string())));
+		assert_eq!(shared.0, " John ");
+		assert!(Arc::new(Name(" ".to_string())).preprocess().is_err());
+	}
+
+	#[test]
+	fn test_preprocess_rc() {
+		let name = Rc::new(Name(" John ".to_string()));
+		assert_eq!(name.preprocess(), Ok(Rc::new("John".to_string())));
+		assert!(Rc::new(Name(" ".to_string())).preprocess().is_err());
+	}
+
+	#[test]
+	fn test_preprocess_box() {
+		let name = Box::new(Name(" John ".to_string()));
+		assert_eq!(name.preprocess(), Ok(Box::new("John".to_string())));
+		assert!(Box::new(Name(" ".to_string())).preprocess().is_err());
+	}
+}
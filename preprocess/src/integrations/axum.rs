@@ -0,0 +1,194 @@
+use axum::{
+	extract::{FromRequest, Request},
+	http::StatusCode,
+	response::{IntoResponse, Response},
+	Form,
+	Json,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{utils::ValidationErrors, Preprocessable};
+
+/// An extractor that deserializes the JSON request body into `T` and then
+/// preprocesses it, giving the processed value. If the body cannot be
+/// deserialized, the rejection from [`Json`] is returned. If preprocessing
+/// fails, a `422 Unprocessable Entity` response is returned with the
+/// [`ValidationErrors`] as the JSON body.
+///
+/// # Example
+/// ```rust
+/// use preprocess::{integrations::PreprocessedJson, prelude::*};
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct LoginRequest {
+///     #[preprocess(trim, lowercase, email)]
+///     pub email: String,
+/// }
+///
+/// async fn login(PreprocessedJson(body): PreprocessedJson<LoginRequest>) {
+///     println!("{}", body.email);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreprocessedJson<T: Preprocessable>(pub T::Processed);
+
+impl<T, S> FromRequest<S> for PreprocessedJson<T>
+where
+	T: Preprocessable + DeserializeOwned,
+	ValidationErrors: From<T::Error>,
+	S: Send + Sync,
+{
+	type Rejection = Response;
+
+	async fn from_request(req: Request, state: &S) -> Result<Self, Response> {
+		let Json(value) = Json::<T>::from_request(req, state)
+			.await
+			.map_err(IntoResponse::into_response)?;
+
+		value
+			.preprocess()
+			.map(Self)
+			.map_err(|err| ValidationErrors::from(err).into_response())
+	}
+}
+
+/// An extractor that deserializes the URL-encoded form data into `T` and then
+/// preprocesses it, giving the processed value. For `GET` and `HEAD`
+/// requests, the form data is read from the query string. Otherwise, it is
+/// read from the request body. If the form data cannot be deserialized, the
+/// rejection from [`Form`] is returned. If preprocessing fails, a
+/// `422 Unprocessable Entity` response is returned with the
+/// [`ValidationErrors`] as the JSON body.
+///
+/// # Example
+/// ```rust
+/// use preprocess::{integrations::PreprocessedForm, prelude::*};
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SubscribeRequest {
+///     #[preprocess(trim, lowercase, email)]
+///     pub email: String,
+/// }
+///
+/// async fn subscribe(PreprocessedForm(body): PreprocessedForm<SubscribeRequest>) {
+///     println!("{}", body.email);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreprocessedForm<T: Preprocessable>(pub T::Processed);
+
+impl<T, S> FromRequest<S> for PreprocessedForm<T>
+where
+	T: Preprocessable + DeserializeOwned,
+	ValidationErrors: From<T::Error>,
+	S: Send + Sync,
+{
+	type Rejection = Response;
+
+	async fn from_request(req: Request, state: &S) -> Result<Self, Response> {
+		let Form(value) = Form::<T>::from_request(req, state)
+			.await
+			.map_err(IntoResponse::into_response)?;
+
+		value
+			.preprocess()
+			.map(Self)
+			.map_err(|err| ValidationErrors::from(err).into_response())
+	}
+}
+
+impl IntoResponse for ValidationErrors {
+	fn into_response(self) -> Response {
+		(
+			StatusCode::UNPROCESSABLE_ENTITY,
+			Json(super::errors_to_json(&self)),
+		)
+			.into_response()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use axum::{
+		body::{to_bytes, Body},
+		http::{header, Method},
+	};
+	use serde::Deserialize;
+
+	use super::*;
+	use crate::{validators::validate_email, Error};
+
+	#[derive(Debug, Deserialize)]
+	struct LoginRequest {
+		email: String,
+	}
+
+	impl Preprocessable for LoginRequest {
+		type Processed = String;
+		type Error = Error;
+
+		fn preprocess(self) -> crate::prelude::Result<Self::Processed> {
+			validate_email(self.email, true)
+				.map_err(|err| err.set_field("email"))
+		}
+	}
+
+	fn json_request(body: &'static str) -> Request {
+		Request::builder()
+			.method(Method::POST)
+			.header(header::CONTENT_TYPE, "application/json")
+			.body(Body::from(body))
+			.unwrap()
+	}
+
+	#[tokio::test]
+	async fn test_preprocessed_json() {
+		let PreprocessedJson(email) =
+			PreprocessedJson::<LoginRequest>::from_request(
+				json_request(r#"{"email":"user@example.com"}"#),
+				&(),
+			)
+			.await
+			.unwrap();
+		assert_eq!(email, "user@example.com");
+	}
+
+	#[tokio::test]
+	async fn test_preprocessed_json_invalid() {
+		let response = PreprocessedJson::<LoginRequest>::from_request(
+			json_request(r#"{"email":"not-an-email"}"#),
+			&(),
+		)
+		.await
+		.unwrap_err();
+		assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+		let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+		let body = serde_json::from_slice::<serde_json::Value>(&body).unwrap();
+		assert_eq!(body["errors"][0]["field"], "email");
+	}
+
+	#[tokio::test]
+	async fn test_preprocessed_form() {
+		let request = Request::builder()
+			.method(Method::GET)
+			.uri("/?email=user%40example.com")
+			.body(Body::empty())
+			.unwrap();
+		let PreprocessedForm(email) =
+			PreprocessedForm::<LoginRequest>::from_request(request, &())
+				.await
+				.unwrap();
+		assert_eq!(email, "user@example.com");
+	}
+
+	#[test]
+	fn test_validation_errors_into_response() {
+		let response =
+			ValidationErrors::from(Error::new("invalid").set_field("name"))
+				.into_response();
+		assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+	}
+}
@@ -0,0 +1,80 @@
+use http::{header, HeaderValue, Response, StatusCode};
+
+use crate::utils::{Error, ValidationErrors};
+
+impl From<Error> for StatusCode {
+	/// Preprocessing errors are always caused by invalid input, so they are
+	/// converted to `422 Unprocessable Entity`.
+	fn from(_: Error) -> Self {
+		StatusCode::UNPROCESSABLE_ENTITY
+	}
+}
+
+impl From<ValidationErrors> for Response<String> {
+	fn from(errors: ValidationErrors) -> Self {
+		errors.to_http_response()
+	}
+}
+
+impl ValidationErrors {
+	/// Converts the errors into a `422 Unprocessable Entity` response, with
+	/// the errors as the JSON body:
+	///
+	/// ```json
+	/// {
+	///     "errors": [
+	///         {
+	///             "field": "email",
+	///             "message": "invalid email address"
+	///         }
+	///     ]
+	/// }
+	/// ```
+	pub fn to_http_response(&self) -> Response<String> {
+		let mut response =
+			Response::new(super::errors_to_json(self).to_string());
+		*response.status_mut() = StatusCode::UNPROCESSABLE_ENTITY;
+		response.headers_mut().insert(
+			header::CONTENT_TYPE,
+			HeaderValue::from_static("application/json"),
+		);
+		response
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_error_into_status_code() {
+		assert_eq!(
+			StatusCode::from(Error::new("invalid email").set_field("email")),
+			StatusCode::UNPROCESSABLE_ENTITY
+		);
+	}
+
+	#[test]
+	fn test_validation_errors_into_response() {
+		let mut errors = ValidationErrors::from(
+			Error::new("invalid email").set_field("email"),
+		);
+		errors.push(Error::new("too short").set_field("password"));
+
+		let response = Response::from(errors);
+		assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+		assert_eq!(
+			response.headers().get(header::CONTENT_TYPE),
+			Some(&HeaderValue::from_static("application/json"))
+		);
+		assert_eq!(
+			serde_json::from_str::<serde_json::Value>(response.body()).unwrap(),
+			serde_json::json!({
+				"errors": [
+					{ "field": "email", "message": "invalid email" },
+					{ "field": "password", "message": "too short" },
+				]
+			})
+		);
+	}
+}
@@ -0,0 +1,96 @@
+//! Integrations with other crates, like web frameworks. Each integration is
+//! only available when the respective feature is enabled.
+//!
+//! # Axum
+//!
+//! The `axum` feature adds the
+//! [`PreprocessedJson`](crate::integrations::PreprocessedJson) and
+//! [`PreprocessedForm`](crate::integrations::PreprocessedForm) extractors,
+//! which deserialize the request body and then preprocess it. If the
+//! preprocessing fails, a `422 Unprocessable Entity` response is returned with
+//! the errors as the JSON body:
+//!
+//! ```json
+//! {
+//!     "errors": [
+//!         {
+//!             "field": "email",
+//!             "message": "invalid email address"
+//!         }
+//!     ]
+//! }
+//! ```
+//!
+//! ## Usage
+//!
+//! ```rust
+//! # #[cfg(feature = "axum")]
+//! use preprocess::{integrations::PreprocessedJson, prelude::*};
+//!
+//! # #[cfg(feature = "axum")]
+//! #[preprocess::sync]
+//! #[derive(Debug, Deserialize, Serialize)]
+//! pub struct LoginRequest {
+//!     #[preprocess(trim, lowercase, email)]
+//!     pub email: String,
+//! }
+//!
+//! # #[cfg(feature = "axum")]
+//! async fn login(PreprocessedJson(body): PreprocessedJson<LoginRequest>) {
+//!     // `body` is of the type `LoginRequestProcessed`
+//! }
+//! ```
+
+//!
+//! # HTTP
+//!
+//! The `http` feature converts errors into types of the
+//! [`http`](::http) crate, for web frameworks that are built on top of it.
+//! [`Error`](crate::Error) can be converted into a
+//! [`StatusCode`](::http::StatusCode), which is always
+//! `422 Unprocessable Entity`, and
+//! [`ValidationErrors`](crate::utils::ValidationErrors) can be converted into
+//! a [`Response<String>`](::http::Response) with the same JSON body as above,
+//! using [`From`] or
+//! [`ValidationErrors::to_http_response`](crate::utils::ValidationErrors::to_http_response).
+//!
+//! ## Usage
+//!
+//! ```rust
+//! # #[cfg(feature = "http")]
+//! # {
+//! use preprocess::utils::{Error, ValidationErrors};
+//!
+//! let errors = ValidationErrors::from(Error::new("invalid email address"));
+//! let response = errors.to_http_response();
+//! assert_eq!(response.status(), http::StatusCode::UNPROCESSABLE_ENTITY);
+//! # }
+//! ```
+
+#[cfg(feature = "axum")]
+mod axum;
+#[cfg(feature = "http")]
+mod http;
+
+use serde_json::{json, Value};
+
+#[cfg(feature = "axum")]
+pub use self::axum::*;
+use crate::utils::ValidationErrors;
+
+/// Converts the given errors into the JSON body that is returned by the
+/// integrations, in the format `{"errors": [{"field": ..., "message": ...}]}`.
+fn errors_to_json(errors: &ValidationErrors) -> Value {
+	let errors = errors
+		.errors
+		.iter()
+		.map(|error| {
+			json!({
+				"field": error.field,
+				"message": error.message,
+			})
+		})
+		.collect::<Vec<_>>();
+
+	json!({ "errors": errors })
+}
@@ -31,7 +31,12 @@
 //!     pub password: String,
 //! }
 //!
+//! # let raw_value = UserSignUpRequest {
+//! #     email: " User@Example.com ".to_string(),
+//! #     password: "hunter2hunter2".to_string(),
+//! # };
 //! let processed_value = raw_value.preprocess()?;
+//! # Ok::<(), preprocess::Error>(())
 //! ```
 //!
 //! ## Inheriting derive attributes
@@ -60,6 +65,7 @@
 //! The above code will generate:
 //!
 //! ```rust
+//! # use preprocess::prelude::*;
 //! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 //! #[serde(rename_all = "camelCase")]
 //! pub struct UserSignUpRequestProcessed {
@@ -75,6 +81,11 @@
 //! preprocess your struct / enum and send the preprocessed version to the
 //! client, without having to write any extra code.
 //!
+//! Additionally, `Debug`, `Clone` and `PartialEq` are always derived on the
+//! generated struct / enum, even if the original doesn't derive them. This
+//! can be turned off using `#[preprocess::sync(auto_derive = false)]`, for
+//! example when a field's type doesn't implement one of these traits.
+//!
 //! ## List of allowed preprocessors
 //!
 //! | Preprocessor                                               | Description                                         |
@@ -82,17 +93,125 @@
 //! | [`email`](`crate::validators#email`)                       | Validates a string to be a valid email address.     |
 //! | [`domain`](`crate::validators#domain`)                     | Validates a string to be a valid domain name.       |
 //! | [`ip`](`crate::validators#ip`)                             | Validates a string to be a valid IP Address.        |
+//! | [`ipv4`](`crate::validators#ip`)                           | Validates a string to be a valid IPv4 Address.      |
+//! | [`ipv6`](`crate::validators#ip`)                           | Validates a string to be a valid IPv6 Address.      |
+//! | [`private_ip_only`](`crate::validators#ip`)                | Validates a string to be a private IP Address.      |
+//! | [`public_ip_only`](`crate::validators#ip`)                 | Validates a string to be a public IP Address.       |
+//! | [`ipv4_mapped`](`crate::validators#ip`)                    | Unwraps IPv4-mapped IPv6 addresses to IPv4.         |
+//! | [`cron`](`crate::validators#cron`)                         | Validates a string to be a valid cron expression.   |
+//! | [`date`](`crate::validators#date`)                         | Validates a string to be a date in a given format.  |
+//! | [`datetime`](`crate::validators#date-time`)                | Validates a string to be a date and time.           |
+//! | [`datetime_local`](`crate::validators#date-time`)          | Validates a date and time in the local time zone.   |
+//! | [`future_date`](`crate::validators#date`)                  | Validates a date to be in the future.               |
+//! | [`past_date`](`crate::validators#date`)                    | Validates a date to be in the past.                 |
+//! | [`min_age`](`crate::validators#date`)                      | Validates the minimum age from a date of birth.     |
+//! | [`max_age`](`crate::validators#date`)                      | Validates the maximum age from a date of birth.     |
+//! | [`ip_cidr`](`crate::validators#ip-cidr`)                   | Validates a string to be a valid CIDR network.      |
+//! | [`hex`](`crate::validators#hex`)                           | Validates a string to be a hexadecimal string.      |
+//! | [`alphabet`](`crate::validators#alphabet`)                 | Validates a string to only use the given alphabet.  |
+//! | [`isbn`](`crate::validators#isbn`)                         | Validates a string to be a valid ISBN.              |
+//! | [`valid_json_path`](`crate::validators#jsonpath`)          | Validates a string to be a JSONPath expression.     |
+//! | [`json`](`crate::validators#jsonpath`)                     | Validates that a JSONPath selects from a JSON string. |
+//! | [`zip_code`](`crate::validators#zip-code`)                 | Validates a string to be a valid postal code.       |
 //! | [`url`](`crate::validators#url`)                           | Validates a string to be a valid URL.               |
+//! | [`url_component`](`crate::validators#url-component`)       | Validates a single component of a URL.              |
+//! | [`uuid`](`crate::validators#uuid`)                         | Validates a string to be a valid UUID.              |
+//! | [`version`](`crate::validators#version`)                   | Validates a version string, like `v1.2.3`.          |
+//! | [`no_script_tags`](`crate::validators#no-script-tags`)     | Rejects strings with common XSS patterns.           |
 //! | [`length`](`crate::validators#length`)                     | Validates the length of a string.                   |
+//! | [`exact_length`](`crate::validators#length`)               | Validates the exact length of a string.             |
+//! | [`min_items`](`crate::validators#length`)                  | Validates the minimum items in a collection.        |
+//! | [`max_items`](`crate::validators#length`)                  | Validates the maximum items in a collection.        |
+//! | [`number_in_range`](`crate::validators#number-in-range`)   | Validates a string to be a number within a range.   |
+//! | [`numeric_string`](`crate::validators#numeric-string`)     | Validates a string to be a valid number.            |
+//! | [`password_strength`](`crate::validators#password-strength`) | Validates the strength of a password using zxcvbn.  |
+//! | [`language_code`](`crate::validators#language-code`)       | Validates a string to be a BCP 47 language tag.     |
+//! | [`phone`](`crate::validators#phone`)                       | Validates a string to be a valid phone number.      |
+//! | [`decimal_string`](`crate::validators#numeric-string`)     | Validates a string to be a plain decimal number.    |
+//! | [`length_bytes`](`crate::validators#length-bytes`)         | Validates the length of a string in bytes.          |
+//! | [`min_bytes`](`crate::validators#length-bytes`)            | Validates the minimum length of a string in bytes.  |
+//! | [`max_bytes`](`crate::validators#length-bytes`)            | Validates the maximum length of a string in bytes.  |
 //! | [`range`](`crate::validators#range`)                       | Validates the range of a number.                    |
+//! | [`min`](`crate::validators#range`)                         | Validates the minimum value of a number.            |
+//! | [`max`](`crate::validators#range`)                         | Validates the maximum value of a number.            |
+//! | [`lower_bound`](`crate::validators#range`)                 | Validates the minimum value, naming it on errors.   |
+//! | [`upper_bound`](`crate::validators#range`)                 | Validates the maximum value, naming it on errors.   |
+//! | [`no_whitespace`](`crate::validators#whitespace`)          | Validates that a string has no whitespaces.         |
+//! | [`no_leading_whitespace`](`crate::validators#whitespace`)  | Validates that a string has no leading whitespace.  |
+//! | [`no_trailing_whitespace`](`crate::validators#whitespace`) | Validates that a string has no trailing whitespace. |
+//! | [`word_count`](`crate::validators#word-count`)             | Validates the number of words in a string.          |
+//! | [`min_words`](`crate::validators#word-count`)              | Validates the minimum number of words in a string.  |
+//! | [`max_words`](`crate::validators#word-count`)              | Validates the maximum number of words in a string.  |
+//! | [`csv_length`](`crate::validators#csv-length`)             | Validates the number of items in a CSV string.      |
+//! | [`currency_code`](`crate::validators#currency-code`)       | Validates a string to be an ISO 4217 currency code. |
 //! | [`contains`](`crate::validators#contains`)                 | Validates if a string contains a substring.         |
 //! | [`does_not_contain`](`crate::validators#does_not_contain`) | Validates if a string does not contain a substring. |
+//! | [`require_prefix`](`crate::validators#require-prefix-and-suffix`) | Validates that a string starts with a prefix.       |
+//! | [`require_suffix`](`crate::validators#require-prefix-and-suffix`) | Validates that a string ends with a suffix.         |
+//! | [`interpolate`](`crate::validators#template`)              | Validates that a string matches a template.         |
+//! | [`contains_item`](`crate::validators#contains-item`)       | Validates if a collection contains an item.         |
+//! | [`does_not_contain_item`](`crate::validators#contains-item`) | Validates if a collection does not contain an item. |
+//! | [`no_control_characters`](`crate::validators#control-characters`) | Validates a string has no control characters.       |
+//! | [`whitelist`](`crate::validators#whitelist`)               | Validates that a value is one of the given items.   |
+//! | [`blacklist`](`crate::validators#whitelist`)               | Validates that a value is not one of the given items. |
+//! | [`equal`](`crate::validators#whitelist`)                   | Validates that a value is equal to a given value.   |
+//! | [`not_equal`](`crate::validators#whitelist`)               | Validates that a value is not equal to a given value. |
+//! | [`enum_variant`](`crate::validators#enum-variant`)         | Validates a string to be an enum variant's name.    |
 //! | [`regex`](`crate::validators#regex`)                       | Validates a string using a regex.                   |
-//! | [`type`](#enforcing-the-type-of-a-value)                   | Enforces the type of a value using `TryFrom`.       |
+//! | [`regex_named`](`crate::validators#regex`)                 | Validates a string using a regex constant.          |
+//! | [`regex_ref`](`crate::validators#regex`)                   | Validates a string using a compiled regex.          |
+//! | [`all_match`](`crate::validators#regex`)                   | Validates a string using multiple labelled regexes. |
+//! | [`unique`](`crate::validators#unique`)                     | Validates that a collection has no duplicates.      |
+//! | [`positive`](`crate::validators#sign`)                     | Validates that a number is greater than zero.       |
+//! | [`negative`](`crate::validators#sign`)                     | Validates that a number is less than zero.          |
+//! | [`non_negative`](`crate::validators#sign`)                 | Validates that a number is not less than zero.      |
+//! | [`non_nan`](`crate::validators#float`)                     | Validates that a float is not `NaN`.                |
+//! | [`finite`](`crate::validators#float`)                      | Validates that a float is neither `NaN` nor infinite. |
+//! | [`non_zero`](`crate::validators#sign`)                     | Validates that a number is not zero.                |
+//! | [`coerce`](#enforcing-the-type-of-a-value)                 | Enforces the type of a value using `TryFrom`.       |
+//! | [`from_str`](#parsing-strings)                             | Parses a string into a type using `FromStr`.        |
 //! | [`trim`](`crate::preprocessors#trim`)                      | Trims a string.                                     |
 //! | [`lowercase`](`crate::preprocessors#lowercase`)            | Converts a string to lowercase.                     |
 //! | [`uppercase`](`crate::preprocessors#uppercase`)            | Converts a string to uppercase.                     |
+//! | [`abs`](`crate::preprocessors#sign`)                       | Converts a number to its absolute value.            |
+//! | [`neg`](`crate::preprocessors#sign`)                       | Negates a number.                                   |
+//! | [`snake_case`](`crate::preprocessors#case-conversion`)     | Converts a string to snake_case.                    |
+//! | [`kebab_case`](`crate::preprocessors#case-conversion`)     | Converts a string to kebab-case.                    |
+//! | [`camel_case`](`crate::preprocessors#case-conversion`)     | Converts a string to camelCase.                     |
+//! | [`pascal_case`](`crate::preprocessors#case-conversion`)    | Converts a string to PascalCase.                    |
+//! | [`uppercase_first`](`crate::preprocessors#case-conversion`) | Converts the first character to uppercase.          |
+//! | [`lowercase_first`](`crate::preprocessors#case-conversion`) | Converts the first character to lowercase.          |
+//! | [`digits_only`](`crate::preprocessors#character-classes`)  | Removes all non-digit characters from a string.     |
+//! | [`letters_only`](`crate::preprocessors#character-classes`) | Removes all non-letter characters from a string.    |
+//! | [`alphanumeric_only`](`crate::preprocessors#character-classes`) | Removes all non-alphanumeric characters.            |
+//! | [`title_case`](`crate::preprocessors#title-case`)          | Converts a string to Title Case.                    |
+//! | [`normalize_unicode`](`crate::preprocessors#normalize-unicode`) | Normalizes a string to a Unicode normalization form. |
+//! | [`normalize_isbn`](`crate::preprocessors#normalize-isbn`)  | Converts an ISBN to an ISBN-13.                     |
+//! | [`normalize_phone`](`crate::preprocessors#normalize-phone`) | Converts a phone number to the E.164 format.        |
+//! | [`ip(normalize)`](`crate::preprocessors#normalize-ip`)     | Converts an IP address to its canonical form.       |
+//! | [`normalize_ip_any`](`crate::preprocessors#normalize-ip`)  | Normalizes an IP address, unwrapping IPv4-mapped.   |
+//! | [`normalize_zip_code`](`crate::preprocessors#normalize-zip-code`) | Normalizes a postal code for consistent storage.    |
+//! | [`normalize_language_code`](`crate::preprocessors#normalize-language-code`) | Converts a language tag to its conventional case.   |
+//! | [`normalize_currency_code`](`crate::preprocessors#normalize-currency-code`) | Converts a currency code to uppercase.              |
+//! | [`number_format`](`crate::preprocessors#number-format`)    | Normalizes a locale-specific number string.         |
+//! | [`hex_decode`](`crate::preprocessors#hex-decode`)          | Decodes a hexadecimal string into bytes.            |
+//! | [`strip_html`](`crate::preprocessors#strip-html`)          | Removes HTML tags from a string.                    |
+//! | [`strip_subaddress`](`crate::preprocessors#strip-sub-address`) | Removes the sub-address from an email address. |
+//! | [`strip_control_characters`](`crate::preprocessors#strip-control-characters`) | Removes control characters from a string.           |
+//! | [`strip_non_printable`](`crate::preprocessors#strip-control-characters`) | Removes non-printable characters from a string.     |
+//! | [`strip_prefix`](`crate::preprocessors#strip-prefix-and-suffix`) | Removes a prefix from a string, if present.         |
+//! | [`strip_suffix`](`crate::preprocessors#strip-prefix-and-suffix`) | Removes a suffix from a string, if present.         |
 //! | [`custom`](#custom-preprocessors)                          | Validates a string using a custom function.         |
+//! | [`custom_type`](#custom-preprocessors)                     | Validates a value using a `FieldValidator` type.    |
+//! | [`transform`](#transforming-values)                        | Transforms a value using a custom function.         |
+//! | [`transform_ref`](#transforming-values)                    | Transforms a borrowed value using a function.       |
+//! | [`keys`](#map-fields)                                      | Preprocesses every key of a map.                    |
+//! | [`values`](#map-fields)                                    | Preprocesses every value of a map.                  |
+//! | [`each_key`](#map-fields)                                  | Preprocesses every key of a map in a single pass.   |
+//! | [`each_value`](#map-fields)                                | Preprocesses every value of a map in a single pass. |
+//! | [`confirm`](#confirming-fields)                            | Validates that a field is equal to another field.   |
+//! | [`csv_items`](#csv-items)                                  | Preprocesses every item of a CSV string.            |
+//! | [`exactly_one_of`](#mutually-exclusive-fields)             | Validates that exactly one field is set.            |
 //!
 //! More details about each preprocessor can be found in the respective module
 //! documentation of [preprocessors](crate::preprocessors) and
@@ -103,7 +222,7 @@
 //! You can use a custom function as a preprocessor. The function must have the
 //! following signature:
 //!
-//! ```rust
+//! ```rust,ignore
 //! fn custom_preprocessor<T>(value: T) -> Result<T, Error>;
 //! ```
 //!
@@ -113,6 +232,7 @@
 //! the output of the preprocessor.
 //!
 //! ```rust
+//! # use preprocess::{prelude::{Deserialize, Serialize}, Error};
 //! pub fn custom_preprocessor(value: String) -> Result<String, Error> {
 //!     if value.len() < 8 {
 //!         return Err(Error::new(
@@ -130,24 +250,440 @@
 //! }
 //! ```
 //!
+//! Validation logic that is reused across structs can be encapsulated in a
+//! type instead, by implementing the [`FieldValidator`] trait for the type of
+//! the field, and using it with `custom_type`:
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! pub struct Password;
+//!
+//! impl FieldValidator<String> for Password {
+//!     fn validate(value: String) -> Result<String> {
+//!         if value.len() < 8 {
+//!             return Err(Error::new(
+//!                 "Password must be at least 8 characters long",
+//!             ));
+//!         }
+//!         Ok(value)
+//!     }
+//! }
+//!
+//! #[preprocess::sync]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct UserSignUpRequest {
+//!     #[preprocess(custom_type = Password)]
+//!     pub password: String,
+//! }
+//! ```
+//!
+//! ### Transforming values
+//!
+//! If a custom function only transforms the value and can never fail, it can
+//! be used with `transform` instead of `custom`, without having to wrap the
+//! result in `Ok`. Functions that take a reference to the value can be used
+//! with `transform_ref`. Both accept paths to methods as well:
+//!
+//! ```rust,ignore
+//! fn transform<T>(value: T) -> T;
+//! fn transform_ref<T>(value: &T) -> T;
+//! ```
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! pub struct Username;
+//!
+//! impl Username {
+//!     pub fn normalize(value: String) -> String {
+//!         value.to_lowercase()
+//!     }
+//! }
+//!
+//! pub fn collapse_whitespace(value: &String) -> String {
+//!     value.split_whitespace().collect::<Vec<_>>().join(" ")
+//! }
+//!
+//! #[preprocess::sync]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct UpdateProfileRequest {
+//!     #[preprocess(transform = "Username::normalize")]
+//!     pub username: String,
+//!     #[preprocess(transform_ref = "collapse_whitespace")]
+//!     pub display_name: String,
+//! }
+//! ```
+//!
+//! ### Nested structs
+//!
+//! A field containing another struct that uses `#[preprocess::sync]` can be
+//! preprocessed using `#[preprocess(nested)]` (or just `#[preprocess]`). The
+//! field's type will be changed to the processed type of the nested struct.
+//! Other attributes of the field, like `#[serde(flatten)]`, are inherited by
+//! the generated struct, so a flattened nested struct stays flat when the
+//! processed struct is serialized.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! #[preprocess::sync]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct Address {
+//!     #[preprocess(trim)]
+//!     pub city: String,
+//! }
+//!
+//! #[preprocess::sync]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct CreateUserRequest {
+//!     #[preprocess(trim)]
+//!     pub name: String,
+//!     // The type of this field will be changed to `AddressProcessed`
+//!     #[preprocess(nested)]
+//!     #[serde(flatten)]
+//!     pub address: Address,
+//! }
+//! ```
+//!
+//! Enums with only unit variants have nothing to preprocess, but can still be
+//! used as nested fields by deriving [`Preprocessable`](derive@Preprocessable)
+//! on them. The processed type is the enum itself.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! #[derive(Debug, Clone, PartialEq, Preprocessable)]
+//! pub enum Status {
+//!     Active,
+//!     Inactive,
+//! }
+//!
+//! #[preprocess::sync]
+//! pub struct UpdateUserRequest {
+//!     #[preprocess(nested)]
+//!     pub status: Status,
+//! }
+//! ```
+//!
+//! Nested values inside an [`Arc`](std::sync::Arc), an [`Rc`](std::rc::Rc) or
+//! a [`Box`] can be preprocessed too, and the processed value is wrapped in
+//! the same pointer type. The values inside an `Arc` or an `Rc` must implement
+//! [`Clone`], since they are cloned if the pointer is shared.
+//!
+//! Tuples of up to 12 elements are preprocessable as well, as long as every
+//! element is preprocessable and all of them have the same error type. Each
+//! element is preprocessed in order, and the first error is returned.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! #[preprocess::sync]
+//! pub struct Sender(#[preprocess(trim, email)] pub String);
+//!
+//! #[preprocess::sync]
+//! pub struct Recipient(#[preprocess(trim, email)] pub String);
+//!
+//! #[preprocess::sync]
+//! pub struct SendMailRequest {
+//!     // The type of this field will be changed to
+//!     // `(SenderProcessed, RecipientProcessed)`
+//!     #[preprocess(nested)]
+//!     pub route: (Sender, Recipient),
+//! }
+//! ```
+//!
+//! ### Map fields
+//!
+//! The keys and values of a map (like [`HashMap`](std::collections::HashMap)
+//! or [`BTreeMap`](std::collections::BTreeMap)) can be preprocessed
+//! independently using the `keys` and `values` preprocessors. Each of them
+//! takes a list of preprocessors that will be applied to every key or value of
+//! the map respectively. If a preprocessor changes the type of the key or the
+//! value, the type of the map will be changed accordingly.
+//! If two keys are identical after preprocessing them, like `Name` and
+//! `name ` after `keys(trim, lowercase)`, an error is returned instead of
+//! overwriting one of the values. The source of the error is a
+//! [`DuplicateKey`](crate::utils::DuplicateKey).
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! # use std::collections::HashMap;
+//! #[preprocess::sync]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct SetLabelsRequest {
+//!     // The type of this field will be changed to
+//!     // `HashMap<Cow<'static, str>, String>`
+//!     #[preprocess(keys(trim, lowercase), values(length(min = 1)))]
+//!     pub labels: HashMap<String, String>,
+//! }
+//! ```
+//!
+//! `each_key` and `each_value` work like `keys` and `values`, but when both
+//! are used in the same attribute, the keys and values are preprocessed in a
+//! single pass over the map, at the position of the first one, instead of
+//! iterating over the map twice.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! # use std::collections::BTreeMap;
+//! #[preprocess::sync]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct SetHeadersRequest {
+//!     #[preprocess(each_key(trim, lowercase), each_value(length(max = 1000)))]
+//!     pub headers: BTreeMap<String, String>,
+//! }
+//! ```
+//!
+//! ### CSV items
+//!
+//! The items of a delimiter-separated string, like `a, b, c`, can be
+//! preprocessed using `csv_items`, which takes a list of preprocessors that
+//! will be applied to every item. The string is split on the delimiter (`,` by
+//! default, can be changed using `delimiter`), every item is preprocessed, and
+//! the items are joined again using the same delimiter. An empty string has no
+//! items, so it is left empty without running the preprocessors. The type of
+//! the field will be changed to `String`.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! #[preprocess::sync]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct SearchRequest {
+//!     // ` Rust, WEB ` will be converted to `rust,web`
+//!     #[preprocess(csv_items(trim, lowercase), csv_length(max = 5))]
+//!     pub tags: String,
+//!     #[preprocess(csv_items(trim, delimiter = ";"))]
+//!     pub sort: String,
+//! }
+//! ```
+//!
+//! ### Fallback values
+//!
+//! Instead of returning an error when a field fails preprocessing, you can use
+//! a fallback value for that field using `default`. The fallback is applied to
+//! the entire chain of preprocessors of the field, regardless of where it is
+//! placed. If any of the preprocessors fail, the fallback value is used
+//! instead. String literals are converted using [`Into`], so that they can be
+//! used for fields of type `String` or `Cow<'static, str>`. If the chain
+//! changes the type of the field, like `url` or `ip`, string literals are
+//! parsed into the new type using [`FromStr`](std::str::FromStr) instead, and
+//! an invalid literal is returned as an error. For values that
+//! need to be computed, use `default_fn` with the name of a function that
+//! takes no arguments and returns the processed type of the field.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! fn default_page_size() -> u32 {
+//!     20
+//! }
+//!
+//! #[preprocess::sync]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct ListUsersRequest {
+//!     #[preprocess(trim, email, default = "noreply@example.com")]
+//!     pub reply_to: String,
+//!     #[preprocess(range(min = 1, max = 100), default_fn = "default_page_size")]
+//!     pub page_size: u32,
+//! }
+//! ```
+//!
+//! `on_error` works exactly like `default`, and can be used where the intent
+//! of replacing an invalid value is better expressed as error handling. The
+//! fallback value must be of the same type as the output of the chain, which
+//! is checked at compile time. Only one of `default`, `default_fn` and
+//! `on_error` can be used on a field. Since errors are silently discarded,
+//! `on_error` cannot be used with `#[preprocess::sync(strict_mode = true)]`.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! #[preprocess::sync]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct UpdateSettingsRequest {
+//!     #[preprocess(trim, lowercase, on_error = "light")]
+//!     pub theme: String,
+//! }
+//! ```
+//!
+//! ### Sensitive fields
+//!
+//! Error messages from validators (especially custom ones) can contain the
+//! value that failed validation. For fields like passwords or credit card
+//! numbers, this value should not end up in logs or API responses. Marking a
+//! field with `sensitive` redacts any error from the field's chain of
+//! preprocessors using [`Error::redacted`], which replaces the message with a
+//! generic one while keeping the name of the field.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! #[preprocess::sync]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct UserSignUpRequest {
+//!     #[preprocess(sensitive, length(min = 8))]
+//!     pub password: String,
+//! }
+//! ```
+//!
+//! ### Custom error messages
+//!
+//! The error messages of the built-in validators are generic. To replace them
+//! with a domain-specific message, add `error_message` after all the other
+//! preprocessors of a field. If any of them fail, the message of the error is
+//! replaced with the given string, while the name of the field is kept. To add
+//! context to the error instead, use `map_error` with the path to a function
+//! `fn(preprocess::Error) -> preprocess::Error`, which is called with the error
+//! of the chain. Only one of `map_error` and `error_message` can be used on a
+//! field, and they can only be followed by other modifiers, like `default` or
+//! `warn_only`. With `sensitive`, the error is redacted after it is mapped.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! fn email_error(error: preprocess::Error) -> preprocess::Error {
+//!     let message = format!("please enter a valid email address ({})", error.message);
+//!     preprocess::Error::new(message).set_field(error.field)
+//! }
+//!
+//! #[preprocess::sync]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct UserSignUpRequest {
+//!     #[preprocess(trim, lowercase, email, map_error = "email_error")]
+//!     pub email: String,
+//!     #[preprocess(trim, length(min = 3, max = 32), error_message = "username must be 3 to 32 characters long")]
+//!     pub username: String,
+//! }
+//! ```
+//!
+//! ### Confirming fields
+//!
+//! `confirm = "other_field"` checks that the processed value of a field is
+//! equal to the processed value of another field of the same struct or enum
+//! variant, like a password and its confirmation. The check is done using
+//! [`PartialEq`] after all the fields are processed, so the other field can be
+//! declared either before or after it. If the values differ, the error is set
+//! on the confirming field. Referring to a field that doesn't exist is a
+//! compile error, and `confirm` can only be used on named fields.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! #[preprocess::sync]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct UserSignUpRequest {
+//!     #[preprocess(length(min = 8))]
+//!     pub password: String,
+//!     #[preprocess(confirm = "password")]
+//!     pub confirm_password: String,
+//! }
+//! ```
+//!
+//! ### Mutually exclusive fields
+//!
+//! `exactly_one_of = ["field_a", "field_b"]` can be used on a struct to check
+//! that exactly one of the given fields is `Some`, like an OAuth token request
+//! that takes either a `code` or an `assertion`, but not both. The check is
+//! done after all the fields are processed. The fields must exist and be of
+//! type `Option`, which is checked at compile time. The error is not set on
+//! any specific field.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! #[preprocess::sync]
+//! #[preprocess(exactly_one_of = ["code", "assertion"])]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct TokenRequest {
+//!     #[preprocess(trim)]
+//!     pub client_id: String,
+//!     pub code: Option<String>,
+//!     pub assertion: Option<String>,
+//! }
+//! ```
+//!
+//! ### C-style enums
+//!
+//! Enums with only unit variants and an integer `#[repr]`, like `#[repr(u8)]`,
+//! can have explicit discriminants. `range`, `min` and `max` can be used on
+//! such an enum to validate the discriminant of the variant, which is useful
+//! when the enum is deserialized from a number, and only some of the values
+//! are accepted. Discriminants are not supported on any other enums.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! #[preprocess::sync]
+//! #[preprocess(range(min = 1, max = 3))]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! #[repr(u8)]
+//! pub enum Priority {
+//!     None = 0,
+//!     Low = 1,
+//!     Medium,
+//!     High,
+//! }
+//! ```
+//!
+//! ### Generic types
+//!
+//! Structs and enums with type parameters, lifetimes and `where` clauses are
+//! supported. The processed type has the same generic parameters and bounds
+//! as the original type. Since every generic parameter must be used by the
+//! processed type, a preprocessor that changes the type of a field (like
+//! `trim`) cannot be applied to the only field using a generic parameter.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! #[preprocess::sync]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct Page<T>
+//! where
+//!     T: Clone,
+//! {
+//!     #[preprocess(length(max = 100))]
+//!     pub items: Vec<T>,
+//!     #[preprocess(range(min = 1))]
+//!     pub page: u32,
+//! }
+//! ```
+//!
 //! ### Enforcing the type of a value
 //!
-//! You can use the `type` preprocessor to enforce the type of a value. This is
-//! useful when you want to convert a value to a different type. For example,
-//! you might want to convert a string to an integer. You can use the `type`
-//! preprocessor to do this. The `type` preprocessor uses [`TryFrom`] to convert
-//! the value to the desired type. If the conversion fails, the preprocessor
-//! will return an error.
+//! You can use the `coerce` preprocessor to enforce the type of a value. This
+//! is useful when you want to convert a value to a different type. For
+//! example, you might want to convert a wider integer to a narrower one. The
+//! `coerce` preprocessor uses [`TryFrom`] to convert the value to the desired
+//! type, which can be given as a string or as a path. If the conversion fails,
+//! the preprocessor will return an error.
 //!
 //! ```rust
+//! # use preprocess::prelude::*;
 //! #[preprocess::sync]
 //! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 //! pub struct UserSignUpRequest {
-//!     #[preprocess(type = "i32")]
-//!     pub age: i16,
+//!     #[preprocess(coerce = "u8")]
+//!     pub age: i32, // This type will be changed to u8
+//!     #[preprocess(coerce = std::num::NonZeroU32)]
+//!     pub seats: u32, // This type will be changed to NonZeroU32
+//! }
+//! ```
+//!
+//! The older `#[preprocess(type = "u8")]` form still works the same way, but
+//! emits a deprecation warning suggesting `coerce` instead, since `type` is a
+//! keyword in Rust.
+//!
+//! ### Parsing strings
+//!
+//! Strings can be parsed into any type implementing [`FromStr`] using
+//! `from_str`, which changes the type of the field to the given type. If the
+//! string cannot be parsed, the error returned by [`str::parse`] is used as
+//! the error message, and is available as the [`source`] of the error.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! #[preprocess::sync]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct CreateServerRequest {
+//!     #[preprocess(trim, from_str(as = "u64"), range(min = 1))]
+//!     pub cpu_count: String, // This type will be changed to u64
+//!     #[preprocess(from_str(as = "std::net::IpAddr"))]
+//!     pub address: String, // This type will be changed to IpAddr
 //! }
 //! ```
 //!
+//! [`FromStr`]: std::str::FromStr
+//! [`source`]: std::error::Error::source
+//!
 //! ## Usage
 //!
 //! Add this to your `Cargo.toml`:
@@ -174,6 +710,133 @@
 //! }
 //! ```
 //!
+//! ## Collecting all the errors
+//!
+//! By default, preprocessing stops at the first field that fails. Using
+//! `#[preprocess::sync(collect_errors = true)]`, every field is preprocessed
+//! and all the errors are returned together, with the error type of
+//! [`Preprocessable`](trait@Preprocessable) changed to `Vec<Error>`. A field
+//! that fails is set to the default value of its processed type, so that the
+//! remaining fields can still be preprocessed, which means that the processed
+//! type of every field with preprocessors needs to implement [`Default`].
+//! `confirm` checks are skipped if either of the fields failed. Every error of
+//! a nested field is kept, with the name of the nested field prefixed by the
+//! name of the parent field, like `address.city`. Without `collect_errors`, a
+//! nested field fails with the message of its first error, and the errors of
+//! the nested type are available as the source of the error.
+//!
+//! **Breaking change:** [`Preprocessable`](trait@Preprocessable) now has an
+//! associated `Error` type, so manual implementations of the trait need to
+//! add `type Error = preprocess::Error;` to keep their previous behavior.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! #[preprocess::sync(collect_errors = true)]
+//! #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+//! pub struct UserSignUpRequest {
+//!     #[preprocess(trim, lowercase, email)]
+//!     pub email: String,
+//!     #[preprocess(trim, length(min = 8))]
+//!     pub password: String,
+//! }
+//!
+//! let errors = UserSignUpRequest {
+//!     email: "not an email".to_string(),
+//!     password: "short".to_string(),
+//! }
+//! .preprocess()
+//! .unwrap_err();
+//! assert_eq!(errors.len(), 2);
+//! ```
+//!
+//! ## Dereferencing the processed type
+//!
+//! If no preprocessor of a struct changes the type of a field, like when it
+//! only uses validators, the processed struct has the same fields as the
+//! original struct. With the `opt_deref` feature enabled, such a struct can
+//! use `#[preprocess::sync(deref = true)]` to implement
+//! [`Deref`](std::ops::Deref) from the processed struct to the original
+//! struct, so that a reference to the processed struct can be used wherever a
+//! reference to the original struct is needed. To guarantee that both structs
+//! have the same layout, the struct must have a `#[repr(C)]` or
+//! `#[repr(transparent)]` attribute, which is inherited by the processed
+//! struct. Using `deref = true` without one of them, or on a struct where a
+//! preprocessor changes the type of a field, is a compile error. The
+//! generated implementation uses `unsafe`, so it cannot be used in crates that
+//! forbid `unsafe` code.
+//!
+//! ```toml
+//! [dependencies]
+//! preprocess = { version = "<version>", features = ["opt_deref"] }
+//! ```
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! # #[cfg(feature = "opt_deref")]
+//! #[preprocess::sync(deref = true)]
+//! #[derive(Debug, Deserialize, Serialize)]
+//! #[repr(C)]
+//! pub struct LoginRequest {
+//!     #[preprocess(email)]
+//!     pub email: String,
+//!     #[preprocess(length(min = 8))]
+//!     pub password: String,
+//! }
+//! ```
+//!
+//! ## Debugging the generated code
+//!
+//! The code generated by the macro can be printed to the build output using
+//! `#[preprocess::sync(debug_output = true)]`. To print the generated code of
+//! every struct / enum at once, set the `PREPROCESS_DEBUG` environment
+//! variable to `1` while building. This is only meant to be used during
+//! development.
+//!
+//! ```rust
+//! # use preprocess::prelude::*;
+//! #[preprocess::sync(debug_output = true)]
+//! pub struct UserSignUpRequest {
+//!     #[preprocess(trim, lowercase, email)]
+//!     pub email: String,
+//! }
+//! ```
+//!
+//! ## Tracing
+//!
+//! With the `tracing` feature enabled, the generated code emits
+//! [tracing](https://docs.rs/tracing) spans while preprocessing. The entire
+//! struct / enum is preprocessed inside a `preprocess::struct` span at the
+//! `DEBUG` level, and every field is preprocessed inside a `preprocess::field`
+//! span at the `DEBUG` level, with the name of the field. Spans of fields
+//! marked as `sensitive` are emitted at the `TRACE` level instead, and their
+//! value is always redacted. The values of fields are never recorded.
+//!
+//! ```toml
+//! [dependencies]
+//! preprocess = { version = "<version>", features = ["tracing"] }
+//! ```
+//!
+//! ### Logging instead of failing
+//!
+//! Some validation failures are worth logging, but should not fail the entire
+//! request. Adding `warn_only` as the last preprocessor of a field emits a
+//! `tracing::warn!` event with the name of the field and the error when
+//! preprocessing fails, and keeps the original value of the field instead. The
+//! type of the field is not changed, so the processed value needs to be
+//! convertible into the original type using [`Into`], and the original type
+//! needs to implement [`Clone`]. `warn_only` requires the `tracing` feature,
+//! and cannot be used in strict mode or together with `default`, `default_fn`
+//! or `on_error`.
+//!
+//! ```rust
+//! # #[cfg(feature = "tracing")]
+//! #[preprocess::sync]
+//! pub struct UpdateProfileRequest {
+//!     #[preprocess(trim, url, warn_only)]
+//!     pub website: String,
+//! }
+//! ```
+//!
 //! ## MSRV
 //!
 //! There is no MSRV as such, and to be honest, I don't see the point of an
@@ -189,10 +852,21 @@ pub use preprocess_macro::sync;
 /// Error type for the library. This type is used to return errors from the
 /// preprocessors and validators.
 pub use crate::utils::Error;
+/// Trait for reusable validator types, used with
+/// `#[preprocess(custom_type = MyValidator)]`.
+pub use crate::utils::FieldValidator;
 
+/// Implementations of [`Preprocessable`] for types from the standard library.
+mod impls;
+/// Integrations with other crates, like web frameworks.
+#[cfg(any(feature = "axum", feature = "http"))]
+pub mod integrations;
 /// List of all the preprocessors that mutates the given field, including
 /// changing the type if required.
 pub mod preprocessors;
+/// Helpers for testing validators.
+#[cfg(feature = "testing")]
+pub mod testing;
 /// Utility module for the library.
 pub mod utils;
 /// List of all the validators that validates the given field without mutating
@@ -205,10 +879,17 @@ pub mod validators;
 /// types and traits from the library. This module is useful when you want to
 /// use the library without importing the individual modules.
 ///
+/// The [`Serialize`](serde::Serialize) and [`Deserialize`](serde::Deserialize)
+/// traits and derive macros from Serde are re-exported as well, unless the
+/// default `serde` feature is disabled.
+///
 /// # Example
 /// ```rust
 /// use preprocess::prelude::*;
 ///
+/// # fn validate_password(value: String) -> Result<String> {
+/// #     Ok(value)
+/// # }
 /// #[preprocess::sync]
 /// #[derive(Debug, Deserialize, Serialize)]
 /// pub struct LoginRequest {
@@ -219,6 +900,9 @@ pub mod validators;
 /// }
 /// ```
 pub mod prelude {
+	#[cfg(feature = "serde")]
+	pub use serde::{Deserialize, Serialize};
+
 	pub use crate::{
 		preprocessors::*,
 		utils::*,
@@ -234,19 +918,205 @@ pub mod prelude {
 /// A list of all the types that are re-exported from supporting crates. Used by
 /// the preprocessor to set the types for a field if required.
 pub mod types {
+	#[cfg(feature = "chrono")]
+	pub use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, Utc};
+	#[cfg(feature = "phonenumber")]
+	pub use phonenumber::country::Id as PhoneCountry;
 	pub use url::Url;
+	#[cfg(feature = "uuid")]
+	pub use uuid::Uuid;
+}
+
+/// Crates used by the code generated by the macro. This is not a public API and
+/// can change at any time.
+#[doc(hidden)]
+pub mod __private {
+	use std::fmt::Display;
+
+	#[cfg(feature = "tracing")]
+	pub use tracing;
+
+	use crate::utils::{Error, ValidationErrors};
+
+	/// The errors of a nested field, kept as the source of the error returned
+	/// by [`nested_error`], so that every one of them can be collected by
+	/// [`field_errors`].
+	#[derive(Debug)]
+	pub struct NestedErrors(pub ValidationErrors);
+
+	impl Display for NestedErrors {
+		fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+			Display::fmt(&self.0, f)
+		}
+	}
+
+	impl std::error::Error for NestedErrors {}
+
+	/// Converts the error of a nested field into a single error, with the
+	/// message of the first error. All the errors of the nested type are kept
+	/// as the source of the error, as [`NestedErrors`].
+	pub fn nested_error<E>(error: E) -> Error
+	where
+		ValidationErrors: From<E>,
+	{
+		let errors = ValidationErrors::from(error);
+		let message = errors.errors.first().map_or_else(
+			|| "invalid value".to_string(),
+			|error| error.message.clone(),
+		);
+		Error::with_source(message, NestedErrors(errors))
+	}
+
+	/// Returns the errors to collect for a field that failed when
+	/// `collect_errors = true` is used. If the error comes from a nested
+	/// field, every error of the nested type is returned, with its field name
+	/// prefixed by the name of the field, like `address.city`. Otherwise, the
+	/// error is returned as is.
+	pub fn field_errors(error: Error) -> Vec<Error> {
+		let nested = std::error::Error::source(&error)
+			.and_then(|source| source.downcast_ref::<NestedErrors>());
+		let Some(NestedErrors(nested)) = nested else {
+			return vec![error];
+		};
+
+		nested
+			.errors
+			.iter()
+			.map(|nested| {
+				let field = if nested.field.is_empty() {
+					error.field.clone()
+				} else {
+					format!("{}.{}", error.field, nested.field)
+				};
+				nested.clone().set_field(field)
+			})
+			.collect()
+	}
 }
 
+/// Derives [`Preprocessable`](trait@Preprocessable) on an enum with only
+/// unit variants, which has nothing to preprocess. The processed type is
+/// the enum itself, so that it can be used in a field marked with
+/// `#[preprocess(nested)]`.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[derive(Debug, Clone, PartialEq, Preprocessable, Deserialize, Serialize)]
+/// pub enum Status {
+///     Active,
+///     Inactive,
+/// }
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct UpdateUserRequest {
+///     #[preprocess(trim)]
+///     pub name: String,
+///     #[preprocess(nested)]
+///     pub status: Status,
+/// }
+/// ```
+pub use preprocess_macro::Preprocessable;
+
 /// A trait that can be implemented by any type to allow it to be preprocessed.
 /// This trait is automatically implemented for all types that use the
-/// `#[preprocess::sync]` macro.
+/// `#[preprocess::sync]` macro, and can be derived for enums with only unit
+/// variants.
 pub trait Preprocessable: Sized {
 	/// The type of the preprocessed struct / enum. This is the type that will
 	/// be returned by the preprocessor. This type is automatically generated
 	/// by the `#[preprocess::sync]` macro.
 	type Processed;
 
+	/// The error returned when preprocessing fails. This is [`Error`] for the
+	/// types generated by the `#[preprocess::sync]` macro, which stop at the
+	/// first error, or `Vec<Error>` if `collect_errors = true` is given to the
+	/// macro.
+	///
+	/// This associated type was added along with `collect_errors`, which is a
+	/// breaking change for manual implementations of this trait. Use
+	/// `type Error = preprocess::Error;` to keep the previous behavior.
+	type Error;
+
 	/// The function that will be called to preprocess the struct / enum. This
 	/// function is automatically generated by the `#[preprocess::sync]` macro.
-	fn preprocess(self) -> crate::prelude::Result<Self::Processed>;
+	fn preprocess(self) -> std::result::Result<Self::Processed, Self::Error>;
+}
+
+/// Preprocesses the value of a [`Result`] if it is [`Ok`], and passes an
+/// [`Err`] through unchanged. This is useful for fields that already carry the
+/// result of a previous layer of validation.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct UserProfile {
+///     #[preprocess(trim, length(min = 1))]
+///     pub name: String,
+/// }
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct UpdateUserRequest {
+///     // The type of this field will be changed to
+///     // `Result<UserProfileProcessed, String>`
+///     #[preprocess]
+///     pub profile: std::result::Result<UserProfile, String>,
+/// }
+/// ```
+impl<T, E> Preprocessable for std::result::Result<T, E>
+where
+	T: Preprocessable,
+{
+	type Processed = std::result::Result<T::Processed, E>;
+	type Error = T::Error;
+
+	fn preprocess(self) -> std::result::Result<Self::Processed, Self::Error> {
+		match self {
+			Ok(value) => value.preprocess().map(Ok),
+			Err(err) => Ok(Err(err)),
+		}
+	}
+}
+
+/// Implements [`Preprocessable`] for a tuple, by preprocessing each element in
+/// order and stopping at the first error. All the elements must have the same
+/// error type.
+macro_rules! impl_preprocessable_for_tuple {
+	($first:ident $(, $rest:ident)*) => {
+		impl<$first $(, $rest)*> Preprocessable for ($first, $($rest,)*)
+		where
+			$first: Preprocessable,
+			$($rest: Preprocessable<Error = $first::Error>,)*
+		{
+			type Processed = ($first::Processed, $($rest::Processed,)*);
+			type Error = $first::Error;
+
+			#[allow(non_snake_case)]
+			fn preprocess(
+				self,
+			) -> std::result::Result<Self::Processed, Self::Error> {
+				let ($first, $($rest,)*) = self;
+				Ok(($first.preprocess()?, $($rest.preprocess()?,)*))
+			}
+		}
+	};
 }
+
+impl_preprocessable_for_tuple!(A);
+impl_preprocessable_for_tuple!(A, B);
+impl_preprocessable_for_tuple!(A, B, C);
+impl_preprocessable_for_tuple!(A, B, C, D);
+impl_preprocessable_for_tuple!(A, B, C, D, E);
+impl_preprocessable_for_tuple!(A, B, C, D, E, F);
+impl_preprocessable_for_tuple!(A, B, C, D, E, F, G);
+impl_preprocessable_for_tuple!(A, B, C, D, E, F, G, H);
+impl_preprocessable_for_tuple!(A, B, C, D, E, F, G, H, I);
+impl_preprocessable_for_tuple!(A, B, C, D, E, F, G, H, I, J);
+impl_preprocessable_for_tuple!(A, B, C, D, E, F, G, H, I, J, K);
+impl_preprocessable_for_tuple!(A, B, C, D, E, F, G, H, I, J, K, L);
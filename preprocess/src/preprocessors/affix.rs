@@ -0,0 +1,138 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Preprocesses the given string and removes the given prefix from it, using
+/// [`str::strip_prefix`]. Only one occurrence of the prefix is removed, and
+/// the value is left unchanged if it does not start with the prefix. Returns a
+/// Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct AuthorizationHeader {
+///     #[preprocess(trim, strip_prefix = "Bearer ")]
+///     pub token: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_strip_prefix<'a, T>(
+	value: T,
+	prefix: &str,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	Ok(match value.into() {
+		Cow::Borrowed(value) => {
+			Cow::Borrowed(value.strip_prefix(prefix).unwrap_or(value))
+		}
+		Cow::Owned(mut value) => {
+			if value.starts_with(prefix) {
+				value.drain(..prefix.len());
+			}
+			Cow::Owned(value)
+		}
+	})
+}
+
+/// Preprocesses the given string and removes the given suffix from it, using
+/// [`str::strip_suffix`]. Only one occurrence of the suffix is removed, and
+/// the value is left unchanged if it does not end with the suffix. Returns a
+/// Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct GetDocumentRequest {
+///     #[preprocess(strip_suffix = ".json")]
+///     pub name: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_strip_suffix<'a, T>(
+	value: T,
+	suffix: &str,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	Ok(match value.into() {
+		Cow::Borrowed(value) => {
+			Cow::Borrowed(value.strip_suffix(suffix).unwrap_or(value))
+		}
+		Cow::Owned(mut value) => {
+			if value.ends_with(suffix) {
+				value.truncate(value.len() - suffix.len());
+			}
+			Cow::Owned(value)
+		}
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_strip_prefix() {
+		let test_cases = [
+			("Bearer abc.def", "abc.def"),
+			("Bearer Bearer abc", "Bearer abc"),
+			("bearer abc", "bearer abc"),
+			("abc", "abc"),
+			("Bearer ", ""),
+			("", ""),
+		];
+		for (input, expected) in test_cases {
+			assert_eq!(
+				preprocess_strip_prefix(input, "Bearer ").unwrap(),
+				expected
+			);
+			assert_eq!(
+				preprocess_strip_prefix(input.to_string(), "Bearer ").unwrap(),
+				expected
+			);
+		}
+		assert!(matches!(
+			preprocess_strip_prefix("Bearer abc", "Bearer ").unwrap(),
+			Cow::Borrowed("abc")
+		));
+	}
+
+	#[test]
+	fn test_strip_suffix() {
+		let test_cases = [
+			("report.json", "report"),
+			("report.json.json", "report.json"),
+			("report.JSON", "report.JSON"),
+			("réport€.json", "réport€"),
+			(".json", ""),
+			("", ""),
+		];
+		for (input, expected) in test_cases {
+			assert_eq!(
+				preprocess_strip_suffix(input, ".json").unwrap(),
+				expected
+			);
+			assert_eq!(
+				preprocess_strip_suffix(input.to_string(), ".json").unwrap(),
+				expected
+			);
+		}
+	}
+}
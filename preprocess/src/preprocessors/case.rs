@@ -0,0 +1,462 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Splits the given string into words. Words are separated by whitespaces,
+/// hyphens, underscores and any other non-alphanumeric characters, as well as
+/// by case boundaries (`helloWorld` becomes `hello` and `World`, and
+/// `HTTPServer` becomes `HTTP` and `Server`).
+fn split_words(value: &str) -> Vec<&str> {
+	let mut words = vec![];
+	let chars = value.char_indices().collect::<Vec<_>>();
+	let mut start = None;
+
+	for (position, &(index, ch)) in chars.iter().enumerate() {
+		if !ch.is_alphanumeric() {
+			if let Some(start) = start.take() {
+				words.push(&value[start..index]);
+			}
+			continue;
+		}
+
+		let Some(word_start) = start else {
+			start = Some(index);
+			continue;
+		};
+
+		let previous = chars[position - 1].1;
+		let next = chars.get(position + 1).map(|&(_, ch)| ch);
+		let is_boundary = ch.is_uppercase() &&
+			(previous.is_lowercase() ||
+				previous.is_numeric() ||
+				(previous.is_uppercase() &&
+					next.is_some_and(char::is_lowercase)));
+
+		if is_boundary {
+			words.push(&value[word_start..index]);
+			start = Some(index);
+		}
+	}
+
+	if let Some(start) = start {
+		words.push(&value[start..]);
+	}
+
+	words
+}
+
+/// Converts the first character of the given word to uppercase and the rest of
+/// the word to lowercase.
+fn capitalize_word(word: &str) -> String {
+	let mut chars = word.chars();
+	chars
+		.next()
+		.map(|first| {
+			first
+				.to_uppercase()
+				.chain(chars.flat_map(char::to_lowercase))
+				.collect()
+		})
+		.unwrap_or_default()
+}
+
+/// Preprocesses the given string and converts it to `snake_case`. Words are
+/// split on whitespaces, hyphens, underscores and case boundaries, converted to
+/// lowercase, and joined using `_`. Returns a Cow<'a, str> to avoid
+/// unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateFieldRequest {
+///     #[preprocess(snake_case)]
+///     pub name: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_snake_case<'a, T>(value: T) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	let converted = split_words(&value)
+		.into_iter()
+		.map(str::to_lowercase)
+		.collect::<Vec<_>>()
+		.join("_");
+
+	Ok(if converted == value {
+		value
+	} else {
+		converted.into()
+	})
+}
+
+/// Preprocesses the given string and converts it to `kebab-case`. Words are
+/// split on whitespaces, hyphens, underscores and case boundaries, converted to
+/// lowercase, and joined using `-`. Returns a Cow<'a, str> to avoid
+/// unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreatePostRequest {
+///     #[preprocess(kebab_case)]
+///     pub slug: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_kebab_case<'a, T>(value: T) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	let converted = split_words(&value)
+		.into_iter()
+		.map(str::to_lowercase)
+		.collect::<Vec<_>>()
+		.join("-");
+
+	Ok(if converted == value {
+		value
+	} else {
+		converted.into()
+	})
+}
+
+/// Preprocesses the given string and converts it to `camelCase`. Words are
+/// split on whitespaces, hyphens, underscores and case boundaries. The first
+/// word is converted to lowercase and every other word is capitalized. Returns
+/// a Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateFieldRequest {
+///     #[preprocess(camel_case)]
+///     pub name: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_camel_case<'a, T>(value: T) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	let converted = split_words(&value)
+		.into_iter()
+		.enumerate()
+		.map(|(index, word)| {
+			if index == 0 {
+				word.to_lowercase()
+			} else {
+				capitalize_word(word)
+			}
+		})
+		.collect::<String>();
+
+	Ok(if converted == value {
+		value
+	} else {
+		converted.into()
+	})
+}
+
+/// Preprocesses the given string and converts it to `PascalCase`. Words are
+/// split on whitespaces, hyphens, underscores and case boundaries, and every
+/// word is capitalized. Returns a Cow<'a, str> to avoid unnecessary
+/// allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateTypeRequest {
+///     #[preprocess(pascal_case)]
+///     pub name: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_pascal_case<'a, T>(value: T) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	let converted = split_words(&value)
+		.into_iter()
+		.map(capitalize_word)
+		.collect::<String>();
+
+	Ok(if converted == value {
+		value
+	} else {
+		converted.into()
+	})
+}
+
+/// Preprocesses the given string and converts its first character to
+/// uppercase, leaving the rest of the string unchanged. Unlike `pascal_case`,
+/// the remaining characters are not lowercased, so `hELLO` becomes `HELLO`.
+/// Returns a Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreatePostRequest {
+///     #[preprocess(uppercase_first)]
+///     pub title: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_uppercase_first<'a, T>(
+	value: T,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	Ok(convert_first_char(value.into(), char::is_uppercase, |ch| {
+		ch.to_uppercase().collect()
+	}))
+}
+
+/// Preprocesses the given string and converts its first character to
+/// lowercase, leaving the rest of the string unchanged, so `HELLO` becomes
+/// `hELLO`. Returns a Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateVariableRequest {
+///     #[preprocess(lowercase_first)]
+///     pub name: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_lowercase_first<'a, T>(
+	value: T,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	Ok(convert_first_char(value.into(), char::is_lowercase, |ch| {
+		ch.to_lowercase().collect()
+	}))
+}
+
+/// Converts the first character of the given value using the given function,
+/// unless it is already converted according to `is_converted`. The first
+/// character can span multiple bytes, so the rest of the string is taken from
+/// the byte index of the second character.
+fn convert_first_char<'a>(
+	value: Cow<'a, str>,
+	is_converted: fn(char) -> bool,
+	convert: fn(char) -> String,
+) -> Cow<'a, str> {
+	let mut chars = value.char_indices();
+	let Some((_, first)) = chars.next() else {
+		return value;
+	};
+	let converted = convert(first);
+	if is_converted(first) || converted.chars().eq([first]) {
+		return value;
+	}
+
+	let rest = chars.next().map(|(index, _)| index).unwrap_or(value.len());
+	(converted + &value[rest..]).into()
+}
+
+#[cfg(test)]
+mod tests {
+	use std::borrow::Cow;
+
+	use super::*;
+
+	#[test]
+	fn test_split_words() {
+		assert_eq!(split_words("hello world"), vec!["hello", "world"]);
+		assert_eq!(split_words("helloWorld"), vec!["hello", "World"]);
+		assert_eq!(split_words("HTTPServer"), vec!["HTTP", "Server"]);
+		assert_eq!(split_words("version2Update"), vec!["version2", "Update"]);
+		assert_eq!(
+			split_words("  --hello__World-wide  web "),
+			vec!["hello", "World", "wide", "web"]
+		);
+		assert!(split_words("").is_empty());
+		assert!(split_words(" -_ ").is_empty());
+	}
+
+	#[test]
+	fn test_snake_case() {
+		let test_cases = [
+			("hello world", "hello_world"),
+			("helloWorld", "hello_world"),
+			("HelloWorld", "hello_world"),
+			("hello-world", "hello_world"),
+			("HELLO_WORLD", "hello_world"),
+			("parseHTTPResponse", "parse_http_response"),
+			("  Hello  World  ", "hello_world"),
+			("hello_world", "hello_world"),
+			("über straße", "über_straße"),
+			("", ""),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(preprocess_snake_case(input), Ok(expected.into()));
+		}
+	}
+
+	#[test]
+	fn test_kebab_case() {
+		let test_cases = [
+			("hello world", "hello-world"),
+			("helloWorld", "hello-world"),
+			("HelloWorld", "hello-world"),
+			("hello_world", "hello-world"),
+			("HELLO-WORLD", "hello-world"),
+			("parseHTTPResponse", "parse-http-response"),
+			("hello-world", "hello-world"),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(preprocess_kebab_case(input), Ok(expected.into()));
+		}
+	}
+
+	#[test]
+	fn test_camel_case() {
+		let test_cases = [
+			("hello world", "helloWorld"),
+			("hello_world", "helloWorld"),
+			("hello-world", "helloWorld"),
+			("HelloWorld", "helloWorld"),
+			("HELLO_WORLD", "helloWorld"),
+			("parse HTTP response", "parseHttpResponse"),
+			("helloWorld", "helloWorld"),
+			("hello", "hello"),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(preprocess_camel_case(input), Ok(expected.into()));
+		}
+	}
+
+	#[test]
+	fn test_pascal_case() {
+		let test_cases = [
+			("hello world", "HelloWorld"),
+			("hello_world", "HelloWorld"),
+			("hello-world", "HelloWorld"),
+			("helloWorld", "HelloWorld"),
+			("HELLO_WORLD", "HelloWorld"),
+			("parse HTTP response", "ParseHttpResponse"),
+			("HelloWorld", "HelloWorld"),
+			("élan vital", "ÉlanVital"),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(preprocess_pascal_case(input), Ok(expected.into()));
+		}
+	}
+
+	#[test]
+	fn test_uppercase_first() {
+		let test_cases = [
+			("hello world", "Hello world"),
+			("hELLO", "HELLO"),
+			("Hello", "Hello"),
+			("élan", "Élan"),
+			("ßeta", "SSeta"),
+			("1st place", "1st place"),
+			("", ""),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(preprocess_uppercase_first(input), Ok(expected.into()));
+		}
+	}
+
+	#[test]
+	fn test_lowercase_first() {
+		let test_cases = [
+			("Hello World", "hello World"),
+			("HELLO", "hELLO"),
+			("hello", "hello"),
+			("Élan", "élan"),
+			("", ""),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(preprocess_lowercase_first(input), Ok(expected.into()));
+		}
+	}
+
+	#[test]
+	fn test_already_converted_does_not_allocate() {
+		let test = String::from("hello_world");
+		assert!(matches!(
+			preprocess_snake_case(Cow::Borrowed(test.as_str())),
+			Ok(Cow::Borrowed(_))
+		));
+		assert!(matches!(
+			preprocess_kebab_case("hello-world"),
+			Ok(Cow::Borrowed(_))
+		));
+		assert!(matches!(
+			preprocess_camel_case("helloWorld"),
+			Ok(Cow::Borrowed(_))
+		));
+		assert!(matches!(
+			preprocess_pascal_case("HelloWorld"),
+			Ok(Cow::Borrowed(_))
+		));
+		assert!(matches!(
+			preprocess_uppercase_first("Hello"),
+			Ok(Cow::Borrowed(_))
+		));
+		assert!(matches!(
+			preprocess_lowercase_first("hello"),
+			Ok(Cow::Borrowed(_))
+		));
+	}
+}
@@ -0,0 +1,175 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Retains only the characters of the given string that match the given
+/// predicate. Borrows the string if all of its characters already match.
+pub(super) fn retain_chars<'a>(
+	value: Cow<'a, str>,
+	predicate: impl Fn(char) -> bool,
+) -> Cow<'a, str> {
+	if value.chars().all(&predicate) {
+		return value;
+	}
+
+	value
+		.chars()
+		.filter(|ch| predicate(*ch))
+		.collect::<String>()
+		.into()
+}
+
+/// Preprocesses the given string and removes every character that is not an
+/// ASCII digit (`0` to `9`). This is useful for normalizing values like phone
+/// numbers, where parentheses, spaces and dashes are commonly used for
+/// formatting. Returns a Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetPhoneRequest {
+///     // `(555) 123-4567` will be converted to `5551234567`
+///     #[preprocess(digits_only, length(min = 10, max = 15))]
+///     pub phone: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_digits_only<'a, T>(value: T) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	Ok(retain_chars(value.into(), |ch| ch.is_ascii_digit()))
+}
+
+/// Preprocesses the given string and removes every character that is not a
+/// letter. Letters are matched using [`char::is_alphabetic`], so letters of
+/// all scripts are retained. Returns a Cow<'a, str> to avoid unnecessary
+/// allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetInitialsRequest {
+///     // `J. R. R.` will be converted to `JRR`
+///     #[preprocess(letters_only)]
+///     pub initials: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_letters_only<'a, T>(value: T) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	Ok(retain_chars(value.into(), char::is_alphabetic))
+}
+
+/// Preprocesses the given string and removes every character that is neither
+/// a letter nor a digit. Characters are matched using
+/// [`char::is_alphanumeric`], so letters and digits of all scripts are
+/// retained. Returns a Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct RedeemCouponRequest {
+///     // `SAVE-20 NOW` will be converted to `SAVE20NOW`
+///     #[preprocess(alphanumeric_only, uppercase)]
+///     pub code: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_alphanumeric_only<'a, T>(
+	value: T,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	Ok(retain_chars(value.into(), char::is_alphanumeric))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_digits_only() {
+		let test_cases = [
+			("(555) 123-4567", "5551234567"),
+			("+1 555.123.4567", "15551234567"),
+			("١٢٣ 42", "42"),
+			("no digits", ""),
+			("", ""),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(preprocess_digits_only(input), Ok(expected.into()));
+		}
+	}
+
+	#[test]
+	fn test_letters_only() {
+		let test_cases = [
+			("J. R. R.", "JRR"),
+			("Zoë-123", "Zoë"),
+			("日本 語", "日本語"),
+			("42", ""),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(preprocess_letters_only(input), Ok(expected.into()));
+		}
+	}
+
+	#[test]
+	fn test_alphanumeric_only() {
+		let test_cases = [
+			("SAVE-20 NOW", "SAVE20NOW"),
+			("a_b.c!1", "abc1"),
+			("   ", ""),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				preprocess_alphanumeric_only(input),
+				Ok(expected.into())
+			);
+		}
+	}
+
+	#[test]
+	fn test_already_filtered_does_not_allocate() {
+		assert!(matches!(
+			preprocess_digits_only("5551234567"),
+			Ok(Cow::Borrowed(_))
+		));
+		assert!(matches!(
+			preprocess_letters_only("abc"),
+			Ok(Cow::Borrowed(_))
+		));
+		assert!(matches!(
+			preprocess_alphanumeric_only("abc123"),
+			Ok(Cow::Borrowed(_))
+		));
+	}
+}
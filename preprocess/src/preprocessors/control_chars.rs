@@ -0,0 +1,179 @@
+use std::borrow::Cow;
+
+use super::character_class::retain_chars;
+use crate::utils::Error;
+
+/// Checks if the given character is not printable. This includes control
+/// characters, invisible formatting characters (like zero-width spaces and
+/// bidirectional overrides), the line and paragraph separators, private-use
+/// characters and noncharacters.
+fn is_non_printable(ch: char) -> bool {
+	ch.is_control() ||
+		matches!(
+			ch,
+			// Soft hyphen
+			'\u{AD}' |
+			// Arabic number signs and letter mark
+			'\u{600}'..='\u{605}' |
+			'\u{61C}' |
+			'\u{6DD}' |
+			'\u{70F}' |
+			// Mongolian vowel separator
+			'\u{180E}' |
+			// Zero-width characters and directional marks
+			'\u{200B}'..='\u{200F}' |
+			// Line and paragraph separators, and bidirectional overrides
+			'\u{2028}'..='\u{202E}' |
+			// Invisible operators and bidirectional isolates
+			'\u{2060}'..='\u{2064}' |
+			'\u{2066}'..='\u{206F}' |
+			// Byte order mark
+			'\u{FEFF}' |
+			// Interlinear annotation characters
+			'\u{FFF9}'..='\u{FFFB}' |
+			// Tag characters
+			'\u{E0001}' |
+			'\u{E0020}'..='\u{E007F}' |
+			// Private use areas
+			'\u{E000}'..='\u{F8FF}' |
+			'\u{F0000}'..='\u{FFFFD}' |
+			'\u{100000}'..='\u{10FFFD}' |
+			// Noncharacters
+			'\u{FDD0}'..='\u{FDEF}'
+		) || (ch as u32 & 0xFFFE) == 0xFFFE
+}
+
+/// Preprocesses the given string and removes every control character from it.
+/// Control characters are matched using [`char::is_control`], which includes
+/// the ASCII control characters (`U+0000` to `U+001F` and `U+007F`), like tabs
+/// and newlines, as well as the C1 control characters (`U+0080` to `U+009F`).
+/// This never fails. Returns a Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetDisplayNameRequest {
+///     // `John\u{7}Doe` will be converted to `JohnDoe`
+///     #[preprocess(strip_control_characters, trim)]
+///     pub display_name: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_strip_control_chars<'a, T>(
+	value: T,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	Ok(retain_chars(value.into(), |ch| !ch.is_control()))
+}
+
+/// Preprocesses the given string and removes every character that is not
+/// printable from it. In addition to the control characters removed by
+/// [`preprocess_strip_control_chars`], this removes invisible formatting
+/// characters like zero-width spaces (`U+200B`) and bidirectional overrides
+/// (`U+202E`), which can be used to spoof how text is displayed, as well as
+/// the line and paragraph separators, private-use characters and
+/// noncharacters. This never fails. Returns a Cow<'a, str> to avoid
+/// unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateUserRequest {
+///     // `ad\u{200B}min` will be converted to `admin`
+///     #[preprocess(strip_non_printable, length(min = 3))]
+///     pub username: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_strip_non_printable<'a, T>(
+	value: T,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	Ok(retain_chars(value.into(), |ch| !is_non_printable(ch)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_strip_control_chars() {
+		let test_cases = [
+			("John\u{7}Doe", "JohnDoe"),
+			("\u{0}null\u{1F}", "null"),
+			("line one\nline two\r\n", "line oneline two"),
+			("tab\tseparated", "tabseparated"),
+			("delete\u{7F}", "delete"),
+			("c1\u{85}\u{9B}", "c1"),
+			("zero\u{200B}width", "zero\u{200B}width"),
+			("日本語 text", "日本語 text"),
+			("", ""),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				preprocess_strip_control_chars(input),
+				Ok(expected.into()),
+				"{:?}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_strip_non_printable() {
+		let test_cases = [
+			("John\u{7}Doe", "JohnDoe"),
+			("ad\u{200B}min", "admin"),
+			("user\u{202E}gpj.exe", "usergpj.exe"),
+			("\u{FEFF}bom", "bom"),
+			("soft\u{AD}hyphen", "softhyphen"),
+			("private\u{E000}\u{F0000}", "private"),
+			("non\u{FFFE}\u{FDD0}\u{1FFFF}character", "noncharacter"),
+			("para\u{2029}graph", "paragraph"),
+			("tag\u{E0041}", "tag"),
+			("emoji 👋🏽", "emoji 👋🏽"),
+			("日本語\u{3000}text", "日本語\u{3000}text"),
+			("café", "café"),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				preprocess_strip_non_printable(input),
+				Ok(expected.into()),
+				"{:?}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_strip_control_chars_does_not_allocate() {
+		assert!(matches!(
+			preprocess_strip_control_chars("printable text"),
+			Ok(Cow::Borrowed(_))
+		));
+		assert!(matches!(
+			preprocess_strip_non_printable("printable text"),
+			Ok(Cow::Borrowed(_))
+		));
+	}
+}
@@ -0,0 +1,52 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Preprocesses the given hexadecimal string and decodes it into bytes. The
+/// string must have an even number of hexadecimal digits, without any prefix.
+/// Use `hex(prefix = "0x")` before it to strip a prefix. The type of the field
+/// will be changed to `Vec<u8>`.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct VerifySignatureRequest {
+///     #[preprocess(trim, hex_decode)]
+///     pub signature: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_hex_decode<'a, T>(value: T) -> Result<Vec<u8>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	hex::decode(value.into().as_ref()).map_err(|err| {
+		Error::with_source(format!("invalid hex string: {}", err), err)
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_preprocess_hex_decode() {
+		assert_eq!(
+			preprocess_hex_decode("deadBEEF"),
+			Ok(vec![0xde, 0xad, 0xbe, 0xef])
+		);
+		assert_eq!(preprocess_hex_decode(""), Ok(vec![]));
+		assert_eq!(
+			preprocess_hex_decode("abc").unwrap_err().message,
+			"invalid hex string: Odd number of digits"
+		);
+		assert!(preprocess_hex_decode("zz").is_err());
+	}
+}
@@ -47,9 +47,436 @@
 //!     pub my_string: String,
 //! }
 //! ```
+//!
+//! # Case conversion
+//!
+//! The `snake_case`, `kebab_case`, `camel_case` and `pascal_case`
+//! preprocessors convert the given value to the respective case. The value is
+//! split into words on whitespaces, hyphens, underscores and case boundaries
+//! (`helloWorld` is split into `hello` and `World`). Values that are already in
+//! the target case are left unchanged.
+//!
+//! | Preprocessor  | Input         | Output        |
+//! | ------------- | ------------- | ------------- |
+//! | `snake_case`  | `Hello World` | `hello_world` |
+//! | `kebab_case`  | `Hello World` | `hello-world` |
+//! | `camel_case`  | `Hello World` | `helloWorld`  |
+//! | `pascal_case` | `Hello World` | `HelloWorld`  |
+//!
+//! ## Usage
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(snake_case)]
+//!     pub my_string: String,
+//! }
+//! ```
+//!
+//! The `uppercase_first` and `lowercase_first` preprocessors only convert the
+//! first character of the given value, leaving the rest of the value unchanged.
+//! `hELLO` becomes `HELLO` with `uppercase_first`, and `Hello World` becomes
+//! `hello World` with `lowercase_first`.
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(trim, uppercase_first)]
+//!     pub my_string: String,
+//! }
+//! ```
+//!
+//! # Character classes
+//!
+//! The `digits_only`, `letters_only` and `alphanumeric_only` preprocessors
+//! remove every character from the given value that is not an ASCII digit, a
+//! letter, or either a letter or a digit, respectively. Letters and digits of
+//! all scripts are retained by `letters_only` and `alphanumeric_only`. The
+//! value is not copied if all of its characters are retained. The type of the
+//! field will be changed to `Cow<'static, str>`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(digits_only, length(min = 10, max = 15))]
+//!     pub phone: String,
+//! }
+//! ```
+//!
+//! # Title case
+//!
+//! The `title_case` preprocessor capitalizes every word in the given value,
+//! except for stop words like `a`, `the` or `of`, which are converted to
+//! lowercase. The first and the last words are always capitalized. By default,
+//! the English stop words in
+//! [`DEFAULT_TITLE_CASE_STOP_WORDS`](crate::preprocessors::DEFAULT_TITLE_CASE_STOP_WORDS)
+//! are used. A custom list of stop words can be given using the `stop_words`
+//! argument.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(title_case)]
+//!     pub my_string: String,
+//! }
+//! ```
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(title_case(stop_words = ["le", "la", "les"]))]
+//!     pub my_string: String,
+//! }
+//! ```
+//!
+//! # Normalize Unicode
+//!
+//! The `normalize_unicode` preprocessor normalizes the given value to the given
+//! [Unicode normalization form](https://unicode.org/reports/tr15/). The
+//! allowed forms are `nfc`, `nfd`, `nfkc` and `nfkd`. If no form is given,
+//! `nfc` is used. This is especially useful before validating a value using
+//! the `email` or `domain` validators. The type of the field will be changed to
+//! [`String`].
+//!
+//! This preprocessor is only available with the `unicode-normalization`
+//! feature.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! # #[cfg(feature = "unicode-normalization")]
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(normalize_unicode(form = "nfc"))]
+//!     pub my_string: String,
+//! }
+//! ```
+//!
+//! # Hex decode
+//!
+//! The `hex_decode` preprocessor decodes the given hexadecimal string into
+//! bytes. The string must have an even number of digits, without any prefix.
+//! Use [`hex(prefix = "0x")`](crate::validators#hex) before it to strip a
+//! prefix. The type of the field will be changed to `Vec<u8>`.
+//!
+//! This preprocessor is only available with the `hex` feature.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! # #[cfg(feature = "hex")]
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(hex(prefix = "0x"), hex_decode)]
+//!     pub signature: String, // This type will be changed to Vec<u8>
+//! }
+//! ```
+//!
+//! # Normalize ISBN
+//!
+//! The `normalize_isbn` preprocessor validates the given value as an ISBN and
+//! converts it to an ISBN-13 without any hyphens or spaces. ISBN-10s are
+//! converted by prefixing them with `978` and calculating the new check digit.
+//! The type of the field will be changed to `Cow<'static, str>`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(normalize_isbn)]
+//!     pub isbn: String,
+//! }
+//! ```
+//!
+//! # Normalize phone
+//!
+//! The `normalize_phone` preprocessor converts the given phone number to the
+//! E.164 format, like `+14155552671`, using the
+//! [phonenumber](https://docs.rs/phonenumber) crate. Any format that is
+//! commonly used to write phone numbers is accepted. Use `country` to set the
+//! country of numbers without a country code, which are rejected otherwise.
+//! Returns an error if the value is not a valid phone number. The type of the
+//! field will be changed to `Cow<'static, str>`.
+//!
+//! This preprocessor is only available with the `phonenumber` feature.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! # #[cfg(feature = "phonenumber")]
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(normalize_phone(country = "US"))]
+//!     pub phone: String,
+//! }
+//! ```
+//!
+//! # Normalize IP
+//!
+//! The `ip(normalize)` preprocessor parses the given IP address and converts
+//! it back to a string in its canonical form, so that the same address is
+//! always stored the same way. For example, `0:0:0:0:0:0:0:1` becomes `::1`
+//! and `1.002.003.004` becomes `1.2.3.4`. Only IPv4 or IPv6 addresses can be
+//! allowed using `ip(v4, normalize)` and `ip(v6, normalize)`. Unlike the `ip`
+//! validator, the type of the field will be changed to `Cow<'static, str>`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(ip(normalize))]
+//!     pub address: String,
+//! }
+//! ```
+//!
+//! The `normalize_ip_any` preprocessor works like `ip(normalize)`, but also
+//! unwraps IPv4-mapped IPv6 addresses into the IPv4 address they contain. For
+//! example, `::ffff:192.0.2.1` becomes `192.0.2.1`.
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(normalize_ip_any)]
+//!     pub client: String,
+//! }
+//! ```
+//!
+//! # Normalize zip code
+//!
+//! The `normalize_zip_code` preprocessor converts the given postal code to
+//! uppercase and removes all the whitespaces, so that the same postal code is
+//! always stored the same way. For example, `sw1a 1aa` becomes `SW1A1AA`. The
+//! type of the field will be changed to `Cow<'static, str>`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(zip_code(country = "GB"), normalize_zip_code)]
+//!     pub postcode: String,
+//! }
+//! ```
+//!
+//! # Normalize language code
+//!
+//! The `normalize_language_code` preprocessor converts each subtag of the
+//! given BCP 47 language tag to its conventional case: the language is
+//! lowercased, two-letter regions are uppercased and four-letter scripts are
+//! titlecased, so `en-us` becomes `en-US` and `zh-hant-tw` becomes
+//! `zh-Hant-TW`. Extension and private-use subtags are lowercased. The tag is
+//! not validated, so this is usually used after the `language_code`
+//! [validator](crate::validators#language-code). The type of the field will be
+//! changed to `Cow<'static, str>`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(language_code, normalize_language_code)]
+//!     pub language: String,
+//! }
+//! ```
+//!
+//! # Normalize currency code
+//!
+//! The `normalize_currency_code` preprocessor converts the given currency code
+//! to uppercase, so that `usd` becomes `USD`. The code is not validated, so
+//! this is usually used before the `currency_code`
+//! [validator](crate::validators#currency-code). The type of the field will be
+//! changed to `Cow<'static, str>`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(trim, normalize_currency_code, currency_code)]
+//!     pub currency: String,
+//! }
+//! ```
+//!
+//! # Number format
+//!
+//! The `number_format` preprocessor converts a number in a locale-specific
+//! format to a plain decimal number, by removing the thousands separators and
+//! replacing the decimal separator with a `.`. By default, the thousands
+//! separator is `,` and the decimal separator is `.`, so `1,234,567.89`
+//! becomes `1234567.89`. Numbers in other formats can be normalized using
+//! `thousands` and `decimal`, like `thousands = "."` and `decimal = ","` for
+//! `1.234.567,89`. The thousands separators must separate groups of 3 digits,
+//! so that a number in a different format, like `1.5` with `.` as the
+//! thousands separator, is rejected instead of becoming `15`. The type of the
+//! field will be changed to `Cow<'static, str>`.
+//!
+//! The result can be validated using `decimal_string`, or parsed into a number
+//! using `from_str`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(number_format(thousands = ".", decimal = ","), from_str(as = "f64"))]
+//!     pub amount: String, // This type will be changed to f64
+//! }
+//! ```
+//!
+//! # Strip HTML
+//!
+//! The `strip_html` preprocessor removes all the HTML tags and comments from
+//! the given value, leaving only the text content. The content of `<script>`
+//! and `<style>` tags is removed as well. HTML entities like `&amp;` can be
+//! decoded using `decode_entities = true`. Entities are decoded before the tags
+//! are removed, so that encoded tags like `&lt;script&gt;` are removed as well.
+//! The type of the field will be changed to `Cow<'static, str>`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(strip_html(decode_entities = true))]
+//!     pub my_string: String,
+//! }
+//! ```
+//!
+//! # Strip sub-address
+//!
+//! The `strip_subaddress` preprocessor removes the sub-address from the given
+//! email address, so that `user+tag@example.com` becomes `user@example.com`.
+//! This is the same as using `email(normalize_subaddress = true)`, without
+//! validating the email address. The type of the field will be changed to
+//! `Cow<'static, str>`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(strip_subaddress)]
+//!     pub email: String,
+//! }
+//! ```
+//!
+//! # Strip control characters
+//!
+//! The `strip_control_characters` preprocessor removes every control character
+//! from the given value, as matched by [`char::is_control`]. This includes the
+//! ASCII control characters, like tabs and newlines, and the C1 control
+//! characters. The `strip_non_printable` preprocessor is more conservative,
+//! and also removes invisible formatting characters, like zero-width spaces
+//! and bidirectional overrides, as well as the line and paragraph separators,
+//! private-use characters and noncharacters. Neither of them ever fails. The
+//! value is not copied if nothing is removed. The type of the field will be
+//! changed to `Cow<'static, str>`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(strip_control_characters)]
+//!     pub display_name: String,
+//!     #[preprocess(strip_non_printable)]
+//!     pub username: String,
+//! }
+//! ```
+//!
+//! # Strip prefix and suffix
+//!
+//! The `strip_prefix` and `strip_suffix` preprocessors remove the given string
+//! from the start or the end of the given value, like the `Bearer ` prefix of
+//! an `Authorization` header. Only one occurrence is removed, and the value is
+//! left unchanged if it does not start or end with the given string. Use the
+//! [`require_prefix` and
+//! `require_suffix`](crate::validators#require-prefix-and-suffix) validators
+//! before them if the prefix or suffix is mandatory. The type of the field will
+//! be changed to `Cow<'static, str>`.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(trim, strip_prefix = "Bearer ")]
+//!     pub token: String,
+//!     #[preprocess(strip_suffix = ".json")]
+//!     pub document: String,
+//! }
+//! ```
+//!
+//! # Sign
+//!
+//! The `abs` preprocessor converts the given number to its absolute value, and
+//! the `neg` preprocessor negates it. Both of them only work on signed numbers
+//! that implement the [`Signed`](crate::validators::Signed) trait, and return
+//! an error if the result cannot be represented by the type (like
+//! `i32::MIN.abs()`). The type of the field is not changed.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! #[preprocess::sync]
+//! pub struct MyStruct {
+//!     #[preprocess(abs)]
+//!     pub my_number: i32,
+//! }
+//! ```
 
+mod affix;
+mod case;
+mod character_class;
+mod control_chars;
+#[cfg(feature = "hex")]
+mod hex_decode;
 mod lowercase;
+mod normalize_currency_code;
+mod normalize_ip;
+mod normalize_isbn;
+mod normalize_language_code;
+#[cfg(feature = "phonenumber")]
+mod normalize_phone;
+#[cfg(feature = "unicode-normalization")]
+mod normalize_unicode;
+mod normalize_zip_code;
+mod number_format;
+mod sign;
+mod strip_html;
+mod strip_subaddress;
+mod title_case;
 mod trim;
 mod uppercase;
 
-pub use self::{lowercase::*, trim::*, uppercase::*};
+#[cfg(feature = "hex")]
+pub use self::hex_decode::*;
+#[cfg(feature = "phonenumber")]
+pub use self::normalize_phone::*;
+#[cfg(feature = "unicode-normalization")]
+pub use self::normalize_unicode::*;
+pub use self::{
+	affix::*,
+	case::*,
+	character_class::*,
+	control_chars::*,
+	lowercase::*,
+	normalize_currency_code::*,
+	normalize_ip::*,
+	normalize_isbn::*,
+	normalize_language_code::*,
+	normalize_zip_code::*,
+	number_format::*,
+	sign::*,
+	strip_html::*,
+	strip_subaddress::*,
+	title_case::*,
+	trim::*,
+	uppercase::*,
+};
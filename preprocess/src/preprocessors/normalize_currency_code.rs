@@ -0,0 +1,73 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Preprocesses the given currency code and converts it to uppercase, so that
+/// `usd` is converted to `USD`. Only ASCII letters are converted, since ISO
+/// 4217 codes only consist of them. The code is not validated, so this is
+/// usually used before
+/// [`validate_currency_code`](crate::validators::validate_currency_code).
+/// Returns a Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreatePaymentRequest {
+///     #[preprocess(trim, normalize_currency_code, currency_code)]
+///     pub currency: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_normalize_currency_code<'a, T>(
+	value: T,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	if value.bytes().any(|byte| byte.is_ascii_lowercase()) {
+		Ok(value.to_ascii_uppercase().into())
+	} else {
+		Ok(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_normalize_currency_code() {
+		let test_cases = [
+			("usd", "USD"),
+			("Eur", "EUR"),
+			("JPY", "JPY"),
+			("xau", "XAU"),
+			("", ""),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				preprocess_normalize_currency_code(input),
+				Ok(expected.into()),
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_normalize_currency_code_does_not_allocate() {
+		assert!(matches!(
+			preprocess_normalize_currency_code("USD"),
+			Ok(Cow::Borrowed(_))
+		));
+	}
+}
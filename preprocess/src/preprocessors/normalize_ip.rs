@@ -0,0 +1,259 @@
+use std::{
+	borrow::Cow,
+	net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use crate::{utils::Error, validators::unmap_ipv4};
+
+/// Parses the given IPv4 address, allowing leading zeros in the octets, like
+/// `1.002.003.004`. The octets are always read as decimal numbers. These
+/// addresses are rejected by the [`FromStr`](std::str::FromStr)
+/// implementation of [`Ipv4Addr`], since they are ambiguous with the octal
+/// notation that some other parsers support.
+fn parse_ipv4(value: &str) -> Option<Ipv4Addr> {
+	let mut octets = [0u8; 4];
+	let mut parts = value.split('.');
+	for octet in &mut octets {
+		let part = parts.next()?;
+		if part.is_empty() ||
+			part.len() > 3 ||
+			!part.bytes().all(|byte| byte.is_ascii_digit())
+		{
+			return None;
+		}
+		*octet = part.parse().ok()?;
+	}
+	parts.next().is_none().then_some(Ipv4Addr::from(octets))
+}
+
+/// Parses the given IPv4 or IPv6 address, allowing leading zeros in the
+/// octets of IPv4 addresses.
+fn parse_ip(value: &str) -> Result<IpAddr, Error> {
+	match parse_ipv4(value) {
+		Some(address) => Ok(IpAddr::V4(address)),
+		None => value
+			.parse()
+			.map_err(|err| Error::with_source("invalid ip address", err)),
+	}
+}
+
+/// Returns the given value if it is already equal to the normalized address,
+/// to avoid an allocation, or the normalized address otherwise.
+fn normalized(value: Cow<'_, str>, address: impl ToString) -> Cow<'_, str> {
+	let address = address.to_string();
+	if address == value {
+		value
+	} else {
+		address.into()
+	}
+}
+
+/// Preprocesses the given IP address into its canonical form, using the
+/// [`Display`](std::fmt::Display) implementation of [`IpAddr`]. For example,
+/// `::0001` is converted to `::1`, and `1.002.003.004` is converted to
+/// `1.2.3.4`. Unlike the `ip` validator, the type of the field stays a string.
+/// Returns a Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct AddServerRequest {
+///     #[preprocess(trim, ip(normalize))]
+///     pub address: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_normalize_ip<'a, T>(value: T) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	let address = parse_ip(&value)?;
+	Ok(normalized(value, address))
+}
+
+/// Preprocesses the given IPv4 address into its canonical form. For example,
+/// `1.002.003.004` is converted to `1.2.3.4`. IPv6 addresses are rejected.
+/// Returns a Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct AddServerRequest {
+///     #[preprocess(ip(v4, normalize))]
+///     pub address: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_normalize_ipv4<'a, T>(value: T) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	let address =
+		parse_ipv4(&value).ok_or_else(|| Error::new("invalid ipv4 address"))?;
+	Ok(normalized(value, address))
+}
+
+/// Preprocesses the given IPv6 address into its canonical form, as defined by
+/// [RFC 5952](https://www.rfc-editor.org/rfc/rfc5952). For example,
+/// `0:0:0:0:0:0:0:0001` is converted to `::1`. IPv4 addresses are rejected.
+/// Returns a Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct AddServerRequest {
+///     #[preprocess(ip(v6, normalize))]
+///     pub address: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_normalize_ipv6<'a, T>(value: T) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	let address = value
+		.parse::<Ipv6Addr>()
+		.map_err(|err| Error::with_source("invalid ipv6 address", err))?;
+	Ok(normalized(value, address))
+}
+
+/// Preprocesses the given IP address into its canonical form, like
+/// [`preprocess_normalize_ip`], after unwrapping IPv4-mapped IPv6 addresses
+/// into the IPv4 address they contain. For example, `::ffff:192.0.2.1` is
+/// converted to `192.0.2.1`. Returns a Cow<'a, str> to avoid unnecessary
+/// allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct LogVisitRequest {
+///     #[preprocess(normalize_ip_any)]
+///     pub client: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_normalize_ip_any<'a, T>(
+	value: T,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	let address = unmap_ipv4(parse_ip(&value)?);
+	Ok(normalized(value, address))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::error::Error as _;
+
+	use super::*;
+
+	#[test]
+	fn test_normalize_ip() {
+		let test_cases = [
+			("::0001", "::1"),
+			("0:0:0:0:0:0:0:1", "::1"),
+			("2001:DB8:0:0:0:0:0:1", "2001:db8::1"),
+			("1.002.003.004", "1.2.3.4"),
+			("010.0.0.1", "10.0.0.1"),
+			("1.2.3.4", "1.2.3.4"),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(preprocess_normalize_ip(input), Ok(expected.into()));
+		}
+	}
+
+	#[test]
+	fn test_normalize_ip_invalid() {
+		assert!(preprocess_normalize_ip("1.2.3").is_err());
+		assert!(preprocess_normalize_ip("1.2.3.256").is_err());
+		assert!(preprocess_normalize_ip("1.2.3.0004").is_err());
+		assert!(preprocess_normalize_ip("1.2.3.+4").is_err());
+		assert!(preprocess_normalize_ip("localhost").is_err());
+		assert!(preprocess_normalize_ip("").is_err());
+
+		let error = preprocess_normalize_ip("localhost").unwrap_err();
+		assert_eq!(error.message, "invalid ip address");
+		assert!(error.source().is_some());
+		assert_eq!(
+			preprocess_normalize_ipv4("::1").unwrap_err().message,
+			"invalid ipv4 address"
+		);
+	}
+
+	#[test]
+	fn test_normalize_ipv4_and_ipv6() {
+		assert_eq!(
+			preprocess_normalize_ipv4("1.002.003.004"),
+			Ok("1.2.3.4".into())
+		);
+		assert!(preprocess_normalize_ipv4("::1").is_err());
+		assert_eq!(preprocess_normalize_ipv6("::0001"), Ok("::1".into()));
+		assert!(preprocess_normalize_ipv6("1.2.3.4").is_err());
+	}
+
+	#[test]
+	fn test_normalize_ip_any() {
+		let test_cases = [
+			("1.002.003.004", "1.2.3.4"),
+			("0:0:0:0:0:0:0:1", "::1"),
+			("::ffff:192.0.2.1", "192.0.2.1"),
+			("::FFFF:C000:201", "192.0.2.1"),
+			("2001:DB8:0:0:0:0:0:1", "2001:db8::1"),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(preprocess_normalize_ip_any(input), Ok(expected.into()));
+		}
+		assert!(preprocess_normalize_ip_any("::ffff:1.2.3").is_err());
+		assert!(matches!(
+			preprocess_normalize_ip_any("192.0.2.1"),
+			Ok(Cow::Borrowed(_))
+		));
+	}
+
+	#[test]
+	fn test_normalized_does_not_allocate() {
+		assert!(matches!(
+			preprocess_normalize_ip("::1"),
+			Ok(Cow::Borrowed(_))
+		));
+		assert!(matches!(
+			preprocess_normalize_ipv4("1.2.3.4"),
+			Ok(Cow::Borrowed(_))
+		));
+	}
+}
@@ -0,0 +1,85 @@
+use std::borrow::Cow;
+
+use crate::{
+	utils::Error,
+	validators::{parse_isbn, IsbnFormat},
+};
+
+/// Preprocesses the given ISBN and converts it to an ISBN-13 without hyphens or
+/// spaces. ISBN-10s are converted by prefixing them with `978` and calculating
+/// the new check digit. Returns an error if the value is not a valid ISBN.
+/// Returns a Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct AddBookRequest {
+///     #[preprocess(normalize_isbn)]
+///     pub isbn: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_normalize_isbn<'a, T>(value: T) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	let (format, isbn) = parse_isbn(&value)?;
+
+	let isbn = match format {
+		IsbnFormat::Isbn13 => isbn,
+		IsbnFormat::Isbn10 => {
+			let mut isbn13 = format!("978{}", &isbn[..9]);
+			let sum = isbn13
+				.chars()
+				.filter_map(|ch| ch.to_digit(10))
+				.zip([1, 3].into_iter().cycle())
+				.map(|(digit, weight)| digit * weight)
+				.sum::<u32>();
+			isbn13.push_str(&((10 - sum % 10) % 10).to_string());
+			isbn13
+		}
+	};
+
+	Ok(if isbn == value { value } else { isbn.into() })
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_normalize_isbn() {
+		let test_cases = [
+			("0-306-40615-2", "9780306406157"),
+			("0-8044-2957-X", "9780804429573"),
+			("978-0-306-40615-7", "9780306406157"),
+			("978 3 16 148410 0", "9783161484100"),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(preprocess_normalize_isbn(input), Ok(expected.into()));
+		}
+	}
+
+	#[test]
+	fn test_normalize_isbn_invalid() {
+		assert!(preprocess_normalize_isbn("0-306-40615-3").is_err());
+		assert!(preprocess_normalize_isbn("not an isbn").is_err());
+	}
+
+	#[test]
+	fn test_normalize_isbn_does_not_allocate() {
+		assert!(matches!(
+			preprocess_normalize_isbn("9780306406157"),
+			Ok(Cow::Borrowed(_))
+		));
+	}
+}
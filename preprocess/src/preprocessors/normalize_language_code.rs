@@ -0,0 +1,110 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Preprocesses the given BCP 47 language tag to use the conventional case of
+/// each of its subtags. The language and most other subtags are lowercased,
+/// two-letter region subtags are uppercased, and four-letter script subtags
+/// are titlecased. For example, `EN-us` is converted to `en-US`, and
+/// `zh-hant-tw` to `zh-Hant-TW`. Subtags after a single-character subtag, like
+/// extensions and private-use subtags, are always lowercased. The tag is not
+/// validated, so this is usually used after
+/// [`validate_language_code`](crate::validators::validate_language_code).
+/// Returns a Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetLocaleRequest {
+///     #[preprocess(language_code, normalize_language_code)]
+///     pub language: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_normalize_language_code<'a, T>(
+	value: T,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+
+	let mut in_extension = false;
+	let normalized = value
+		.split('-')
+		.enumerate()
+		.map(|(index, subtag)| {
+			if subtag.len() == 1 {
+				in_extension = true;
+			}
+			if index == 0 || in_extension {
+				subtag.to_ascii_lowercase()
+			} else if subtag.len() == 2 {
+				subtag.to_ascii_uppercase()
+			} else if subtag.len() == 4 &&
+				subtag.chars().all(|ch| ch.is_ascii_alphabetic())
+			{
+				let (first, rest) = subtag.split_at(1);
+				first.to_ascii_uppercase() + &rest.to_ascii_lowercase()
+			} else {
+				subtag.to_ascii_lowercase()
+			}
+		})
+		.collect::<Vec<_>>()
+		.join("-");
+
+	if normalized == value {
+		Ok(value)
+	} else {
+		Ok(normalized.into())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_normalize_language_code() {
+		let test_cases = [
+			("en", "en"),
+			("EN", "en"),
+			("en-us", "en-US"),
+			("EN-us", "en-US"),
+			("pt-br", "pt-BR"),
+			("zh-hant", "zh-Hant"),
+			("ZH-HANT-TW", "zh-Hant-TW"),
+			("es-419", "es-419"),
+			("de-ch-1901", "de-CH-1901"),
+			("sl-ROZAJ", "sl-rozaj"),
+			("en-US-u-CA-gregory", "en-US-u-ca-gregory"),
+			("en-x-AB-CDEF", "en-x-ab-cdef"),
+			("X-Private", "x-private"),
+			("", ""),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				preprocess_normalize_language_code(input),
+				Ok(expected.into()),
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_normalize_language_code_does_not_allocate() {
+		assert!(matches!(
+			preprocess_normalize_language_code("zh-Hant-TW"),
+			Ok(Cow::Borrowed(_))
+		));
+	}
+}
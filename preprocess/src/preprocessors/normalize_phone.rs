@@ -0,0 +1,87 @@
+use std::borrow::Cow;
+
+use phonenumber::{country::Id, Mode};
+
+use crate::{utils::Error, validators::parse_phone};
+
+/// Preprocesses the given phone number and converts it to the E.164 format,
+/// like `+14155552671`, using the [`phonenumber`](https://docs.rs/phonenumber)
+/// crate. Any format that is commonly used to write phone numbers is accepted.
+/// Numbers without a country code are assumed to be from `country`, and are
+/// rejected if `country` is not given. Extensions are removed. Returns an
+/// error if the value is not a valid phone number.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetPhoneRequest {
+///     #[preprocess(normalize_phone(country = "US"))]
+///     pub phone: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_normalize_phone<'a, T>(
+	value: T,
+	country: Option<Id>,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let number = parse_phone(&value.into(), country)?;
+
+	Ok(number.format().mode(Mode::E164).to_string().into())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_normalize_phone() {
+		let test_cases = [
+			(None, "+1 (201) 555-0123", "+12015550123"),
+			(Some(Id::US), "201.555.0123", "+12015550123"),
+			(Some(Id::US), "+12015550123", "+12015550123"),
+			(Some(Id::US), "+1 201-555-0123 ext. 12", "+12015550123"),
+			(Some(Id::GB), "07400 123456", "+447400123456"),
+			(None, "+44 (0) 7400 123456", "+447400123456"),
+			(Some(Id::DE), "030 123456", "+4930123456"),
+			(Some(Id::US), "+49 30 123456", "+4930123456"),
+		];
+		for (country, input, expected) in test_cases {
+			assert_eq!(
+				preprocess_normalize_phone(input, country).unwrap(),
+				expected,
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_normalize_phone_invalid() {
+		let test_cases = [
+			(None, "201-555-0123"),
+			(Some(Id::US), "(555) 555-5555"),
+			(Some(Id::GB), "07400 12"),
+			(Some(Id::DE), ""),
+		];
+		for (country, input) in test_cases {
+			assert_eq!(
+				preprocess_normalize_phone(input, country)
+					.unwrap_err()
+					.message,
+				"invalid phone number",
+				"{}",
+				input
+			);
+		}
+	}
+}
@@ -0,0 +1,98 @@
+use std::borrow::Cow;
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::utils::Error;
+
+/// The Unicode normalization form to convert a string to. See
+/// [Unicode Normalization Forms](https://unicode.org/reports/tr15/) for more
+/// details on each of the forms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizationForm {
+	/// Canonical decomposition, followed by canonical composition
+	Nfc,
+	/// Canonical decomposition
+	Nfd,
+	/// Compatibility decomposition, followed by canonical composition
+	Nfkc,
+	/// Compatibility decomposition
+	Nfkd,
+}
+
+/// Preprocesses the given string and normalizes it to the given Unicode
+/// normalization form. This is useful when the same text can come from
+/// different sources in different forms. For example, `é` can be represented
+/// as `U+00E9` or as `U+0065 U+0301`.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct LoginRequest {
+///     #[preprocess(normalize_unicode(form = "nfc"), email)]
+///     pub email: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_normalize_unicode<'a, T>(
+	value: T,
+	form: NormalizationForm,
+) -> Result<String, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	Ok(match form {
+		NormalizationForm::Nfc => value.nfc().collect(),
+		NormalizationForm::Nfd => value.nfd().collect(),
+		NormalizationForm::Nfkc => value.nfkc().collect(),
+		NormalizationForm::Nfkd => value.nfkd().collect(),
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_normalize_unicode_nfc() {
+		let composed = "caf\u{00E9}";
+		let decomposed = "cafe\u{0301}";
+		assert_ne!(composed, decomposed);
+		assert_eq!(
+			preprocess_normalize_unicode(composed, NormalizationForm::Nfc),
+			preprocess_normalize_unicode(decomposed, NormalizationForm::Nfc)
+		);
+	}
+
+	#[test]
+	fn test_normalize_unicode_nfd() {
+		assert_eq!(
+			preprocess_normalize_unicode("caf\u{00E9}", NormalizationForm::Nfd),
+			Ok("cafe\u{0301}".to_string())
+		);
+	}
+
+	#[test]
+	fn test_normalize_unicode_compatibility() {
+		// The `ﬁ` ligature is only decomposed in the compatibility forms
+		assert_eq!(
+			preprocess_normalize_unicode("\u{FB01}le", NormalizationForm::Nfc),
+			Ok("\u{FB01}le".to_string())
+		);
+		assert_eq!(
+			preprocess_normalize_unicode("\u{FB01}le", NormalizationForm::Nfkc),
+			Ok("file".to_string())
+		);
+		assert_eq!(
+			preprocess_normalize_unicode("\u{FB01}le", NormalizationForm::Nfkd),
+			Ok("file".to_string())
+		);
+	}
+}
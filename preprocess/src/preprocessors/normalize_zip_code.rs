@@ -0,0 +1,76 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Preprocesses the given postal code for consistent storage, by converting it
+/// to uppercase and removing all the whitespaces. For example, `sw1a 1aa` is
+/// converted to `SW1A1AA`. Returns a Cow<'a, str> to avoid unnecessary
+/// allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetAddressRequest {
+///     #[preprocess(zip_code(country = "GB"), normalize_zip_code)]
+///     pub postcode: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_normalize_zip_code<'a, T>(
+	value: T,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	if !value
+		.chars()
+		.any(|ch| ch.is_whitespace() || ch.is_lowercase())
+	{
+		return Ok(value);
+	}
+
+	Ok(value
+		.chars()
+		.filter(|ch| !ch.is_whitespace())
+		.flat_map(char::to_uppercase)
+		.collect::<String>()
+		.into())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_normalize_zip_code() {
+		let test_cases = [
+			("sw1a 1aa", "SW1A1AA"),
+			(" K1A 0B1 ", "K1A0B1"),
+			("12345-6789", "12345-6789"),
+			("", ""),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				preprocess_normalize_zip_code(input),
+				Ok(expected.into())
+			);
+		}
+	}
+
+	#[test]
+	fn test_normalize_zip_code_does_not_allocate() {
+		assert!(matches!(
+			preprocess_normalize_zip_code("SW1A1AA"),
+			Ok(Cow::Borrowed(_))
+		));
+	}
+}
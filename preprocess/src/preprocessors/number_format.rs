@@ -0,0 +1,166 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Preprocesses the given number in a locale-specific format, by removing the
+/// given thousands separators and replacing the given decimal separator with
+/// a `.`. For example, `1.234.567,89` is converted to `1234567.89` with `.` as
+/// the thousands separator and `,` as the decimal separator. Returns an error
+/// if the value has more than one decimal separator, if a thousands separator
+/// is used after the decimal separator, or if the thousands separators don't
+/// separate groups of 3 digits, so that a value in a different format, like
+/// `1.5` with `.` as the thousands separator, is not silently converted into a
+/// different number. The value is not checked to
+/// be a number otherwise, so use `decimal_string` or `from_str` after this.
+/// Returns a Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreatePaymentRequest {
+///     #[preprocess(number_format(thousands = ".", decimal = ","), decimal_string)]
+///     pub amount: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_number_format<'a, T>(
+	value: T,
+	thousands: char,
+	decimal: char,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+
+	let mut decimal_found = false;
+	for ch in value.chars() {
+		if ch == decimal {
+			if decimal_found {
+				return Err(Error::new(format!(
+					"number must have at most one decimal separator `{}`",
+					decimal
+				)));
+			}
+			decimal_found = true;
+		} else if ch == thousands && decimal_found {
+			return Err(Error::new(format!(
+				"thousands separator `{}` cannot be used after the decimal separator `{}`",
+				thousands, decimal
+			)));
+		}
+	}
+
+	if !value.contains(thousands) && (decimal == '.' || !decimal_found) {
+		return Ok(value);
+	}
+
+	// Every group after the first must have exactly 3 digits, and the first
+	// one must have 1 to 3 digits, after an optional sign
+	let integer = value.split(decimal).next().unwrap_or_default();
+	let integer = integer.strip_prefix(['-', '+']).unwrap_or(integer);
+	let mut groups = integer.split(thousands);
+	let first_group_valid = groups
+		.next()
+		.is_some_and(|group| (1..=3).contains(&group.chars().count()));
+	if integer.contains(thousands) &&
+		(!first_group_valid ||
+			groups.any(|group| group.chars().count() != 3))
+	{
+		return Err(Error::new(format!(
+			"thousands separator `{}` must separate groups of 3 digits",
+			thousands
+		)));
+	}
+
+	Ok(value
+		.chars()
+		.filter(|ch| *ch != thousands)
+		.map(|ch| if ch == decimal { '.' } else { ch })
+		.collect::<String>()
+		.into())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_number_format() {
+		let test_cases = [
+			("1,234,567.89", ',', '.', "1234567.89"),
+			("1.234.567,89", '.', ',', "1234567.89"),
+			("1 234 567,89", ' ', ',', "1234567.89"),
+			("1'234.5", '\'', '.', "1234.5"),
+			("-1,000", ',', '.', "-1000"),
+			("+12,345.6", ',', '.', "+12345.6"),
+			("1.5", ',', '.', "1.5"),
+			("1234", ',', '.', "1234"),
+			("0,5", '.', ',', "0.5"),
+			("", ',', '.', ""),
+		];
+
+		for (input, thousands, decimal, expected) in test_cases {
+			assert_eq!(
+				preprocess_number_format(input, thousands, decimal),
+				Ok(expected.into()),
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_number_format_invalid() {
+		assert_eq!(
+			preprocess_number_format("1.234.567", ',', '.')
+				.unwrap_err()
+				.message,
+			"number must have at most one decimal separator `.`"
+		);
+		assert_eq!(
+			preprocess_number_format("1,234.5,6", ',', '.')
+				.unwrap_err()
+				.message,
+			"thousands separator `,` cannot be used after the decimal separator `.`"
+		);
+
+		let test_cases = [
+			("1.5", '.', ','),
+			("1,23,4", ',', '.'),
+			("12,34,567", ',', '.'),
+			("1234,567", ',', '.'),
+			(",123", ',', '.'),
+			("1,000,", ',', '.'),
+			("1 00,5", ' ', ','),
+		];
+		for (input, thousands, decimal) in test_cases {
+			assert_eq!(
+				preprocess_number_format(input, thousands, decimal)
+					.unwrap_err()
+					.message,
+				format!(
+					"thousands separator `{}` must separate groups of 3 digits",
+					thousands
+				),
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_number_format_does_not_allocate() {
+		assert!(matches!(
+			preprocess_number_format("1234.5", ',', '.'),
+			Ok(Cow::Borrowed(_))
+		));
+	}
+}
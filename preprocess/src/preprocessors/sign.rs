@@ -0,0 +1,83 @@
+use crate::{utils::Error, validators::Signed};
+
+/// Preprocesses the given number and converts it to its absolute value. Returns
+/// an error if the absolute value cannot be represented by the type, which is
+/// only the case for the minimum value of signed integers (like `i32::MIN`).
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateTransferRequest {
+///     #[preprocess(abs)]
+///     pub amount: i64,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_abs<T>(value: T) -> Result<T, Error>
+where
+	T: Signed,
+{
+	value
+		.checked_abs()
+		.ok_or_else(|| Error::new("absolute value is out of range"))
+}
+
+/// Preprocesses the given number and negates it. Returns an error if the
+/// negated value cannot be represented by the type, which is only the case for
+/// the minimum value of signed integers (like `i32::MIN`).
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateRefundRequest {
+///     #[preprocess(abs, neg)]
+///     pub amount: i64,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_neg<T>(value: T) -> Result<T, Error>
+where
+	T: Signed,
+{
+	value
+		.checked_neg()
+		.ok_or_else(|| Error::new("negated value is out of range"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_abs() {
+		assert_eq!(preprocess_abs(-5), Ok(5));
+		assert_eq!(preprocess_abs(5i8), Ok(5));
+		assert_eq!(preprocess_abs(0i64), Ok(0));
+		assert_eq!(preprocess_abs(-2.5f64), Ok(2.5));
+		assert_eq!(preprocess_abs(f32::NEG_INFINITY), Ok(f32::INFINITY));
+		assert!(preprocess_abs(i32::MIN).is_err());
+	}
+
+	#[test]
+	fn test_neg() {
+		assert_eq!(preprocess_neg(5), Ok(-5));
+		assert_eq!(preprocess_neg(-5i16), Ok(5));
+		assert_eq!(preprocess_neg(i64::MAX), Ok(-i64::MAX));
+		assert_eq!(preprocess_neg(2.5f32), Ok(-2.5));
+		assert!(preprocess_neg(i8::MIN).is_err());
+	}
+}
@@ -0,0 +1,268 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Tags whose content is not text, and is removed along with the tag.
+const RAW_TEXT_TAGS: &[&str] = &["script", "style"];
+
+/// Finds the index of the `>` that closes the tag at the start of the given
+/// string, ignoring any `>` inside quoted attribute values.
+fn find_tag_end(tag: &str) -> Option<usize> {
+	let mut quote = None;
+	for (index, ch) in tag.char_indices().skip(1) {
+		match (quote, ch) {
+			(None, '"' | '\'') => quote = Some(ch),
+			(Some(open), _) if open == ch => quote = None,
+			(None, '>') => return Some(index),
+			_ => (),
+		}
+	}
+	None
+}
+
+/// Removes all the tags and comments from the given string, along with the
+/// content of raw text tags like `<script>` and `<style>`. A `<` that doesn't
+/// start a tag (like in `1 < 2`) is retained. An unterminated tag removes the
+/// rest of the string.
+fn strip_tags(value: &str) -> String {
+	let mut output = String::with_capacity(value.len());
+	let mut rest = value;
+
+	while let Some(start) = rest.find('<') {
+		output.push_str(&rest[..start]);
+		let tag = &rest[start..];
+
+		if let Some(comment) = tag.strip_prefix("<!--") {
+			rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+			continue;
+		}
+
+		let starts_tag = tag[1..].chars().next().is_some_and(|ch| {
+			ch.is_ascii_alphabetic() || matches!(ch, '/' | '!' | '?')
+		});
+		if !starts_tag {
+			output.push('<');
+			rest = &tag[1..];
+			continue;
+		}
+
+		let Some(end) = find_tag_end(tag) else {
+			rest = "";
+			break;
+		};
+		rest = &tag[end + 1..];
+
+		let name = tag[1..end]
+			.split(|ch: char| !ch.is_ascii_alphanumeric())
+			.next()
+			.unwrap_or_default()
+			.to_ascii_lowercase();
+		let is_self_closing = tag[..end].ends_with('/');
+		if is_self_closing || !RAW_TEXT_TAGS.contains(&name.as_str()) {
+			continue;
+		}
+
+		// Skip the content of the raw text tag, up to and including its
+		// closing tag. Lowercasing ASCII characters retains the byte indices.
+		let closing_tag = format!("</{}", name);
+		rest = rest
+			.to_ascii_lowercase()
+			.find(&closing_tag)
+			.and_then(|closing| {
+				let closing = &rest[closing..];
+				find_tag_end(closing).map(|end| &closing[end + 1..])
+			})
+			.unwrap_or_default();
+	}
+	output.push_str(rest);
+
+	output
+}
+
+/// Decodes a single HTML entity, given without the leading `&` and the
+/// trailing `;`.
+fn decode_entity(entity: &str) -> Option<char> {
+	match entity {
+		"amp" => Some('&'),
+		"lt" => Some('<'),
+		"gt" => Some('>'),
+		"quot" => Some('"'),
+		"apos" => Some('\''),
+		"nbsp" => Some('\u{00A0}'),
+		_ => {
+			let number = entity.strip_prefix('#')?;
+			let code = if let Some(hex) = number
+				.strip_prefix('x')
+				.or_else(|| number.strip_prefix('X'))
+			{
+				u32::from_str_radix(hex, 16).ok()?
+			} else {
+				number.parse().ok()?
+			};
+			char::from_u32(code)
+		}
+	}
+}
+
+/// Decodes the named entities `&amp;`, `&lt;`, `&gt;`, `&quot;`, `&apos;` and
+/// `&nbsp;`, as well as numeric entities like `&#39;` or `&#x27;`. Unknown
+/// entities are retained as is.
+fn decode_entities(value: &str) -> String {
+	let mut output = String::with_capacity(value.len());
+	let mut rest = value;
+
+	while let Some(start) = rest.find('&') {
+		output.push_str(&rest[..start]);
+		rest = &rest[start..];
+
+		let decoded = rest.find(';').and_then(|end| {
+			decode_entity(&rest[1..end]).map(|ch| (ch, end + 1))
+		});
+		if let Some((ch, length)) = decoded {
+			output.push(ch);
+			rest = &rest[length..];
+		} else {
+			output.push('&');
+			rest = &rest[1..];
+		}
+	}
+	output.push_str(rest);
+
+	output
+}
+
+/// Preprocesses the given string and removes all the HTML tags and comments
+/// from it, leaving only the text content. The content of `<script>` and
+/// `<style>` tags is removed as well. If `decode_entities` is `true`, HTML
+/// entities like `&amp;` are decoded before the tags are removed, so that
+/// encoded markup like `&lt;script&gt;` is removed as well, instead of being
+/// turned back into a tag. This uses a
+/// simple parser and is not a replacement for a full HTML sanitizer, but
+/// handles nested and malformed tags. Returns a Cow<'a, str> to avoid
+/// unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateCommentRequest {
+///     #[preprocess(strip_html, trim, length(min = 1))]
+///     pub comment: String,
+///     #[preprocess(strip_html(decode_entities = true))]
+///     pub signature: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_strip_html<'a, T>(
+	value: T,
+	decode_entities: bool,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	// Entities are decoded first, so that decoding cannot produce new tags
+	let stripped = if decode_entities {
+		strip_tags(&self::decode_entities(&value))
+	} else {
+		strip_tags(&value)
+	};
+
+	Ok(if stripped == value {
+		value
+	} else {
+		stripped.into()
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_strip_html() {
+		let test_cases = [
+			("<p>Hello <b>world</b></p>", "Hello world"),
+			("<div><p><i>nested</i> tags</p></div>", "nested tags"),
+			("line<br/>break<br />", "linebreak"),
+			("<a href=\"https://example.com\">link</a>", "link"),
+			("<a title='1 > 0'>quoted</a>", "quoted"),
+			("before<!-- <b>comment</b> -->after", "beforeafter"),
+			("1 < 2 and 3 > 2", "1 < 2 and 3 > 2"),
+			("plain text", "plain text"),
+			("unterminated <b", "unterminated "),
+			("", ""),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				preprocess_strip_html(input, false),
+				Ok(expected.into())
+			);
+		}
+	}
+
+	#[test]
+	fn test_strip_html_raw_text_tags() {
+		assert_eq!(
+			preprocess_strip_html(
+				"hi<script>alert('<b>xss</b>')</script> there",
+				false
+			),
+			Ok("hi there".into())
+		);
+		assert_eq!(
+			preprocess_strip_html(
+				"<STYLE type=\"text/css\">p { color: red; }</Style>text",
+				false
+			),
+			Ok("text".into())
+		);
+		assert_eq!(
+			preprocess_strip_html("<script>never closed", false),
+			Ok("".into())
+		);
+	}
+
+	#[test]
+	fn test_strip_html_decode_entities() {
+		assert_eq!(
+			preprocess_strip_html("<p>Tom &amp; Jerry &lt;3</p>", true),
+			Ok("Tom & Jerry <3".into())
+		);
+		assert_eq!(
+			preprocess_strip_html("&quot;hi&#39;&#x21; &unknown; & co", true),
+			Ok("\"hi'! &unknown; & co".into())
+		);
+		assert_eq!(
+			preprocess_strip_html("Tom &amp; Jerry", false),
+			Ok("Tom &amp; Jerry".into())
+		);
+		// Entities are decoded only once, before the tags are removed
+		assert_eq!(
+			preprocess_strip_html("&lt;b&gt;bold&amp;lt;", true),
+			Ok("bold&lt;".into())
+		);
+		assert_eq!(
+			preprocess_strip_html(
+				"hi&lt;script&gt;alert(1)&lt;/script&gt; there",
+				true
+			),
+			Ok("hi there".into())
+		);
+	}
+
+	#[test]
+	fn test_strip_html_does_not_allocate() {
+		assert!(matches!(
+			preprocess_strip_html("no tags here", true),
+			Ok(Cow::Borrowed(_))
+		));
+	}
+}
@@ -0,0 +1,70 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Preprocesses the given email address and removes the sub-address from it,
+/// so that `user+tag@example.com` becomes `user@example.com`. The sub-address
+/// is everything in the local part after the first `+`. Values without a
+/// sub-address, or without an `@`, are left unchanged. Returns a
+/// Cow<'a, str> to avoid unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SignUpRequest {
+///     #[preprocess(email(normalize_subaddress = true))]
+///     pub email: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_strip_subaddress<'a, T>(
+	value: T,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	let Some((user_part, domain_part)) = value.rsplit_once('@') else {
+		return Ok(value);
+	};
+	let Some((user_part, _)) = user_part.split_once('+') else {
+		return Ok(value);
+	};
+
+	Ok(format!("{}@{}", user_part, domain_part).into())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_strip_subaddress() {
+		let test_cases = [
+			("user+tag@example.com", "user@example.com"),
+			("user+tag+more@example.com", "user@example.com"),
+			("user+@example.com", "user@example.com"),
+			("user@example.com", "user@example.com"),
+			("not-an-email", "not-an-email"),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(preprocess_strip_subaddress(input), Ok(expected.into()));
+		}
+	}
+
+	#[test]
+	fn test_strip_subaddress_does_not_allocate() {
+		assert!(matches!(
+			preprocess_strip_subaddress("user@example.com"),
+			Ok(Cow::Borrowed(_))
+		));
+	}
+}
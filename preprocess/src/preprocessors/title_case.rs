@@ -0,0 +1,162 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// The default list of English stop words that are not capitalized by the
+/// [`preprocess_title_case`] preprocessor, unless they are the first or the
+/// last word of the value.
+pub const DEFAULT_TITLE_CASE_STOP_WORDS: &[&str] = &[
+	"a", "an", "and", "as", "at", "but", "by", "for", "from", "in", "into",
+	"nor", "of", "off", "on", "or", "over", "per", "so", "the", "to", "up",
+	"via", "with", "yet",
+];
+
+/// Preprocesses the given string and converts it to title case. Every word is
+/// capitalized, except for the given stop words, which are converted to
+/// lowercase. The first and the last word are always capitalized. Whitespaces
+/// and punctuation are retained as is. Returns a Cow<'a, str> to avoid
+/// unnecessary allocations.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateBookRequest {
+///     #[preprocess(trim, title_case)]
+///     pub title: String,
+///     #[preprocess(title_case(stop_words = ["le", "la", "les"]))]
+///     pub french_title: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn preprocess_title_case<'a, T>(
+	value: T,
+	stop_words: &[&str],
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	let word_count = value.split_whitespace().count();
+
+	let mut converted = String::with_capacity(value.len());
+	let mut word_index = 0;
+	for part in value.split_inclusive(char::is_whitespace) {
+		let word = part.trim_end_matches(char::is_whitespace);
+		let whitespace = &part[word.len()..];
+		if word.is_empty() {
+			converted.push_str(whitespace);
+			continue;
+		}
+
+		let bare_word = word.trim_matches(|ch: char| !ch.is_alphanumeric());
+		let is_stop_word = word_index != 0 &&
+			word_index != word_count - 1 &&
+			stop_words.iter().any(|stop_word| {
+				stop_word.to_lowercase() == bare_word.to_lowercase()
+			});
+		word_index += 1;
+
+		if is_stop_word {
+			converted.push_str(&word.to_lowercase());
+		} else {
+			// Capitalize the first alphanumeric character, so that words
+			// starting with punctuation, like `(hello)`, are converted as well
+			let mut capitalized = false;
+			for ch in word.chars() {
+				if !capitalized && ch.is_alphanumeric() {
+					converted.extend(ch.to_uppercase());
+					capitalized = true;
+				} else {
+					converted.extend(ch.to_lowercase());
+				}
+			}
+		}
+		converted.push_str(whitespace);
+	}
+
+	Ok(if converted == value {
+		value
+	} else {
+		converted.into()
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_title_case_default_stop_words() {
+		let test_cases = [
+			("the lord of the rings", "The Lord of the Rings"),
+			("a tale of two cities", "A Tale of Two Cities"),
+			("war and peace", "War and Peace"),
+			("what the world is made of", "What the World Is Made Of"),
+			("THE CATCHER IN THE RYE", "The Catcher in the Rye"),
+			("  gone   with the wind ", "  Gone   with the Wind "),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				preprocess_title_case(input, DEFAULT_TITLE_CASE_STOP_WORDS),
+				Ok(expected.into())
+			);
+		}
+	}
+
+	#[test]
+	fn test_title_case_single_word() {
+		assert_eq!(
+			preprocess_title_case("the", DEFAULT_TITLE_CASE_STOP_WORDS),
+			Ok("The".into())
+		);
+		assert_eq!(
+			preprocess_title_case("RUST", DEFAULT_TITLE_CASE_STOP_WORDS),
+			Ok("Rust".into())
+		);
+		assert_eq!(
+			preprocess_title_case("", DEFAULT_TITLE_CASE_STOP_WORDS),
+			Ok("".into())
+		);
+	}
+
+	#[test]
+	fn test_title_case_punctuation() {
+		assert_eq!(
+			preprocess_title_case(
+				"hello, world! (the sequel) of the year",
+				DEFAULT_TITLE_CASE_STOP_WORDS
+			),
+			Ok("Hello, World! (the Sequel) of the Year".into())
+		);
+		assert_eq!(
+			preprocess_title_case(
+				"\"the\" end of it all...",
+				DEFAULT_TITLE_CASE_STOP_WORDS
+			),
+			Ok("\"The\" End of It All...".into())
+		);
+	}
+
+	#[test]
+	fn test_title_case_custom_stop_words() {
+		assert_eq!(
+			preprocess_title_case(
+				"le petit prince et la rose",
+				&["le", "la", "les", "et"]
+			),
+			Ok("Le Petit Prince et la Rose".into())
+		);
+		assert_eq!(
+			preprocess_title_case("the lord of the rings", &[]),
+			Ok("The Lord Of The Rings".into())
+		);
+	}
+}
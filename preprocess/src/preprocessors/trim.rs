@@ -14,7 +14,7 @@ use crate::utils::Error;
 /// pub struct LoginRequest {
 ///     #[preprocess(trim)]
 ///     pub email: String,
-///     #[preprocess(regex = "^(?=.*[A-Za-z])(?=.*\\d)[A-Za-z\\d]{8,}$")]
+///     #[preprocess(regex = "^[A-Za-z\\d]{8,}$")]
 ///     pub password: String,
 /// }
 /// ```
@@ -0,0 +1,130 @@
+//! Helpers for testing validators, including your own custom validators.
+//! This module is only available with the `testing` feature, which is meant to
+//! be enabled in your `dev-dependencies`:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! preprocess = { version = "<version>", features = ["testing"] }
+//! ```
+//!
+//! All the validators are re-exported from this module, so that test files
+//! only need a single import:
+//!
+//! ```rust
+//! use preprocess::testing::*;
+//!
+//! #[test]
+//! fn test_email() {
+//!     assert_valid!(validate_email, "user@example.com", false);
+//!     assert_invalid!(validate_email, "not-an-email", false);
+//! }
+//! ```
+//!
+//! [`generate_test_cases!`](crate::testing::generate_test_cases) generates a
+//! separate `#[test]` function for every case, so that every failing case is
+//! reported on its own:
+//!
+//! ```rust
+//! use preprocess::testing::*;
+//!
+//! generate_test_cases!(
+//!     validate_email,
+//!     args = [false],
+//!     valid = ["user@example.com", "first.last@example.co.uk"],
+//!     invalid = ["not-an-email", "user@"],
+//! );
+//! ```
+
+/// Generates a `#[test]` function for every valid and invalid case of the
+/// given validator. The tests are named after the validator, the kind of
+/// the case and its index, like `validate_email_valid_0` and
+/// `validate_email_invalid_1`. Additional arguments of the validator can
+/// be given using `args = [...]`, which are passed after the value.
+pub use preprocess_macro::generate_test_cases;
+
+pub use crate::{assert_invalid, assert_valid, validators::*};
+
+/// Asserts that the given value passes the given validator, and evaluates to
+/// the validated value. Additional arguments of the validator are passed after
+/// the value. On failure, the panic message contains the validator, the value
+/// and the error returned by the validator.
+///
+/// # Example
+/// ```rust
+/// use preprocess::testing::*;
+///
+/// let ip = assert_valid!(validate_ip, "127.0.0.1");
+/// assert!(ip.is_loopback());
+/// ```
+#[macro_export]
+macro_rules! assert_valid {
+	($validator:expr, $value:expr $(, $arg:expr)* $(,)?) => {
+		match ($validator)($value $(, $arg)*) {
+			::std::result::Result::Ok(value) => value,
+			::std::result::Result::Err(err) => ::std::panic!(
+				"expected `{}` to be valid according to `{}`, but it failed: {}",
+				::std::stringify!($value),
+				::std::stringify!($validator),
+				err.message
+			),
+		}
+	};
+}
+
+/// Asserts that the given value fails the given validator, and evaluates to
+/// the returned [`Error`](crate::Error). Additional arguments of the validator
+/// are passed after the value.
+///
+/// # Example
+/// ```rust
+/// use preprocess::testing::*;
+///
+/// let error = assert_invalid!(validate_length, "hi", Some(3), None, None);
+/// assert!(error.message.contains("greater than or equal to 3"));
+/// ```
+#[macro_export]
+macro_rules! assert_invalid {
+	($validator:expr, $value:expr $(, $arg:expr)* $(,)?) => {
+		match ($validator)($value $(, $arg)*) {
+			::std::result::Result::Ok(_) => ::std::panic!(
+				"expected `{}` to be invalid according to `{}`, but it passed",
+				::std::stringify!($value),
+				::std::stringify!($validator),
+			),
+			::std::result::Result::Err(err) => err,
+		}
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_assert_valid() {
+		assert_eq!(assert_valid!(validate_positive, 5), 5);
+		assert_eq!(assert_valid!(validate_range, 5, Some(1), Some(10)), 5);
+	}
+
+	#[test]
+	fn test_assert_invalid() {
+		let error = assert_invalid!(validate_positive, -5);
+		assert!(error.field.is_empty());
+	}
+
+	#[test]
+	#[should_panic(
+		expected = "expected `-5` to be valid according to `validate_positive`"
+	)]
+	fn test_assert_valid_fails() {
+		let _ = assert_valid!(validate_positive, -5);
+	}
+
+	#[test]
+	#[should_panic(
+		expected = "expected `5` to be invalid according to `validate_positive`, but it passed"
+	)]
+	fn test_assert_invalid_fails() {
+		let _ = assert_invalid!(validate_positive, 5);
+	}
+}
@@ -1,9 +1,18 @@
-use std::fmt::Display;
+use std::{
+	fmt::{Debug, Display},
+	hash::{Hash, Hasher},
+	sync::Arc,
+};
 
 /// An error that occurs during preprocessing
 /// The error contains the field that failed validation and the error message
 /// that was returned by the validator.
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+///
+/// With the `serde` feature, the error can be serialized and deserialized as
+/// `{"field": "email", "message": "email is invalid"}`. The source error is
+/// not included.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Error {
 	/// The field that failed validation.
 	/// This is useful when you want to display the error message to the user.
@@ -13,6 +22,10 @@ pub struct Error {
 	pub field: String,
 	/// The error message returned by the validator
 	pub message: String,
+	/// The underlying error that caused this error, if any.
+	/// Can be set using [`Error::with_source`].
+	#[cfg_attr(feature = "serde", serde(skip))]
+	source: Option<Arc<dyn std::error::Error + Send + Sync>>,
 }
 
 impl Error {
@@ -22,14 +35,55 @@ impl Error {
 		Self {
 			field: String::new(),
 			message: message.into(),
+			source: None,
+		}
+	}
+
+	/// Creates a new error with the given message, caused by the given source
+	/// error. The source error can be accessed using
+	/// [`std::error::Error::source`].
+	/// This does not set the field. Use [`Error::set_field`] to set the field.
+	pub fn with_source(
+		message: impl Into<String>,
+		source: impl std::error::Error + Send + Sync + 'static,
+	) -> Self {
+		Self {
+			field: String::new(),
+			message: message.into(),
+			source: Some(Arc::new(source)),
 		}
 	}
 
+	/// Creates a new error for a map, which has two keys that are identical
+	/// after preprocessing them using `#[preprocess(keys(...))]`, like `Name`
+	/// and `name ` after `trim, lowercase`. The source of the error is a
+	/// [`DuplicateKey`] containing the key.
+	pub fn duplicate_key(key: impl Debug) -> Self {
+		Self::with_source(
+			"duplicate key after preprocessing",
+			DuplicateKey {
+				key: format!("{:?}", key),
+			},
+		)
+	}
+
 	/// Sets the field which failed validation.
 	pub fn set_field(mut self, field: impl Into<String>) -> Self {
 		self.field = field.into();
 		self
 	}
+
+	/// Replaces the message with a generic one and removes the source error,
+	/// so that the error cannot contain the value that failed validation. The
+	/// field is retained. This is used for fields marked with
+	/// `#[preprocess(sensitive)]`, like passwords.
+	pub fn redacted(self) -> Self {
+		Self {
+			field: self.field,
+			message: "value is invalid".to_string(),
+			source: None,
+		}
+	}
 }
 
 impl Display for Error {
@@ -42,4 +96,222 @@ impl Display for Error {
 	}
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		self.source
+			.as_deref()
+			.map(|source| source as &(dyn std::error::Error + 'static))
+	}
+}
+
+// The source error is not taken into account when comparing or hashing errors,
+// since trait objects can neither be compared nor hashed.
+impl PartialEq for Error {
+	fn eq(&self, other: &Self) -> bool {
+		self.field == other.field && self.message == other.message
+	}
+}
+
+impl Eq for Error {}
+
+impl Hash for Error {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.field.hash(state);
+		self.message.hash(state);
+	}
+}
+
+/// The source of an [`Error`] returned when two keys of a map are identical
+/// after preprocessing them. This can be used to tell duplicate keys apart
+/// from other errors, using [`std::error::Error::source`] and
+/// [`downcast_ref`](trait@std::error::Error#method.downcast_ref).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DuplicateKey {
+	/// The debug representation of the duplicate key, after preprocessing
+	pub key: String,
+}
+
+impl Display for DuplicateKey {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "duplicate key {}", self.key)
+	}
+}
+
+impl std::error::Error for DuplicateKey {}
+
+/// A list of errors that occurred during preprocessing. This is used when more
+/// than one error needs to be reported at once, like when responding to an API
+/// request. A single [`Error`] can be converted into [`ValidationErrors`]
+/// using [`From`].
+///
+/// With the `serde` feature, the list can be serialized and deserialized as
+/// `{"errors": [{"field": "email", "message": "email is invalid"}]}`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ValidationErrors {
+	/// The errors that occurred, in the order they occurred
+	pub errors: Vec<Error>,
+}
+
+impl ValidationErrors {
+	/// Creates a new, empty list of errors
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds an error to the list
+	pub fn push(&mut self, error: Error) {
+		self.errors.push(error);
+	}
+
+	/// Returns `true` if there are no errors in the list
+	pub fn is_empty(&self) -> bool {
+		self.errors.is_empty()
+	}
+}
+
+impl From<Error> for ValidationErrors {
+	fn from(error: Error) -> Self {
+		Self {
+			errors: vec![error],
+		}
+	}
+}
+
+impl From<Vec<Error>> for ValidationErrors {
+	fn from(errors: Vec<Error>) -> Self {
+		Self { errors }
+	}
+}
+
+impl Display for ValidationErrors {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		for (index, error) in self.errors.iter().enumerate() {
+			if index != 0 {
+				writeln!(f)?;
+			}
+			write!(f, "{}", error)?;
+		}
+		Ok(())
+	}
+}
+
+impl std::error::Error for ValidationErrors {}
+
+#[cfg(test)]
+mod tests {
+	use std::error::Error as _;
+
+	use super::{DuplicateKey, Error, ValidationErrors};
+
+	#[test]
+	fn test_error_without_source() {
+		let error = Error::new("invalid value").set_field("email");
+		assert!(error.source().is_none());
+		assert_eq!(
+			error.to_string(),
+			"error preprocessing field `email`: invalid value"
+		);
+	}
+
+	#[test]
+	fn test_error_with_source() {
+		let source = "abc".parse::<u32>().unwrap_err();
+		let error = Error::with_source("invalid number", source.clone());
+		assert_eq!(
+			error.source().map(ToString::to_string),
+			Some(source.to_string())
+		);
+	}
+
+	#[test]
+	fn test_error_eq_ignores_source() {
+		let source = "abc".parse::<u32>().unwrap_err();
+		assert_eq!(
+			Error::with_source("invalid number", source),
+			Error::new("invalid number")
+		);
+	}
+
+	#[test]
+	fn test_error_redacted() {
+		let source = "hunter2".parse::<u32>().unwrap_err();
+		let error = Error::with_source("hunter2 is not a number", source)
+			.set_field("password")
+			.redacted();
+		assert!(error.source().is_none());
+		assert_eq!(error.field, "password");
+		assert!(!error.message.contains("hunter2"));
+	}
+
+	#[test]
+	fn test_error_duplicate_key() {
+		let error = Error::duplicate_key("name").set_field("labels");
+		assert_eq!(error.message, "duplicate key after preprocessing");
+		assert_eq!(
+			error
+				.source()
+				.and_then(|source| source.downcast_ref::<DuplicateKey>()),
+			Some(&DuplicateKey {
+				key: "\"name\"".to_string()
+			})
+		);
+	}
+
+	#[test]
+	fn test_validation_errors_display() {
+		let mut errors = ValidationErrors::from(
+			Error::new("invalid email").set_field("email"),
+		);
+		errors.push(Error::new("too short").set_field("password"));
+		assert_eq!(
+			errors.to_string(),
+			concat!(
+				"error preprocessing field `email`: invalid email\n",
+				"error preprocessing field `password`: too short"
+			)
+		);
+		assert!(ValidationErrors::new().is_empty());
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_error_serde() {
+		let source = "abc".parse::<u32>().unwrap_err();
+		let error =
+			Error::with_source("email is invalid", source).set_field("email");
+		let json = serde_json::to_value(&error).unwrap();
+		assert_eq!(
+			json,
+			serde_json::json!({
+				"field": "email",
+				"message": "email is invalid",
+			})
+		);
+
+		let deserialized = serde_json::from_value::<Error>(json).unwrap();
+		assert_eq!(deserialized, error);
+		assert!(deserialized.source().is_none());
+	}
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn test_validation_errors_serde() {
+		let errors = ValidationErrors::from(vec![
+			Error::new("invalid email").set_field("email"),
+			Error::new("too short").set_field("password"),
+		]);
+		let json = serde_json::to_string(&errors).unwrap();
+		assert_eq!(
+			json,
+			concat!(
+				r#"{"errors":[{"field":"email","message":"invalid email"},"#,
+				r#"{"field":"password","message":"too short"}]}"#
+			)
+		);
+		assert_eq!(
+			serde_json::from_str::<ValidationErrors>(&json).unwrap(),
+			errors
+		);
+	}
+}
@@ -0,0 +1,42 @@
+use crate::utils::Error;
+
+/// A reusable validator for fields of the type `T`, used with
+/// `#[preprocess(custom_type = MyValidator)]`. Unlike `custom`, which takes
+/// the name of a function, this allows validation logic to be encapsulated in
+/// a type, which can be shared between structs and tested on its own. A type
+/// can implement this trait for multiple field types.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// pub struct Slug;
+///
+/// impl FieldValidator<String> for Slug {
+///     fn validate(value: String) -> Result<String> {
+///         if value.is_empty() ||
+///             !value.bytes().all(|byte| {
+///                 byte.is_ascii_lowercase() ||
+///                     byte.is_ascii_digit() ||
+///                     byte == b'-'
+///             }) {
+///             return Err(Error::new("invalid slug"));
+///         }
+///         Ok(value)
+///     }
+/// }
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreatePostRequest {
+///     #[preprocess(custom_type = Slug)]
+///     pub slug: String,
+/// }
+///
+/// assert!(Slug::validate("hello-world".to_string()).is_ok());
+/// ```
+pub trait FieldValidator<T> {
+	/// Validates the given value, returning the validated value or an error
+	/// if the value is invalid. The field of the error is set by the caller.
+	fn validate(value: T) -> Result<T, Error>;
+}
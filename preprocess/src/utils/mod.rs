@@ -1,3 +1,4 @@
 mod error;
+mod field_validator;
 
-pub use error::*;
+pub use self::{error::*, field_validator::*};
@@ -0,0 +1,112 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Validates that the given string starts with the given prefix. The value is
+/// not modified, so use
+/// [`strip_prefix`](crate::preprocessors::preprocess_strip_prefix) after this
+/// validator to remove the prefix.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct AuthorizationHeader {
+///     #[preprocess(require_prefix = "Bearer ", strip_prefix = "Bearer ")]
+///     pub token: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_require_prefix<'a, T>(
+	value: T,
+	prefix: &str,
+) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	if value.clone().into().starts_with(prefix) {
+		Ok(value)
+	} else {
+		Err(Error::new(format!("value must start with `{}`", prefix)))
+	}
+}
+
+/// Validates that the given string ends with the given suffix. The value is
+/// not modified, so use
+/// [`strip_suffix`](crate::preprocessors::preprocess_strip_suffix) after this
+/// validator to remove the suffix.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct UploadDocumentRequest {
+///     #[preprocess(trim, require_suffix = ".pdf")]
+///     pub file_name: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_require_suffix<'a, T>(
+	value: T,
+	suffix: &str,
+) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	if value.clone().into().ends_with(suffix) {
+		Ok(value)
+	} else {
+		Err(Error::new(format!("value must end with `{}`", suffix)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_require_prefix() {
+		assert_eq!(
+			validate_require_prefix("Bearer abc", "Bearer "),
+			Ok("Bearer abc")
+		);
+		assert!(
+			validate_require_prefix("Bearer ".to_string(), "Bearer ").is_ok()
+		);
+		assert!(validate_require_prefix("bearer abc", "Bearer ").is_err());
+		assert_eq!(
+			validate_require_prefix("abc", "Bearer ")
+				.unwrap_err()
+				.message,
+			"value must start with `Bearer `"
+		);
+	}
+
+	#[test]
+	fn test_validate_require_suffix() {
+		assert_eq!(
+			validate_require_suffix("report.pdf", ".pdf"),
+			Ok("report.pdf")
+		);
+		assert!(validate_require_suffix("report.PDF", ".pdf").is_err());
+		assert!(validate_require_suffix("", ".pdf").is_err());
+		assert_eq!(
+			validate_require_suffix("report.pdf.exe", ".pdf")
+				.unwrap_err()
+				.message,
+			"value must end with `.pdf`"
+		);
+	}
+}
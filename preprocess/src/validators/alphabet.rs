@@ -0,0 +1,111 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// The characters used by Base58, as used by Bitcoin. This is the same as the
+/// alphanumeric characters, except for `0`, `O`, `I` and `l`, which are easy
+/// to confuse with each other.
+pub const BASE58_ALPHABET: &str =
+	"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The characters used by Base62, which are all the ASCII alphanumeric
+/// characters.
+pub const BASE62_ALPHABET: &str =
+	"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Validates that every character of the given string is one of the
+/// characters of the given alphabet, like [`BASE58_ALPHABET`]. The length of
+/// the value is not validated, so an empty string is valid. Use `length`
+/// along with this validator to validate the length. The value is not
+/// modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SendPaymentRequest {
+///     #[preprocess(trim, alphabet = "base58", length(min = 26, max = 35))]
+///     pub address: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_alphabet<'a, T>(value: T, charset: &str) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let string = value.clone().into();
+
+	if let Some(position) = string.chars().position(|ch| !charset.contains(ch))
+	{
+		return Err(Error::new(format!(
+			"invalid character at position {}",
+			position
+		)));
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_alphabet_base58() {
+		let valid = ["1A1zP1eP5QGefi2DMPTfTL5SLmv7DivfNa", "z", ""];
+		for input in valid {
+			assert_eq!(validate_alphabet(input, BASE58_ALPHABET), Ok(input));
+		}
+
+		let invalid = ["0abc", "Oabc", "Iabc", "labc", "abc def", "abc+"];
+		for input in invalid {
+			assert!(
+				validate_alphabet(input, BASE58_ALPHABET).is_err(),
+				"{}",
+				input
+			);
+		}
+		assert_eq!(
+			validate_alphabet("ab0c", BASE58_ALPHABET)
+				.unwrap_err()
+				.message,
+			"invalid character at position 2"
+		);
+	}
+
+	#[test]
+	fn test_validate_alphabet_base62() {
+		assert!(validate_alphabet("0OIl", BASE62_ALPHABET).is_ok());
+		assert!(validate_alphabet("abc-def", BASE62_ALPHABET).is_err());
+	}
+
+	#[test]
+	fn test_validate_alphabet_custom() {
+		assert!(validate_alphabet("ACGTTGCA".to_string(), "ACGT").is_ok());
+		assert!(validate_alphabet("ACGU", "ACGT").is_err());
+		assert!(validate_alphabet("ñé", "éñ").is_ok());
+		assert_eq!(
+			validate_alphabet("aé", "aeé").unwrap(),
+			"aé",
+			"multi-byte characters are matched as a whole"
+		);
+	}
+
+	#[test]
+	fn test_alphabets_have_unique_characters() {
+		for alphabet in [BASE58_ALPHABET, BASE62_ALPHABET] {
+			let mut chars = alphabet.chars().collect::<Vec<_>>();
+			chars.sort_unstable();
+			chars.dedup();
+			assert_eq!(chars.len(), alphabet.len());
+		}
+		assert_eq!(BASE58_ALPHABET.len(), 58);
+		assert_eq!(BASE62_ALPHABET.len(), 62);
+	}
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::needless_lifetimes)]
+
 use std::{
 	borrow::Cow,
 	collections::{BTreeMap, BTreeSet, HashMap, HashSet},
@@ -163,6 +165,46 @@ where
 	}
 }
 
+#[cfg(feature = "indexmap")]
+impl<K, V, S> Contains for &indexmap::IndexMap<K, V, S>
+where
+	K: Display,
+{
+	fn contains(&self, needle: &str) -> bool {
+		self.keys().any(|v| v.to_string() == needle)
+	}
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> Contains for indexmap::IndexMap<K, V, S>
+where
+	K: Display,
+{
+	fn contains(&self, needle: &str) -> bool {
+		self.keys().any(|v| v.to_string() == needle)
+	}
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, S> Contains for &indexmap::IndexSet<T, S>
+where
+	T: Display,
+{
+	fn contains(&self, needle: &str) -> bool {
+		self.iter().any(|v| v.to_string() == needle)
+	}
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, S> Contains for indexmap::IndexSet<T, S>
+where
+	T: Display,
+{
+	fn contains(&self, needle: &str) -> bool {
+		self.iter().any(|v| v.to_string() == needle)
+	}
+}
+
 /// Validates whether the value contains the needle
 /// The value needs to implement the Contains trait, which is implement on
 /// [`String`], [`str`], [`Vec`], [`HashMap<String>`] and [`BTreeMap<String>`]
@@ -182,6 +224,7 @@ pub fn validate_contains<T: Contains>(
 }
 
 #[cfg(test)]
+#[allow(clippy::nonminimal_bool)]
 mod tests {
 	use std::{borrow::Cow, collections::HashMap};
 
@@ -236,4 +279,16 @@ mod tests {
 			[("hey", ())].into_iter().collect();
 		assert!(!validate_contains(test, "o").is_ok());
 	}
+
+	#[test]
+	#[cfg(feature = "indexmap")]
+	fn test_validate_contains_indexmap() {
+		let test: indexmap::IndexMap<String, ()> =
+			[("hey".into(), ())].into_iter().collect();
+		assert!(validate_contains(&test, "hey").is_ok());
+		assert!(validate_contains(test, "o").is_err());
+		let test: indexmap::IndexSet<u32> = [1, 2, 3].into_iter().collect();
+		assert!(validate_contains(&test, "2").is_ok());
+		assert!(validate_contains(test, "4").is_err());
+	}
 }
@@ -0,0 +1,207 @@
+use std::{
+	collections::{BTreeSet, HashSet},
+	fmt::Debug,
+};
+
+use crate::utils::Error;
+
+/// Trait to check if a collection contains a given item.
+/// This is used by the `validate_contains_item` and
+/// `validate_does_not_contain_item` validators to check if the item is inside
+/// the given collection. Unlike [`Contains`](super::Contains), the items are
+/// compared using [`PartialEq`] instead of their string representation.
+///
+/// Implement this trait for your own collections if you want to use the
+/// `validate_contains_item` validator
+pub trait ContainsItem<T> {
+	/// Checks if the collection contains the given item
+	#[must_use = concat!(
+		"validation returns a new value instead of mutating the input.",
+		" The returned value will contain the validated value,",
+		" while the input will remain unchanged"
+	)]
+	fn contains_item(&self, item: &T) -> bool;
+}
+
+impl<T: PartialEq> ContainsItem<T> for Vec<T> {
+	fn contains_item(&self, item: &T) -> bool {
+		self.iter().any(|v| v == item)
+	}
+}
+
+impl<T: PartialEq> ContainsItem<T> for &Vec<T> {
+	fn contains_item(&self, item: &T) -> bool {
+		self.iter().any(|v| v == item)
+	}
+}
+
+impl<T: PartialEq> ContainsItem<T> for &[T] {
+	fn contains_item(&self, item: &T) -> bool {
+		self.iter().any(|v| v == item)
+	}
+}
+
+impl<T: PartialEq, const N: usize> ContainsItem<T> for [T; N] {
+	fn contains_item(&self, item: &T) -> bool {
+		self.iter().any(|v| v == item)
+	}
+}
+
+impl<T: PartialEq, const N: usize> ContainsItem<T> for &[T; N] {
+	fn contains_item(&self, item: &T) -> bool {
+		self.iter().any(|v| v == item)
+	}
+}
+
+impl<T: PartialEq, S> ContainsItem<T> for HashSet<T, S> {
+	fn contains_item(&self, item: &T) -> bool {
+		self.iter().any(|v| v == item)
+	}
+}
+
+impl<T: PartialEq, S> ContainsItem<T> for &HashSet<T, S> {
+	fn contains_item(&self, item: &T) -> bool {
+		self.iter().any(|v| v == item)
+	}
+}
+
+impl<T: PartialEq> ContainsItem<T> for BTreeSet<T> {
+	fn contains_item(&self, item: &T) -> bool {
+		self.iter().any(|v| v == item)
+	}
+}
+
+impl<T: PartialEq> ContainsItem<T> for &BTreeSet<T> {
+	fn contains_item(&self, item: &T) -> bool {
+		self.iter().any(|v| v == item)
+	}
+}
+
+/// Validates that the given collection contains the given item. The items are
+/// compared using [`PartialEq`], so this works with any item type, like
+/// numbers, UUIDs or enums. The collection needs to implement the
+/// [`ContainsItem`] trait, which is implemented for [`Vec<T>`], `&[T]`,
+/// `[T; N]`, [`HashSet<T>`] and [`BTreeSet<T>`] by default.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct AssignRolesRequest {
+///     #[preprocess(contains_item = 1)]
+///     pub role_ids: Vec<u32>,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_contains_item<C, T>(value: C, item: &T) -> Result<C, Error>
+where
+	C: ContainsItem<T>,
+	T: Debug,
+{
+	value.contains_item(item).then_some(value).ok_or_else(|| {
+		Error::new(format!("value does not contain the item {:?}", item))
+	})
+}
+
+/// Validates that the given collection does not contain the given item.
+/// This is the opposite of [`validate_contains_item`].
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetPortsRequest {
+///     #[preprocess(does_not_contain_item = 0)]
+///     pub ports: Vec<u16>,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_does_not_contain_item<C, T>(
+	value: C,
+	item: &T,
+) -> Result<C, Error>
+where
+	C: ContainsItem<T>,
+	T: Debug,
+{
+	(!value.contains_item(item))
+		.then_some(value)
+		.ok_or_else(|| {
+			Error::new(format!("value must not contain the item {:?}", item))
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::{BTreeSet, HashSet};
+
+	use super::*;
+
+	#[derive(Debug, PartialEq)]
+	enum Role {
+		Admin,
+		User,
+	}
+
+	#[test]
+	fn test_validate_contains_item() {
+		assert!(validate_contains_item(vec![1, 2, 3], &2).is_ok());
+		assert!(validate_contains_item(&[1, 2, 3][..], &3).is_ok());
+		assert!(validate_contains_item([1, 2, 3], &1).is_ok());
+		assert_eq!(
+			validate_contains_item(vec![1, 2, 3], &4)
+				.unwrap_err()
+				.message,
+			"value does not contain the item 4"
+		);
+		assert!(validate_contains_item(Vec::<i32>::new(), &0).is_err());
+	}
+
+	#[test]
+	fn test_validate_contains_item_enum() {
+		assert!(validate_contains_item(vec![Role::User], &Role::User).is_ok());
+		assert_eq!(
+			validate_contains_item(vec![Role::User], &Role::Admin)
+				.unwrap_err()
+				.message,
+			"value does not contain the item Admin"
+		);
+	}
+
+	#[test]
+	fn test_validate_contains_item_sets() {
+		let set = [1, 2, 3].into_iter().collect::<HashSet<_>>();
+		assert!(validate_contains_item(&set, &1).is_ok());
+		assert!(validate_contains_item(set, &5).is_err());
+
+		let set = ["a", "b"].into_iter().collect::<BTreeSet<_>>();
+		assert!(validate_contains_item(&set, &"a").is_ok());
+		assert!(validate_contains_item(set, &"c").is_err());
+	}
+
+	#[test]
+	fn test_validate_does_not_contain_item() {
+		assert!(validate_does_not_contain_item(vec![1, 2, 3], &4).is_ok());
+		assert!(validate_does_not_contain_item(Vec::<i32>::new(), &0).is_ok());
+		assert_eq!(
+			validate_does_not_contain_item(vec![Role::Admin], &Role::Admin)
+				.unwrap_err()
+				.message,
+			"value must not contain the item Admin"
+		);
+		let set = [1, 2, 3].into_iter().collect::<HashSet<_>>();
+		assert!(validate_does_not_contain_item(set, &2).is_err());
+	}
+}
@@ -0,0 +1,82 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Validates that the given string does not contain any control characters.
+/// Control characters are matched using [`char::is_control`], like
+/// [`preprocess_strip_control_chars`](crate::preprocessors::preprocess_strip_control_chars),
+/// so tabs and newlines are rejected as well. The error message contains the
+/// first control character found and its position, counted in characters from
+/// zero. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetDisplayNameRequest {
+///     #[preprocess(no_control_characters)]
+///     pub display_name: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_no_control_chars<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let val = value.clone().into();
+	match val.chars().enumerate().find(|(_, ch)| ch.is_control()) {
+		Some((position, ch)) => Err(Error::new(format!(
+			"value must not contain control characters, found `U+{:04X}` at \
+			 position {}",
+			ch as u32, position
+		))),
+		None => Ok(value),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_no_control_chars() {
+		let test_cases = [
+			("John Doe", true),
+			("", true),
+			("日本語 text", true),
+			("zero\u{200B}width", true),
+			("John\u{7}Doe", false),
+			("\u{0}", false),
+			("line\nbreak", false),
+			("tab\tseparated", false),
+			("delete\u{7F}", false),
+			("c1\u{9B}", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_no_control_chars(input).is_ok(),
+				expected,
+				"{:?}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_no_control_chars_reports_position() {
+		assert_eq!(
+			validate_no_control_chars("日本\u{1B}[31m")
+				.unwrap_err()
+				.message,
+			"value must not contain control characters, found `U+001B` at \
+			 position 2"
+		);
+	}
+}
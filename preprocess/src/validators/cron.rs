@@ -0,0 +1,300 @@
+use std::{borrow::Cow, str::FromStr, time::Duration};
+
+use chrono::Utc;
+use cron::Schedule;
+
+use crate::utils::Error;
+
+/// The names of the fields of a cron expression, including the seconds
+const CRON_FIELDS: [&str; 6] = [
+	"second",
+	"minute",
+	"hour",
+	"day of month",
+	"month",
+	"day of week",
+];
+
+/// Converts the day of week field of a standard cron expression, where `0` and
+/// `7` are Sunday and `1` is Monday, into the numbering of the `cron` crate,
+/// where `1` is Sunday and `7` is Saturday. Numeric ranges and steps are
+/// expanded into a list of days, since a range like `5-7` (Friday to Sunday)
+/// wraps around in the crate's numbering. Elements with names like `MON-FRI`
+/// are kept as is, since names mean the same in both. Returns [`None`] if a
+/// numeric element is invalid.
+fn to_crate_day_of_week(field: &str) -> Option<String> {
+	if field == "*" || field == "?" {
+		return Some(field.to_string());
+	}
+
+	let elements = field
+		.split(',')
+		.map(|element| {
+			if element.chars().any(|ch| ch.is_ascii_alphabetic()) {
+				return Some(element.to_string());
+			}
+
+			let (range, step) = match element.split_once('/') {
+				Some((range, step)) => (range, Some(step.parse::<u8>().ok()?)),
+				None => (element, None),
+			};
+			let (start, end) = match range.split_once('-') {
+				_ if range == "*" => (0, 6),
+				Some((start, end)) => (start.parse().ok()?, end.parse().ok()?),
+				None => {
+					let start = range.parse::<u8>().ok()?;
+					(start, if step.is_some() { 7 } else { start })
+				}
+			};
+			if start > end || end > 7 || step == Some(0) {
+				return None;
+			}
+
+			let days = (start..=end)
+				.step_by(step.unwrap_or(1).into())
+				.map(|day| (day % 7 + 1).to_string())
+				.collect::<Vec<_>>();
+			Some(days.join(","))
+		})
+		.collect::<Option<Vec<_>>>()?;
+	Some(elements.join(","))
+}
+
+/// Validates that the given string is a valid cron expression, using the
+/// [`cron`](https://docs.rs/cron) crate. By default, only the standard 5-field
+/// expressions (`minute hour day-of-month month day-of-week`) are allowed. If
+/// `allow_seconds` is `true`, 6-field expressions with a leading seconds field
+/// are allowed as well. The day of week uses the standard numbering, where `0`
+/// and `7` are Sunday and `1` to `6` are Monday to Saturday, so `1-5` means
+/// Monday to Friday. If the expression is invalid, the error message mentions
+/// the field that is invalid.
+///
+/// If `next_run_within` is given, the expression must also fire within the
+/// given duration from now. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateJobRequest {
+///     #[preprocess(cron(allow_seconds = true, next_run_within = "1 day"))]
+///     pub schedule: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_cron<'a, T>(
+	value: T,
+	allow_seconds: bool,
+	next_run_within: Option<Duration>,
+) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let expression = value.clone().into();
+	let mut fields = expression
+		.split_whitespace()
+		.map(|field| field.to_string())
+		.collect::<Vec<_>>();
+	match fields.len() {
+		5 => fields.insert(0, "0".to_string()),
+		6 if allow_seconds => (),
+		_ if allow_seconds => {
+			return Err(Error::new("cron expression must have 5 or 6 fields"));
+		}
+		_ => return Err(Error::new("cron expression must have 5 fields")),
+	}
+
+	fields[5] = to_crate_day_of_week(&fields[5])
+		.ok_or_else(|| Error::new("invalid day of week field"))?;
+
+	let schedule = Schedule::from_str(&fields.join(" ")).map_err(|err| {
+		// Find the invalid field by parsing it alone, with every other field
+		// set to `*`
+		let invalid_field = fields.iter().enumerate().find(|(index, _)| {
+			let probe = fields
+				.iter()
+				.enumerate()
+				.map(
+					|(other, field)| {
+						if other == *index {
+							field.as_str()
+						} else {
+							"*"
+						}
+					},
+				)
+				.collect::<Vec<_>>()
+				.join(" ");
+			Schedule::from_str(&probe).is_err()
+		});
+		match invalid_field {
+			Some((index, _)) => Error::with_source(
+				format!("invalid {} field", CRON_FIELDS[index]),
+				err,
+			),
+			None => Error::with_source("invalid cron expression", err),
+		}
+	})?;
+
+	if let Some(within) = next_run_within {
+		let now = Utc::now();
+		let runs_within = schedule.after(&now).next().is_some_and(|next| {
+			(next - now).to_std().is_ok_and(|until| until <= within)
+		});
+		if !runs_within {
+			return Err(Error::new(format!(
+				"cron expression must run within {} seconds",
+				within.as_secs()
+			)));
+		}
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_cron() {
+		let test_cases = [
+			("* * * * *", true),
+			("0 0 * * *", true),
+			("*/15 9-17 * * Mon-Fri", true),
+			("0 0 1 1 *", true),
+			("0 0 * *", false),
+			("0 0 0 * * *", false),
+			("", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_cron(input, false, None).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_cron_allow_seconds() {
+		assert!(validate_cron("30 0 0 * * *", true, None).is_ok());
+		assert!(validate_cron("0 0 * * *", true, None).is_ok());
+		assert!(validate_cron("0 0 0 * * * *", true, None).is_err());
+	}
+
+	#[test]
+	fn test_validate_cron_invalid_field() {
+		assert_eq!(
+			validate_cron("0 25 * * *", false, None)
+				.unwrap_err()
+				.message,
+			"invalid hour field"
+		);
+		assert_eq!(
+			validate_cron("61 * * * *", false, None)
+				.unwrap_err()
+				.message,
+			"invalid minute field"
+		);
+		assert_eq!(
+			validate_cron("* * * Foo *", false, None)
+				.unwrap_err()
+				.message,
+			"invalid month field"
+		);
+		assert_eq!(
+			validate_cron("99 * * * * *", true, None)
+				.unwrap_err()
+				.message,
+			"invalid second field"
+		);
+	}
+
+	#[test]
+	fn test_validate_cron_day_of_week() {
+		let test_cases = [
+			("0 0 * * 0", true),
+			("0 0 * * 7", true),
+			("0 0 * * 1-5", true),
+			("0 0 * * MON-FRI", true),
+			("0 0 * * 5-7", true),
+			("0 0 * * 0,6", true),
+			("0 0 * * */2", true),
+			("0 0 * * 8", false),
+			("0 0 * * 5-1", false),
+			("0 0 * * 1/0", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_cron(input, false, None).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+		assert_eq!(
+			validate_cron("0 0 * * 8", false, None).unwrap_err().message,
+			"invalid day of week field"
+		);
+	}
+
+	#[test]
+	fn test_to_crate_day_of_week() {
+		let test_cases = [
+			("0", "1"),
+			("7", "1"),
+			("1-5", "2,3,4,5,6"),
+			("5-7", "6,7,1"),
+			("*/2", "1,3,5,7"),
+			("1,3", "2,4"),
+			("MON-FRI", "MON-FRI"),
+			("*", "*"),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				to_crate_day_of_week(input).as_deref(),
+				Some(expected),
+				"{}",
+				input
+			);
+		}
+
+		// `1-5` and `MON-FRI` must fire on the same days
+		let weekdays = |expression: &str| {
+			Schedule::from_str(expression)
+				.unwrap()
+				.upcoming(Utc)
+				.take(7)
+				.map(|next| next.format("%a").to_string())
+				.collect::<Vec<_>>()
+		};
+		assert_eq!(
+			weekdays(&format!(
+				"0 0 0 * * {}",
+				to_crate_day_of_week("1-5").unwrap()
+			)),
+			weekdays("0 0 0 * * MON-FRI")
+		);
+		assert!(!weekdays("0 0 0 * * MON-FRI").contains(&"Sun".to_string()));
+	}
+
+	#[test]
+	fn test_validate_cron_next_run_within() {
+		let hour = Duration::from_secs(60 * 60);
+		assert!(validate_cron("* * * * *", false, Some(hour)).is_ok());
+		assert!(validate_cron("0 * * * *", false, Some(hour)).is_ok());
+		// Only runs on February 30th, which never happens
+		assert!(validate_cron("0 0 30 2 *", false, Some(hour)).is_err());
+	}
+}
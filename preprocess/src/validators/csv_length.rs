@@ -0,0 +1,95 @@
+use crate::utils::Error;
+
+/// Validates the number of items in the given delimiter-separated string, like
+/// `a,b,c`. An empty string has no items, while every other string has one
+/// more item than the number of delimiters in it, so `a,,b` has 3 items. The
+/// `min` and `max` parameters are optional and will only be validated if they
+/// are not `None`.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SearchRequest {
+///     #[preprocess(csv_length(min = 1, max = 5))]
+///     pub tags: String,
+///     #[preprocess(csv_length(max = 3, delimiter = ";"))]
+///     pub sort: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_csv_length<T>(
+	value: T,
+	delimiter: char,
+	min: Option<usize>,
+	max: Option<usize>,
+) -> Result<T, Error>
+where
+	T: AsRef<str>,
+{
+	let item_count = if value.as_ref().is_empty() {
+		0
+	} else {
+		value.as_ref().split(delimiter).count()
+	};
+
+	if let Some(min) = min {
+		if item_count < min {
+			return Err(Error::new(format!(
+				"value must have at least {} items",
+				min
+			)));
+		}
+	}
+
+	if let Some(max) = max {
+		if item_count > max {
+			return Err(Error::new(format!(
+				"value must have at most {} items",
+				max
+			)));
+		}
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_csv_length() {
+		assert_eq!(
+			validate_csv_length("a,b,c", ',', Some(3), Some(3)),
+			Ok("a,b,c")
+		);
+		assert!(validate_csv_length("a", ',', Some(1), None).is_ok());
+		assert!(validate_csv_length("", ',', Some(1), None).is_err());
+		assert!(validate_csv_length("", ',', None, Some(0)).is_ok());
+		assert!(validate_csv_length("a,,b", ',', None, Some(2)).is_err());
+		assert!(validate_csv_length("a,b;c", ';', None, Some(2)).is_ok());
+	}
+
+	#[test]
+	fn test_validate_csv_length_messages() {
+		assert_eq!(
+			validate_csv_length("a", ',', Some(2), None)
+				.unwrap_err()
+				.message,
+			"value must have at least 2 items"
+		);
+		assert_eq!(
+			validate_csv_length("a,b,c", ',', None, Some(2))
+				.unwrap_err()
+				.message,
+			"value must have at most 2 items"
+		);
+	}
+}
@@ -0,0 +1,151 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// The currency codes of ISO 4217 that are currently in use, including the
+/// fund codes (like `BOV`), precious metals (like `XAU`) and the codes
+/// reserved for testing (`XTS`) and for transactions without a currency
+/// (`XXX`). Sorted alphabetically.
+pub const ACTIVE_CURRENCY_CODES: &[&str] = &[
+	"AED", "AFN", "ALL", "AMD", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM",
+	"BBD", "BDT", "BGN", "BHD", "BIF", "BMD", "BND", "BOB", "BOV", "BRL",
+	"BSD", "BTN", "BWP", "BYN", "BZD", "CAD", "CDF", "CHE", "CHF", "CHW",
+	"CLF", "CLP", "CNY", "COP", "COU", "CRC", "CUC", "CUP", "CVE", "CZK",
+	"DJF", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP",
+	"GBP", "GEL", "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL",
+	"HTG", "HUF", "IDR", "ILS", "INR", "IQD", "IRR", "ISK", "JMD", "JOD",
+	"JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW", "KWD", "KYD", "KZT",
+	"LAK", "LBP", "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD",
+	"MMK", "MNT", "MOP", "MRU", "MUR", "MVR", "MWK", "MXN", "MXV", "MYR",
+	"MZN", "NAD", "NGN", "NIO", "NOK", "NPR", "NZD", "OMR", "PAB", "PEN",
+	"PGK", "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD", "RUB", "RWF",
+	"SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SLL", "SOS",
+	"SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TND",
+	"TOP", "TRY", "TTD", "TWD", "TZS", "UAH", "UGX", "USD", "USN", "UYI",
+	"UYU", "UYW", "UZS", "VED", "VES", "VND", "VUV", "WST", "XAF", "XAG",
+	"XAU", "XBA", "XBB", "XBC", "XBD", "XCD", "XCG", "XDR", "XOF", "XPD",
+	"XPF", "XPT", "XSU", "XTS", "XUA", "XXX", "YER", "ZAR", "ZMW", "ZWG",
+];
+
+/// The historical currency codes of ISO 4217, which have been withdrawn from
+/// use, like `DEM` (German mark) or `DDM` (East German mark). Sorted
+/// alphabetically.
+pub const HISTORICAL_CURRENCY_CODES: &[&str] = &[
+	"ADP", "AFA", "ALK", "ANG", "AOK", "AON", "AOR", "ARA", "ARP", "ARY",
+	"ATS", "AYM", "AZM", "BAD", "BEC", "BEF", "BEL", "BGJ", "BGK", "BGL",
+	"BOP", "BRB", "BRC", "BRE", "BRN", "BRR", "BUK", "BYB", "BYR", "CHC",
+	"CSD", "CSJ", "CSK", "CYP", "DDM", "DEM", "ECS", "ECV", "EEK", "ESA",
+	"ESB", "ESP", "FIM", "FRF", "GEK", "GHC", "GHP", "GNE", "GNS", "GQE",
+	"GRD", "GWE", "GWP", "HRD", "HRK", "IEP", "ILP", "ILR", "ISJ", "ITL",
+	"LAJ", "LSM", "LTL", "LTT", "LUC", "LUF", "LUL", "LVL", "LVR", "MGF",
+	"MLF", "MRO", "MTL", "MTP", "MVQ", "MXP", "MZE", "MZM", "NIC", "NLG",
+	"PEH", "PEI", "PES", "PLZ", "PTE", "RHD", "ROK", "ROL", "RUR", "SDD",
+	"SDP", "SIT", "SKK", "SRG", "STD", "SUR", "TJR", "TMM", "TPE", "TRL",
+	"UAK", "UGS", "UGW", "USS", "UYN", "UYP", "VEB", "VEF", "VNC", "XEU",
+	"XFO", "XFU", "XRE", "YDD", "YUD", "YUM", "YUN", "ZAL", "ZMK", "ZRN",
+	"ZRZ", "ZWC", "ZWD", "ZWL", "ZWN", "ZWR",
+];
+
+/// Validates that the given string is an ISO 4217 currency code, like `USD` or
+/// `EUR`. Codes are matched case-sensitively, so lowercase values need to be
+/// converted using
+/// [`preprocess_normalize_currency_code`](crate::preprocessors::preprocess_normalize_currency_code)
+/// first. Historical currencies in [`HISTORICAL_CURRENCY_CODES`], like `DEM`,
+/// are accepted unless `active_only` is `true`, in which case only the codes
+/// in [`ACTIVE_CURRENCY_CODES`] are accepted. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreatePaymentRequest {
+///     #[preprocess(normalize_currency_code, currency_code(active_only = true))]
+///     pub currency: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_currency_code<'a, T>(
+	value: T,
+	active_only: bool,
+) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let code = value.clone().into();
+	if ACTIVE_CURRENCY_CODES.binary_search(&code.as_ref()).is_ok() {
+		return Ok(value);
+	}
+
+	if HISTORICAL_CURRENCY_CODES
+		.binary_search(&code.as_ref())
+		.is_ok()
+	{
+		if active_only {
+			return Err(Error::new(format!(
+				"currency code `{}` is no longer in use",
+				code
+			)));
+		}
+		return Ok(value);
+	}
+
+	Err(Error::new("value must be an ISO 4217 currency code"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_currency_codes_are_sorted() {
+		for codes in [ACTIVE_CURRENCY_CODES, HISTORICAL_CURRENCY_CODES] {
+			assert!(codes.windows(2).all(|pair| pair[0] < pair[1]));
+		}
+	}
+
+	#[test]
+	fn test_validate_currency_code() {
+		let test_cases = [
+			("USD", false, true),
+			("EUR", false, true),
+			("JPY", true, true),
+			("XAU", true, true),
+			("VES", true, true),
+			("DDM", false, true),
+			("DEM", false, true),
+			("DDM", true, false),
+			("HRK", true, false),
+			("usd", false, false),
+			("Usd", false, false),
+			("US", false, false),
+			("USDD", false, false),
+			("ABC", false, false),
+			(" USD", false, false),
+			("", false, false),
+		];
+
+		for (input, active_only, expected) in test_cases {
+			assert_eq!(
+				validate_currency_code(input, active_only).is_ok(),
+				expected,
+				"{} {}",
+				input,
+				active_only
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_currency_code_historical_error_message() {
+		assert_eq!(
+			validate_currency_code("DDM", true).unwrap_err().message,
+			"currency code `DDM` is no longer in use"
+		);
+	}
+}
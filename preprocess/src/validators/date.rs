@@ -0,0 +1,341 @@
+use std::borrow::Cow;
+
+use chrono::{Local, NaiveDate};
+
+use crate::utils::Error;
+
+/// Validates that the given string is a date in the given format, using
+/// [`NaiveDate::parse_from_str`]. See [`chrono::format::strftime`] for the
+/// supported format specifiers. The `min` and `max` parameters are optional
+/// and will only be validated if they are not `None`. Both of them are
+/// inclusive. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateEventRequest {
+///     #[preprocess(date(format = "%Y-%m-%d", min = "2000-01-01"))]
+///     pub date: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_date<'a, T>(
+	value: T,
+	format: &str,
+	min: Option<NaiveDate>,
+	max: Option<NaiveDate>,
+) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	validate_date_as_date(value.clone(), format, min, max)?;
+	Ok(value)
+}
+
+/// Validates that the given string is a date in the given format, the same way
+/// as [`validate_date`], and returns the parsed date. This is used for
+/// `#[preprocess(date(as_date = true))]`, which changes the type of the field
+/// to [`NaiveDate`].
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_date_as_date<'a, T>(
+	value: T,
+	format: &str,
+	min: Option<NaiveDate>,
+	max: Option<NaiveDate>,
+) -> Result<NaiveDate, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let date = NaiveDate::parse_from_str(&value.into(), format)
+		.map_err(|err| Error::with_source("invalid date", err))?;
+
+	if let Some(min) = min {
+		if date < min {
+			return Err(Error::new(format!(
+				"date must be on or after {}",
+				min.format(format)
+			)));
+		}
+	}
+
+	if let Some(max) = max {
+		if date > max {
+			return Err(Error::new(format!(
+				"date must be on or before {}",
+				max.format(format)
+			)));
+		}
+	}
+
+	Ok(date)
+}
+
+/// Validates that the given date is in the future, which means it is after
+/// today's date in the local time zone. Use `date(as_date = true)` to parse a
+/// string into a [`NaiveDate`] first.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateEventRequest {
+///     #[preprocess(date(as_date = true), future_date)]
+///     pub date: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_future_date(value: NaiveDate) -> Result<NaiveDate, Error> {
+	if value <= Local::now().date_naive() {
+		return Err(Error::new("date must be in the future"));
+	}
+
+	Ok(value)
+}
+
+/// Validates that the given date is in the past, which means it is before
+/// today's date in the local time zone. Use `date(as_date = true)` to parse a
+/// string into a [`NaiveDate`] first.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateUserRequest {
+///     #[preprocess(date(as_date = true), past_date)]
+///     pub date_of_birth: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_past_date(value: NaiveDate) -> Result<NaiveDate, Error> {
+	if value >= Local::now().date_naive() {
+		return Err(Error::new("date must be in the past"));
+	}
+
+	Ok(value)
+}
+
+/// Calculates the age in completed years of someone born on the given date,
+/// as of today's date in the local time zone. Dates in the future have an age
+/// of `0`.
+///
+/// # Example
+/// ```rust
+/// use preprocess::{types::NaiveDate, validators::calculate_age};
+///
+/// let birthdate = NaiveDate::from_ymd_opt(1990, 1, 1).unwrap();
+/// assert!(calculate_age(birthdate) >= 30);
+/// ```
+pub fn calculate_age(birthdate: NaiveDate) -> u32 {
+	Local::now()
+		.date_naive()
+		.years_since(birthdate)
+		.unwrap_or(0)
+}
+
+/// Validates that someone born on the given date is at least the given
+/// number of years old. Use `date(as_date = true)` to parse a string into a
+/// [`NaiveDate`] first.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateUserRequest {
+///     #[preprocess(date(as_date = true), min_age = 18)]
+///     pub date_of_birth: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_min_age(
+	value: NaiveDate,
+	years: u32,
+) -> Result<NaiveDate, Error> {
+	if calculate_age(value) < years {
+		return Err(Error::new(format!(
+			"must be at least {} years old",
+			years
+		)));
+	}
+
+	Ok(value)
+}
+
+/// Validates that someone born on the given date is at most the given number
+/// of years old. Use `date(as_date = true)` to parse a string into a
+/// [`NaiveDate`] first.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateUserRequest {
+///     #[preprocess(date(as_date = true), max_age = 120)]
+///     pub date_of_birth: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_max_age(
+	value: NaiveDate,
+	years: u32,
+) -> Result<NaiveDate, Error> {
+	if calculate_age(value) > years {
+		return Err(Error::new(format!("must be at most {} years old", years)));
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{Days, Months};
+
+	use super::*;
+
+	#[test]
+	fn test_validate_date() {
+		let test_cases = [
+			("2024-02-29", true),
+			("1999-12-31", true),
+			("2023-02-29", false),
+			("2024-13-01", false),
+			("2024-1-1", true),
+			("24-01-01", true),
+			("01/01/2024", false),
+			("2024-01-01T00:00:00", false),
+			("", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_date(input, "%Y-%m-%d", None, None).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_date_custom_format() {
+		assert!(validate_date("31/12/2024", "%d/%m/%Y", None, None).is_ok());
+		assert!(validate_date("2024-12-31", "%d/%m/%Y", None, None).is_err());
+	}
+
+	#[test]
+	fn test_validate_date_range() {
+		let min = NaiveDate::from_ymd_opt(2000, 1, 1);
+		let max = NaiveDate::from_ymd_opt(2099, 12, 31);
+		assert!(validate_date("2000-01-01", "%Y-%m-%d", min, max).is_ok());
+		assert!(validate_date("2099-12-31", "%Y-%m-%d", min, max).is_ok());
+		assert_eq!(
+			validate_date("1999-12-31", "%Y-%m-%d", min, max)
+				.unwrap_err()
+				.message,
+			"date must be on or after 2000-01-01"
+		);
+		assert_eq!(
+			validate_date("2100-01-01", "%Y-%m-%d", min, max)
+				.unwrap_err()
+				.message,
+			"date must be on or before 2099-12-31"
+		);
+	}
+
+	#[test]
+	fn test_validate_date_as_date() {
+		assert_eq!(
+			validate_date_as_date("2024-02-29", "%Y-%m-%d", None, None),
+			Ok(NaiveDate::from_ymd_opt(2024, 2, 29).unwrap())
+		);
+	}
+
+	#[test]
+	fn test_validate_future_date() {
+		let today = Local::now().date_naive();
+		let tomorrow = today.succ_opt().unwrap();
+		assert_eq!(validate_future_date(tomorrow), Ok(tomorrow));
+		assert!(validate_future_date(today).is_err());
+		assert!(validate_future_date(today.pred_opt().unwrap()).is_err());
+	}
+
+	#[test]
+	fn test_validate_past_date() {
+		let today = Local::now().date_naive();
+		let yesterday = today.pred_opt().unwrap();
+		assert_eq!(validate_past_date(yesterday), Ok(yesterday));
+		assert!(validate_past_date(today).is_err());
+		assert!(validate_past_date(today.succ_opt().unwrap()).is_err());
+	}
+
+	fn years_ago(years: u32) -> NaiveDate {
+		Local::now()
+			.date_naive()
+			.checked_sub_months(Months::new(years * 12))
+			.unwrap()
+	}
+
+	#[test]
+	fn test_calculate_age() {
+		assert_eq!(calculate_age(years_ago(18)), 18);
+		assert_eq!(calculate_age(years_ago(18) + Days::new(1)), 17);
+		assert_eq!(calculate_age(years_ago(18) - Days::new(1)), 18);
+		assert_eq!(calculate_age(Local::now().date_naive() + Days::new(1)), 0);
+	}
+
+	#[test]
+	fn test_validate_min_age() {
+		assert!(validate_min_age(years_ago(18), 18).is_ok());
+		assert!(validate_min_age(years_ago(30), 18).is_ok());
+		assert_eq!(
+			validate_min_age(years_ago(18) + Days::new(1), 18)
+				.unwrap_err()
+				.message,
+			"must be at least 18 years old"
+		);
+	}
+
+	#[test]
+	fn test_validate_max_age() {
+		assert!(validate_max_age(years_ago(120), 120).is_ok());
+		assert!(validate_max_age(years_ago(121) + Days::new(1), 120).is_ok());
+		assert_eq!(
+			validate_max_age(years_ago(121), 120).unwrap_err().message,
+			"must be at most 120 years old"
+		);
+	}
+}
@@ -0,0 +1,212 @@
+use std::borrow::Cow;
+
+use chrono::{
+	format::ParseErrorKind,
+	DateTime,
+	Local,
+	NaiveDateTime,
+	TimeZone,
+	Utc,
+};
+
+use crate::utils::Error;
+
+/// Validates that the given string is a date and time in the given format,
+/// using [`NaiveDateTime::parse_from_str`], and returns the parsed value. See
+/// [`chrono::format::strftime`] for the supported format specifiers. Any
+/// offset in the value is ignored, so use [`validate_datetime_utc`] if the
+/// value can have an offset.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateEventRequest {
+///     #[preprocess(datetime(format = "%Y-%m-%d %H:%M"))]
+///     pub starts_at: String, // This type will be changed to NaiveDateTime
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_datetime<'a, T>(
+	value: T,
+	format: &str,
+) -> Result<NaiveDateTime, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	NaiveDateTime::parse_from_str(&value.into(), format)
+		.map_err(|err| Error::with_source("invalid date and time", err))
+}
+
+/// Validates that the given string is a date and time in the given format,
+/// and returns it in the given time zone. If the format has an offset, like
+/// `%z`, the value is converted from that offset to the time zone. Otherwise,
+/// the value is assumed to already be in the time zone. Returns an error if
+/// the value does not exist or is ambiguous in the time zone, like during
+/// daylight saving time transitions.
+fn parse_in_timezone<Tz>(
+	value: &str,
+	format: &str,
+	timezone: Tz,
+) -> Result<DateTime<Tz>, Error>
+where
+	Tz: TimeZone,
+{
+	match DateTime::parse_from_str(value, format) {
+		Ok(datetime) => Ok(datetime.with_timezone(&timezone)),
+		Err(err) if err.kind() == ParseErrorKind::NotEnough => {
+			validate_datetime(value, format)?
+				.and_local_timezone(timezone)
+				.single()
+				.ok_or_else(|| {
+					Error::new("date and time is ambiguous in the time zone")
+				})
+		}
+		Err(err) => Err(Error::with_source("invalid date and time", err)),
+	}
+}
+
+/// Validates that the given string is a date and time in the given format,
+/// and returns it in UTC. If the format has an offset, like `%z` or `%:z`, the
+/// value is converted from that offset to UTC. Otherwise, the value is assumed
+/// to already be in UTC.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateEventRequest {
+///     #[preprocess(datetime(format = "%Y-%m-%dT%H:%M:%S%:z", timezone = "UTC"))]
+///     pub starts_at: String, // This type will be changed to DateTime<Utc>
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_datetime_utc<'a, T>(
+	value: T,
+	format: &str,
+) -> Result<DateTime<Utc>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	parse_in_timezone(&value.into(), format, Utc)
+}
+
+/// Validates that the given string is a date and time in the given format,
+/// and returns it in the local time zone. If the format has an offset, like
+/// `%z` or `%:z`, the value is converted from that offset to the local time
+/// zone. Otherwise, the value is assumed to already be in the local time zone,
+/// and an error is returned if it does not exist or is ambiguous, like during
+/// daylight saving time transitions.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetReminderRequest {
+///     #[preprocess(datetime_local(format = "%Y-%m-%d %H:%M"))]
+///     pub remind_at: String, // This type will be changed to DateTime<Local>
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_datetime_local<'a, T>(
+	value: T,
+	format: &str,
+) -> Result<DateTime<Local>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	parse_in_timezone(&value.into(), format, Local)
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{NaiveDate, Timelike};
+
+	use super::*;
+
+	fn datetime(hour: u32, minute: u32) -> NaiveDateTime {
+		NaiveDate::from_ymd_opt(2024, 2, 29)
+			.unwrap()
+			.and_hms_opt(hour, minute, 0)
+			.unwrap()
+	}
+
+	#[test]
+	fn test_validate_datetime() {
+		let format = "%Y-%m-%dT%H:%M:%S";
+		assert_eq!(
+			validate_datetime("2024-02-29T10:30:00", format),
+			Ok(datetime(10, 30))
+		);
+		let test_cases = [
+			"2023-02-29T10:30:00",
+			"2024-02-29T24:00:00",
+			"2024-02-29",
+			"2024-02-29 10:30:00",
+			"",
+		];
+		for input in test_cases {
+			assert!(validate_datetime(input, format).is_err(), "{}", input);
+		}
+		assert_eq!(
+			validate_datetime("", format).unwrap_err().message,
+			"invalid date and time"
+		);
+	}
+
+	#[test]
+	fn test_validate_datetime_utc() {
+		assert_eq!(
+			validate_datetime_utc("2024-02-29T10:30:00", "%Y-%m-%dT%H:%M:%S"),
+			Ok(datetime(10, 30).and_utc())
+		);
+		assert_eq!(
+			validate_datetime_utc(
+				"2024-02-29T10:30:00+05:30",
+				"%Y-%m-%dT%H:%M:%S%:z"
+			),
+			Ok(datetime(5, 0).and_utc())
+		);
+		assert!(validate_datetime_utc(
+			"2024-02-29T10:30:00",
+			"%Y-%m-%dT%H:%M:%S%:z"
+		)
+		.is_err());
+		assert!(
+			validate_datetime_utc("2024-02-29", "%Y-%m-%dT%H:%M:%S").is_err()
+		);
+	}
+
+	#[test]
+	fn test_validate_datetime_local() {
+		let local =
+			validate_datetime_local("2024-02-29 10:30", "%Y-%m-%d %H:%M")
+				.unwrap();
+		assert_eq!((local.hour(), local.minute()), (10, 30));
+
+		let utc = validate_datetime_local(
+			"2024-02-29 10:30 +0000",
+			"%Y-%m-%d %H:%M %z",
+		)
+		.unwrap();
+		assert_eq!(utc.with_timezone(&Utc), datetime(10, 30).and_utc());
+	}
+}
@@ -44,7 +44,7 @@ where
 	}
 
 	idna::domain_to_ascii_cow(val.as_bytes(), idna::AsciiDenyList::URL)
-		.map_err(|err| Error::new(format!("invalid domain: {}", err)))?;
+		.map_err(|err| Error::with_source("invalid domain", err))?;
 
 	Ok(domain)
 }
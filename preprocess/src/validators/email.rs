@@ -13,6 +13,12 @@ static EMAIL_USER_REGEX: OnceLock<Regex> = OnceLock::new();
 /// [RFC 5322](https://tools.ietf.org/html/rfc5322) is not practical in most circumstances and allows email addresses
 /// that are unfamiliar to most users.
 ///
+/// If `allow_subaddressing` is `false`, email addresses with a sub-address
+/// (like `user+tag@example.com`) are rejected. To remove the sub-address
+/// instead, use the
+/// [`preprocess_strip_subaddress`](crate::preprocessors::preprocess_strip_subaddress)
+/// preprocessor before validating.
+///
 /// # Example
 /// ```rust
 /// use preprocess::prelude::*;
@@ -22,8 +28,8 @@ static EMAIL_USER_REGEX: OnceLock<Regex> = OnceLock::new();
 /// pub struct LoginRequest {
 ///     #[preprocess(email)]
 ///     pub email: String,
-///     #[preprocess(custom = "validate_password")]
-///     pub password: String,
+///     #[preprocess(email(allow_subaddressing = false))]
+///     pub recovery_email: String,
 /// }
 /// ```
 #[must_use = concat!(
@@ -31,7 +37,7 @@ static EMAIL_USER_REGEX: OnceLock<Regex> = OnceLock::new();
 	" The returned value will contain the validated value,",
 	" while the input will remain unchanged"
 )]
-pub fn validate_email<'a, T>(input: T) -> Result<T>
+pub fn validate_email<'a, T>(input: T, allow_subaddressing: bool) -> Result<T>
 where
 	T: Into<Cow<'a, str>> + Clone,
 {
@@ -59,12 +65,18 @@ where
 		return Err(Error::new("email has invalid username"));
 	}
 
-	validate_domain(domain_part)?;
+	if !allow_subaddressing && user_part.contains('+') {
+		return Err(Error::new("email must not contain a sub-address"));
+	}
+
+	validate_domain(domain_part)
+		.map_err(|err| Error::with_source("email has invalid domain", err))?;
 
 	Ok(input)
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
 	use super::validate_email;
 
@@ -125,7 +137,7 @@ mod tests {
 
 		for (input, expected) in tests {
 			assert_eq!(
-				validate_email(input).is_ok(),
+				validate_email(input, true).is_ok(),
 				expected,
 				"Email `{}` was not classified correctly",
 				input
@@ -136,22 +148,29 @@ mod tests {
 	#[test]
 	fn test_validate_email_cow() {
 		let test = "email@here.com";
-		assert!(validate_email(test).is_ok());
+		assert!(validate_email(test, true).is_ok());
 		let test = String::from("email@here.com");
-		assert!(validate_email(test).is_ok());
+		assert!(validate_email(test, true).is_ok());
 		let test = "a@[127.0.0.1]\n";
-		assert!(validate_email(test).is_err());
+		assert!(validate_email(test, true).is_err());
 		let test = String::from("a@[127.0.0.1]\n");
-		assert!(validate_email(test).is_err());
+		assert!(validate_email(test, true).is_err());
 	}
 
 	#[test]
 	fn test_validate_email_rfc5321() {
 		// 65 character local part
 		let test = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa@mail.com";
-		assert_eq!(validate_email(test).is_ok(), false);
+		assert_eq!(validate_email(test, true).is_ok(), false);
 		// 256 character domain part
 		let test = "a@aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa.com";
-		assert_eq!(validate_email(test).is_ok(), false);
+		assert_eq!(validate_email(test, true).is_ok(), false);
+	}
+
+	#[test]
+	fn test_validate_email_subaddressing() {
+		assert!(validate_email("user+tag@example.com", true).is_ok());
+		assert!(validate_email("user+tag@example.com", false).is_err());
+		assert!(validate_email("user@example.com", false).is_ok());
 	}
 }
@@ -0,0 +1,113 @@
+/// Derives [`PreprocessAsEnumVariant`](trait@PreprocessAsEnumVariant) on a
+/// fieldless enum, using the names of the variants.
+pub use preprocess_macro::PreprocessAsEnumVariant;
+
+use crate::utils::Error;
+
+/// A trait for enums whose variant names can be used as valid values of a
+/// string field, using `#[preprocess(enum_variant = "MyEnum")]`. This trait
+/// can be derived using `#[derive(PreprocessAsEnumVariant)]`, which keeps the
+/// list of valid values in sync with the enum.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[derive(PreprocessAsEnumVariant)]
+/// pub enum Role {
+///     Admin,
+///     Member,
+/// }
+///
+/// assert!(Role::is_valid_variant("Admin"));
+/// assert!(!Role::is_valid_variant("Guest"));
+/// ```
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` cannot be used with `enum_variant`",
+	note = "add `#[derive(PreprocessAsEnumVariant)]` to the enum `{Self}`"
+)]
+pub trait PreprocessAsEnumVariant {
+	/// The names of all the variants of the enum, in the order they are
+	/// declared
+	const VARIANTS: &'static [&'static str];
+
+	/// Returns `true` if the given string is the name of one of the variants
+	/// of the enum. The comparison is case-sensitive.
+	fn is_valid_variant(s: &str) -> bool {
+		Self::VARIANTS.contains(&s)
+	}
+}
+
+/// Validates that the given string is the name of one of the variants of the
+/// enum `E`, using [`PreprocessAsEnumVariant::is_valid_variant`]. The error
+/// message lists all the valid variant names.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[derive(PreprocessAsEnumVariant)]
+/// pub enum Role {
+///     Admin,
+///     Member,
+/// }
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct InviteUserRequest {
+///     #[preprocess(enum_variant = "Role")]
+///     pub role: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_enum_variant<E, T>(value: T) -> Result<T, Error>
+where
+	E: PreprocessAsEnumVariant,
+	T: AsRef<str>,
+{
+	if E::is_valid_variant(value.as_ref()) {
+		return Ok(value);
+	}
+
+	Err(Error::new(format!(
+		"value must be one of {}",
+		E::VARIANTS
+			.iter()
+			.map(|variant| format!("`{}`", variant))
+			.collect::<Vec<_>>()
+			.join(", ")
+	)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	enum Role {}
+
+	impl PreprocessAsEnumVariant for Role {
+		const VARIANTS: &'static [&'static str] = &["Admin", "Member"];
+	}
+
+	#[test]
+	fn test_validate_enum_variant() {
+		assert!(validate_enum_variant::<Role, _>("Admin").is_ok());
+		assert!(validate_enum_variant::<Role, _>("Member".to_string()).is_ok());
+		assert!(validate_enum_variant::<Role, _>("admin").is_err());
+		assert!(validate_enum_variant::<Role, _>("").is_err());
+	}
+
+	#[test]
+	fn test_validate_enum_variant_lists_variants() {
+		assert_eq!(
+			validate_enum_variant::<Role, _>("Guest")
+				.unwrap_err()
+				.message,
+			"value must be one of `Admin`, `Member`"
+		);
+	}
+}
@@ -0,0 +1,97 @@
+use num_traits::Float;
+
+use crate::utils::Error;
+
+/// Validates that the given floating point number is not `NaN`. Infinite
+/// values are allowed, so use [`validate_finite`] to reject them as well. The
+/// value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetThresholdRequest {
+///     #[preprocess(non_nan)]
+///     pub threshold: f64,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_non_nan<T>(value: T) -> Result<T, Error>
+where
+	T: Float,
+{
+	if value.is_nan() {
+		return Err(Error::new("value must be a number, not NaN"));
+	}
+
+	Ok(value)
+}
+
+/// Validates that the given floating point number is finite, which means that
+/// it is neither `NaN` nor infinite. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetLocationRequest {
+///     #[preprocess(finite, range(min = -90.0, max = 90.0))]
+///     pub latitude: f64,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_finite<T>(value: T) -> Result<T, Error>
+where
+	T: Float,
+{
+	if !value.is_finite() {
+		return Err(Error::new("value must be a finite number"));
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_non_nan() {
+		for input in [0.0, -1.5, f64::MAX, f64::INFINITY, f64::NEG_INFINITY] {
+			assert_eq!(validate_non_nan(input), Ok(input));
+		}
+		assert_eq!(validate_non_nan(1.5f32), Ok(1.5f32));
+		assert_eq!(
+			validate_non_nan(f64::NAN).unwrap_err().message,
+			"value must be a number, not NaN"
+		);
+		assert!(validate_non_nan(f32::NAN).is_err());
+	}
+
+	#[test]
+	fn test_validate_finite() {
+		for input in [0.0, -0.0, -1.5, f64::MAX, f64::MIN_POSITIVE] {
+			assert_eq!(validate_finite(input), Ok(input));
+		}
+		assert_eq!(validate_finite(1.5f32), Ok(1.5f32));
+		for input in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+			assert_eq!(
+				validate_finite(input).unwrap_err().message,
+				"value must be a finite number"
+			);
+		}
+		assert!(validate_finite(f32::INFINITY).is_err());
+	}
+}
@@ -0,0 +1,195 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// The case that the letters of a hexadecimal string are normalized to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexCase {
+	/// Converts `A-F` to `a-f`
+	Lowercase,
+	/// Converts `a-f` to `A-F`
+	Uppercase,
+}
+
+/// Returns the position of the first character of the given string that is
+/// not a hexadecimal digit, if any.
+fn find_non_hex(value: &str) -> Option<usize> {
+	value.chars().position(|ch| !ch.is_ascii_hexdigit())
+}
+
+/// Validates that the given string only contains hexadecimal digits, which
+/// are `0-9`, `a-f` and `A-F`. The value must not be empty. The value is not
+/// modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateApiKeyRequest {
+///     #[preprocess(hex)]
+///     pub key: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_hex<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let hex = value.clone().into();
+
+	if hex.is_empty() {
+		return Err(Error::new("hex string must not be empty"));
+	}
+	if let Some(position) = find_non_hex(&hex) {
+		return Err(Error::new(format!(
+			"invalid hex character at position {}",
+			position
+		)));
+	}
+
+	Ok(value)
+}
+
+/// Validates that the given string is a hexadecimal string, with the given
+/// options. If `prefix` is given, the value must start with the prefix, which
+/// is stripped from the returned value. If `length` is given, the value must
+/// have exactly that many hexadecimal digits, excluding the prefix. This is
+/// useful for hashes, like SHA-256 hashes which have 64 digits. If `case` is
+/// given, the letters of the value are converted to that case.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct VerifyTransactionRequest {
+///     #[preprocess(hex(prefix = "0x", length = 64, normalize = "lowercase"))]
+///     pub hash: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_hex_with_options<'a, T>(
+	value: T,
+	length: Option<usize>,
+	prefix: Option<&str>,
+	case: Option<HexCase>,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let mut hex = value.into();
+
+	if let Some(prefix) = prefix {
+		let Some(stripped) = hex.strip_prefix(prefix) else {
+			return Err(Error::new(format!(
+				"hex string must start with `{}`",
+				prefix
+			)));
+		};
+		hex = Cow::Owned(stripped.to_string());
+	}
+
+	let hex = validate_hex(hex)?;
+
+	if let Some(length) = length {
+		if hex.len() != length {
+			return Err(Error::new(format!(
+				"hex string must have exactly {} digits",
+				length
+			)));
+		}
+	}
+
+	Ok(match case {
+		Some(HexCase::Lowercase) => hex.to_ascii_lowercase().into(),
+		Some(HexCase::Uppercase) => hex.to_ascii_uppercase().into(),
+		None => hex,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_hex() {
+		assert!(validate_hex("0123456789abcdefABCDEF").is_ok());
+		assert!(validate_hex("deadBEEF".to_string()).is_ok());
+		assert_eq!(
+			validate_hex("12g4").unwrap_err().message,
+			"invalid hex character at position 2"
+		);
+		assert!(validate_hex("0x12").is_err());
+		assert!(validate_hex(" 12").is_err());
+		assert_eq!(
+			validate_hex("").unwrap_err().message,
+			"hex string must not be empty"
+		);
+	}
+
+	#[test]
+	fn test_validate_hex_length() {
+		let hash = "a".repeat(64);
+		assert!(validate_hex_with_options(&*hash, Some(64), None, None).is_ok());
+		assert_eq!(
+			validate_hex_with_options("abc", Some(64), None, None)
+				.unwrap_err()
+				.message,
+			"hex string must have exactly 64 digits"
+		);
+	}
+
+	#[test]
+	fn test_validate_hex_prefix() {
+		assert_eq!(
+			validate_hex_with_options("0xFF", None, Some("0x"), None),
+			Ok(Cow::Borrowed("FF"))
+		);
+		assert_eq!(
+			validate_hex_with_options("FF", None, Some("0x"), None)
+				.unwrap_err()
+				.message,
+			"hex string must start with `0x`"
+		);
+		assert!(
+			validate_hex_with_options("0x", None, Some("0x"), None).is_err()
+		);
+		assert_eq!(
+			validate_hex_with_options("0xabcd", Some(4), Some("0x"), None),
+			Ok(Cow::Borrowed("abcd"))
+		);
+	}
+
+	#[test]
+	fn test_validate_hex_case() {
+		assert_eq!(
+			validate_hex_with_options(
+				"DeadBeef",
+				None,
+				None,
+				Some(HexCase::Lowercase)
+			),
+			Ok(Cow::Borrowed("deadbeef"))
+		);
+		assert_eq!(
+			validate_hex_with_options(
+				"0xdeadbeef",
+				None,
+				Some("0x"),
+				Some(HexCase::Uppercase)
+			),
+			Ok(Cow::Borrowed("DEADBEEF"))
+		);
+	}
+}
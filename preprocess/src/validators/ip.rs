@@ -52,3 +52,489 @@ where
 		.parse()
 		.map_err(|err| Error::new(format!("invalid ip address: {}", err)))
 }
+
+/// Checks if a given string is a valid private IP address, and returns the
+/// parsed address. Private addresses are the RFC 1918 IPv4 ranges
+/// (`10.0.0.0/8`, `172.16.0.0/12` and `192.168.0.0/16`), IPv6 unique local
+/// addresses (`fc00::/7`), and loopback addresses. IPv4-mapped IPv6 addresses,
+/// like `::ffff:10.0.0.1`, are classified by the IPv4 address they contain.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct RegisterServiceRequest {
+///     #[preprocess(ip(scope = "private"))]
+///     pub address: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_private_ip<'a, T>(value: T) -> Result<IpAddr, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let address = validate_ip(value)?;
+	let unmapped = unmap_ipv4(address);
+	if !is_private_ip(&unmapped) && !unmapped.is_loopback() {
+		return Err(Error::new("ip address must be a private address"));
+	}
+
+	Ok(address)
+}
+
+/// Checks if a given string is a valid public IP address, and returns the
+/// parsed address. Private, loopback, link-local, unspecified, shared
+/// (`100.64.0.0/10`), multicast, broadcast and documentation addresses are
+/// rejected. IPv4-mapped IPv6 addresses, like `::ffff:127.0.0.1`, are
+/// classified by the IPv4 address they contain, so that they cannot be used to
+/// bypass the check.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct AddDnsRecordRequest {
+///     #[preprocess(ip(scope = "public"))]
+///     pub address: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_public_ip<'a, T>(value: T) -> Result<IpAddr, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let address = validate_ip(value)?;
+	let unmapped = unmap_ipv4(address);
+	let is_reserved = match unmapped {
+		IpAddr::V4(address) => {
+			address.is_link_local() ||
+				address.is_broadcast() ||
+				address.is_documentation() ||
+				// Shared address space used by carrier-grade NAT (RFC 6598)
+				(address.octets()[0] == 100 &&
+					(address.octets()[1] & 0b1100_0000) == 64)
+		}
+		IpAddr::V6(address) => {
+			address.is_unicast_link_local() ||
+				// Documentation prefix `2001:db8::/32` (RFC 3849)
+				(address.segments()[0] == 0x2001 &&
+					address.segments()[1] == 0x0db8)
+		}
+	};
+	if is_private_ip(&unmapped) ||
+		unmapped.is_loopback() ||
+		unmapped.is_unspecified() ||
+		unmapped.is_multicast() ||
+		is_reserved
+	{
+		return Err(Error::new("ip address must be a public address"));
+	}
+
+	Ok(address)
+}
+
+/// Checks if a given string is a valid IP address, and returns the parsed
+/// address. IPv4-mapped IPv6 addresses, like `::ffff:192.0.2.1`, are unwrapped
+/// to the IPv4 address they contain, which is useful for servers that receive
+/// IPv4 clients through IPv6 sockets. Other addresses are returned as is.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct LogVisitRequest {
+///     #[preprocess(ipv4_mapped)]
+///     pub client: String, // This type will be changed to IpAddr
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_ipv4_mapped<'a, T>(value: T) -> Result<IpAddr, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	Ok(unmap_ipv4(validate_ip(value)?))
+}
+
+/// Converts an IPv4-mapped IPv6 address into the IPv4 address it contains.
+/// Other addresses are returned as is.
+pub(crate) fn unmap_ipv4(address: IpAddr) -> IpAddr {
+	match address {
+		IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(address, IpAddr::V4),
+		IpAddr::V4(_) => address,
+	}
+}
+
+/// Checks if the address is in one of the RFC 1918 IPv4 ranges, or is an IPv6
+/// unique local address, which is the IPv6 equivalent of those ranges.
+fn is_private_ip(address: &IpAddr) -> bool {
+	match address {
+		IpAddr::V4(address) => address.is_private(),
+		IpAddr::V6(address) => address.is_unique_local(),
+	}
+}
+
+/// Checks if a given string is a valid IP network in CIDR notation, like
+/// `192.168.1.0/24` or `2001:db8::/32`. The prefix length must be between 0
+/// and 32 for IPv4 addresses, and between 0 and 128 for IPv6 addresses. If
+/// `strict` is `true`, the host bits of the address must be zero, so
+/// `192.168.1.5/24` is rejected. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct AddFirewallRuleRequest {
+///     #[preprocess(ip_cidr)]
+///     pub source: String,
+///     #[preprocess(ip_cidr(strict = false))]
+///     pub destination: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_ip_cidr<'a, T>(value: T, strict: bool) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	validate_ip_cidr_pair(value.clone(), strict)?;
+	Ok(value)
+}
+
+/// Checks if a given string is a valid IP network in CIDR notation, the same
+/// way as [`validate_ip_cidr`], and returns the address along with the prefix
+/// length. This is used for `#[preprocess(ip_cidr(as_pair = true))]`, which
+/// changes the type of the field to `(IpAddr, u8)`.
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_ip_cidr_pair<'a, T>(
+	value: T,
+	strict: bool,
+) -> Result<(IpAddr, u8), Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let value = value.into();
+	let Some((address, prefix)) = value.split_once('/') else {
+		return Err(Error::new("cidr is missing the prefix length"));
+	};
+
+	let address = validate_ip(address)?;
+	let prefix = prefix
+		.parse::<u8>()
+		.map_err(|err| Error::with_source("invalid cidr prefix length", err))?;
+
+	let host_bits_set = match address {
+		IpAddr::V4(address) => {
+			if prefix > 32 {
+				return Err(Error::new(
+					"cidr prefix length must be between 0 and 32",
+				));
+			}
+			u32::from(address)
+				.checked_shl(prefix.into())
+				.is_some_and(|host_bits| host_bits != 0)
+		}
+		IpAddr::V6(address) => {
+			if prefix > 128 {
+				return Err(Error::new(
+					"cidr prefix length must be between 0 and 128",
+				));
+			}
+			u128::from(address)
+				.checked_shl(prefix.into())
+				.is_some_and(|host_bits| host_bits != 0)
+		}
+	};
+	if strict && host_bits_set {
+		return Err(Error::new("cidr address must not have host bits set"));
+	}
+
+	Ok((address, prefix))
+}
+
+/// Checks if a given string is a valid IP address within the given network,
+/// and returns the parsed address. The network is given as an address and a
+/// prefix length, as returned by [`validate_ip_cidr_pair`]. An address is
+/// within the network if its first `prefix` bits are the same as the ones of
+/// the network address. IPv4-mapped IPv6 addresses, like `::ffff:10.1.2.3`,
+/// are checked as the IPv4 address they contain, and an IPv4 address is never
+/// within an IPv6 network (or the other way around). This is used for
+/// `#[preprocess(ip(network = "10.0.0.0/8"))]`, which parses the network only
+/// once.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct RegisterNodeRequest {
+///     #[preprocess(ip(network = "10.0.0.0/8"))]
+///     pub address: String, // This type will be changed to IpAddr
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_ip_in_network<'a, T>(
+	value: T,
+	network: (IpAddr, u8),
+) -> Result<IpAddr, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let address = validate_ip(value)?;
+	let (network_address, prefix) = network;
+
+	let is_within = match (unmap_ipv4(address), network_address) {
+		(IpAddr::V4(address), IpAddr::V4(network_address)) => {
+			let mask =
+				u32::MAX.checked_shl(32 - u32::from(prefix)).unwrap_or(0);
+			u32::from(address) & mask == u32::from(network_address) & mask
+		}
+		(IpAddr::V6(address), IpAddr::V6(network_address)) => {
+			let mask =
+				u128::MAX.checked_shl(128 - u32::from(prefix)).unwrap_or(0);
+			u128::from(address) & mask == u128::from(network_address) & mask
+		}
+		_ => false,
+	};
+	if !is_within {
+		return Err(Error::new(format!(
+			"IP address is not within the allowed network {}/{}",
+			network_address, prefix
+		)));
+	}
+
+	Ok(address)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_private_ip() {
+		let test_cases = [
+			("10.1.2.3", true),
+			("172.16.0.1", true),
+			("192.168.1.1", true),
+			("127.0.0.1", true),
+			("::1", true),
+			("fd12:3456::1", true),
+			("::ffff:10.0.0.1", true),
+			("::ffff:127.0.0.1", true),
+			("8.8.8.8", false),
+			("::ffff:8.8.8.8", false),
+			("172.32.0.1", false),
+			("169.254.0.1", false),
+			("2001:4860::8888", false),
+			("not-an-ip", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_private_ip(input).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_ipv4_mapped() {
+		let test_cases = [
+			("192.0.2.1", "192.0.2.1"),
+			("::ffff:192.0.2.1", "192.0.2.1"),
+			("::FFFF:c000:0201", "192.0.2.1"),
+			("2001:db8::1", "2001:db8::1"),
+			("::1", "::1"),
+			("::192.0.2.1", "::192.0.2.1"),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_ipv4_mapped(input),
+				Ok(expected.parse().unwrap()),
+				"{}",
+				input
+			);
+		}
+		assert!(validate_ipv4_mapped("::ffff:192.0.2.256").is_err());
+		assert!(validate_ipv4_mapped("not-an-ip").is_err());
+	}
+
+	#[test]
+	fn test_validate_public_ip() {
+		let test_cases = [
+			("8.8.8.8", true),
+			("1.1.1.1", true),
+			("2001:4860::8888", true),
+			("10.1.2.3", false),
+			("192.168.1.1", false),
+			("127.0.0.1", false),
+			("169.254.0.1", false),
+			("0.0.0.0", false),
+			("::", false),
+			("::1", false),
+			("fe80::1", false),
+			("fd12:3456::1", false),
+			("::ffff:127.0.0.1", false),
+			("::ffff:10.0.0.1", false),
+			("::ffff:169.254.169.254", false),
+			("::ffff:8.8.8.8", true),
+			("100.64.0.1", false),
+			("100.127.255.254", false),
+			("100.128.0.1", true),
+			("224.0.0.1", false),
+			("239.255.255.250", false),
+			("ff02::1", false),
+			("255.255.255.255", false),
+			("192.0.2.1", false),
+			("198.51.100.1", false),
+			("203.0.113.1", false),
+			("2001:db8::1", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_public_ip(input).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+		assert_eq!(
+			validate_public_ip("8.8.8.8"),
+			Ok(IpAddr::V4(Ipv4Addr::new(8, 8, 8, 8)))
+		);
+	}
+
+	#[test]
+	fn test_validate_ip_cidr() {
+		let test_cases = [
+			("192.168.1.0/24", true),
+			("10.0.0.0/8", true),
+			("0.0.0.0/0", true),
+			("192.168.1.5/32", true),
+			("2001:db8::/32", true),
+			("::/0", true),
+			("::1/128", true),
+			("192.168.1.5/24", false),
+			("2001:db8::1/64", false),
+			("192.168.1.0/33", false),
+			("2001:db8::/129", false),
+			("192.168.1.0", false),
+			("192.168.1.0/", false),
+			("192.168.1.0/-1", false),
+			("192.168.1/24", false),
+			("example.com/24", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_ip_cidr(input, true).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_ip_cidr_not_strict() {
+		assert_eq!(
+			validate_ip_cidr("192.168.1.5/24", false),
+			Ok("192.168.1.5/24")
+		);
+		assert!(validate_ip_cidr("192.168.1.5/33", false).is_err());
+	}
+
+	#[test]
+	fn test_validate_ip_cidr_pair() {
+		assert_eq!(
+			validate_ip_cidr_pair("10.0.0.0/8", true),
+			Ok((IpAddr::V4(Ipv4Addr::new(10, 0, 0, 0)), 8))
+		);
+		assert_eq!(
+			validate_ip_cidr_pair("fe80::/10", true),
+			Ok((IpAddr::V6("fe80::".parse().unwrap()), 10))
+		);
+	}
+
+	#[test]
+	fn test_validate_ip_in_network() {
+		let test_cases = [
+			("10.0.0.0/8", "10.0.0.1", true),
+			("10.0.0.0/8", "10.255.255.255", true),
+			("10.0.0.0/8", "11.0.0.1", false),
+			("10.0.0.0/8", "9.255.255.255", false),
+			("192.168.1.0/24", "192.168.1.42", true),
+			("192.168.1.0/24", "192.168.2.42", false),
+			("172.16.0.0/12", "172.31.255.255", true),
+			("172.16.0.0/12", "172.32.0.0", false),
+			("203.0.113.7/32", "203.0.113.7", true),
+			("203.0.113.7/32", "203.0.113.8", false),
+			("0.0.0.0/0", "8.8.8.8", true),
+			("0.0.0.0/0", "::1", false),
+			("10.0.0.0/8", "::ffff:10.1.2.3", true),
+			("10.0.0.0/8", "::ffff:11.1.2.3", false),
+			("fd00::/8", "fd12:3456::1", true),
+			("fd00::/8", "fe80::1", false),
+			("2001:db8::/32", "2001:db8:ffff::1", true),
+			("2001:db8::/32", "2001:db9::1", false),
+			("::/0", "2001:db8::1", true),
+			("::/0", "10.0.0.1", false),
+			("10.0.0.0/8", "not-an-ip", false),
+		];
+
+		for (network, input, expected) in test_cases {
+			let network = validate_ip_cidr_pair(network, true).unwrap();
+			assert_eq!(
+				validate_ip_in_network(input, network).is_ok(),
+				expected,
+				"{} {:?}",
+				input,
+				network
+			);
+		}
+		assert_eq!(
+			validate_ip_in_network(
+				"11.0.0.1",
+				validate_ip_cidr_pair("10.0.0.0/8", true).unwrap()
+			)
+			.unwrap_err()
+			.message,
+			"IP address is not within the allowed network 10.0.0.0/8"
+		);
+	}
+}
@@ -0,0 +1,156 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// The version of an ISBN (International Standard Book Number).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IsbnFormat {
+	/// A 10 digit ISBN, used for books published before 2007. The last digit
+	/// can be `X`, which stands for 10.
+	Isbn10,
+	/// A 13 digit ISBN, used for books published since 2007.
+	Isbn13,
+}
+
+/// Strips hyphens and spaces from the given ISBN and validates its check
+/// digit. Returns the format of the ISBN along with the stripped value.
+pub(crate) fn parse_isbn(value: &str) -> Result<(IsbnFormat, String), Error> {
+	let isbn = value
+		.chars()
+		.filter(|ch| *ch != '-' && *ch != ' ')
+		.map(|ch| ch.to_ascii_uppercase())
+		.collect::<String>();
+
+	let format = match isbn.len() {
+		10 => IsbnFormat::Isbn10,
+		13 => IsbnFormat::Isbn13,
+		_ => return Err(Error::new("isbn must have 10 or 13 digits")),
+	};
+
+	let digits = isbn
+		.chars()
+		.enumerate()
+		.map(|(index, ch)| match ch {
+			// Only the check digit of an ISBN-10 can be `X`
+			'X' if format == IsbnFormat::Isbn10 && index == 9 => Some(10),
+			ch => ch.to_digit(10),
+		})
+		.collect::<Option<Vec<_>>>()
+		.ok_or_else(|| Error::new("isbn contains invalid characters"))?;
+
+	let is_valid = match format {
+		IsbnFormat::Isbn10 => {
+			digits
+				.iter()
+				.zip((1..=10).rev())
+				.map(|(digit, weight)| digit * weight)
+				.sum::<u32>() %
+				11 == 0
+		}
+		IsbnFormat::Isbn13 => {
+			digits
+				.iter()
+				.zip([1, 3].into_iter().cycle())
+				.map(|(digit, weight)| digit * weight)
+				.sum::<u32>() %
+				10 == 0
+		}
+	};
+	if !is_valid {
+		return Err(Error::new("isbn has an invalid check digit"));
+	}
+
+	Ok((format, isbn))
+}
+
+/// Validates that the given string is a valid ISBN-10 or ISBN-13, by checking
+/// its check digit. Hyphens and spaces are ignored. If `format` is given, only
+/// ISBNs of that version are allowed. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct AddBookRequest {
+///     #[preprocess(isbn)]
+///     pub isbn: String,
+///     #[preprocess(isbn(format = "isbn13"))]
+///     pub isbn13: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_isbn<'a, T>(
+	value: T,
+	format: Option<IsbnFormat>,
+) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let (parsed_format, _) = parse_isbn(&value.clone().into())?;
+	match format {
+		Some(IsbnFormat::Isbn10) if parsed_format != IsbnFormat::Isbn10 => {
+			Err(Error::new("isbn must be an ISBN-10"))
+		}
+		Some(IsbnFormat::Isbn13) if parsed_format != IsbnFormat::Isbn13 => {
+			Err(Error::new("isbn must be an ISBN-13"))
+		}
+		_ => Ok(value),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_isbn() {
+		let test_cases = [
+			("0-306-40615-2", true),
+			("0306406152", true),
+			("0 306 40615 2", true),
+			("0-8044-2957-X", true),
+			("0-8044-2957-x", true),
+			("978-0-306-40615-7", true),
+			("9780306406157", true),
+			("978-3-16-148410-0", true),
+			("979-10-90636-07-1", true),
+			("0-306-40615-3", false),
+			("978-0-306-40615-8", false),
+			("X-306-40615-2", false),
+			("978-0-306-40615-X", false),
+			("0-306-40615", false),
+			("978-0-306-40615-77", false),
+			("abcdefghij", false),
+			("", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_isbn(input, None).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_isbn_format() {
+		assert!(
+			validate_isbn("0-306-40615-2", Some(IsbnFormat::Isbn10)).is_ok()
+		);
+		assert!(
+			validate_isbn("0-306-40615-2", Some(IsbnFormat::Isbn13)).is_err()
+		);
+		assert!(validate_isbn("978-0-306-40615-7", Some(IsbnFormat::Isbn13))
+			.is_ok());
+		assert!(validate_isbn("978-0-306-40615-7", Some(IsbnFormat::Isbn10))
+			.is_err());
+	}
+}
@@ -0,0 +1,146 @@
+use std::borrow::Cow;
+
+use jsonpath_rust::{parser::parse_json_path, JsonPath};
+use serde_json::Value;
+
+use crate::utils::Error;
+
+/// Validates that the given string is a valid JSONPath expression, like
+/// `$.items[*].name`, using the
+/// [`jsonpath-rust`](https://docs.rs/jsonpath-rust) crate. The value is not
+/// modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateExtractorRequest {
+///     #[preprocess(valid_json_path)]
+///     pub selector: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_json_path<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	parse_json_path(&value.clone().into()).map_err(|err| {
+		Error::with_source("invalid JSONPath expression", err)
+	})?;
+
+	Ok(value)
+}
+
+/// Validates that the given string is valid JSON, and that the given JSONPath
+/// expression selects at least one element from it. The value is not
+/// modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct ImportUserRequest {
+///     #[preprocess(json(json_path = "$.name"))]
+///     pub payload: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_json_matches_path<'a, T>(
+	value: T,
+	json_path: &str,
+) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let json = serde_json::from_str::<Value>(&value.clone().into())
+		.map_err(|err| Error::with_source("invalid JSON", err))?;
+
+	let selected = json.query(json_path).map_err(|err| {
+		Error::with_source("invalid JSONPath expression", err)
+	})?;
+	if selected.is_empty() {
+		return Err(Error::new(format!(
+			"JSON must contain a value at `{}`",
+			json_path
+		)));
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_json_path() {
+		let test_cases = [
+			("$", true),
+			("$.name", true),
+			("$.items[*].name", true),
+			("$..price", true),
+			("$.items[?@.price > 10]", true),
+			("$.items[0:2]", true),
+			("", false),
+			("name", false),
+			("$.items[", false),
+			("$..", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_json_path(input).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+		assert_eq!(
+			validate_json_path("$.items[").unwrap_err().message,
+			"invalid JSONPath expression"
+		);
+	}
+
+	#[test]
+	fn test_validate_json_matches_path() {
+		let json = r#"{"name": "Alice", "items": [{"id": 1}, {"id": 2}]}"#;
+		assert_eq!(validate_json_matches_path(json, "$.name"), Ok(json));
+		assert_eq!(validate_json_matches_path(json, "$.items[*].id"), Ok(json));
+		assert_eq!(
+			validate_json_matches_path(json, "$.email")
+				.unwrap_err()
+				.message,
+			"JSON must contain a value at `$.email`"
+		);
+		assert_eq!(
+			validate_json_matches_path(json, "$.items[*].name")
+				.unwrap_err()
+				.message,
+			"JSON must contain a value at `$.items[*].name`"
+		);
+		assert_eq!(
+			validate_json_matches_path("{name}", "$.name")
+				.unwrap_err()
+				.message,
+			"invalid JSON"
+		);
+		assert_eq!(
+			validate_json_matches_path(json, "name")
+				.unwrap_err()
+				.message,
+			"invalid JSONPath expression"
+		);
+	}
+}
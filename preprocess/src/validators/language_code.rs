@@ -0,0 +1,196 @@
+use std::borrow::Cow;
+
+#[cfg(feature = "language-tags")]
+use language_tags::LanguageTag;
+
+use crate::utils::Error;
+
+/// Validates that the given string has the structure of a BCP 47 language
+/// tag, like `en`, `en-US`, `zh-Hant` or `pt-BR`. The tag is made of subtags
+/// separated by `-`, each of them 1 to 8 ASCII letters or digits long. The
+/// first subtag is the primary language, which must be 2 to 8 letters long,
+/// unless it is the `x` or `i` singleton used by private-use and grandfathered
+/// tags. Singletons, which start extensions (like `u` in `en-US-u-ca-gregory`),
+/// must be followed by a longer subtag. The subtags are not checked against
+/// the IANA registry, so `zz-YY` is accepted. Letters are matched
+/// case-insensitively. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetLocaleRequest {
+///     #[preprocess(language_code)]
+///     pub language: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_language_code<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let tag = value.clone().into();
+	let subtags = tag.split('-').collect::<Vec<_>>();
+
+	if subtags.iter().any(|subtag| {
+		subtag.is_empty() ||
+			subtag.len() > 8 ||
+			!subtag.chars().all(|ch| ch.is_ascii_alphanumeric())
+	}) {
+		return Err(Error::new(
+			"language code must be made of subtags of 1 to 8 letters or \
+			 digits, separated by `-`",
+		));
+	}
+
+	let primary = subtags[0];
+	let is_singleton =
+		primary.eq_ignore_ascii_case("x") || primary.eq_ignore_ascii_case("i");
+	if !is_singleton &&
+		(primary.len() < 2 ||
+			!primary.chars().all(|ch| ch.is_ascii_alphabetic()))
+	{
+		return Err(Error::new(
+			"language code must start with a language of 2 to 8 letters",
+		));
+	}
+
+	// Every singleton must be followed by a subtag that isn't a singleton
+	let has_empty_extension =
+		subtags.last().is_some_and(|subtag| subtag.len() == 1) ||
+			subtags
+				.windows(2)
+				.any(|pair| pair[0].len() == 1 && pair[1].len() == 1);
+	if has_empty_extension {
+		return Err(Error::new(
+			"single-character subtags in a language code must be followed by \
+			 another subtag",
+		));
+	}
+
+	Ok(value)
+}
+
+/// Validates that the given string is a well-formed and valid BCP 47 language
+/// tag, using the [`language-tags`](https://docs.rs/language-tags) crate.
+/// Unlike [`validate_language_code`], the subtags must follow the order
+/// defined by BCP 47, and the language, script, region and variant subtags
+/// must be registered in the IANA language subtag registry, so `zz-YY` is
+/// rejected. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetLocaleRequest {
+///     #[preprocess(language_code(strict = true))]
+///     pub language: String,
+/// }
+/// ```
+#[cfg(feature = "language-tags")]
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_language_code_strict<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	LanguageTag::parse(&value.clone().into())
+		.map_err(|err| Error::with_source("invalid language code", err))?
+		.validate()
+		.map_err(|err| Error::with_source("invalid language code", err))?;
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_language_code() {
+		let test_cases = [
+			("en", true),
+			("EN", true),
+			("en-US", true),
+			("en-us", true),
+			("zh-Hant", true),
+			("zh-Hant-TW", true),
+			("pt-BR", true),
+			("es-419", true),
+			("sr-Latn-RS", true),
+			("de-CH-1901", true),
+			("en-US-u-ca-gregory", true),
+			("x-private", true),
+			("i-klingon", true),
+			("zz-YY", true),
+			("", false),
+			("e", false),
+			("a-b", false),
+			("12", false),
+			("en_US", false),
+			("en-", false),
+			("-en", false),
+			("en--US", false),
+			("en-US-", false),
+			("en-u", false),
+			("en-u-x-private", false),
+			("x", false),
+			("toolonglanguage", false),
+			("en-toolongsubtag", false),
+			("en US", false),
+			("en-ÜS", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_language_code(input).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[cfg(feature = "language-tags")]
+	#[test]
+	fn test_validate_language_code_strict() {
+		let test_cases = [
+			("en", true),
+			("en-US", true),
+			("zh-Hant", true),
+			("pt-BR", true),
+			("es-419", true),
+			("sr-Latn-RS", true),
+			("zz-YY", false),
+			("en-YY", false),
+			("zq-US", false),
+			("en-US-US", false),
+			("en_US", false),
+			("", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_language_code_strict(input).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+
+		let error = validate_language_code_strict("zz-YY").unwrap_err();
+		assert_eq!(error.message, "invalid language code");
+		assert!(std::error::Error::source(&error).is_some());
+	}
+}
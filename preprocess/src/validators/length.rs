@@ -1,6 +1,16 @@
+#![allow(clippy::needless_lifetimes)]
+
 use std::{
 	borrow::Cow,
-	collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+	collections::{
+		BTreeMap,
+		BTreeSet,
+		BinaryHeap,
+		HashMap,
+		HashSet,
+		LinkedList,
+		VecDeque,
+	},
 };
 
 use crate::utils::Error;
@@ -118,6 +128,70 @@ impl<T> HasLen for BTreeSet<T> {
 	}
 }
 
+impl<T> HasLen for &VecDeque<T> {
+	fn length(&self) -> usize {
+		self.len()
+	}
+}
+
+impl<T> HasLen for VecDeque<T> {
+	fn length(&self) -> usize {
+		self.len()
+	}
+}
+
+impl<T> HasLen for &LinkedList<T> {
+	fn length(&self) -> usize {
+		self.len()
+	}
+}
+
+impl<T> HasLen for LinkedList<T> {
+	fn length(&self) -> usize {
+		self.len()
+	}
+}
+
+impl<T> HasLen for &BinaryHeap<T> {
+	fn length(&self) -> usize {
+		self.len()
+	}
+}
+
+impl<T> HasLen for BinaryHeap<T> {
+	fn length(&self) -> usize {
+		self.len()
+	}
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> HasLen for &indexmap::IndexMap<K, V, S> {
+	fn length(&self) -> usize {
+		self.len()
+	}
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> HasLen for indexmap::IndexMap<K, V, S> {
+	fn length(&self) -> usize {
+		self.len()
+	}
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, S> HasLen for &indexmap::IndexSet<T, S> {
+	fn length(&self) -> usize {
+		self.len()
+	}
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, S> HasLen for indexmap::IndexSet<T, S> {
+	fn length(&self) -> usize {
+		self.len()
+	}
+}
+
 /// Validates the length of the value given.
 /// If the validator has `equal` set, it will ignore any `min` and `max` value.
 ///
@@ -162,11 +236,97 @@ pub fn validate_length<T: HasLen>(
 	Ok(value)
 }
 
+/// Validates that the given value has exactly the given length. This is the
+/// same as `validate_length` with only `equal` set, except that the error
+/// message states the expected length, like "value must be exactly 6
+/// characters long".
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct VerifyOtpRequest {
+///     #[preprocess(exact_length = 6)]
+///     pub code: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_exact_length<T: HasLen>(
+	value: T,
+	length: usize,
+) -> Result<T, Error> {
+	if value.length() != length {
+		return Err(Error::new(format!(
+			"value must be exactly {} characters long",
+			length
+		)));
+	}
+
+	Ok(value)
+}
+
+/// Validates the number of items in the given collection. This is the same
+/// as [`validate_length`], except that the error messages are tailored for
+/// collections, like `Vec` or `HashMap`. Both `min` and `max` are inclusive.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateTeamRequest {
+///     #[preprocess(min_items = 1, max_items = 10)]
+///     pub members: Vec<String>,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_item_count<T: HasLen>(
+	value: T,
+	min: Option<usize>,
+	max: Option<usize>,
+) -> Result<T, Error> {
+	let items = value.length();
+
+	if let Some(min) = min {
+		if items < min {
+			return Err(Error::new(format!(
+				"collection must have at least {} items",
+				min
+			)));
+		}
+	}
+	if let Some(max) = max {
+		if items > max {
+			return Err(Error::new(format!(
+				"collection must have at most {} items",
+				max
+			)));
+		}
+	}
+
+	Ok(value)
+}
+
 #[cfg(test)]
+#[allow(clippy::nonminimal_bool)]
 mod tests {
-	use std::borrow::Cow;
+	use std::{
+		borrow::Cow,
+		collections::{BinaryHeap, LinkedList, VecDeque},
+	};
 
-	use super::validate_length;
+	use super::{validate_exact_length, validate_item_count, validate_length};
 
 	#[test]
 	fn test_validate_length_equal_overrides_min_max() {
@@ -206,4 +366,70 @@ mod tests {
 	fn test_validate_length_unicode_chars() {
 		assert!(validate_length("日本", None, None, Some(2)).is_ok());
 	}
+
+	#[test]
+	fn test_validate_length_vec_deque() {
+		let queue = VecDeque::from([1, 2, 3]);
+		assert!(validate_length(&queue, Some(1), Some(3), None).is_ok());
+		assert!(validate_length(queue, None, Some(2), None).is_err());
+	}
+
+	#[test]
+	fn test_validate_length_linked_list() {
+		let list = LinkedList::from(["a", "b"]);
+		assert!(validate_length(&list, None, None, Some(2)).is_ok());
+		assert!(validate_length(list, Some(3), None, None).is_err());
+	}
+
+	#[test]
+	fn test_validate_length_binary_heap() {
+		let heap = BinaryHeap::from([5, 1, 3]);
+		assert!(validate_length(&heap, None, None, Some(3)).is_ok());
+		assert!(
+			validate_length(BinaryHeap::<u32>::new(), Some(1), None, None)
+				.is_err()
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "indexmap")]
+	fn test_validate_length_indexmap() {
+		let map: indexmap::IndexMap<&str, u32> =
+			[("a", 1), ("b", 2)].into_iter().collect();
+		assert!(validate_length(&map, None, None, Some(2)).is_ok());
+		assert!(validate_length(map, Some(3), None, None).is_err());
+		let set: indexmap::IndexSet<&str> =
+			["a", "b", "c"].into_iter().collect();
+		assert!(validate_length(&set, None, Some(3), None).is_ok());
+		assert!(validate_length(set, None, Some(2), None).is_err());
+	}
+
+	#[test]
+	fn test_validate_item_count() {
+		assert!(validate_item_count(vec![1, 2], Some(1), Some(2)).is_ok());
+		assert_eq!(
+			validate_item_count(Vec::<i32>::new(), Some(1), None)
+				.unwrap_err()
+				.message,
+			"collection must have at least 1 items"
+		);
+		assert_eq!(
+			validate_item_count(vec![1, 2, 3], None, Some(2))
+				.unwrap_err()
+				.message,
+			"collection must have at most 2 items"
+		);
+	}
+
+	#[test]
+	fn test_validate_exact_length() {
+		assert_eq!(validate_exact_length("123456", 6), Ok("123456"));
+		assert_eq!(validate_exact_length("ÄÖÜäöü", 6), Ok("ÄÖÜäöü"));
+		assert!(validate_exact_length(vec![1, 2], 2).is_ok());
+		assert_eq!(
+			validate_exact_length("12345", 6).unwrap_err().message,
+			"value must be exactly 6 characters long"
+		);
+		assert!(validate_exact_length("1234567", 6).is_err());
+	}
 }
@@ -0,0 +1,154 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Trait to get the length of a value in bytes
+/// This is used by the `validate_length_bytes` validator
+/// to get the number of bytes of the value given
+///
+/// Implement this trait for your own types if you want to use the
+/// `validate_length_bytes` validator
+pub trait HasByteLen {
+	/// Returns the length of the value in bytes
+	#[must_use = concat!(
+		"validation returns a new value instead of mutating the input.",
+		" The returned value will contain the validated value,",
+		" while the input will remain unchanged"
+	)]
+	fn byte_length(&self) -> usize;
+}
+
+impl HasByteLen for String {
+	fn byte_length(&self) -> usize {
+		self.len()
+	}
+}
+
+impl HasByteLen for &String {
+	fn byte_length(&self) -> usize {
+		self.len()
+	}
+}
+
+impl HasByteLen for &str {
+	fn byte_length(&self) -> usize {
+		self.len()
+	}
+}
+
+impl HasByteLen for Cow<'_, str> {
+	fn byte_length(&self) -> usize {
+		self.len()
+	}
+}
+
+impl HasByteLen for Vec<u8> {
+	fn byte_length(&self) -> usize {
+		self.len()
+	}
+}
+
+impl HasByteLen for &Vec<u8> {
+	fn byte_length(&self) -> usize {
+		self.len()
+	}
+}
+
+impl HasByteLen for &[u8] {
+	fn byte_length(&self) -> usize {
+		self.len()
+	}
+}
+
+/// Validates the length of the value given in bytes, unlike
+/// [`validate_length`](super::validate_length), which counts the characters
+/// of strings. This is useful for database columns whose size is measured in
+/// bytes. If the validator has `equal` set, it will ignore any `min` and `max`
+/// value.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreatePostRequest {
+///     #[preprocess(length_bytes(min = 1, max = 255))]
+///     pub title: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_length_bytes<T: HasByteLen>(
+	value: T,
+	min: Option<usize>,
+	max: Option<usize>,
+	equal: Option<usize>,
+) -> Result<T, Error> {
+	let val_length = value.byte_length();
+
+	if let Some(m) = equal {
+		if val_length != m {
+			return Err(Error::new(format!(
+				"length must be equal to {} bytes",
+				m
+			)));
+		}
+		return Ok(value);
+	}
+
+	if let Some(m) = min {
+		if val_length < m {
+			return Err(Error::new(format!(
+				"length must be greater than or equal to {} bytes",
+				m
+			)));
+		}
+	}
+	if let Some(m) = max {
+		if val_length > m {
+			return Err(Error::new(format!(
+				"length must be less than or equal to {} bytes",
+				m
+			)));
+		}
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_length_bytes_counts_bytes() {
+		// 2 characters, but 6 bytes
+		assert!(validate_length_bytes("日本", None, None, Some(6)).is_ok());
+		assert_eq!(
+			validate_length_bytes("日本", None, Some(5), None)
+				.unwrap_err()
+				.message,
+			"length must be less than or equal to 5 bytes"
+		);
+		assert!(validate_length_bytes("hello", Some(5), Some(5), None).is_ok());
+	}
+
+	#[test]
+	fn test_validate_length_bytes_types() {
+		let value: Cow<'static, str> = "hey".into();
+		assert!(validate_length_bytes(value, None, None, Some(3)).is_ok());
+		assert!(
+			validate_length_bytes(String::new(), Some(1), None, None).is_err()
+		);
+		assert!(
+			validate_length_bytes(vec![0u8; 4], None, Some(4), None).is_ok()
+		);
+		assert!(
+			validate_length_bytes(&[1u8, 2][..], None, None, Some(3)).is_err()
+		);
+	}
+}
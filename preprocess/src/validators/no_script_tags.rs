@@ -0,0 +1,160 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// The patterns rejected by [`validate_no_script_tags`]. The patterns are
+/// matched case-insensitively, after removing all the whitespaces and control
+/// characters from the value, since browsers ignore some of them, like in
+/// `java\tscript:`.
+pub const SCRIPT_PATTERNS: &[&str] = &[
+	"<script",
+	"</script",
+	"<iframe",
+	"<object",
+	"<embed",
+	"<base",
+	"<meta",
+	"javascript:",
+	"vbscript:",
+	"data:text/html",
+	"srcdoc=",
+	"onabort=",
+	"onblur=",
+	"onchange=",
+	"onclick=",
+	"ondblclick=",
+	"onerror=",
+	"onfocus=",
+	"oninput=",
+	"onkeydown=",
+	"onkeypress=",
+	"onkeyup=",
+	"onload=",
+	"onmousedown=",
+	"onmouseenter=",
+	"onmouseleave=",
+	"onmousemove=",
+	"onmouseout=",
+	"onmouseover=",
+	"onmouseup=",
+	"onpointerdown=",
+	"onpointerenter=",
+	"onpointerover=",
+	"onreset=",
+	"onresize=",
+	"onscroll=",
+	"onselect=",
+	"onsubmit=",
+	"ontoggle=",
+	"onunload=",
+	"onanimationstart=",
+	"ontransitionend=",
+];
+
+/// Validates that the given string does not contain any of the
+/// [`SCRIPT_PATTERNS`], like `<script`, `javascript:` or `onerror=`, which are
+/// commonly used for cross-site scripting (XSS). This is useful for values
+/// that are rendered as HTML without being escaped. The value is not modified.
+///
+/// This is intentionally conservative, and is not a replacement for escaping
+/// the value or sanitizing it using an HTML parser. It can reject harmless
+/// values, like text about JavaScript, and cannot catch every way of running
+/// scripts, like HTML entities or event handlers that are not in the list. Use
+/// [`strip_html`](crate::preprocessors::preprocess_strip_html) to remove all
+/// the HTML tags from the value instead.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct UpdateProfileRequest {
+///     #[preprocess(no_script_tags)]
+///     pub bio: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_no_script_tags<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let normalized = value
+		.clone()
+		.into()
+		.chars()
+		.filter(|ch| !ch.is_whitespace() && !ch.is_control())
+		.flat_map(char::to_lowercase)
+		.collect::<String>();
+
+	if let Some(pattern) = SCRIPT_PATTERNS
+		.iter()
+		.find(|pattern| normalized.contains(*pattern))
+	{
+		return Err(Error::new(format!(
+			"value must not contain `{}`",
+			pattern
+		)));
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_no_script_tags() {
+		let valid = [
+			"Hello, world!",
+			"I like <b>bold</b> text",
+			"Visit https://example.com",
+			"2 < 3 and 5 > 4",
+			"Turn the light on = off",
+			"",
+		];
+		for input in valid {
+			assert_eq!(validate_no_script_tags(input), Ok(input));
+		}
+	}
+
+	#[test]
+	fn test_validate_no_script_tags_rejects_patterns() {
+		let test_cases = [
+			("<script>alert(1)</script>", "<script"),
+			("<SCRIPT SRC=//evil.com>", "<script"),
+			("<a href=\"javascript:alert(1)\">", "javascript:"),
+			("<a href=\"java\tscript:alert(1)\">", "javascript:"),
+			("<img src=x onerror=alert(1)>", "onerror="),
+			("<img src=x OnError = alert(1)>", "onerror="),
+			("<body onload=\"alert(1)\">", "onload="),
+			("<iframe src=\"data:text/html,<b>\">", "<iframe"),
+			(
+				"<a href=\"data:text/html;base64,PHNjcmlwdD4=\">",
+				"data:text/html",
+			),
+		];
+
+		for (input, pattern) in test_cases {
+			assert_eq!(
+				validate_no_script_tags(input).unwrap_err().message,
+				format!("value must not contain `{}`", pattern),
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_script_patterns_are_normalized() {
+		for pattern in SCRIPT_PATTERNS {
+			assert_eq!(*pattern, pattern.to_lowercase());
+			assert!(!pattern.contains(char::is_whitespace));
+		}
+	}
+}
@@ -0,0 +1,117 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Validates that the given string is a number within the given range, which
+/// is useful for numbers that are received as strings, like query parameters.
+/// The value is parsed as an [`f64`], and leading or trailing whitespaces are
+/// not allowed. Infinite and `NaN` values are rejected. If `integer_only` is
+/// `true`, numbers with a decimal part, like `1.5`, are rejected as well. The
+/// value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct ListOrdersQuery {
+///     #[preprocess(number_in_range(min = "1", max = "100", integer_only = true))]
+///     pub limit: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_number_in_range<'a, T>(
+	value: T,
+	min: Option<f64>,
+	max: Option<f64>,
+	integer_only: bool,
+) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let number = value
+		.clone()
+		.into()
+		.parse::<f64>()
+		.map_err(|err| Error::with_source("value must be a number", err))?;
+
+	if !number.is_finite() {
+		return Err(Error::new("value must be a finite number"));
+	}
+	if integer_only && number.fract() != 0.0 {
+		return Err(Error::new("value must be an integer"));
+	}
+	if let Some(min) = min {
+		if number < min {
+			return Err(Error::new(format!(
+				"value must be greater than or equal to {}",
+				min
+			)));
+		}
+	}
+	if let Some(max) = max {
+		if number > max {
+			return Err(Error::new(format!(
+				"value must be less than or equal to {}",
+				max
+			)));
+		}
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_number_in_range() {
+		let test_cases = [
+			("1", true),
+			("100", true),
+			("50.5", true),
+			("1e2", true),
+			("0", false),
+			("100.1", false),
+			("-5", false),
+			("abc", false),
+			("", false),
+			(" 5", false),
+			("NaN", false),
+			("inf", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_number_in_range(input, Some(1.0), Some(100.0), false)
+					.is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_number_in_range_integer_only() {
+		assert_eq!(validate_number_in_range("42", None, None, true), Ok("42"));
+		assert_eq!(
+			validate_number_in_range("4.2", None, None, true)
+				.unwrap_err()
+				.message,
+			"value must be an integer"
+		);
+		assert_eq!(
+			validate_number_in_range("1000", None, Some(999.0), true)
+				.unwrap_err()
+				.message,
+			"value must be less than or equal to 999"
+		);
+	}
+}
@@ -0,0 +1,278 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Validates that the given string is a valid number, which is useful for
+/// numbers that are received as strings, like form fields. The value is parsed
+/// as an [`f64`], and leading or trailing whitespaces are not allowed.
+/// Infinite and `NaN` values are rejected. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct UpdatePriceRequest {
+///     #[preprocess(numeric_string)]
+///     pub price: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_numeric_string<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	validate_numeric_string_with_options(value, false, false, false)
+}
+
+/// Validates that the given string is a valid number, with the given options.
+/// This is the same as [`validate_numeric_string`], except that:
+///
+/// - If `integer_only` is `true`, numbers with a decimal part, like `1.5` or
+///   `1e-2`, are rejected, the same way as
+///   [`validate_number_in_range`](crate::validators::validate_number_in_range).
+///   Numbers like `1.0` or `1e2` are integers, so they are allowed.
+/// - If `positive_only` is `true`, negative numbers are rejected. `-0` is
+///   treated as zero, so it is allowed.
+/// - If `no_scientific` is `true`, numbers in scientific notation, like `1e3`,
+///   are rejected.
+///
+/// The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateOrderRequest {
+///     #[preprocess(numeric_string(integer_only = true, positive_only = true))]
+///     pub quantity: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_numeric_string_with_options<'a, T>(
+	value: T,
+	integer_only: bool,
+	positive_only: bool,
+	no_scientific: bool,
+) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let string = value.clone().into();
+
+	let number = string
+		.parse::<f64>()
+		.map_err(|err| Error::with_source("value must be a number", err))?;
+
+	if !number.is_finite() {
+		return Err(Error::new("value must be a finite number"));
+	}
+	if no_scientific && string.contains(['e', 'E']) {
+		return Err(Error::new(
+			"value must not be written in scientific notation",
+		));
+	}
+	if integer_only && number.fract() != 0.0 {
+		return Err(Error::new("value must be an integer"));
+	}
+	if positive_only && number < 0.0 {
+		return Err(Error::new("value must not be negative"));
+	}
+
+	Ok(value)
+}
+
+/// Validates that the given string is a plain decimal number, like `-12.50`,
+/// which is what `number_format` produces. Unlike [`validate_numeric_string`],
+/// the number must consist of an optional sign, at least one digit, and an
+/// optional `.` followed by at least one digit. Scientific notation, thousands
+/// separators and whitespaces are not allowed. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreatePaymentRequest {
+///     #[preprocess(number_format, decimal_string)]
+///     pub amount: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_decimal_string<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let string = value.clone().into();
+
+	let unsigned = string.strip_prefix(['+', '-']).unwrap_or(&string);
+	let (integer, fraction) = match unsigned.split_once('.') {
+		Some((integer, fraction)) => (integer, Some(fraction)),
+		None => (unsigned, None),
+	};
+	let is_digits = |part: &str| {
+		!part.is_empty() && part.bytes().all(|b| b.is_ascii_digit())
+	};
+	if !is_digits(integer) || !fraction.is_none_or(is_digits) {
+		return Err(Error::new("value is not a valid decimal number"));
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_decimal_string() {
+		let test_cases = [
+			("1", true),
+			("-12.50", true),
+			("+0.5", true),
+			("1234567.89", true),
+			("", false),
+			("-", false),
+			(".5", false),
+			("5.", false),
+			("1e3", false),
+			("1,000", false),
+			("1.2.3", false),
+			(" 1", false),
+			("--1", false),
+			("NaN", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_decimal_string(input).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+		assert_eq!(
+			validate_decimal_string("1,000").unwrap_err().message,
+			"value is not a valid decimal number"
+		);
+	}
+
+	#[test]
+	fn test_validate_numeric_string() {
+		let test_cases = [
+			("1", true),
+			("-1.5", true),
+			("+2", true),
+			(".5", true),
+			("1e3", true),
+			("", false),
+			("abc", false),
+			("1,000", false),
+			(" 1", false),
+			("NaN", false),
+			("inf", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_numeric_string(input).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+		assert_eq!(
+			validate_numeric_string("abc").unwrap_err().message,
+			"value must be a number"
+		);
+	}
+
+	#[test]
+	fn test_validate_numeric_string_integer_only() {
+		assert!(
+			validate_numeric_string_with_options("42", true, false, false)
+				.is_ok()
+		);
+		assert!(validate_numeric_string_with_options(
+			"1e2", true, false, false
+		)
+		.is_ok());
+		assert!(validate_numeric_string_with_options(
+			"1.0", true, false, false
+		)
+		.is_ok());
+		assert_eq!(
+			validate_numeric_string_with_options("1.5", true, false, false)
+				.unwrap_err()
+				.message,
+			"value must be an integer"
+		);
+		assert!(validate_numeric_string_with_options(
+			"1e-2", true, false, false
+		)
+		.is_err());
+	}
+
+	#[test]
+	fn test_validate_numeric_string_integer_only_matches_number_in_range() {
+		use crate::validators::validate_number_in_range;
+
+		for input in ["42", "-3", "1.0", "1e2", "1.5", "1e-2", "abc"] {
+			assert_eq!(
+				validate_numeric_string_with_options(input, true, false, false)
+					.is_ok(),
+				validate_number_in_range(input, None, None, true).is_ok(),
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_numeric_string_positive_only() {
+		assert!(
+			validate_numeric_string_with_options("0", false, true, false)
+				.is_ok()
+		);
+		assert_eq!(
+			validate_numeric_string_with_options("-1", false, true, false)
+				.unwrap_err()
+				.message,
+			"value must not be negative"
+		);
+		assert!(
+			validate_numeric_string_with_options("-0", false, true, false)
+				.is_ok()
+		);
+	}
+
+	#[test]
+	fn test_validate_numeric_string_no_scientific() {
+		assert!(validate_numeric_string_with_options(
+			"1000", false, false, true
+		)
+		.is_ok());
+		assert_eq!(
+			validate_numeric_string_with_options("1E3", false, false, true)
+				.unwrap_err()
+				.message,
+			"value must not be written in scientific notation"
+		);
+	}
+}
@@ -0,0 +1,97 @@
+use std::borrow::Cow;
+
+use zxcvbn::zxcvbn;
+
+use crate::utils::Error;
+
+/// Validates that the given password is strong enough, using the
+/// [`zxcvbn`](https://docs.rs/zxcvbn) crate. Unlike length and character class
+/// checks, zxcvbn estimates how many guesses are needed to crack the password
+/// by looking for dictionary words, common passwords, keyboard patterns,
+/// dates and repetitions. The password is given a score from 0 (too guessable)
+/// to 4 (very unguessable), which must be at least `min_score`. The error
+/// message contains the score and an estimate of how long it would take to
+/// crack the password offline, assuming a slow hash function. The value is not
+/// modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SignUpRequest {
+///     #[preprocess(length(min = 8), password_strength(min_score = 3))]
+///     pub password: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_password_strength<'a, T>(
+	value: T,
+	min_score: u8,
+) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let password = value.clone().into();
+	let entropy = zxcvbn(&password, &[]);
+	let score = u8::from(entropy.score());
+	if score < min_score {
+		return Err(Error::new(format!(
+			"password is too weak, with a score of {} out of 4 (at least {} is \
+			 required), and could be cracked in {}",
+			score,
+			min_score,
+			entropy.crack_times().offline_slow_hashing_1e4_per_second()
+		)));
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_password_strength() {
+		let test_cases = [
+			("password", 3, false),
+			("123456", 1, false),
+			("qwertyuiop", 3, false),
+			("John1990", 3, false),
+			("aaaaaaaaaaaa", 3, false),
+			("", 1, false),
+			("", 0, true),
+			("password", 0, true),
+			("correct horse battery staple", 3, true),
+			("Tr0ub4dour&3-xkcd-Zebra!", 4, true),
+			("rW8#mQz!2vLp@9Xs", 4, true),
+		];
+
+		for (input, min_score, expected) in test_cases {
+			assert_eq!(
+				validate_password_strength(input, min_score).is_ok(),
+				expected,
+				"{} {}",
+				input,
+				min_score
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_password_strength_error_message() {
+		assert_eq!(
+			validate_password_strength("password", 3)
+				.unwrap_err()
+				.message,
+			"password is too weak, with a score of 0 out of 4 (at least 3 is \
+			 required), and could be cracked in less than a second"
+		);
+	}
+}
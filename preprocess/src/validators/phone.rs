@@ -0,0 +1,218 @@
+use std::borrow::Cow;
+
+use phonenumber::country::Id;
+
+use crate::utils::Error;
+
+/// The format that a phone number must be written in, for [`validate_phone`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhoneFormat {
+	/// The E.164 format, which is a `+`, followed by the country code and the
+	/// number, without any spaces or punctuation, like `+14155552671`
+	E164,
+	/// Any format that is commonly used to write phone numbers, including the
+	/// national format of the country, like `(415) 555-2671` in the US
+	Local,
+}
+
+/// Returns `true` if the given string is in the E.164 format, which is a `+`
+/// followed by up to 15 digits, the first of which is not `0`.
+fn is_e164(value: &str) -> bool {
+	let Some(digits) = value.strip_prefix('+') else {
+		return false;
+	};
+
+	(2..=15).contains(&digits.len()) &&
+		!digits.starts_with('0') &&
+		digits.chars().all(|ch| ch.is_ascii_digit())
+}
+
+/// Parses the given phone number using the
+/// [`phonenumber`](https://docs.rs/phonenumber) crate, with `country` as the
+/// country of numbers without a country code, and checks that it is a valid
+/// number.
+pub(crate) fn parse_phone(
+	value: &str,
+	country: Option<Id>,
+) -> Result<phonenumber::PhoneNumber, Error> {
+	let number = phonenumber::parse(country, value)
+		.map_err(|err| Error::with_source("invalid phone number", err))?;
+
+	if !phonenumber::is_valid(&number) {
+		return Err(Error::new("invalid phone number"));
+	}
+
+	Ok(number)
+}
+
+/// Validates that the given string is a valid phone number in the given
+/// format, using the [`phonenumber`](https://docs.rs/phonenumber) crate. If
+/// `country` is given, the number must belong to that country, so a Canadian
+/// number is rejected for `US`, even though both use the `+1` country code.
+/// Numbers in the [`PhoneFormat::Local`] format without a country code are
+/// assumed to be from `country`. The value is not modified, so use
+/// [`normalize_phone`](crate::preprocessors::preprocess_normalize_phone) to
+/// convert it to the E.164 format.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetPhoneRequest {
+///     #[preprocess(phone(country = "US", format = "local"))]
+///     pub phone: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_phone<'a, T>(
+	value: T,
+	country: Option<Id>,
+	format: PhoneFormat,
+) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let phone = value.clone().into();
+
+	if format == PhoneFormat::E164 && !is_e164(&phone) {
+		return Err(Error::new(
+			"phone number must be in the E.164 format, like `+14155552671`",
+		));
+	}
+
+	let number = parse_phone(&phone, country)?;
+	if let Some(country) = country {
+		if number.country().id() != Some(country) {
+			return Err(Error::new(format!(
+				"phone number must be from `{}`",
+				country.as_ref()
+			)));
+		}
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_phone_e164() {
+		let valid = ["+12015550123", "+447400123456", "+4930123456"];
+		for input in valid {
+			assert_eq!(
+				validate_phone(input, None, PhoneFormat::E164),
+				Ok(input)
+			);
+		}
+
+		let test_cases = [
+			"12015550123",
+			"+1 201-555-0123",
+			"(201) 555-0123",
+			"+44 7400 123456",
+			"+012015550123",
+			"+1234567890123456",
+			"",
+		];
+		for input in test_cases {
+			assert_eq!(
+				validate_phone(input, None, PhoneFormat::E164)
+					.unwrap_err()
+					.message,
+				"phone number must be in the E.164 format, like `+14155552671`",
+				"{}",
+				input
+			);
+		}
+
+		let invalid = ["+15555555555", "+4912", "+999123456"];
+		for input in invalid {
+			assert_eq!(
+				validate_phone(input, None, PhoneFormat::E164)
+					.unwrap_err()
+					.message,
+				"invalid phone number",
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_phone_country() {
+		let test_cases = [
+			(Id::US, "+12015550123"),
+			(Id::GB, "+447400123456"),
+			(Id::DE, "+4930123456"),
+		];
+		for (country, input) in test_cases {
+			assert!(
+				validate_phone(input, Some(country), PhoneFormat::E164).is_ok(),
+				"{}",
+				input
+			);
+		}
+
+		assert_eq!(
+			validate_phone("+14165550123", Some(Id::US), PhoneFormat::E164)
+				.unwrap_err()
+				.message,
+			"phone number must be from `US`"
+		);
+		assert!(
+			validate_phone("+4930123456", Some(Id::GB), PhoneFormat::E164)
+				.is_err()
+		);
+		assert!(validate_phone(
+			"+447400123456",
+			Some(Id::DE),
+			PhoneFormat::Local
+		)
+		.is_err());
+	}
+
+	#[test]
+	fn test_validate_phone_local() {
+		let valid = [
+			(Id::US, "(201) 555-0123"),
+			(Id::US, "201-555-0123"),
+			(Id::US, "+1 201 555 0123"),
+			(Id::GB, "07400 123456"),
+			(Id::GB, "+44 7400 123456"),
+			(Id::DE, "030 123456"),
+			(Id::DE, "+49 30 123456"),
+		];
+		for (country, input) in valid {
+			assert!(
+				validate_phone(input, Some(country), PhoneFormat::Local)
+					.is_ok(),
+				"{}",
+				input
+			);
+		}
+
+		let invalid = [
+			(Id::US, "(555) 555-5555"),
+			(Id::US, "555-0123"),
+			(Id::GB, "07400 12"),
+			(Id::DE, "030"),
+			(Id::DE, "not a number"),
+		];
+		for (country, input) in invalid {
+			assert!(
+				validate_phone(input, Some(country), PhoneFormat::Local)
+					.is_err(),
+				"{}",
+				input
+			);
+		}
+	}
+}
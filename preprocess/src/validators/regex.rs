@@ -11,6 +11,9 @@ static REGEX_LIST: OnceLock<DashMap<String, Regex>> = OnceLock::new();
 /// The regex is compiled using [`regex::Regex::new`].
 /// If the regex is invalid, then an error is returned.
 ///
+/// The regex can also be a constant, using
+/// `#[preprocess(regex_named = MY_PATTERN)]`.
+///
 /// # Example
 /// ```rust
 /// use preprocess::prelude::*;
@@ -18,9 +21,9 @@ static REGEX_LIST: OnceLock<DashMap<String, Regex>> = OnceLock::new();
 /// #[preprocess::sync]
 /// #[derive(Debug, Deserialize, Serialize)]
 /// pub struct LoginRequest {
-///     #[preprocess(custom = "validate_regex")]
+///     #[preprocess(email)]
 ///     pub email: String,
-///     #[preprocess(regex = "^(?=.*[A-Za-z])(?=.*\\d)[A-Za-z\\d]{8,}$")]
+///     #[preprocess(regex = "^[A-Za-z\\d]{8,}$")]
 ///     pub password: String,
 /// }
 /// ```
@@ -34,18 +37,258 @@ where
 	T: Into<Cow<'a, str>> + Clone,
 {
 	let val = value.clone().into();
+	is_match_cached(regex, &val)?
+		.then_some(value)
+		.ok_or_else(|| Error::new("regex validation failed"))
+}
+
+/// Validates whether the given string matches every one of the given regexes.
+/// Each regex is given with a label, which is used in the error message to
+/// describe the rule that was not met. The error lists the labels of all the
+/// regexes that did not match, in the order they were given. Each regex is
+/// compiled and cached separately, like in [`validate_regex`].
+///
+/// This is mostly useful for password complexity rules.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SignUpRequest {
+///     #[preprocess(all_match(uppercase = "[A-Z]", digit = "[0-9]"))]
+///     pub password: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_all_match<'a, T>(
+	value: T,
+	patterns: &[(&str, &str)],
+) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let val = value.clone().into();
+	let mut failed = Vec::new();
+	for (label, regex) in patterns {
+		if !is_match_cached(regex, &val)? {
+			failed.push(format!("`{}`", label));
+		}
+	}
+
+	match failed.as_slice() {
+		[] => Ok(value),
+		[label] => Err(Error::new(format!("value must match {}", label))),
+		[rest @ .., last] => Err(Error::new(format!(
+			"value must match {} and {}",
+			rest.join(", "),
+			last
+		))),
+	}
+}
+
+/// Checks whether the given string matches the given regex, compiling the
+/// regex and storing it in the global cache if it isn't already there.
+fn is_match_cached(regex: &str, value: &str) -> Result<bool, Error> {
 	if regex.is_empty() {
 		return Err(Error::new("regex cannot be empty"));
 	}
 
-	REGEX_LIST
+	Ok(REGEX_LIST
 		.get_or_init(DashMap::new)
 		.entry(regex.to_string())
 		.or_try_insert_with(|| {
 			Regex::new(regex)
 				.map_err(|err| Error::new(format!("invalid regex: {}", err)))
 		})?
+		.is_match(value))
+}
+
+/// Validates whether the given string matches the given, already compiled
+/// regex. This is useful for regexes that are built at runtime, like the ones
+/// loaded from a config file, which cannot be given as a string literal. The
+/// regex is not cached, since it is already compiled.
+///
+/// # Example
+/// ```rust
+/// use std::sync::LazyLock;
+///
+/// use preprocess::prelude::*;
+/// use regex::Regex;
+///
+/// static USERNAME_REGEX: LazyLock<Regex> =
+///     LazyLock::new(|| Regex::new("^[a-z0-9_]+$").unwrap());
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SignUpRequest {
+///     #[preprocess(regex_ref = USERNAME_REGEX)]
+///     pub username: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_regex_with_ref<'a, T>(
+	value: T,
+	regex: &Regex,
+) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let val = value.clone().into();
+	regex
 		.is_match(&val)
 		.then_some(value)
 		.ok_or_else(|| Error::new("regex validation failed"))
 }
+
+/// Compiles the given regex and stores it in the same global cache that is
+/// used by [`validate_regex`], so that it does not have to be compiled during
+/// the first validation. Returns an error if the regex is empty or invalid.
+///
+/// This is mostly useful for patterns that are referenced using
+/// `#[preprocess(regex_named = MY_PATTERN)]`, which cannot be checked at
+/// compile time. See
+/// [`validate_regex_pattern_at_startup`](crate::validate_regex_pattern_at_startup)
+/// to check all of them at once.
+pub fn precompile_regex(regex: &str) -> Result<(), Error> {
+	if regex.is_empty() {
+		return Err(Error::new("regex cannot be empty"));
+	}
+
+	REGEX_LIST
+		.get_or_init(DashMap::new)
+		.entry(regex.to_string())
+		.or_try_insert_with(|| {
+			Regex::new(regex)
+				.map_err(|err| Error::new(format!("invalid regex: {}", err)))
+		})?;
+	Ok(())
+}
+
+/// Checks that the given regex constants, which are used with
+/// `#[preprocess(regex_named = ...)]`, are valid regexes, and panics with the
+/// name of the first invalid one otherwise. Call this at the start of your
+/// program, so that an invalid pattern fails fast instead of failing every
+/// validation that uses it.
+///
+/// # Example
+/// ```rust
+/// pub const USERNAME_PATTERN: &str = "^[a-z0-9_]+$";
+/// pub const SLUG_PATTERN: &str = "^[a-z0-9-]+$";
+///
+/// fn main() {
+///     preprocess::validate_regex_pattern_at_startup!(
+///         USERNAME_PATTERN,
+///         SLUG_PATTERN,
+///     );
+/// }
+/// ```
+#[macro_export]
+macro_rules! validate_regex_pattern_at_startup {
+	($($pattern:path),* $(,)?) => {
+		$(
+			if let ::std::result::Result::Err(err) =
+				$crate::validators::precompile_regex($pattern)
+			{
+				::std::panic!(
+					"invalid regex pattern `{}`: {}",
+					::std::stringify!($pattern),
+					err.message
+				);
+			}
+		)*
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const USERNAME_PATTERN: &str = "^[a-z0-9_]+$";
+	const INVALID_PATTERN: &str = "^[a-z";
+
+	#[test]
+	fn test_validate_regex() {
+		assert!(validate_regex("user_1", USERNAME_PATTERN).is_ok());
+		assert!(validate_regex("User 1", USERNAME_PATTERN).is_err());
+		assert!(validate_regex("user", INVALID_PATTERN)
+			.unwrap_err()
+			.message
+			.starts_with("invalid regex"));
+	}
+
+	#[test]
+	fn test_validate_all_match() {
+		let patterns = [
+			("uppercase", "[A-Z]"),
+			("digit", "[0-9]"),
+			("special", "[!@#]"),
+		];
+		assert_eq!(validate_all_match("Passw0rd!", &patterns), Ok("Passw0rd!"));
+		assert_eq!(
+			validate_all_match("Password!", &patterns)
+				.unwrap_err()
+				.message,
+			"value must match `digit`"
+		);
+		assert_eq!(
+			validate_all_match("password!", &patterns)
+				.unwrap_err()
+				.message,
+			"value must match `uppercase` and `digit`"
+		);
+		assert_eq!(
+			validate_all_match("password", &patterns)
+				.unwrap_err()
+				.message,
+			"value must match `uppercase`, `digit` and `special`"
+		);
+		assert_eq!(validate_all_match("anything", &[]), Ok("anything"));
+		assert!(validate_all_match("user", &[("invalid", INVALID_PATTERN)])
+			.unwrap_err()
+			.message
+			.starts_with("invalid regex"));
+	}
+
+	#[test]
+	fn test_validate_regex_with_ref() {
+		let regex = Regex::new(USERNAME_PATTERN).unwrap();
+		assert_eq!(validate_regex_with_ref("user_1", &regex), Ok("user_1"));
+		assert_eq!(
+			validate_regex_with_ref(String::from("User 1"), &regex)
+				.unwrap_err()
+				.message,
+			"regex validation failed"
+		);
+	}
+
+	#[test]
+	fn test_precompile_regex() {
+		assert!(precompile_regex(USERNAME_PATTERN).is_ok());
+		assert!(precompile_regex(INVALID_PATTERN).is_err());
+		assert!(precompile_regex("").is_err());
+	}
+
+	#[test]
+	fn test_validate_regex_pattern_at_startup() {
+		crate::validate_regex_pattern_at_startup!(USERNAME_PATTERN);
+	}
+
+	#[test]
+	#[should_panic(expected = "invalid regex pattern `INVALID_PATTERN`")]
+	fn test_validate_regex_pattern_at_startup_panics() {
+		crate::validate_regex_pattern_at_startup!(
+			USERNAME_PATTERN,
+			INVALID_PATTERN
+		);
+	}
+}
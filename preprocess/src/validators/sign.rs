@@ -0,0 +1,230 @@
+use crate::utils::Error;
+
+/// A trait for signed numbers, used by the [`validate_positive`],
+/// [`validate_negative`] and [`validate_non_negative`] validators, as well as
+/// the [`preprocess_abs`](crate::preprocessors::preprocess_abs) and
+/// [`preprocess_neg`](crate::preprocessors::preprocess_neg) preprocessors. By
+/// default, this trait is implemented for all the signed integer types, as
+/// well as [`f32`] and [`f64`]. It is intentionally not implemented for
+/// unsigned integers, since they cannot carry a sign.
+#[diagnostic::on_unimplemented(
+	message = "`{Self}` is not a signed number",
+	note = "`abs`, `neg`, `positive`, `negative` and `non_negative` can only be used on signed integers and floats"
+)]
+pub trait Signed: Copy + PartialOrd {
+	/// The zero value of the type.
+	const ZERO: Self;
+
+	/// Returns the absolute value, or [`None`] if it cannot be represented by
+	/// the type (like `i32::MIN.abs()`).
+	fn checked_abs(self) -> Option<Self>;
+
+	/// Returns the negated value, or [`None`] if it cannot be represented by
+	/// the type (like `-i32::MIN`).
+	fn checked_neg(self) -> Option<Self>;
+}
+
+macro_rules! impl_signed_int {
+	($($ty:ty),*) => {
+		$(
+			impl Signed for $ty {
+				const ZERO: Self = 0;
+
+				fn checked_abs(self) -> Option<Self> {
+					<$ty>::checked_abs(self)
+				}
+
+				fn checked_neg(self) -> Option<Self> {
+					<$ty>::checked_neg(self)
+				}
+			}
+		)*
+	};
+}
+
+macro_rules! impl_signed_float {
+	($($ty:ty),*) => {
+		$(
+			impl Signed for $ty {
+				const ZERO: Self = 0.0;
+
+				fn checked_abs(self) -> Option<Self> {
+					Some(self.abs())
+				}
+
+				fn checked_neg(self) -> Option<Self> {
+					Some(-self)
+				}
+			}
+		)*
+	};
+}
+
+impl_signed_int!(i8, i16, i32, i64, i128, isize);
+impl_signed_float!(f32, f64);
+
+/// Validates that the given number is strictly greater than zero. The value is
+/// not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateTransferRequest {
+///     #[preprocess(positive)]
+///     pub amount: i64,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_positive<T>(value: T) -> Result<T, Error>
+where
+	T: Signed,
+{
+	if value > T::ZERO {
+		Ok(value)
+	} else {
+		Err(Error::new("value must be positive"))
+	}
+}
+
+/// Validates that the given number is strictly less than zero. The value is
+/// not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateRefundRequest {
+///     #[preprocess(negative)]
+///     pub amount: i64,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_negative<T>(value: T) -> Result<T, Error>
+where
+	T: Signed,
+{
+	if value < T::ZERO {
+		Ok(value)
+	} else {
+		Err(Error::new("value must be negative"))
+	}
+}
+
+/// Validates that the given number is greater than or equal to zero. The value
+/// is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetBalanceRequest {
+///     #[preprocess(non_negative)]
+///     pub balance: i64,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_non_negative<T>(value: T) -> Result<T, Error>
+where
+	T: Signed,
+{
+	if value >= T::ZERO {
+		Ok(value)
+	} else {
+		Err(Error::new("value must not be negative"))
+	}
+}
+
+/// Validates that the given number is not zero, by comparing it with
+/// [`T::default()`](Default::default), which is zero for all the integer and
+/// float types. Unlike the other sign validators, this works on unsigned
+/// integers as well. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SplitBillRequest {
+///     #[preprocess(non_zero)]
+///     pub people: u32,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_non_zero<T>(value: T) -> Result<T, Error>
+where
+	T: PartialEq + Default,
+{
+	if value != T::default() {
+		Ok(value)
+	} else {
+		Err(Error::new("value must not be zero"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_positive() {
+		assert_eq!(validate_positive(5), Ok(5));
+		assert_eq!(validate_positive(0.5f32), Ok(0.5));
+		assert!(validate_positive(0).is_err());
+		assert!(validate_positive(-0.0f64).is_err());
+		assert!(validate_positive(-3i8).is_err());
+		assert!(validate_positive(f64::NAN).is_err());
+	}
+
+	#[test]
+	fn test_validate_negative() {
+		assert_eq!(validate_negative(-5i128), Ok(-5));
+		assert_eq!(validate_negative(f64::NEG_INFINITY), Ok(f64::NEG_INFINITY));
+		assert!(validate_negative(0isize).is_err());
+		assert!(validate_negative(3.0f32).is_err());
+		assert!(validate_negative(f32::NAN).is_err());
+	}
+
+	#[test]
+	fn test_validate_non_negative() {
+		assert_eq!(validate_non_negative(0), Ok(0));
+		assert_eq!(validate_non_negative(5i16), Ok(5));
+		assert_eq!(validate_non_negative(-0.0f64), Ok(-0.0));
+		assert!(validate_non_negative(-1i64).is_err());
+		assert!(validate_non_negative(-0.5f32).is_err());
+		assert!(validate_non_negative(f64::NAN).is_err());
+	}
+
+	#[test]
+	fn test_validate_non_zero() {
+		assert_eq!(validate_non_zero(-3i8), Ok(-3));
+		assert_eq!(validate_non_zero(7u64), Ok(7));
+		assert_eq!(validate_non_zero(0.1f32), Ok(0.1));
+		assert!(validate_non_zero(0i128).is_err());
+		assert!(validate_non_zero(0usize).is_err());
+		assert!(validate_non_zero(-0.0f64).is_err());
+	}
+}
@@ -0,0 +1,251 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// A part of a template, which is either literal text or a `{name}`
+/// placeholder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplateSegment<'a> {
+	Literal(String),
+	Placeholder(&'a str),
+}
+
+/// Parses a template into its segments. `{{` and `}}` can be used for literal
+/// braces, like in [`format!`]. Placeholders must not be empty, and two
+/// placeholders must be separated by some literal text, since there is no way
+/// to tell where one of them ends otherwise.
+fn parse_template(template: &str) -> Result<Vec<TemplateSegment<'_>>, Error> {
+	let mut segments = Vec::new();
+	let mut literal = String::new();
+	let mut chars = template.char_indices().peekable();
+	while let Some((index, ch)) = chars.next() {
+		match ch {
+			'{' if chars.next_if(|(_, next)| *next == '{').is_some() => {
+				literal.push('{');
+			}
+			'}' if chars.next_if(|(_, next)| *next == '}').is_some() => {
+				literal.push('}');
+			}
+			'{' => {
+				let Some(end) = template[index..].find('}') else {
+					return Err(Error::new(
+						"invalid template: unclosed `{` in template",
+					));
+				};
+				let name = &template[index + 1..index + end];
+				if name.is_empty() || name.contains('{') {
+					return Err(Error::new(
+						"invalid template: placeholders must have a name",
+					));
+				}
+				if literal.is_empty() &&
+					matches!(
+						segments.last(),
+						Some(TemplateSegment::Placeholder(_))
+					) {
+					return Err(Error::new(
+						"invalid template: placeholders must be separated by \
+						 literal text",
+					));
+				}
+				if !literal.is_empty() {
+					segments.push(TemplateSegment::Literal(std::mem::take(
+						&mut literal,
+					)));
+				}
+				segments.push(TemplateSegment::Placeholder(name));
+				// Skip the name and the closing brace
+				chars.nth(name.chars().count());
+			}
+			'}' => {
+				return Err(Error::new(
+					"invalid template: unmatched `}` in template",
+				));
+			}
+			_ => literal.push(ch),
+		}
+	}
+	if !literal.is_empty() {
+		segments.push(TemplateSegment::Literal(literal));
+	}
+
+	Ok(segments)
+}
+
+/// Validates that the given string matches the format of the given template.
+/// The template is made of literal text and `{name}` placeholders, like
+/// `order-{id}`. The literal text must appear in the value in the same order,
+/// while each placeholder can match any non-empty text. Use `{{` and `}}` for
+/// literal braces. If the value does not match, the error message mentions the
+/// literal text that was not found. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateOrderRequest {
+///     #[preprocess(interpolate(template = "order-{id}"))]
+///     pub reference: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_template<'a, T>(value: T, template: &str) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let segments = parse_template(template)?;
+	let string = value.clone().into();
+	let mut rest = string.as_ref();
+	let mut placeholder = None;
+	for (index, segment) in segments.iter().enumerate() {
+		let literal = match segment {
+			TemplateSegment::Placeholder(name) => {
+				placeholder = Some(*name);
+				continue;
+			}
+			TemplateSegment::Literal(literal) => literal.as_str(),
+		};
+
+		let Some(name) = placeholder.take() else {
+			rest = rest.strip_prefix(literal).ok_or_else(|| {
+				Error::new(format!("value must start with `{}`", literal))
+			})?;
+			continue;
+		};
+
+		// Placeholders can't be empty, so the literal is only searched for
+		// after the first character of the placeholder
+		let placeholder_start =
+			rest.chars().next().map(char::len_utf8).ok_or_else(|| {
+				Error::new(format!("`{{{}}}` must not be empty", name))
+			})?;
+		let position = if index == segments.len() - 1 {
+			rest.ends_with(literal)
+				.then(|| rest.len() - literal.len())
+				.filter(|position| *position >= placeholder_start)
+				.ok_or_else(|| {
+					Error::new(format!("value must end with `{}`", literal))
+				})?
+		} else {
+			rest[placeholder_start..]
+				.find(literal)
+				.map(|position| position + placeholder_start)
+				.ok_or_else(|| {
+					Error::new(format!(
+						"value must contain `{}` after `{{{}}}`",
+						literal, name
+					))
+				})?
+		};
+		rest = &rest[position + literal.len()..];
+	}
+
+	match (placeholder, segments.last()) {
+		(Some(name), _) if rest.is_empty() => {
+			Err(Error::new(format!("`{{{}}}` must not be empty", name)))
+		}
+		(None, Some(TemplateSegment::Literal(literal))) if !rest.is_empty() => {
+			Err(Error::new(format!("value must end with `{}`", literal)))
+		}
+		_ => Ok(value),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_parse_template() {
+		assert_eq!(
+			parse_template("order-{id}").unwrap(),
+			[
+				TemplateSegment::Literal("order-".to_string()),
+				TemplateSegment::Placeholder("id"),
+			]
+		);
+		assert_eq!(
+			parse_template("{{{key}}}: {value}").unwrap(),
+			[
+				TemplateSegment::Literal("{".to_string()),
+				TemplateSegment::Placeholder("key"),
+				TemplateSegment::Literal("}: ".to_string()),
+				TemplateSegment::Placeholder("value"),
+			]
+		);
+		for template in ["{a}{b}", "{}", "order-{id", "order-}", "{a{b}}"] {
+			assert!(parse_template(template).is_err(), "{}", template);
+		}
+	}
+
+	#[test]
+	fn test_validate_template() {
+		let test_cases = [
+			("Hello, {name}!", "Hello, John!", true),
+			("Hello, {name}!", "Hello, !", false),
+			("Hello, {name}!", "Hi, John!", false),
+			("Hello, {name}!", "Hello, John", false),
+			("order-{id}", "order-123", true),
+			("order-{id}", "order-", false),
+			("order-{id}", "invoice-123", false),
+			("{user}@{domain}", "john@example.com", true),
+			("{user}@{domain}", "@example.com", false),
+			("{user}@{domain}", "john@", false),
+			("{a}-{b}-{c}", "1-2-3", true),
+			("{a}-{b}-{c}", "1-2-3-4", true),
+			("{a}-{b}-{c}", "1-2--3", true),
+			("{a}-{b}-{c}", "1--3", false),
+			("{a}-{b}-{c}", "1-2", false),
+			("{{{key}}}", "{name}", true),
+			("{{{key}}}", "name", false),
+			("v{major}.{minor}", "v1.ü", true),
+		];
+
+		for (template, input, expected) in test_cases {
+			assert_eq!(
+				validate_template(input, template).is_ok(),
+				expected,
+				"{} {}",
+				template,
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_template_error_messages() {
+		let test_cases = [
+			(
+				"Hello, {name}!",
+				"Hi, John!",
+				"value must start with `Hello, `",
+			),
+			("Hello, {name}!", "Hello, John", "value must end with `!`"),
+			("Hello, {name}!", "Hello, !", "value must end with `!`"),
+			("order-{id}", "order-", "`{id}` must not be empty"),
+			("{a}-{b}.{c}", "1-2", "value must contain `.` after `{b}`"),
+			("{a}/{b}", "/x", "value must contain `/` after `{a}`"),
+			("{a}", "", "`{a}` must not be empty"),
+		];
+
+		for (template, input, expected) in test_cases {
+			assert_eq!(
+				validate_template(input, template).unwrap_err().message,
+				expected,
+				"{} {}",
+				template,
+				input
+			);
+		}
+		assert!(validate_template("value", "{a}{b}")
+			.unwrap_err()
+			.message
+			.starts_with("invalid template"));
+	}
+}
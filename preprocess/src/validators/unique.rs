@@ -0,0 +1,158 @@
+use std::{collections::HashSet, fmt::Debug, hash::Hash};
+
+use crate::utils::Error;
+
+/// Validates that the given collection does not contain any duplicate items.
+/// The items are compared by hashing them, without cloning or consuming the
+/// collection. The collection needs to be iterable by reference, which is the
+/// case for [`Vec<T>`], [`VecDeque<T>`](std::collections::VecDeque),
+/// `[T; N]`, `Box<[T]>` and any custom collection that implements
+/// [`IntoIterator`] for a reference to itself.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreatePostRequest {
+///     #[preprocess(unique)]
+///     pub tags: Vec<String>,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_unique<C, T>(value: C) -> Result<C, Error>
+where
+	for<'a> &'a C: IntoIterator<Item = &'a T>,
+	T: Eq + Hash + Debug,
+{
+	let mut seen = HashSet::new();
+	if let Some(duplicate) =
+		(&value).into_iter().find(|item| !seen.insert(*item))
+	{
+		return Err(Error::new(format!(
+			"value contains duplicate item {:?}",
+			duplicate
+		)));
+	}
+
+	Ok(value)
+}
+
+/// Validates that the given collection does not contain any duplicate items.
+/// Unlike [`validate_unique`], the items are compared by sorting references to
+/// them, which only requires the items to implement [`Ord`] instead of
+/// [`Hash`].
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetPrioritiesRequest {
+///     #[preprocess(unique_sorted)]
+///     pub priorities: Vec<u32>,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_unique_sorted<C, T>(value: C) -> Result<C, Error>
+where
+	for<'a> &'a C: IntoIterator<Item = &'a T>,
+	T: Ord + Debug,
+{
+	let mut items = (&value).into_iter().collect::<Vec<_>>();
+	items.sort_unstable();
+	if let Some(duplicate) = items
+		.windows(2)
+		.find(|window| window[0] == window[1])
+		.map(|window| window[0])
+	{
+		return Err(Error::new(format!(
+			"value contains duplicate item {:?}",
+			duplicate
+		)));
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::VecDeque;
+
+	use super::*;
+	use crate::validators::HasLen;
+
+	#[test]
+	fn test_validate_unique() {
+		assert!(validate_unique(vec!["a", "b", "c"]).is_ok());
+		assert!(validate_unique(Vec::<String>::new()).is_ok());
+		assert!(validate_unique([1, 2, 3]).is_ok());
+		assert!(validate_unique([1, 2, 1]).is_err());
+		assert!(validate_unique(Box::<[_]>::from([1, 2, 1])).is_err());
+		assert!(validate_unique(VecDeque::from([1, 2, 3])).is_ok());
+		assert!(validate_unique(VecDeque::from([1, 2, 1])).is_err());
+	}
+
+	#[test]
+	fn test_validate_unique_custom_collection() {
+		struct Tags(Vec<String>);
+
+		impl HasLen for Tags {
+			fn length(&self) -> usize {
+				self.0.len()
+			}
+		}
+
+		impl<'a> IntoIterator for &'a Tags {
+			type Item = &'a String;
+			type IntoIter = std::slice::Iter<'a, String>;
+
+			fn into_iter(self) -> Self::IntoIter {
+				self.0.iter()
+			}
+		}
+
+		let tags = Tags(vec!["rust".to_string(), "web".to_string()]);
+		assert_eq!(validate_unique(tags).unwrap().length(), 2);
+		let tags = Tags(vec!["web".to_string(), "web".to_string()]);
+		assert!(validate_unique(tags).is_err());
+		let tags = Tags(vec!["web".to_string(), "rust".to_string()]);
+		assert!(validate_unique_sorted(tags).is_ok());
+	}
+
+	#[test]
+	fn test_validate_unique_names_first_duplicate() {
+		let tags = vec![
+			"rust".to_string(),
+			"web".to_string(),
+			"web".to_string(),
+			"rust".to_string(),
+		];
+		assert_eq!(
+			validate_unique(tags).unwrap_err().message,
+			r#"value contains duplicate item "web""#
+		);
+	}
+
+	#[test]
+	fn test_validate_unique_sorted() {
+		assert!(validate_unique_sorted(vec![3, 1, 2]).is_ok());
+		assert!(validate_unique_sorted(Vec::<u32>::new()).is_ok());
+		assert_eq!(
+			validate_unique_sorted(vec![3, 1, 2, 1])
+				.unwrap_err()
+				.message,
+			"value contains duplicate item 1"
+		);
+	}
+}
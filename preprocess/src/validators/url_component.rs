@@ -0,0 +1,428 @@
+use std::borrow::Cow;
+
+use url::Host;
+
+use crate::utils::Error;
+
+/// Checks that every character of the given URL component is either
+/// unreserved, a sub-delimiter, one of the `extra` characters, or part of a
+/// valid percent-encoded byte, as defined by RFC 3986. Anything else, like
+/// spaces, control characters or non-ASCII characters, has to be
+/// percent-encoded.
+fn check_component_chars(
+	value: &str,
+	component: &str,
+	extra: &str,
+) -> Result<(), Error> {
+	let mut chars = value.chars();
+	while let Some(ch) = chars.next() {
+		match ch {
+			'%' => {
+				let is_encoded = chars.next().zip(chars.next()).is_some_and(
+					|(high, low)| {
+						high.is_ascii_hexdigit() && low.is_ascii_hexdigit()
+					},
+				);
+				if !is_encoded {
+					return Err(Error::new(format!(
+						"URL {} contains an invalid percent-encoding",
+						component
+					)));
+				}
+			}
+			'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '.' | '_' | '~' => (),
+			'!' | '$' | '&' | '\'' | '(' | ')' | '*' | '+' | ',' | ';' |
+			'=' => (),
+			ch if extra.contains(ch) => (),
+			ch => {
+				return Err(Error::new(format!(
+					"URL {} must not contain the character {:?}",
+					component, ch
+				)));
+			}
+		}
+	}
+
+	Ok(())
+}
+
+/// Validates that the given string is the path of a URL, like
+/// `/api/v1/users`. The path must start with `/`, and can only contain the
+/// characters allowed in a URL path, with everything else percent-encoded. To
+/// prevent path traversal, `.` and `..` segments are rejected, even when they
+/// are percent-encoded, along with percent-encoded slashes. The value is not
+/// modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateRouteRequest {
+///     #[preprocess(url_component = "path")]
+///     pub path: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_url_path<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let path = value.clone().into();
+	if !path.starts_with('/') {
+		return Err(Error::new("URL path must start with `/`"));
+	}
+	check_component_chars(&path, "path", ":@/")?;
+
+	for segment in path.split('/') {
+		let segment = segment.to_ascii_lowercase();
+		if segment.contains("%2f") || segment.contains("%5c") {
+			return Err(Error::new(
+				"URL path must not contain percent-encoded slashes",
+			));
+		}
+		if matches!(segment.replace("%2e", ".").as_str(), "." | "..") {
+			return Err(Error::new(
+				"URL path must not contain `.` or `..` segments",
+			));
+		}
+	}
+
+	Ok(value)
+}
+
+/// Validates that the given string is the query of a URL, like
+/// `page=2&sort=name`, with or without the leading `?`. The query must be a
+/// list of `key=value` pairs (or just `key`) separated by `&`, where every key
+/// is non-empty, and can only contain the characters allowed in a URL query,
+/// with everything else percent-encoded. This rejects a `#`, which would start
+/// a fragment. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateRedirectRequest {
+///     #[preprocess(url_component = "query")]
+///     pub query: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_url_query<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let query = value.clone().into();
+	let query = query.strip_prefix('?').unwrap_or(&query);
+	check_component_chars(query, "query", ":@/?")?;
+
+	let is_valid = query.is_empty() ||
+		query.split('&').all(|pair| {
+			let key = pair.split_once('=').map_or(pair, |(key, _)| key);
+			!key.is_empty()
+		});
+	if !is_valid {
+		return Err(Error::new(
+			"URL query must be a list of `key=value` pairs separated by `&`",
+		));
+	}
+
+	Ok(value)
+}
+
+/// Validates that the given string is the fragment of a URL, like `section-2`,
+/// with or without the leading `#`. The fragment can only contain the
+/// characters allowed in a URL fragment, with everything else
+/// percent-encoded. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateAnchorRequest {
+///     #[preprocess(url_component = "fragment")]
+///     pub anchor: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_url_fragment<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let fragment = value.clone().into();
+	let fragment = fragment.strip_prefix('#').unwrap_or(&fragment);
+	check_component_chars(fragment, "fragment", ":@/?")?;
+
+	Ok(value)
+}
+
+/// Validates that the given string is the host of a URL, which can be a
+/// domain like `example.com`, an IPv4 address, or an IPv6 address in square
+/// brackets, like `[::1]`. Ports, credentials and paths are not allowed. The
+/// host is parsed using [`Host::parse`](::url::Host::parse). The value is not
+/// modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct AllowOriginRequest {
+///     #[preprocess(url_component = "host")]
+///     pub host: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_url_host<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let host = value.clone().into();
+	if host.is_empty() {
+		return Err(Error::new("URL host must not be empty"));
+	}
+	Host::parse(&host)
+		.map_err(|err| Error::with_source("invalid URL host", err))?;
+
+	Ok(value)
+}
+
+/// Validates that the given string is the scheme of a URL, like `https`,
+/// without the trailing `:`. The scheme must start with a letter, followed by
+/// letters, digits, `+`, `-` or `.`. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct RegisterProtocolRequest {
+///     #[preprocess(url_component = "scheme")]
+///     pub scheme: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_url_scheme_name<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let scheme = value.clone().into();
+	let mut chars = scheme.chars();
+	let is_valid = chars.next().is_some_and(|ch| ch.is_ascii_alphabetic()) &&
+		chars.all(|ch| {
+			ch.is_ascii_alphanumeric() || matches!(ch, '+' | '-' | '.')
+		});
+	if !is_valid {
+		return Err(Error::new(
+			"URL scheme must start with a letter, followed by letters, digits, \
+			 `+`, `-` or `.`",
+		));
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_url_path() {
+		let test_cases = [
+			("/", true),
+			("/api/v1/users", true),
+			("/users/john.doe/profile", true),
+			("/files/report%20final.pdf", true),
+			("/a:b@c/~user/(1)", true),
+			("/.well-known/openid-configuration", true),
+			("api/v1/users", false),
+			("", false),
+			("/users/john doe", false),
+			("/users?id=1", false),
+			("/users#profile", false),
+			("/users/<script>", false),
+			("/caf\u{e9}", false),
+			("/users\\admin", false),
+			("/users/%zz", false),
+			("/users/%2", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(validate_url_path(input).is_ok(), expected, "{}", input);
+		}
+	}
+
+	#[test]
+	fn test_validate_url_path_traversal() {
+		for input in [
+			"/../etc/passwd",
+			"/static/../../etc/passwd",
+			"/static/./index.html",
+			"/static/..",
+			"/static/%2e%2e/secret",
+			"/static/%2E%2E/secret",
+			"/static/.%2e/secret",
+			"/static/%2e/secret",
+		] {
+			assert_eq!(
+				validate_url_path(input).unwrap_err().message,
+				"URL path must not contain `.` or `..` segments",
+				"{}",
+				input
+			);
+		}
+		for input in ["/static/..%2fsecret", "/static/%2F..", "/a%5c..%5cb"] {
+			assert_eq!(
+				validate_url_path(input).unwrap_err().message,
+				"URL path must not contain percent-encoded slashes",
+				"{}",
+				input
+			);
+		}
+		assert_eq!(
+			validate_url_path("/users/john doe").unwrap_err().message,
+			"URL path must not contain the character ' '"
+		);
+		assert_eq!(
+			validate_url_path("/users\n/admin").unwrap_err().message,
+			"URL path must not contain the character '\\n'"
+		);
+	}
+
+	#[test]
+	fn test_validate_url_query() {
+		let test_cases = [
+			("", true),
+			("?", true),
+			("page=2", true),
+			("?page=2&sort=name", true),
+			("q=rust%20lang&verbose", true),
+			("redirect=/home?tab=1", true),
+			("filter=a:b@c", true),
+			("empty=", true),
+			("=value", false),
+			("a=1&&b=2", false),
+			("a=1&", false),
+			("a=1#fragment", false),
+			("a=1 2", false),
+			("a=1\r\nSet-Cookie: x=y", false),
+			("q=<script>", false),
+			("q=\"quoted\"", false),
+			("q=%G1", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_url_query(input).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+		assert_eq!(
+			validate_url_query("a=1#evil").unwrap_err().message,
+			"URL query must not contain the character '#'"
+		);
+	}
+
+	#[test]
+	fn test_validate_url_fragment() {
+		let test_cases = [
+			("", true),
+			("section-2", true),
+			("#section-2", true),
+			("/route?tab=1", true),
+			("heading%20one", true),
+			("##section", false),
+			("section two", false),
+			("<img>", false),
+			("%", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_url_fragment(input).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_url_host() {
+		let test_cases = [
+			("example.com", true),
+			("api.example.com", true),
+			("münchen.de", true),
+			("127.0.0.1", true),
+			("[::1]", true),
+			("localhost", true),
+			("", false),
+			("::1", false),
+			("example.com/path", false),
+			("user@example.com", false),
+			("exa mple.com", false),
+			("example.com#", false),
+			("[::1", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(validate_url_host(input).is_ok(), expected, "{}", input);
+		}
+	}
+
+	#[test]
+	fn test_validate_url_scheme_name() {
+		let test_cases = [
+			("https", true),
+			("http", true),
+			("git+ssh", true),
+			("coap+tcp", true),
+			("web-3.0", true),
+			("", false),
+			("https:", false),
+			("https://", false),
+			("1http", false),
+			("+git", false),
+			("ht tp", false),
+			("javascript:alert(1)", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_url_scheme_name(input).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+	}
+}
@@ -0,0 +1,138 @@
+use std::borrow::Cow;
+
+use uuid::Uuid;
+
+use crate::utils::Error;
+
+/// Validates that the given string is a valid UUID of any version, and returns
+/// the parsed [`Uuid`]. The hyphenated
+/// (`67e55044-10b1-426f-9247-bb680e5fe0c8`), simple (without hyphens), braced
+/// and URN forms are accepted, in any case.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct GetUserRequest {
+///     #[preprocess(uuid)]
+///     pub id: String, // This type will be changed to Uuid
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_uuid<'a, T>(value: T) -> Result<Uuid, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	Uuid::parse_str(&value.into())
+		.map_err(|err| Error::with_source("invalid UUID", err))
+}
+
+/// Validates that the given string is a valid UUID of the given version, like
+/// `4` for random UUIDs, and returns the parsed [`Uuid`]. The same forms as
+/// [`validate_uuid`] are accepted.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateSessionRequest {
+///     #[preprocess(uuid(version = 4))]
+///     pub nonce: String, // This type will be changed to Uuid
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_uuid_version<'a, T>(
+	value: T,
+	version: usize,
+) -> Result<Uuid, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let uuid = validate_uuid(value)?;
+
+	if uuid.get_version_num() != version {
+		return Err(Error::new(format!(
+			"UUID must be version {}, found version {}",
+			version,
+			uuid.get_version_num()
+		)));
+	}
+
+	Ok(uuid)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const V1: &str = "c232ab00-9414-11ec-b3c8-9e6bdeced846";
+	const V4: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+	const V5: &str = "886313e1-3b8a-5372-9b90-0c9aee199e5d";
+
+	#[test]
+	fn test_validate_uuid() {
+		let test_cases = [
+			V1,
+			V4,
+			"67E55044-10B1-426F-9247-BB680E5FE0C8",
+			"67e5504410b1426f9247bb680e5fe0c8",
+			"{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+			"urn:uuid:67e55044-10b1-426f-9247-bb680e5fe0c8",
+			"00000000-0000-0000-0000-000000000000",
+		];
+		for input in test_cases {
+			assert!(validate_uuid(input).is_ok(), "{}", input);
+		}
+		assert_eq!(
+			validate_uuid("67E55044-10B1-426F-9247-BB680E5FE0C8")
+				.unwrap()
+				.to_string(),
+			V4
+		);
+
+		let invalid = [
+			"",
+			"67e55044-10b1-426f-9247-bb680e5fe0c",
+			"67e55044-10b1-426f-9247-bb680e5fe0cg",
+			"67e55044_10b1_426f_9247_bb680e5fe0c8",
+		];
+		for input in invalid {
+			assert_eq!(
+				validate_uuid(input).unwrap_err().message,
+				"invalid UUID",
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_uuid_version() {
+		assert_eq!(validate_uuid_version(V1, 1).unwrap().to_string(), V1);
+		assert!(validate_uuid_version(V4, 4).is_ok());
+		assert!(validate_uuid_version(V5, 5).is_ok());
+		assert!(validate_uuid_version("not-a-uuid", 4).is_err());
+		assert_eq!(
+			validate_uuid_version(V1, 4).unwrap_err().message,
+			"UUID must be version 4, found version 1"
+		);
+		assert_eq!(
+			validate_uuid_version("00000000-0000-0000-0000-000000000000", 4)
+				.unwrap_err()
+				.message,
+			"UUID must be version 4, found version 0"
+		);
+	}
+}
@@ -0,0 +1,254 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Checks that the given part of a version is a dot-separated list of
+/// non-empty identifiers, made of ASCII letters, digits and hyphens.
+fn is_valid_identifier_list(value: &str) -> bool {
+	value.split('.').all(|identifier| {
+		!identifier.is_empty() &&
+			identifier
+				.chars()
+				.all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+	})
+}
+
+/// Validates that the given string is a version, like `1.2.3`, `v1.2.3` or
+/// `1.0.0-rc1+build.5`. The version must have three numeric parts
+/// (`MAJOR.MINOR.PATCH`) without leading zeros, optionally followed by a
+/// pre-release suffix starting with `-` and build metadata starting with `+`,
+/// each made of dot-separated identifiers of letters, digits and hyphens.
+/// Unlike [semver](https://semver.org), the version can start with a `v`, like
+/// git tags usually do, if `allow_v_prefix` is `true`. Pre-release suffixes and
+/// build metadata can be rejected using `allow_prerelease` and
+/// `allow_build_metadata`. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct PublishReleaseRequest {
+///     #[preprocess(version(allow_v_prefix = true, allow_prerelease = false))]
+///     pub tag: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_version<'a, T>(
+	value: T,
+	allow_v_prefix: bool,
+	allow_prerelease: bool,
+	allow_build_metadata: bool,
+) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let version = value.clone().into();
+	let version = match version.strip_prefix('v') {
+		Some(_) if !allow_v_prefix => {
+			return Err(Error::new("version must not start with `v`"));
+		}
+		Some(stripped) => stripped,
+		None => &version,
+	};
+
+	let (version, build_metadata) = match version.split_once('+') {
+		Some((version, build_metadata)) => (version, Some(build_metadata)),
+		None => (version, None),
+	};
+	let (core, prerelease) = match version.split_once('-') {
+		Some((core, prerelease)) => (core, Some(prerelease)),
+		None => (version, None),
+	};
+
+	let parts = core.split('.').collect::<Vec<_>>();
+	let is_numeric = parts.len() == 3 &&
+		parts.iter().all(|part| {
+			!part.is_empty() && part.chars().all(|ch| ch.is_ascii_digit())
+		});
+	if !is_numeric {
+		return Err(Error::new(
+			"version must be in the format `MAJOR.MINOR.PATCH`",
+		));
+	}
+	if parts
+		.iter()
+		.any(|part| part.len() > 1 && part.starts_with('0'))
+	{
+		return Err(Error::new("version numbers must not have leading zeros"));
+	}
+
+	if let Some(prerelease) = prerelease {
+		if !allow_prerelease {
+			return Err(Error::new(
+				"version must not have a pre-release suffix",
+			));
+		}
+		if !is_valid_identifier_list(prerelease) {
+			return Err(Error::new("invalid pre-release suffix in version"));
+		}
+	}
+	if let Some(build_metadata) = build_metadata {
+		if !allow_build_metadata {
+			return Err(Error::new("version must not have build metadata"));
+		}
+		if !is_valid_identifier_list(build_metadata) {
+			return Err(Error::new("invalid build metadata in version"));
+		}
+	}
+
+	Ok(value)
+}
+
+/// Validates that the given string is a version, like [`validate_version`]
+/// with `allow_v_prefix` set to `true`, and strips the `v` prefix from it, so
+/// that `v1.2.3` becomes `1.2.3`. Values without a prefix are returned as-is.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct PublishReleaseRequest {
+///     #[preprocess(version(normalize = true))]
+///     pub version: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_version_normalized<'a, T>(
+	value: T,
+	allow_prerelease: bool,
+	allow_build_metadata: bool,
+) -> Result<Cow<'a, str>, Error>
+where
+	T: Into<Cow<'a, str>>,
+{
+	let version = validate_version(
+		value.into(),
+		true,
+		allow_prerelease,
+		allow_build_metadata,
+	)?;
+
+	Ok(match version {
+		Cow::Borrowed(version) => {
+			Cow::Borrowed(version.strip_prefix('v').unwrap_or(version))
+		}
+		Cow::Owned(version) => match version.strip_prefix('v') {
+			Some(stripped) => Cow::Owned(stripped.to_string()),
+			None => Cow::Owned(version),
+		},
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_version() {
+		let test_cases = [
+			("1.2.3", true),
+			("0.0.0", true),
+			("v1.2.3", true),
+			("10.20.30", true),
+			("1.0.0-rc1", true),
+			("1.0.0-alpha.1", true),
+			("1.0.0-x-y-z.0", true),
+			("1.0.0+build.5", true),
+			("v1.0.0-beta+exp.sha.5114f85", true),
+			("", false),
+			("v", false),
+			("1", false),
+			("1.2", false),
+			("1.2.3.4", false),
+			("1..3", false),
+			("01.2.3", false),
+			("1.02.3", false),
+			("V1.2.3", false),
+			("vv1.2.3", false),
+			(" 1.2.3", false),
+			("1.2.3 ", false),
+			("1.2.a", false),
+			("1.2.3-", false),
+			("1.2.3-rc..1", false),
+			("1.2.3-rc_1", false),
+			("1.2.3+", false),
+			("1.2.3+build+5", false),
+			("-1.2.3", false),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_version(input, true, true, true).is_ok(),
+				expected,
+				"{}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_version_options() {
+		assert_eq!(
+			validate_version("v1.2.3", false, true, true)
+				.unwrap_err()
+				.message,
+			"version must not start with `v`"
+		);
+		assert_eq!(
+			validate_version("1.0.0-rc1", true, false, true)
+				.unwrap_err()
+				.message,
+			"version must not have a pre-release suffix"
+		);
+		assert_eq!(
+			validate_version("1.0.0+build.5", true, true, false)
+				.unwrap_err()
+				.message,
+			"version must not have build metadata"
+		);
+		assert_eq!(
+			validate_version("1.2", true, true, true)
+				.unwrap_err()
+				.message,
+			"version must be in the format `MAJOR.MINOR.PATCH`"
+		);
+		assert!(validate_version("1.2.3", false, false, false).is_ok());
+	}
+
+	#[test]
+	fn test_validate_version_normalized() {
+		let test_cases = [
+			("v1.2.3", "1.2.3"),
+			("1.2.3", "1.2.3"),
+			("v1.0.0-rc1+build.5", "1.0.0-rc1+build.5"),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_version_normalized(input, true, true),
+				Ok(expected.into())
+			);
+			assert_eq!(
+				validate_version_normalized(input.to_string(), true, true),
+				Ok(expected.into())
+			);
+		}
+		assert!(matches!(
+			validate_version_normalized("v1.2.3", true, true),
+			Ok(Cow::Borrowed("1.2.3"))
+		));
+		assert!(validate_version_normalized("v1.0.0-rc1", false, true).is_err());
+	}
+}
@@ -0,0 +1,211 @@
+use std::fmt::Debug;
+
+use crate::utils::Error;
+
+/// Validates that the given value is one of the allowed values. The values are
+/// compared using [`PartialEq`], so this works with any type, like strings,
+/// numbers or enums. The allowed values can be of a different type than the
+/// value, as long as they can be compared, like `&str` values for a [`String`]
+/// field.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetRegionRequest {
+///     #[preprocess(whitelist = ["us-east-1", "eu-west-1"])]
+///     pub region: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_whitelist<T, U>(value: T, allowed: &[U]) -> Result<T, Error>
+where
+	T: PartialEq<U>,
+	U: Debug,
+{
+	if allowed.iter().any(|item| value == *item) {
+		Ok(value)
+	} else {
+		Err(Error::new(format!("value must be one of {:?}", allowed)))
+	}
+}
+
+/// Validates that the given value is not one of the disallowed values. This is
+/// the opposite of [`validate_whitelist`].
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateUserRequest {
+///     #[preprocess(trim, lowercase, blacklist = ["admin", "root"])]
+///     pub username: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_blacklist<T, U>(value: T, disallowed: &[U]) -> Result<T, Error>
+where
+	T: PartialEq<U>,
+	U: Debug,
+{
+	if disallowed.iter().any(|item| value == *item) {
+		Err(Error::new(format!(
+			"value must not be one of {:?}",
+			disallowed
+		)))
+	} else {
+		Ok(value)
+	}
+}
+
+/// Validates that the given value is equal to the expected value. The values
+/// are compared using [`PartialEq`], so the expected value can be of a
+/// different type than the value, as long as they can be compared, like a
+/// `&str` for a [`String`] field. The expected value is not included in the
+/// error message, since it might be sensitive.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct DeleteAccountRequest {
+///     #[preprocess(equal = "DELETE")]
+///     pub confirmation: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_equal<T, U>(value: T, expected: &U) -> Result<T, Error>
+where
+	T: PartialEq<U>,
+	U: ?Sized,
+{
+	if value == *expected {
+		Ok(value)
+	} else {
+		Err(Error::new("value must be equal to the expected value"))
+	}
+}
+
+/// Validates that the given value is not equal to the forbidden value. This is
+/// the opposite of [`validate_equal`], and works like [`validate_blacklist`]
+/// with a single item, except that the forbidden value is not included in the
+/// error message, since it might be sensitive.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateUserRequest {
+///     #[preprocess(trim, lowercase, not_equal = "admin")]
+///     pub username: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_not_equal<T, U>(value: T, forbidden: &U) -> Result<T, Error>
+where
+	T: PartialEq<U>,
+	U: ?Sized,
+{
+	if value == *forbidden {
+		Err(Error::new("value must not be equal to the forbidden value"))
+	} else {
+		Ok(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::borrow::Cow;
+
+	use super::*;
+
+	#[derive(Debug, PartialEq)]
+	enum Plan {
+		Free,
+		Pro,
+		Enterprise,
+	}
+
+	#[test]
+	fn test_validate_whitelist() {
+		assert!(validate_whitelist("a".to_string(), &["a", "b"]).is_ok());
+		assert!(validate_whitelist(Cow::Borrowed("b"), &["a", "b"]).is_ok());
+		assert!(validate_whitelist(2, &[1, 2, 3]).is_ok());
+		assert!(validate_whitelist(Plan::Pro, &[Plan::Free, Plan::Pro]).is_ok());
+		assert_eq!(
+			validate_whitelist("c".to_string(), &["a", "b"])
+				.unwrap_err()
+				.message,
+			r#"value must be one of ["a", "b"]"#
+		);
+		assert!(validate_whitelist(Plan::Enterprise, &[Plan::Free]).is_err());
+		assert!(validate_whitelist(1, &[] as &[i32]).is_err());
+	}
+
+	#[test]
+	fn test_validate_blacklist() {
+		assert!(validate_blacklist("user".to_string(), &["admin"]).is_ok());
+		assert!(validate_blacklist(4, &[1, 2, 3]).is_ok());
+		assert!(validate_blacklist(1, &[] as &[i32]).is_ok());
+		assert_eq!(
+			validate_blacklist(2, &[1, 2, 3]).unwrap_err().message,
+			"value must not be one of [1, 2, 3]"
+		);
+		assert!(validate_blacklist(Plan::Free, &[Plan::Free]).is_err());
+	}
+
+	#[test]
+	fn test_validate_equal() {
+		assert!(validate_equal("DELETE".to_string(), &"DELETE").is_ok());
+		assert!(validate_equal("DELETE".to_string(), "DELETE").is_ok());
+		assert!(validate_equal(Cow::Borrowed("yes"), &"yes").is_ok());
+		assert!(validate_equal(42, &42).is_ok());
+		assert!(validate_equal(Plan::Pro, &Plan::Pro).is_ok());
+		assert_eq!(
+			validate_equal("delete".to_string(), &"DELETE")
+				.unwrap_err()
+				.message,
+			"value must be equal to the expected value"
+		);
+		assert!(validate_equal(Plan::Free, &Plan::Pro).is_err());
+	}
+
+	#[test]
+	fn test_validate_not_equal() {
+		assert!(validate_not_equal("user".to_string(), &"admin").is_ok());
+		assert!(validate_not_equal(1, &0).is_ok());
+		assert!(validate_not_equal(Plan::Pro, &Plan::Free).is_ok());
+		assert_eq!(
+			validate_not_equal("admin".to_string(), "admin")
+				.unwrap_err()
+				.message,
+			"value must not be equal to the forbidden value"
+		);
+		assert!(validate_not_equal(0, &0).is_err());
+		assert!(validate_not_equal(Plan::Free, &Plan::Free).is_err());
+	}
+}
@@ -0,0 +1,197 @@
+use std::borrow::Cow;
+
+use crate::utils::Error;
+
+/// Returns a readable name for the given whitespace character, to be used in
+/// error messages.
+fn whitespace_name(ch: char) -> String {
+	match ch {
+		' ' => "space".to_string(),
+		'\t' => "tab".to_string(),
+		'\n' => "newline".to_string(),
+		'\r' => "carriage return".to_string(),
+		ch => format!("`U+{:04X}`", ch as u32),
+	}
+}
+
+/// Validates that the given string does not contain any whitespace characters.
+/// Whitespaces are matched using [`char::is_whitespace`], so Unicode
+/// whitespaces like the non-breaking space (`U+00A0`) are rejected as well.
+/// The error message contains the first whitespace character found and its
+/// position, counted in characters from zero. The value is not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateUserRequest {
+///     #[preprocess(no_whitespace, length(min = 3))]
+///     pub username: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_no_whitespace<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let val = value.clone().into();
+	match val.chars().enumerate().find(|(_, ch)| ch.is_whitespace()) {
+		Some((position, ch)) => Err(Error::new(format!(
+			"value must not contain whitespace, found {} at position {}",
+			whitespace_name(ch),
+			position
+		))),
+		None => Ok(value),
+	}
+}
+
+/// Validates that the given string does not start with a whitespace
+/// character. Whitespaces in the rest of the string are allowed. The value is
+/// not modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreatePostRequest {
+///     #[preprocess(no_leading_whitespace, no_trailing_whitespace)]
+///     pub title: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_no_leading_whitespace<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let val = value.clone().into();
+	match val.chars().next() {
+		Some(ch) if ch.is_whitespace() => Err(Error::new(format!(
+			"value must not start with whitespace, found {}",
+			whitespace_name(ch)
+		))),
+		_ => Ok(value),
+	}
+}
+
+/// Validates that the given string does not end with a whitespace character.
+/// Whitespaces in the rest of the string are allowed. The value is not
+/// modified.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreatePostRequest {
+///     #[preprocess(no_trailing_whitespace)]
+///     pub body: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_no_trailing_whitespace<'a, T>(value: T) -> Result<T, Error>
+where
+	T: Into<Cow<'a, str>> + Clone,
+{
+	let val = value.clone().into();
+	match val.chars().next_back() {
+		Some(ch) if ch.is_whitespace() => Err(Error::new(format!(
+			"value must not end with whitespace, found {}",
+			whitespace_name(ch)
+		))),
+		_ => Ok(value),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_no_whitespace() {
+		let test_cases = [
+			("username", true),
+			("user_name-1", true),
+			("", true),
+			("user name", false),
+			("user\tname", false),
+			("username\n", false),
+			(" username", false),
+			("user\u{00A0}name", false),
+			("user\u{2003}name", false),
+			("user\u{3000}name", false),
+			("user\u{200B}name", true),
+		];
+
+		for (input, expected) in test_cases {
+			assert_eq!(
+				validate_no_whitespace(input).is_ok(),
+				expected,
+				"{:?}",
+				input
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_no_whitespace_reports_position() {
+		assert_eq!(
+			validate_no_whitespace("ab cd").unwrap_err().message,
+			"value must not contain whitespace, found space at position 2"
+		);
+		assert_eq!(
+			validate_no_whitespace("日本\u{00A0}語")
+				.unwrap_err()
+				.message,
+			"value must not contain whitespace, found `U+00A0` at position 2"
+		);
+		assert_eq!(
+			validate_no_whitespace("a\tb c").unwrap_err().message,
+			"value must not contain whitespace, found tab at position 1"
+		);
+	}
+
+	#[test]
+	fn test_validate_no_leading_whitespace() {
+		assert!(validate_no_leading_whitespace("hello world ").is_ok());
+		assert!(validate_no_leading_whitespace("").is_ok());
+		assert!(validate_no_leading_whitespace(" hello").is_err());
+		assert!(validate_no_leading_whitespace("\u{00A0}hello").is_err());
+		assert_eq!(
+			validate_no_leading_whitespace("\u{2028}hello")
+				.unwrap_err()
+				.message,
+			"value must not start with whitespace, found `U+2028`"
+		);
+	}
+
+	#[test]
+	fn test_validate_no_trailing_whitespace() {
+		assert!(validate_no_trailing_whitespace(" hello world").is_ok());
+		assert!(validate_no_trailing_whitespace("").is_ok());
+		assert!(validate_no_trailing_whitespace("hello\n").is_err());
+		assert!(validate_no_trailing_whitespace("hello\u{3000}").is_err());
+		assert_eq!(
+			validate_no_trailing_whitespace("hello\r")
+				.unwrap_err()
+				.message,
+			"value must not end with whitespace, found carriage return"
+		);
+	}
+}
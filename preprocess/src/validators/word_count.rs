@@ -0,0 +1,90 @@
+use crate::utils::Error;
+
+/// Validates the number of words in the given string. Words are separated by
+/// any amount of whitespace, so leading, trailing and repeated whitespaces do
+/// not count as words. The `min` and `max` parameters are optional and will
+/// only be validated if they are not `None`.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct CreateReviewRequest {
+///     #[preprocess(min_words = 5, max_words = 200)]
+///     pub review: String,
+///     #[preprocess(word_count(max = 10))]
+///     pub title: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_word_count<T>(
+	value: T,
+	min: Option<usize>,
+	max: Option<usize>,
+) -> Result<T, Error>
+where
+	T: AsRef<str>,
+{
+	let word_count = value.as_ref().split_whitespace().count();
+
+	if let Some(min) = min {
+		if word_count < min {
+			return Err(Error::new(format!(
+				"value must have at least {} words",
+				min
+			)));
+		}
+	}
+
+	if let Some(max) = max {
+		if word_count > max {
+			return Err(Error::new(format!(
+				"value must have at most {} words",
+				max
+			)));
+		}
+	}
+
+	Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_word_count() {
+		assert_eq!(
+			validate_word_count("the quick brown fox", Some(4), Some(4)),
+			Ok("the quick brown fox")
+		);
+		assert!(
+			validate_word_count("  lots   of\tspace\n", Some(3), None).is_ok()
+		);
+		assert!(validate_word_count("too short", Some(3), None).is_err());
+		assert!(validate_word_count(
+			String::from("one two three"),
+			None,
+			Some(2)
+		)
+		.is_err());
+		assert!(validate_word_count("", None, Some(0)).is_ok());
+		assert!(validate_word_count("   ", Some(1), None).is_err());
+	}
+
+	#[test]
+	fn test_validate_word_count_message() {
+		assert_eq!(
+			validate_word_count("one", Some(5), None)
+				.unwrap_err()
+				.message,
+			"value must have at least 5 words"
+		);
+	}
+}
@@ -0,0 +1,134 @@
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::utils::Error;
+
+/// The list of country codes supported by the [`validate_zip_code`]
+/// validator. `UK` is accepted as an alias of `GB`.
+pub const SUPPORTED_ZIP_CODE_COUNTRIES: &[&str] =
+	&["US", "GB", "UK", "DE", "CA", "FR"];
+
+static US_REGEX: OnceLock<Regex> = OnceLock::new();
+static GB_REGEX: OnceLock<Regex> = OnceLock::new();
+static CA_REGEX: OnceLock<Regex> = OnceLock::new();
+static FIVE_DIGIT_REGEX: OnceLock<Regex> = OnceLock::new();
+
+/// Returns the regex used to validate postal codes of the given country, or
+/// [`None`] if the country is not supported.
+fn zip_code_regex(country: &str) -> Option<&'static Regex> {
+	let (cell, pattern) = match country {
+		// 5 digits, optionally followed by a hyphen and 4 more digits (ZIP+4)
+		"US" => (&US_REGEX, r"^\d{5}(-\d{4})?$"),
+		// Outward code (area and district), followed by the inward code
+		// (sector and unit), optionally separated by a space
+		"GB" | "UK" => (
+			&GB_REGEX,
+			r"(?i)^(GIR ?0AA|[A-Z]{1,2}\d[A-Z\d]? ?\d[A-Z]{2})$",
+		),
+		// Alternating letters and digits (A1A 1A1), optionally separated by a
+		// space. Some letters are never used, to avoid confusion with digits.
+		"CA" => (
+			&CA_REGEX,
+			r"(?i)^[ABCEGHJ-NPRSTVXY]\d[ABCEGHJ-NPRSTV-Z] ?\d[ABCEGHJ-NPRSTV-Z]\d$",
+		),
+		"DE" | "FR" => (&FIVE_DIGIT_REGEX, r"^\d{5}$"),
+		_ => return None,
+	};
+
+	Some(cell.get_or_init(|| {
+		Regex::new(pattern).expect("zip code regex should be valid")
+	}))
+}
+
+/// Validates that the given string is a valid postal code for the given
+/// country. The country is given as an ISO 3166-1 alpha-2 code, and must be
+/// one of [`SUPPORTED_ZIP_CODE_COUNTRIES`]. Letters are matched
+/// case-insensitively, and the space between the two parts of UK and Canadian
+/// postal codes is optional, so that values normalized using
+/// [`preprocess_normalize_zip_code`](crate::preprocessors::preprocess_normalize_zip_code)
+/// are accepted as well.
+///
+/// # Example
+/// ```rust
+/// use preprocess::prelude::*;
+///
+/// #[preprocess::sync]
+/// #[derive(Debug, Deserialize, Serialize)]
+/// pub struct SetAddressRequest {
+///     #[preprocess(trim, zip_code(country = "US"))]
+///     pub zip_code: String,
+/// }
+/// ```
+#[must_use = concat!(
+	"validation returns a new value instead of mutating the input.",
+	" The returned value will contain the validated value,",
+	" while the input will remain unchanged"
+)]
+pub fn validate_zip_code<T>(value: T, country: &str) -> Result<T, Error>
+where
+	T: AsRef<str>,
+{
+	let regex = zip_code_regex(country).ok_or_else(|| {
+		Error::new(format!("unsupported country `{}`", country))
+	})?;
+
+	if regex.is_match(value.as_ref()) {
+		Ok(value)
+	} else {
+		Err(Error::new(format!("invalid postal code for {}", country)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_validate_zip_code() {
+		let test_cases = [
+			("12345", "US", true),
+			("12345-6789", "US", true),
+			("1234", "US", false),
+			("123456789", "US", false),
+			("SW1A 1AA", "GB", true),
+			("sw1a1aa", "UK", true),
+			("M1 1AE", "GB", true),
+			("GIR 0AA", "GB", true),
+			("SW1A 1A", "GB", false),
+			("10115", "DE", true),
+			("1011", "DE", false),
+			("K1A 0B1", "CA", true),
+			("k1a0b1", "CA", true),
+			("D1A 0B1", "CA", false),
+			("K1A-0B1", "CA", false),
+			("75008", "FR", true),
+			("7500A", "FR", false),
+		];
+
+		for (input, country, expected) in test_cases {
+			assert_eq!(
+				validate_zip_code(input, country).is_ok(),
+				expected,
+				"{} ({})",
+				input,
+				country
+			);
+		}
+	}
+
+	#[test]
+	fn test_validate_zip_code_unsupported_country() {
+		assert_eq!(
+			validate_zip_code("12345", "XX").unwrap_err().message,
+			"unsupported country `XX`"
+		);
+	}
+
+	#[test]
+	fn test_supported_countries_have_regex() {
+		for country in SUPPORTED_ZIP_CODE_COUNTRIES {
+			assert!(zip_code_regex(country).is_some(), "{}", country);
+		}
+	}
+}